@@ -0,0 +1,163 @@
+use crate::codegen::context::Context;
+use crate::codegen::output::{OverwritePolicy, Writer};
+use crate::codegen::rs_serde::RsSerde;
+use crate::codegen::{load_codegen, Codegen};
+use std::path::PathBuf;
+
+/// Generate code from specs in build script, e.g:
+///
+/// ```no_run
+/// // build.rs
+/// tot_spec::Builder::new()
+///     .input("specs")
+///     .codegen("rs_serde")
+///     .compile()
+///     .unwrap();
+///
+/// // lib.rs
+/// // include!(concat!(env!("OUT_DIR"), "/mod.rs"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Builder {
+    input: Option<PathBuf>,
+    codegen: String,
+    out_dir: Option<PathBuf>,
+    emit_rerun_if_changed: bool,
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            input: None,
+            codegen: "rs_serde".to_string(),
+            out_dir: None,
+            emit_rerun_if_changed: true,
+        }
+    }
+}
+
+impl Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// root folder of specs
+    pub fn input(mut self, input: impl Into<PathBuf>) -> Self {
+        self.input = Some(input.into());
+        self
+    }
+
+    /// codegen name, default `rs_serde`
+    pub fn codegen(mut self, codegen: impl Into<String>) -> Self {
+        self.codegen = codegen.into();
+        self
+    }
+
+    /// output folder, default `OUT_DIR`
+    pub fn out_dir(mut self, out_dir: impl Into<PathBuf>) -> Self {
+        self.out_dir = Some(out_dir.into());
+        self
+    }
+
+    /// whether to print `cargo:rerun-if-changed` for input folder, specs and spec_config.yaml,
+    /// default true
+    pub fn emit_rerun_if_changed(mut self, emit: bool) -> Self {
+        self.emit_rerun_if_changed = emit;
+        self
+    }
+
+    /// generate code into out dir
+    pub fn compile(&self) -> anyhow::Result<()> {
+        let input = self
+            .input
+            .clone()
+            .ok_or_else(|| anyhow::anyhow!("input is required"))?;
+        if self.emit_rerun_if_changed {
+            // the folder is watched first, so new specs and fixes of invalid specs trigger rebuild
+            println!("cargo:rerun-if-changed={}", input.display());
+        }
+
+        let watched = self.generate(&input)?;
+        if self.emit_rerun_if_changed {
+            for path in watched {
+                println!("cargo:rerun-if-changed={}", path.display());
+            }
+        }
+        Ok(())
+    }
+
+    /// generate code, returns specs and spec_config.yaml used
+    fn generate(&self, input: &PathBuf) -> anyhow::Result<Vec<PathBuf>> {
+        let out_dir = match &self.out_dir {
+            Some(out_dir) => out_dir.clone(),
+            None => std::env::var_os("OUT_DIR")
+                .map(PathBuf::from)
+                .ok_or_else(|| anyhow::anyhow!("OUT_DIR is not set, call out_dir() instead"))?,
+        };
+
+        let context = Context::new_from_folder(input)?;
+        let mut watched = context
+            .iter_specs()
+            .map(|(spec, _)| context.root_folder().join(spec))
+            .collect::<Vec<_>>();
+        let config = context.root_folder().join("spec_config.yaml");
+        if config.exists() {
+            watched.push(config);
+        }
+
+        let codegen: Box<dyn Codegen> = match self.codegen.as_str() {
+            "rs_serde" => Box::new(RsSerde::load_from_context(&context)?.include_out_dir()),
+            name => load_codegen(name, &context)?,
+        };
+        let tree = codegen.generate()?;
+        for diagnostic in tree.diagnostics.iter() {
+            println!(
+                "cargo:warning={}: {}",
+                diagnostic.spec.display(),
+                diagnostic.message
+            );
+        }
+
+        // unchanged files are kept, so cargo does not rebuild for them
+        Writer::new(out_dir)
+            .overwrite(OverwritePolicy::IfChanged)
+            .log(false)
+            .write(&tree)?;
+        Ok(watched)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder() {
+        let out_dir = std::env::temp_dir().join(format!("tot_spec_builder_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&out_dir);
+
+        let input = PathBuf::from("src/codegen/fixtures/specs");
+        let watched = Builder::new()
+            .input(&input)
+            .out_dir(&out_dir)
+            .generate(&input)
+            .unwrap();
+        assert!(watched
+            .iter()
+            .any(|p| p.ends_with("specs/simple_struct.yaml")));
+        assert!(watched
+            .iter()
+            .any(|p| p.ends_with("specs/ignore_styles/ignore_style.yaml")));
+
+        let mod_rs = std::fs::read_to_string(out_dir.join("mod.rs")).unwrap();
+        assert!(mod_rs.contains(
+            r#"pub mod simple_struct { include!(concat!(env!("OUT_DIR"), "/simple_struct.rs")); }"#
+        ));
+        assert!(mod_rs.contains(
+            r#"pub mod ignore_styles { include!(concat!(env!("OUT_DIR"), "/ignore_styles/mod.rs")); }"#
+        ));
+        assert!(out_dir.join("ignore_styles/ignore_style.rs").exists());
+
+        std::fs::remove_dir_all(&out_dir).unwrap();
+    }
+}
@@ -1,13 +1,20 @@
 use crate::codegen::style::Style;
 use crate::codegen::utils::folder_tree::FolderTree;
-use crate::{Definition, ModelDef, ModelType, Type, TypeReference};
+use crate::diagnostic::{Diagnostic, Diagnostics, Location};
+use crate::source_map::SourceMap;
+use crate::{
+    ConstType, Constraints, Definition, EnumRepresentation, FieldDef, ModelDef, ModelType,
+    StringOrInteger, TimestampFormat, Type, TypeReference, VariantDef,
+};
 use anyhow::anyhow;
 use indexmap::IndexMap;
 use path_absolutize::Absolutize;
+use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
 
 /// Context stores use info for a codegen pass
+#[derive(Clone)]
 pub struct Context {
     /// All loaded definitions
     definitions: IndexMap<PathBuf, Definition>,
@@ -18,10 +25,94 @@ pub struct Context {
 
     /// optional style config
     style: Option<Style>,
+
+    /// output targets from spec_config.yaml
+    targets: Vec<Target>,
+}
+
+/// An output target declared in spec_config.yaml's `targets`
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+pub struct Target {
+    /// codegen name, e.g: `rs_serde`
+    pub codegen: String,
+    /// output folder, relative path is relative to spec folder
+    pub output: PathBuf,
 }
 
 impl Context {
     pub fn new_from_folder(folder: &PathBuf) -> anyhow::Result<Self> {
+        let context = Self::new_from_folder_unvalidated(folder)?;
+        let violations = context.validate();
+        if !violations.is_empty() {
+            return Err(Diagnostics(violations).into());
+        }
+
+        Ok(context)
+    }
+
+    /// reload changed, added or deleted specs and keep others, paths not in root folder are ignored.
+    /// everything is reloaded if spec_config.yaml changed
+    pub fn reload(&self, changed: &[PathBuf]) -> anyhow::Result<Self> {
+        let folder = &self.root_folder;
+        let changed = changed
+            .iter()
+            .filter_map(|path| {
+                let path = path.absolutize().ok()?;
+                path.strip_prefix(folder).ok().map(Path::to_path_buf)
+            })
+            .filter(|path| {
+                path.extension()
+                    .map(|ext| ext == "yaml")
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>();
+        if changed.iter().any(|path| !is_spec_file(path)) {
+            return Self::new_from_folder(folder);
+        }
+
+        let mut definitions = self.definitions.clone();
+        let mut parse_errors = vec![];
+        for relative_path in changed {
+            let spec = folder.join(&relative_path);
+            if !spec.is_file() {
+                definitions.shift_remove(&relative_path);
+                continue;
+            }
+            match Definition::load_from_yaml(relative_to_current_dir(&spec)) {
+                Ok(def) => {
+                    definitions.insert(relative_path, def);
+                }
+                Err(e) => match e.downcast::<Diagnostic>() {
+                    Ok(diagnostic) => parse_errors.push(diagnostic),
+                    Err(e) => return Err(e),
+                },
+            }
+        }
+        if !parse_errors.is_empty() {
+            return Err(Diagnostics(parse_errors).into());
+        }
+        // same order as walking the folder
+        definitions.sort_keys();
+
+        let mut folder_tree = FolderTree::new();
+        definitions.keys().for_each(|spec| folder_tree.insert(spec));
+
+        let context = Self {
+            definitions,
+            folder_tree,
+            root_folder: folder.clone(),
+            style: self.style.clone(),
+            targets: self.targets.clone(),
+        };
+        let violations = context.validate();
+        if !violations.is_empty() {
+            return Err(Diagnostics(violations).into());
+        }
+        Ok(context)
+    }
+
+    /// load all specs in folder without validation
+    fn new_from_folder_unvalidated(folder: &PathBuf) -> anyhow::Result<Self> {
         let folder = folder.absolutize().unwrap().as_ref().to_path_buf();
         let folder = &folder;
 
@@ -36,59 +127,57 @@ impl Context {
             }
             style
         };
+        let targets = match config_value.as_ref().and_then(|c| c.get("targets")) {
+            Some(targets_value) => {
+                let targets = serde_json::from_value::<Vec<Target>>(targets_value.clone())
+                    .map_err(|e| anyhow!("invalid targets in spec_config.yaml: {e}"))?;
+                targets
+                    .into_iter()
+                    .map(|target| Target {
+                        output: folder.join(&target.output).absolutize().unwrap().into(),
+                        ..target
+                    })
+                    .collect()
+            }
+            None => vec![],
+        };
 
         let mut definitions = IndexMap::new();
         let mut spec_folder = FolderTree::new();
+        let mut parse_errors = vec![];
 
         for entry in WalkDir::new(folder).sort_by_file_name() {
             let entry = entry.unwrap();
             let spec = entry.path();
 
-            if !spec.is_file() {
-                continue;
-            }
-            if !spec
-                .extension()
-                .map(|ext| ext == "yaml")
-                .unwrap_or_default()
-            {
-                continue;
-            }
-
-            if spec
-                .file_stem()
-                .map(|s| s.to_string_lossy().eq("spec_config"))
-                .unwrap_or_default()
-            {
-                // skip spec config file
+            if !spec.is_file() || !is_spec_file(spec) {
                 continue;
             }
 
             let relative_path = spec.strip_prefix(folder).unwrap();
             spec_folder.insert(relative_path);
 
-            let def = Definition::load_from_yaml(&spec)?;
-            definitions.insert(relative_path.to_owned(), def);
+            // parse errors of all specs are reported together
+            match Definition::load_from_yaml(relative_to_current_dir(spec)) {
+                Ok(def) => {
+                    definitions.insert(relative_path.to_owned(), def);
+                }
+                Err(e) => match e.downcast::<Diagnostic>() {
+                    Ok(diagnostic) => parse_errors.push(diagnostic),
+                    Err(e) => return Err(e),
+                },
+            }
         }
-        let context = Self {
+        if !parse_errors.is_empty() {
+            return Err(Diagnostics(parse_errors).into());
+        }
+        Ok(Self {
             definitions,
             folder_tree: spec_folder,
             root_folder: folder.clone(),
             style,
-        };
-
-        // validate
-        let mut violations = context.validate_style();
-        violations.extend(context.validate_examples());
-
-        if !violations.is_empty() {
-            for violation in violations {
-                println!("{violation}");
-            }
-            anyhow::bail!("validation failed");
-        }
-
-        Ok(context)
+            targets,
+        })
     }
 
     /// get a ref to spec's root folder
@@ -96,6 +185,11 @@ impl Context {
         &self.root_folder
     }
 
+    /// output targets declared in spec_config.yaml, outputs are absolute
+    pub fn targets(&self) -> &[Target] {
+        &self.targets
+    }
+
     /// get a ref to the `FolderTree`
     pub fn folder_tree(&self) -> &FolderTree {
         &self.folder_tree
@@ -159,6 +253,23 @@ impl Context {
         .ok_or_else(|| anyhow!("model {:?} not find", type_ref))
     }
 
+    /// whether ty references a const model with string values
+    pub fn is_string_const(&self, ty: &Type, spec_path: &Path) -> bool {
+        let Type::Reference(type_ref) = ty else {
+            return false;
+        };
+        self.get_model_def_for_reference(type_ref, spec_path)
+            .map_or(false, |m| {
+                matches!(
+                    m.type_,
+                    ModelType::Const {
+                        value_type: ConstType::String,
+                        ..
+                    }
+                )
+            })
+    }
+
     /// get an iterator for all specs
     pub fn iter_specs(&self) -> impl Iterator<Item = (&PathBuf, &Definition)> {
         self.definitions.iter()
@@ -192,8 +303,373 @@ impl Context {
         pathdiff::diff_paths(path, &self.root_folder).unwrap()
     }
 
+    /// validate all definitions, return all violations
+    fn validate(&self) -> Vec<Diagnostic> {
+        let mut violations = self.validate_includes();
+        violations.extend(self.validate_references());
+        violations.extend(self.validate_names());
+        violations.extend(self.validate_extends());
+        // examples are only checked when all references resolve
+        let references_resolved = violations.is_empty();
+        violations.extend(self.validate_style());
+        violations.extend(self.validate_constraints());
+        violations.extend(self.validate_defaults());
+        violations.extend(self.validate_wire_names());
+        violations.extend(self.validate_nullability());
+        violations.extend(self.validate_enum_representation());
+        violations.extend(self.validate_type_params());
+        violations.extend(self.validate_key_types());
+        if references_resolved {
+            violations.extend(self.validate_examples());
+        }
+
+        violations
+    }
+
+    /// diagnostic located by locate, or at the start of spec if not able to locate
+    fn located_diagnostic(
+        &self,
+        code: &'static str,
+        spec: &Path,
+        message: String,
+        locate: impl FnOnce(&SourceMap) -> Option<Location>,
+    ) -> Diagnostic {
+        let path = relative_to_current_dir(&self.root_folder.join(spec));
+        let diagnostic = Diagnostic::new(path, code, message);
+        let Ok(def) = self.get_definition(spec) else {
+            return diagnostic;
+        };
+        let location = locate(&def.source_map).unwrap_or(Location { line: 1, column: 1 });
+        diagnostic.with_location(location, def.source_map.line(location.line))
+    }
+
+    /// diagnostic at the model, or at its field, variant or const value if member is set
+    fn model_diagnostic(
+        &self,
+        code: &'static str,
+        spec: &Path,
+        model: &str,
+        member: Option<&str>,
+        message: String,
+    ) -> Diagnostic {
+        let diagnostic = self.located_diagnostic(code, spec, message, |source_map| {
+            member
+                .and_then(|m| source_map.member(model, m))
+                .or_else(|| source_map.model(model))
+        });
+        let diagnostic = diagnostic.with_model(model);
+        match member {
+            Some(member) => diagnostic.with_field(member),
+            None => diagnostic,
+        }
+    }
+
+    /// diagnostic at the type string of member, or new type's inner type if member is None
+    fn type_diagnostic(
+        &self,
+        code: &'static str,
+        spec: &Path,
+        model: &str,
+        member: Option<&str>,
+        message: String,
+    ) -> Diagnostic {
+        let diagnostic = self.located_diagnostic(code, spec, message, |source_map| {
+            source_map
+                .member_type(model, member)
+                .or_else(|| member.and_then(|m| source_map.member(model, m)))
+                .or_else(|| source_map.model(model))
+        });
+        let diagnostic = diagnostic.with_model(model);
+        match member {
+            Some(member) => diagnostic.with_field(member),
+            None => diagnostic,
+        }
+    }
+
+    /// validate includes point to loaded specs and do not form a cycle
+    fn validate_includes(&self) -> Vec<Diagnostic> {
+        let mut violations = vec![];
+        for (spec, def) in self.definitions.iter() {
+            for include in def.includes.iter() {
+                let exists = self
+                    .get_include_path(&include.namespace, spec)
+                    .map_or(false, |path| self.definitions.contains_key(&path));
+                if !exists {
+                    let message =
+                        format!("include {} {:?} not found", include.namespace, include.path);
+                    violations.push(self.located_diagnostic(
+                        "missing-include",
+                        spec,
+                        message,
+                        |source_map| source_map.include(&include.namespace),
+                    ));
+                }
+            }
+        }
+
+        for spec in self.definitions.keys() {
+            let mut path = vec![spec];
+            let mut visited = HashSet::new();
+            self.find_include_cycles(spec, &mut path, &mut visited, &mut violations);
+        }
+        violations
+    }
+
+    /// dfs from path's first spec, each cycle is reported once from its smallest spec
+    fn find_include_cycles<'a>(
+        &'a self,
+        spec: &'a PathBuf,
+        path: &mut Vec<&'a PathBuf>,
+        visited: &mut HashSet<&'a PathBuf>,
+        violations: &mut Vec<Diagnostic>,
+    ) {
+        let Some(def) = self.definitions.get(spec) else {
+            return;
+        };
+        for include in def.includes.iter() {
+            let Ok(include_path) = self.get_include_path(&include.namespace, spec) else {
+                continue;
+            };
+            let Some((include_spec, _)) = self.definitions.get_key_value(&include_path) else {
+                continue;
+            };
+
+            if include_spec == path[0] {
+                if path.iter().all(|p| *p >= include_spec) {
+                    let cycle = path
+                        .iter()
+                        .chain([&include_spec])
+                        .map(|p| format!("{p:?}"))
+                        .collect::<Vec<_>>();
+                    let message = format!("include cycle: {}", cycle.join(" -> "));
+                    // reported at the include in the first spec of the cycle
+                    let (first, namespace) = match path.get(1) {
+                        Some(second) => (path[0], self.include_namespace(path[0], second)),
+                        None => (spec, Some(include.namespace.as_str())),
+                    };
+                    violations.push(self.located_diagnostic(
+                        "include-cycle",
+                        first,
+                        message,
+                        |source_map| source_map.include(namespace?),
+                    ));
+                }
+                continue;
+            }
+
+            if path.contains(&include_spec) || !visited.insert(include_spec) {
+                continue;
+            }
+            path.push(include_spec);
+            self.find_include_cycles(include_spec, path, visited, violations);
+            path.pop();
+        }
+    }
+
+    /// namespace of the include in spec which points to included
+    fn include_namespace(&self, spec: &Path, included: &Path) -> Option<&str> {
+        let def = self.get_definition(spec).ok()?;
+        def.includes
+            .iter()
+            .find(|i| {
+                self.get_include_path(&i.namespace, spec)
+                    .map_or(false, |p| p == included)
+            })
+            .map(|i| i.namespace.as_str())
+    }
+
+    /// validate all type references resolve to a model
+    fn validate_references(&self) -> Vec<Diagnostic> {
+        let mut violations = vec![];
+        for (spec, def) in self.definitions.iter() {
+            for model in def.models.iter() {
+                for (member, ty) in model.member_types() {
+                    let mut type_refs = vec![];
+                    collect_type_refs(ty, &mut type_refs);
+                    for type_ref in type_refs {
+                        if let Some(violation) = self.validate_reference(type_ref, spec) {
+                            let message = format!("{} {violation}", model.name);
+                            violations.push(self.type_diagnostic(
+                                "unresolved-reference",
+                                spec,
+                                &model.name,
+                                member,
+                                message,
+                            ));
+                        }
+                    }
+                }
+            }
+            for method in def.methods.iter() {
+                for type_ref in [&method.request, &method.response] {
+                    if let Some(violation) = self.validate_reference(type_ref, spec) {
+                        let message = format!("{} {violation}", method.name);
+                        violations.push(self.located_diagnostic(
+                            "unresolved-reference",
+                            spec,
+                            message,
+                            |source_map| source_map.method(&method.name),
+                        ));
+                    }
+                }
+            }
+        }
+        violations
+    }
+
+    /// returns violation if type_ref does not resolve
+    fn validate_reference(&self, type_ref: &TypeReference, spec: &Path) -> Option<String> {
+        if let Some(namespace) = &type_ref.namespace {
+            let def = self.get_definition(spec).ok()?;
+            if def.get_include(namespace).is_none() {
+                return Some(format!(
+                    "references {}.{}, namespace {namespace} is not included",
+                    namespace, type_ref.target
+                ));
+            }
+        }
+
+        match self.get_model_def_for_reference(type_ref, spec) {
+            Ok(_) => None,
+            Err(_) => Some(format!(
+                "references unknown model {}",
+                type_ref_name(type_ref)
+            )),
+        }
+    }
+
+    /// validate names of models, fields, variants and consts are unique, and const values are unique
+    fn validate_names(&self) -> Vec<Diagnostic> {
+        let mut violations = vec![];
+        for (spec, def) in self.definitions.iter() {
+            for name in duplicates(def.models.iter().map(|m| m.name.as_str())) {
+                let message = format!("model name {name} is used more than once");
+                violations.push(self.model_diagnostic("duplicate-name", spec, name, None, message));
+            }
+
+            for model in def.models.iter() {
+                // code, duplicated member name and the message
+                let mut duplicated = vec![];
+                match &model.type_ {
+                    ModelType::Struct(st_) | ModelType::Virtual(st_) => {
+                        // fields of the extended virtual model are part of the struct
+                        let base_fields = st_
+                            .extend
+                            .as_deref()
+                            .and_then(TypeReference::try_parse)
+                            .and_then(|r| self.get_model_def_for_reference(&r, spec).ok())
+                            .and_then(|m| match &m.type_ {
+                                ModelType::Virtual(base) => Some(base.fields.as_slice()),
+                                _ => None,
+                            })
+                            .unwrap_or_default();
+                        let names = base_fields.iter().chain(st_.fields.iter());
+                        for name in duplicates(names.map(|f| f.name.as_str())) {
+                            duplicated.push(("duplicate-name", name, format!("field name {name}")));
+                        }
+                    }
+                    ModelType::Enum { variants, .. } => {
+                        for name in duplicates(variants.iter().map(|v| v.name.as_str())) {
+                            duplicated.push((
+                                "duplicate-name",
+                                name,
+                                format!("variant name {name}"),
+                            ));
+                        }
+                        for variant in variants.iter() {
+                            let fields = variant.payload_fields.iter().flatten();
+                            for name in duplicates(fields.map(|f| f.name.as_str())) {
+                                duplicated.push((
+                                    "duplicate-name",
+                                    &variant.name,
+                                    format!("variant:{} field name {name}", variant.name),
+                                ));
+                            }
+                        }
+                    }
+                    ModelType::Const { values, .. } => {
+                        for name in duplicates(values.iter().map(|v| v.name.as_str())) {
+                            duplicated.push(("duplicate-name", name, format!("const name {name}")));
+                        }
+                        let literals = values
+                            .iter()
+                            .map(|v| match &v.value {
+                                StringOrInteger::String(s) => format!("{s:?}"),
+                                StringOrInteger::Integer(i) => i.to_string(),
+                            })
+                            .collect::<Vec<_>>();
+                        for value in duplicates(literals.iter().map(|v| v.as_str())) {
+                            // located at the last value with the literal
+                            let (value_def, _) = values
+                                .iter()
+                                .zip(literals.iter())
+                                .rfind(|(_, l)| *l == value)
+                                .unwrap();
+                            duplicated.push((
+                                "duplicate-value",
+                                &value_def.name,
+                                format!("const value {value}"),
+                            ));
+                        }
+                    }
+                    ModelType::NewType { .. } => {}
+                }
+
+                for (code, member, duplicated) in duplicated {
+                    let message = format!("{} {duplicated} is used more than once", model.name);
+                    violations.push(self.model_diagnostic(
+                        code,
+                        spec,
+                        &model.name,
+                        Some(member),
+                        message,
+                    ));
+                }
+            }
+        }
+        violations
+    }
+
+    /// validate struct only extends virtual model
+    fn validate_extends(&self) -> Vec<Diagnostic> {
+        let mut violations = vec![];
+        for (spec, def) in self.definitions.iter() {
+            for model in def.models.iter() {
+                let Some(extend) = model.type_.struct_def().and_then(|st_| st_.extend.as_ref())
+                else {
+                    continue;
+                };
+                let message = match TypeReference::try_parse(extend) {
+                    None => format!("{} extend {extend} is not a valid reference", model.name),
+                    // codegens only look up the base in the same spec
+                    Some(type_ref) if type_ref.namespace.is_some() => format!(
+                        "{} extends {extend} which is not in the same spec",
+                        model.name
+                    ),
+                    Some(type_ref) => match self.validate_reference(&type_ref, spec) {
+                        Some(violation) => format!("{} extend {violation}", model.name),
+                        None => match self.get_model_def_for_reference(&type_ref, spec) {
+                            Ok(base) if !matches!(base.type_, ModelType::Virtual(_)) => {
+                                format!("{} extends {extend} which is not virtual", model.name)
+                            }
+                            _ => continue,
+                        },
+                    },
+                };
+                violations.push(self.model_diagnostic(
+                    "invalid-extend",
+                    spec,
+                    &model.name,
+                    None,
+                    message,
+                ));
+            }
+        }
+        violations
+    }
+
     /// validate all definitions against styles, return violations
-    fn validate_style(&self) -> Vec<String> {
+    fn validate_style(&self) -> Vec<Diagnostic> {
         let mut violations = vec![];
         let Some(style) = self.style.as_ref() else {
             return violations;
@@ -205,31 +681,360 @@ impl Context {
             }
 
             for model in def.models.iter() {
-                let model_violations = style.validate_model(model);
-                if model_violations.is_empty() {
+                for (field, model_violation) in style.validate_model(model) {
+                    let message = format!("model:{} {}", model.name, model_violation);
+                    violations.push(self.model_diagnostic(
+                        "style",
+                        spec,
+                        &model.name,
+                        Some(field),
+                        message,
+                    ));
+                }
+            }
+        }
+        violations
+    }
+
+    /// validate constraints are applicable to the field's type
+    fn validate_constraints(&self) -> Vec<Diagnostic> {
+        let mut violations = vec![];
+        for (spec, def) in self.definitions.iter() {
+            for model in def.models.iter() {
+                let fields = match &model.type_ {
+                    ModelType::Struct(st_) | ModelType::Virtual(st_) => &st_.fields,
+                    _ => continue,
+                };
+
+                for field in fields {
+                    for v in constraints_violations_for_type(&field.constraints, &field.type_) {
+                        let message = format!("{} field:{} {v}", model.name, field.name);
+                        violations.push(self.model_diagnostic(
+                            "invalid-constraint",
+                            spec,
+                            &model.name,
+                            Some(&field.name),
+                            message,
+                        ));
+                    }
+                }
+            }
+        }
+        violations
+    }
+
+    /// validate nullability is only set for non required field without default
+    fn validate_nullability(&self) -> Vec<Diagnostic> {
+        let mut violations = vec![];
+        for (spec, def) in self.definitions.iter() {
+            for model in def.models.iter() {
+                let fields = match &model.type_ {
+                    ModelType::Struct(st_) | ModelType::Virtual(st_) => &st_.fields,
+                    _ => continue,
+                };
+
+                for field in fields {
+                    if field.nullability.is_some() && (field.required || field.default.is_some()) {
+                        let message = format!(
+                            "{} field:{} nullability should not be set for required field or field with default",
+                            model.name, field.name
+                        );
+                        violations.push(self.model_diagnostic(
+                            "invalid-nullability",
+                            spec,
+                            &model.name,
+                            Some(&field.name),
+                            message,
+                        ));
+                    }
+                }
+            }
+        }
+        violations
+    }
+
+    /// validate internally tagged enum's payload is an object
+    fn validate_enum_representation(&self) -> Vec<Diagnostic> {
+        let mut violations = vec![];
+        for (spec, def) in self.definitions.iter() {
+            for model in def.models.iter() {
+                let ModelType::Enum {
+                    variants,
+                    representation: EnumRepresentation::Internal,
+                    ..
+                } = &model.type_
+                else {
                     continue;
+                };
+
+                for variant in variants {
+                    let Some(payload_type) = &variant.payload_type else {
+                        continue;
+                    };
+                    let is_struct = match &**payload_type {
+                        Type::Reference(type_ref) => self
+                            .get_model_def_for_reference(type_ref, spec)
+                            .map_or(false, |m| matches!(m.type_, ModelType::Struct(_))),
+                        _ => false,
+                    };
+                    if !is_struct {
+                        let message = format!(
+                            "{} variant:{} internal representation requires payload to be fields or a struct",
+                            model.name, variant.name
+                        );
+                        violations.push(self.type_diagnostic(
+                            "invalid-enum-representation",
+                            spec,
+                            &model.name,
+                            Some(&variant.name),
+                            message,
+                        ));
+                    }
                 }
+            }
+        }
+        violations
+    }
 
-                for model_violation in model_violations {
-                    violations.push(format!(
-                        "spec:{:?} model:{} {}",
-                        spec, model.name, model_violation
+    /// validate generic models are structs, and references to them have matching type args
+    fn validate_type_params(&self) -> Vec<Diagnostic> {
+        let mut violations = vec![];
+        for (spec, def) in self.definitions.iter() {
+            for model in def.models.iter() {
+                if !model.type_params.is_empty() && model.type_.struct_def().is_none() {
+                    let message =
+                        format!("{} type_params is only supported for struct", model.name);
+                    violations.push(self.model_diagnostic(
+                        "invalid-type-args",
+                        spec,
+                        &model.name,
+                        None,
+                        message,
                     ));
                 }
+                for (member, ty) in model.member_types() {
+                    let mut type_refs = vec![];
+                    collect_type_refs(ty, &mut type_refs);
+                    for type_ref in type_refs {
+                        if let Some(message) = self.type_args_violation(type_ref, spec) {
+                            violations.push(self.type_diagnostic(
+                                "invalid-type-args",
+                                spec,
+                                &model.name,
+                                member,
+                                message,
+                            ));
+                        }
+                    }
+                }
+            }
+            for method in def.methods.iter() {
+                for type_ref in [&method.request, &method.response] {
+                    if let Some(message) = self.type_args_violation(type_ref, spec) {
+                        violations.push(self.located_diagnostic(
+                            "invalid-type-args",
+                            spec,
+                            message,
+                            |source_map| source_map.method(&method.name),
+                        ));
+                    }
+                }
             }
         }
         violations
     }
 
-    fn validate_examples(&self) -> Vec<String> {
+    /// returns violation if type args of type_ref do not match the model's type params
+    fn type_args_violation(&self, type_ref: &TypeReference, spec: &Path) -> Option<String> {
+        let model = self.get_model_def_for_reference(type_ref, spec).ok()?;
+        if model.type_params.len() == type_ref.args.len() {
+            return None;
+        }
+        Some(format!(
+            "{} expects {} type args, got {}",
+            type_ref.target,
+            model.type_params.len(),
+            type_ref.args.len()
+        ))
+    }
+
+    /// validate map keys and set items are string, integer or string const
+    fn validate_key_types(&self) -> Vec<Diagnostic> {
         let mut violations = vec![];
         for (spec, def) in self.definitions.iter() {
             for model in def.models.iter() {
-                violations.extend(
-                    self.validate_example_for_model(model, spec)
-                        .into_iter()
-                        .map(|v| format!("{spec:?} {} {v}", model.name)),
-                );
+                for (member, ty) in model.member_types() {
+                    let mut types = vec![];
+                    collect_types(ty, &mut types);
+                    for ty in types {
+                        let (kind, key_type) = match ty {
+                            Type::Map { key_type, .. } => ("map key", &**key_type),
+                            Type::Set { item_type } => ("set item", &***item_type),
+                            _ => continue,
+                        };
+                        if !self.is_key_type(key_type, spec) {
+                            let message = format!(
+                                "{} {kind} should be string, integer or string const, got {key_type:?}",
+                                model.name
+                            );
+                            violations.push(self.type_diagnostic(
+                                "invalid-key-type",
+                                spec,
+                                &model.name,
+                                member,
+                                message,
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+        violations
+    }
+
+    /// whether ty is string, integer or string const
+    fn is_key_type(&self, ty: &Type, spec: &Path) -> bool {
+        match ty {
+            Type::String => true,
+            ty => ty.is_integer() || self.is_string_const(ty, spec),
+        }
+    }
+
+    /// validate wire names are unique within a model
+    fn validate_wire_names(&self) -> Vec<Diagnostic> {
+        let mut violations = vec![];
+        for (spec, def) in self.definitions.iter() {
+            for model in def.models.iter() {
+                let wire_names = match &model.type_ {
+                    ModelType::Struct(st_) | ModelType::Virtual(st_) => st_
+                        .fields
+                        .iter()
+                        .map(|f| (&f.name, def.field_wire_name(f)))
+                        .collect::<Vec<_>>(),
+                    ModelType::Enum { variants, .. } => variants
+                        .iter()
+                        .map(|v| (&v.name, def.variant_wire_name(v)))
+                        .collect::<Vec<_>>(),
+                    _ => continue,
+                };
+
+                let mut seen_names = HashSet::new();
+                let mut seen = HashSet::new();
+                for (name, wire_name) in wire_names {
+                    // duplicate names are reported by validate_names
+                    if !seen_names.insert(name) {
+                        continue;
+                    }
+                    if !seen.insert(wire_name.clone()) {
+                        let message = format!(
+                            "{} wire name {wire_name} is used more than once",
+                            model.name
+                        );
+                        violations.push(self.model_diagnostic(
+                            "duplicate-wire-name",
+                            spec,
+                            &model.name,
+                            Some(name),
+                            message,
+                        ));
+                    }
+                }
+            }
+        }
+        violations
+    }
+
+    /// validate default values against field's type and constraints
+    fn validate_defaults(&self) -> Vec<Diagnostic> {
+        let mut violations = vec![];
+        for (spec, def) in self.definitions.iter() {
+            for model in def.models.iter() {
+                let fields = match &model.type_ {
+                    ModelType::Struct(st_) | ModelType::Virtual(st_) => &st_.fields,
+                    ModelType::Enum { variants, .. } => {
+                        for variant in variants {
+                            for field in variant.payload_fields.iter().flatten() {
+                                if field.default.is_some() {
+                                    let message = format!(
+                                        "{} variant:{} field:{} default is not supported for variant field",
+                                        model.name, variant.name, field.name
+                                    );
+                                    violations.push(self.model_diagnostic(
+                                        "invalid-default",
+                                        spec,
+                                        &model.name,
+                                        Some(&variant.name),
+                                        message,
+                                    ));
+                                }
+                            }
+                        }
+                        continue;
+                    }
+                    _ => continue,
+                };
+
+                for field in fields {
+                    let Some(default) = &field.default else {
+                        continue;
+                    };
+
+                    let mut field_violations = vec![];
+                    if field.required {
+                        field_violations.push("field with default should not be required".into());
+                    }
+                    if !is_default_supported(&field.type_) {
+                        field_violations.push(format!(
+                            "default is only supported for bool, numeric, string, list and map, got {:?}",
+                            &*field.type_
+                        ));
+                    } else if default.is_null() {
+                        field_violations.push("default should not be null".into());
+                    } else {
+                        field_violations.extend(self.validate_value_for_type(
+                            default,
+                            &field.type_,
+                            true,
+                            spec,
+                        ));
+                        if field_violations.is_empty() {
+                            field_violations.extend(validate_value_for_constraints(
+                                default,
+                                &field.constraints,
+                            ));
+                        }
+                    }
+
+                    for v in field_violations {
+                        let message = format!("{} field:{} default {v}", model.name, field.name);
+                        violations.push(self.model_diagnostic(
+                            "invalid-default",
+                            spec,
+                            &model.name,
+                            Some(&field.name),
+                            message,
+                        ));
+                    }
+                }
+            }
+        }
+        violations
+    }
+
+    fn validate_examples(&self) -> Vec<Diagnostic> {
+        let mut violations = vec![];
+        for (spec, def) in self.definitions.iter() {
+            for model in def.models.iter() {
+                for v in self.validate_example_for_model(model, spec) {
+                    let message = format!("{} {v}", model.name);
+                    violations.push(self.model_diagnostic(
+                        "invalid-example",
+                        spec,
+                        &model.name,
+                        None,
+                        message,
+                    ));
+                }
             }
         }
         violations
@@ -268,25 +1073,26 @@ impl Context {
         spec: &PathBuf,
     ) -> Vec<String> {
         match &model_type {
-            crate::ModelType::Enum { .. } => {
-                // todo: support example validate for enum
+            crate::ModelType::Enum {
+                variants,
+                tag_name,
+                payload_name,
+                representation,
+            } => {
+                let tag_name = tag_name.as_deref().unwrap_or("type");
+                let payload_name = payload_name.as_deref().unwrap_or("payload");
+                return self.validate_value_for_enum(
+                    value,
+                    variants,
+                    *representation,
+                    tag_name,
+                    payload_name,
+                    spec,
+                );
             }
             crate::ModelType::Struct(st_) => {
-                let mut violations = vec![];
                 // todo: support extend
-                for field in &st_.fields {
-                    violations.extend(
-                        self.validate_value_for_type(
-                            &value.get(&field.name).cloned().unwrap_or_default(),
-                            &field.type_,
-                            field.required,
-                            spec,
-                        )
-                        .into_iter()
-                        .map(|v| format!("field:{} {v}", field.name)),
-                    );
-                }
-                return violations;
+                return self.validate_value_for_fields(value, &st_.fields, spec);
             }
             crate::ModelType::Virtual(_) => {
                 // skip example validate for virtual model
@@ -302,6 +1108,190 @@ impl Context {
         vec![]
     }
 
+    fn validate_value_for_fields(
+        &self,
+        value: &serde_json::Value,
+        fields: &[FieldDef],
+        spec: &PathBuf,
+    ) -> Vec<String> {
+        if !value.is_object() {
+            return vec![format!("expect object, got {:?}", value)];
+        }
+
+        let mut violations = vec![];
+        let def = self.get_definition(spec).ok();
+        for field in fields {
+            let wire_name = match def {
+                Some(def) => def.field_wire_name(field),
+                None => field.name.as_str().into(),
+            };
+            violations.extend(
+                self.validate_value_for_field(value.get(wire_name.as_ref()), field, spec)
+                    .into_iter()
+                    .map(|v| format!("field:{} {v}", field.name)),
+            );
+        }
+        violations
+    }
+
+    fn validate_value_for_enum(
+        &self,
+        value: &serde_json::Value,
+        variants: &[VariantDef],
+        representation: EnumRepresentation,
+        tag_name: &str,
+        payload_name: &str,
+        spec: &PathBuf,
+    ) -> Vec<String> {
+        let def = match self.get_definition(spec) {
+            Ok(def) => def,
+            Err(e) => return vec![e.to_string()],
+        };
+        let find_variant = |tag: &str| {
+            variants
+                .iter()
+                .find(|v| def.variant_wire_name(v) == tag)
+                .ok_or_else(|| vec![format!("unknown variant {tag:?}")])
+        };
+
+        // resolve variant and its payload, payload is None if missing
+        let (variant, payload) = match representation {
+            EnumRepresentation::Adjacent | EnumRepresentation::Internal => {
+                let Some(tag) = value.get(tag_name).and_then(|t| t.as_str()) else {
+                    return vec![format!(
+                        "expect tag {tag_name:?} in string, got {:?}",
+                        value
+                    )];
+                };
+                let variant = match find_variant(tag) {
+                    Ok(variant) => variant,
+                    Err(violations) => return violations,
+                };
+                if representation == EnumRepresentation::Adjacent {
+                    (variant, value.get(payload_name))
+                } else {
+                    (variant, Some(value))
+                }
+            }
+            EnumRepresentation::External => {
+                let (tag, payload) = match value {
+                    serde_json::Value::String(tag) => (tag, None),
+                    serde_json::Value::Object(obj) if obj.len() == 1 => {
+                        let (tag, payload) = obj.iter().next().unwrap();
+                        (tag, Some(payload))
+                    }
+                    _ => {
+                        return vec![format!(
+                            "expect variant name or object with one key, got {:?}",
+                            value
+                        )]
+                    }
+                };
+                match find_variant(tag) {
+                    Ok(variant) => (variant, payload),
+                    Err(violations) => return violations,
+                }
+            }
+            EnumRepresentation::Untagged => {
+                let matched = variants.iter().any(|variant| {
+                    if variant.payload_type.is_none() && variant.payload_fields.is_none() {
+                        // variant without payload is null
+                        return value.is_null();
+                    }
+                    self.validate_value_for_variant(Some(value), variant, spec)
+                        .is_empty()
+                });
+                if matched {
+                    return vec![];
+                }
+                return vec![format!("no variant matches {:?}", value)];
+            }
+        };
+
+        self.validate_value_for_variant(payload, variant, spec)
+            .into_iter()
+            .map(|v| format!("variant:{} {v}", variant.name))
+            .collect()
+    }
+
+    fn validate_value_for_variant(
+        &self,
+        payload: Option<&serde_json::Value>,
+        variant: &VariantDef,
+        spec: &PathBuf,
+    ) -> Vec<String> {
+        match (&variant.payload_type, &variant.payload_fields, payload) {
+            (Some(payload_type), _, Some(payload)) => {
+                self.validate_value_for_type(payload, payload_type, true, spec)
+            }
+            (None, Some(fields), Some(payload)) => {
+                self.validate_value_for_fields(payload, fields, spec)
+            }
+            (None, None, _) => vec![],
+            (_, _, None) => vec!["expect payload".to_string()],
+        }
+    }
+
+    /// validate value against field's nullability, type and constraints, `None` means the
+    /// field is absent
+    pub fn validate_value_for_field(
+        &self,
+        value: Option<&serde_json::Value>,
+        field: &FieldDef,
+        spec: &PathBuf,
+    ) -> Vec<String> {
+        let value = match value {
+            Some(value) => value,
+            None if field.may_be_absent() => return vec![],
+            None if field.nullability.is_some() => return vec!["expect field present".into()],
+            // keep reporting missing required field as null
+            None => &serde_json::Value::Null,
+        };
+
+        let violations =
+            self.validate_value_for_type(value, &field.type_, !field.may_be_null(), spec);
+        if !violations.is_empty() || value.is_null() {
+            return violations;
+        }
+
+        validate_value_for_constraints(value, &field.constraints)
+    }
+
+    /// validate json object's key against map's key type, integer key is encoded as string
+    fn validate_map_key(&self, key: &str, key_type: &Type, spec: &PathBuf) -> Vec<String> {
+        if key_type.is_integer() {
+            let number = match key.parse::<i64>() {
+                Ok(n) => serde_json::Value::from(n),
+                Err(_) => match key.parse::<u64>() {
+                    Ok(n) => serde_json::Value::from(n),
+                    Err(_) => return vec![format!("expect integer key, got {key:?}")],
+                },
+            };
+            return self
+                .validate_value_for_type(&number, key_type, true, spec)
+                .into_iter()
+                .map(|v| format!("key {v}"))
+                .collect();
+        }
+
+        if let Type::Reference(type_ref) = key_type {
+            if let Ok(ModelDef {
+                type_: ModelType::Const { values, .. },
+                ..
+            }) = self.get_model_def_for_reference(type_ref, spec)
+            {
+                let is_valid = values
+                    .iter()
+                    .any(|v| matches!(&v.value, StringOrInteger::String(s) if s == key));
+                if !is_valid {
+                    return vec![format!("expect key in {}, got {key:?}", type_ref.target)];
+                }
+            }
+        }
+
+        vec![]
+    }
+
     pub fn validate_value_for_type(
         &self,
         value: &serde_json::Value,
@@ -320,17 +1310,17 @@ impl Context {
                 }
             }
             Type::I8 => {
-                if !value.is_i64() || value.as_i64().unwrap() > i8::MAX as i64 {
+                if !is_integer_in_range(value, i8::MIN.into(), i8::MAX.into()) {
                     return vec![format!("expect i8, got {:?}", value)];
                 }
             }
             Type::I16 => {
-                if !value.is_i64() || value.as_i64().unwrap() > i16::MAX as i64 {
+                if !is_integer_in_range(value, i16::MIN.into(), i16::MAX.into()) {
                     return vec![format!("expect i16, got {:?}", value)];
                 }
             }
             Type::I32 => {
-                if !value.is_i64() || value.as_i64().unwrap() > i32::MAX as i64 {
+                if !is_integer_in_range(value, i32::MIN.into(), i32::MAX.into()) {
                     return vec![format!("expect i32, got {:?}", value)];
                 }
             }
@@ -339,8 +1329,34 @@ impl Context {
                     return vec![format!("expect i64, got {:?}", value)];
                 }
             }
+            Type::U8 => {
+                if !is_integer_in_range(value, u8::MIN.into(), u8::MAX.into()) {
+                    return vec![format!("expect u8, got {:?}", value)];
+                }
+            }
+            Type::U16 => {
+                if !is_integer_in_range(value, u16::MIN.into(), u16::MAX.into()) {
+                    return vec![format!("expect u16, got {:?}", value)];
+                }
+            }
+            Type::U32 => {
+                if !is_integer_in_range(value, u32::MIN.into(), u32::MAX.into()) {
+                    return vec![format!("expect u32, got {:?}", value)];
+                }
+            }
+            Type::U64 => {
+                if !value.is_u64() {
+                    return vec![format!("expect u64, got {:?}", value)];
+                }
+            }
+            Type::F32 => {
+                // integer literal is also a valid float on the wire
+                if !value.as_f64().map_or(false, |v| v.abs() <= f32::MAX as f64) {
+                    return vec![format!("expect f32, got {:?}", value)];
+                }
+            }
             Type::F64 => {
-                if !value.is_f64() {
+                if !value.is_number() {
                     return vec![format!("expect f64, got {:?}", value)];
                 }
             }
@@ -364,6 +1380,30 @@ impl Context {
                     return vec![format!("expect string, got {:?}", value)];
                 }
             }
+            Type::Timestamp {
+                format: TimestampFormat::Rfc3339,
+            } => {
+                if !value.as_str().map(is_rfc3339).unwrap_or_default() {
+                    return vec![format!("expect rfc3339 timestamp, got {:?}", value)];
+                }
+            }
+            Type::Timestamp {
+                format: TimestampFormat::EpochMillis,
+            } => {
+                if !value.is_i64() {
+                    return vec![format!("expect timestamp in epoch millis, got {:?}", value)];
+                }
+            }
+            Type::Date => {
+                if !value.as_str().map(is_date).unwrap_or_default() {
+                    return vec![format!("expect date in YYYY-MM-DD, got {:?}", value)];
+                }
+            }
+            Type::Duration => {
+                if !value.is_u64() {
+                    return vec![format!("expect duration in millis, got {:?}", value)];
+                }
+            }
             Type::List { item_type } => {
                 if !value.is_array() {
                     return vec![format!("expect array, got {:?}", value)];
@@ -375,20 +1415,72 @@ impl Context {
                 }
                 return violations;
             }
-            Type::Map { value_type } => {
+            Type::Set { item_type } => {
+                if !value.is_array() {
+                    return vec![format!("expect array, got {:?}", value)];
+                }
+
+                let mut violations = vec![];
+                let mut items = HashSet::new();
+                for item in value.as_array().unwrap() {
+                    violations.extend(self.validate_value_for_type(item, item_type, true, spec));
+                    if !items.insert(item.to_string()) {
+                        violations.push(format!("duplicate item {:?}", item));
+                    }
+                }
+                return violations;
+            }
+            Type::Map {
+                key_type,
+                value_type,
+            } => {
                 if !value.is_object() {
                     return vec![format!("expect object, got {:?}", value)];
                 }
 
                 let mut violations = vec![];
-                for (_key, item) in value.as_object().unwrap() {
+                for (key, item) in value.as_object().unwrap() {
+                    violations.extend(self.validate_map_key(key, key_type, spec));
                     violations.extend(self.validate_value_for_type(item, &value_type, true, spec));
                 }
                 return violations;
             }
             Type::Reference(type_ref) => {
-                let model_def = self.get_model_def_for_reference(type_ref, spec).unwrap();
-                return self.validate_example_for_model_type(&model_def.type_, value, spec);
+                let model_def = match self.get_model_def_for_reference(type_ref, spec) {
+                    Ok(model_def) => model_def,
+                    Err(e) => return vec![e.to_string()],
+                };
+                // the referenced model is validated within its own spec
+                let model_spec = match &type_ref.namespace {
+                    Some(namespace) => match self.get_include_path(namespace, spec) {
+                        Ok(model_spec) => model_spec,
+                        Err(e) => return vec![e.to_string()],
+                    },
+                    None => spec.clone(),
+                };
+                // type args are resolved in current spec, so only substitute for model in
+                // the same spec, otherwise values of type params are not validated
+                if let (Some(st_), None) = (model_def.type_.struct_def(), &type_ref.namespace) {
+                    if !model_def.type_params.is_empty() {
+                        let fields = st_
+                            .fields
+                            .iter()
+                            .map(|field| {
+                                let mut field = field.clone();
+                                field.type_ = field
+                                    .type_
+                                    .substitute(&model_def.type_params, &type_ref.args)
+                                    .into();
+                                field
+                            })
+                            .collect::<Vec<_>>();
+                        return self.validate_value_for_fields(value, &fields, &model_spec);
+                    }
+                }
+                return self.validate_example_for_model_type(&model_def.type_, value, &model_spec);
+            }
+            Type::TypeParam { .. } => {
+                // type param without arg, e.g: example of the generic model
             }
             Type::Json => {
                 // always valid
@@ -399,6 +1491,253 @@ impl Context {
     }
 }
 
+/// yaml file except spec_config.yaml
+fn is_spec_file(path: &Path) -> bool {
+    let is_yaml = path
+        .extension()
+        .map(|ext| ext == "yaml")
+        .unwrap_or_default();
+    let is_config = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().eq("spec_config"))
+        .unwrap_or_default();
+    is_yaml && !is_config
+}
+
+/// path relative to current dir if it is inside, so it is short and clickable in diagnostics
+pub(crate) fn relative_to_current_dir(path: &Path) -> PathBuf {
+    std::env::current_dir()
+        .ok()
+        .and_then(|cwd| path.strip_prefix(cwd).ok().map(Path::to_path_buf))
+        .unwrap_or_else(|| path.to_path_buf())
+}
+
+/// names appear more than once, in order of first duplicate
+fn duplicates<'a>(names: impl Iterator<Item = &'a str>) -> Vec<&'a str> {
+    let mut seen = HashSet::new();
+    let mut duplicated = vec![];
+    for name in names {
+        if !seen.insert(name) && !duplicated.contains(&name) {
+            duplicated.push(name);
+        }
+    }
+    duplicated
+}
+
+/// name of type_ref as written in spec, without type args
+fn type_ref_name(type_ref: &TypeReference) -> String {
+    match &type_ref.namespace {
+        Some(namespace) => format!("{namespace}.{}", type_ref.target),
+        None => type_ref.target.clone(),
+    }
+}
+
+/// collect all type references in ty, including type args
+fn collect_type_refs<'a>(ty: &'a Type, refs: &mut Vec<&'a TypeReference>) {
+    match ty {
+        Type::List { item_type } | Type::Set { item_type } => collect_type_refs(item_type, refs),
+        Type::Map {
+            key_type,
+            value_type,
+        } => {
+            collect_type_refs(key_type, refs);
+            collect_type_refs(value_type, refs);
+        }
+        Type::Reference(type_ref) => {
+            refs.push(type_ref);
+            for arg in type_ref.args.iter() {
+                collect_type_refs(arg, refs);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// collect ty and all types nested in it
+fn collect_types<'a>(ty: &'a Type, types: &mut Vec<&'a Type>) {
+    types.push(ty);
+    match ty {
+        Type::List { item_type } | Type::Set { item_type } => collect_types(item_type, types),
+        Type::Map {
+            key_type,
+            value_type,
+        } => {
+            collect_types(key_type, types);
+            collect_types(value_type, types);
+        }
+        Type::Reference(type_ref) => {
+            for arg in type_ref.args.iter() {
+                collect_types(arg, types);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// whether default value is supported for ty, default is rendered as literal in codegens
+fn is_default_supported(ty: &Type) -> bool {
+    match ty {
+        Type::Bool | Type::String => true,
+        Type::List { item_type } => is_default_supported(item_type),
+        Type::Map {
+            key_type,
+            value_type,
+        } => matches!(**key_type, Type::String) && is_default_supported(value_type),
+        ty => ty.is_numeric(),
+    }
+}
+
+/// checks constraints are applicable to ty
+fn constraints_violations_for_type(constraints: &Constraints, ty: &Type) -> Vec<String> {
+    let mut violations = vec![];
+
+    for (name, bound) in [("min", &constraints.min), ("max", &constraints.max)] {
+        let Some(bound) = bound else {
+            continue;
+        };
+        if !ty.is_numeric() {
+            violations.push(format!("{name} only applies to numeric type"));
+        } else if ty.is_integer() && !(bound.is_i64() || bound.is_u64()) {
+            violations.push(format!("{name} should be integer for integer type"));
+        }
+    }
+
+    let has_length = constraints.min_length.is_some()
+        || constraints.max_length.is_some()
+        || constraints.non_empty;
+    if has_length
+        && !matches!(
+            ty,
+            Type::String | Type::List { .. } | Type::Set { .. } | Type::Map { .. }
+        )
+    {
+        violations.push("length constraints only apply to string, list, set and map".to_string());
+    }
+
+    if let Some(pattern) = &constraints.pattern {
+        if !matches!(ty, Type::String) {
+            violations.push("pattern only applies to string".to_string());
+        } else if let Err(e) = regex::Regex::new(pattern) {
+            violations.push(format!("invalid pattern {pattern:?}: {e}"));
+        }
+    }
+
+    violations
+}
+
+/// checks value against constraints, value is expected to already match field's type
+fn validate_value_for_constraints(
+    value: &serde_json::Value,
+    constraints: &Constraints,
+) -> Vec<String> {
+    let mut violations = vec![];
+
+    if let Some(number) = value.as_f64() {
+        if let Some(min) = &constraints.min {
+            if number < min.as_f64().unwrap_or_default() {
+                violations.push(format!("expect value >= {min}, got {value}"));
+            }
+        }
+        if let Some(max) = &constraints.max {
+            if number > max.as_f64().unwrap_or_default() {
+                violations.push(format!("expect value <= {max}, got {value}"));
+            }
+        }
+    }
+
+    let length = match value {
+        serde_json::Value::String(s) => Some(s.chars().count() as u64),
+        serde_json::Value::Array(items) => Some(items.len() as u64),
+        serde_json::Value::Object(items) => Some(items.len() as u64),
+        _ => None,
+    };
+    if let Some(length) = length {
+        if constraints.non_empty && length == 0 {
+            violations.push("expect non empty value".to_string());
+        }
+        if let Some(min_length) = constraints.min_length {
+            if length < min_length {
+                violations.push(format!("expect length >= {min_length}, got {length}"));
+            }
+        }
+        if let Some(max_length) = constraints.max_length {
+            if length > max_length {
+                violations.push(format!("expect length <= {max_length}, got {length}"));
+            }
+        }
+    }
+
+    if let (Some(pattern), Some(s)) = (&constraints.pattern, value.as_str()) {
+        // pattern is checked in validate_constraints
+        if let Ok(regex) = regex::Regex::new(pattern) {
+            if !regex.is_match(s) {
+                violations.push(format!("expect value to match {pattern:?}, got {value}"));
+            }
+        }
+    }
+
+    violations
+}
+
+/// checks value is an integer within [min, max]
+fn is_integer_in_range(value: &serde_json::Value, min: i128, max: i128) -> bool {
+    value
+        .as_i64()
+        .map(i128::from)
+        .or_else(|| value.as_u64().map(i128::from))
+        .map(|v| min <= v && v <= max)
+        .unwrap_or_default()
+}
+
+/// checks s is in "YYYY-MM-DD" format
+fn is_date(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    bytes.len() == 10
+        && bytes.iter().enumerate().all(|(idx, c)| match idx {
+            4 | 7 => *c == b'-',
+            _ => c.is_ascii_digit(),
+        })
+}
+
+/// checks s is a rfc3339 timestamp, e.g: "2023-01-01T00:00:00.123+08:00"
+fn is_rfc3339(s: &str) -> bool {
+    if s.len() < 20 || !s.is_char_boundary(10) || !is_date(&s[..10]) {
+        return false;
+    }
+
+    let rest = &s.as_bytes()[10..];
+    if !matches!(rest[0], b'T' | b't' | b' ') {
+        return false;
+    }
+
+    // HH:MM:SS
+    let time = &rest[1..9];
+    let time_valid = time.iter().enumerate().all(|(idx, c)| match idx {
+        2 | 5 => *c == b':',
+        _ => c.is_ascii_digit(),
+    });
+    if !time_valid {
+        return false;
+    }
+
+    // optional fraction
+    let mut rest = &rest[9..];
+    if let Some(fraction) = rest.strip_prefix(b".") {
+        let digits = fraction.iter().take_while(|c| c.is_ascii_digit()).count();
+        if digits == 0 {
+            return false;
+        }
+        rest = &fraction[digits..];
+    }
+
+    // offset
+    match rest {
+        [b'Z' | b'z'] => true,
+        [b'+' | b'-', h1, h2, b':', m1, m2] => [h1, h2, m1, m2].iter().all(|c| c.is_ascii_digit()),
+        _ => false,
+    }
+}
+
 // private methods
 impl Context {
     fn load_spec_config(
@@ -416,3 +1755,363 @@ impl Context {
         Ok(Some(config_value))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_format() {
+        assert!(is_date("2023-01-01"));
+        assert!(!is_date("2023-1-01"));
+        assert!(!is_date("2023-01-01T00:00:00Z"));
+
+        assert!(is_rfc3339("2023-01-01T00:00:00Z"));
+        assert!(is_rfc3339("2023-01-01T00:00:00.123+08:00"));
+        assert!(is_rfc3339("2023-01-01t00:00:00-00:30"));
+        assert!(!is_rfc3339("2023-01-01T00:00:00"));
+        assert!(!is_rfc3339("2023-01-01T00:00:00.Z"));
+        assert!(!is_rfc3339("2023-01-01 00:00"));
+    }
+
+    #[test]
+    fn test_integer_range() {
+        use serde_json::json;
+
+        let i8_range = (i8::MIN as i128, i8::MAX as i128);
+        let u8_range = (u8::MIN as i128, u8::MAX as i128);
+        let u32_range = (u32::MIN as i128, u32::MAX as i128);
+        let check =
+            |v: serde_json::Value, (min, max): (i128, i128)| is_integer_in_range(&v, min, max);
+
+        assert!(check(json!(-128), i8_range));
+        assert!(!check(json!(-129), i8_range));
+        assert!(!check(json!(-1), u8_range));
+        assert!(!check(json!(256), u8_range));
+        assert!(check(json!(u32::MAX), u32_range));
+        assert!(!check(json!(1.5), u32_range));
+        assert!(!check(json!("1"), u32_range));
+    }
+
+    #[test]
+    fn test_constraints() {
+        use serde_json::json;
+
+        let constraints = serde_json::from_value::<Constraints>(json!({
+            "min": 1,
+            "max": 10,
+        }))
+        .unwrap();
+        assert!(constraints_violations_for_type(&constraints, &Type::U8).is_empty());
+        assert!(!constraints_violations_for_type(&constraints, &Type::String).is_empty());
+        assert!(validate_value_for_constraints(&json!(1), &constraints).is_empty());
+        assert!(!validate_value_for_constraints(&json!(0), &constraints).is_empty());
+        assert!(!validate_value_for_constraints(&json!(11), &constraints).is_empty());
+
+        let constraints = serde_json::from_value::<Constraints>(json!({
+            "max_length": 3,
+            "non_empty": true,
+            "pattern": "^[a-z]+$",
+        }))
+        .unwrap();
+        assert!(constraints_violations_for_type(&constraints, &Type::String).is_empty());
+        assert!(!constraints_violations_for_type(&constraints, &Type::I32).is_empty());
+        assert!(validate_value_for_constraints(&json!("abc"), &constraints).is_empty());
+        assert!(!validate_value_for_constraints(&json!(""), &constraints).is_empty());
+        assert!(!validate_value_for_constraints(&json!("abcd"), &constraints).is_empty());
+        assert!(!validate_value_for_constraints(&json!("ab1"), &constraints).is_empty());
+
+        let constraints = serde_json::from_value::<Constraints>(json!({
+            "min": 0.5,
+            "pattern": "[",
+        }))
+        .unwrap();
+        assert_eq!(
+            constraints_violations_for_type(&constraints, &Type::I32).len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_nullability() {
+        use crate::Nullability;
+        use serde_json::json;
+
+        let context =
+            Context::new_from_folder(&PathBuf::from("src/codegen/fixtures/specs")).unwrap();
+        let spec = PathBuf::from("nullability.yaml");
+        let null = json!(null);
+        let value = json!("a");
+
+        for (nullability, absent_ok, null_ok) in [
+            (None, true, true),
+            (Some(Nullability::Optional), true, false),
+            (Some(Nullability::Nullable), false, true),
+            (Some(Nullability::OptionalNullable), true, true),
+        ] {
+            let mut field = FieldDef::new("value", Type::String);
+            field.nullability = nullability;
+
+            let check = |value| {
+                context
+                    .validate_value_for_field(value, &field, &spec)
+                    .is_empty()
+            };
+            assert_eq!(check(None), absent_ok, "{nullability:?}");
+            assert_eq!(check(Some(&null)), null_ok, "{nullability:?}");
+            assert!(check(Some(&value)), "{nullability:?}");
+        }
+    }
+
+    #[test]
+    fn test_enum_example() {
+        use serde_json::json;
+
+        let context =
+            Context::new_from_folder(&PathBuf::from("src/codegen/fixtures/specs")).unwrap();
+        let spec = PathBuf::from("enum_representation.yaml");
+        let def = context.get_definition(&spec).unwrap();
+        let check = |model_name: &str, value: serde_json::Value| {
+            let model = def.get_model(model_name).unwrap();
+            context
+                .validate_example_for_model_type(&model.type_, &value, &spec)
+                .is_empty()
+        };
+
+        assert!(check(
+            "InternalShape",
+            json!({"kind": "rect", "width": 1, "height": 2})
+        ));
+        assert!(!check("InternalShape", json!({"kind": "rect", "width": 1})));
+        assert!(!check("InternalShape", json!({"kind": "triangle"})));
+        assert!(check("ExternalShape", json!({"Size": 1.5})));
+        assert!(check("ExternalShape", json!("Empty")));
+        assert!(!check("ExternalShape", json!({"Size": "1.5"})));
+        assert!(!check("ExternalShape", json!("Size")));
+        assert!(check("UntaggedValue", json!(null)));
+        assert!(check("UntaggedValue", json!({"radius": 1})));
+        assert!(!check("UntaggedValue", json!(true)));
+    }
+
+    #[test]
+    fn test_generic_example() {
+        use serde_json::json;
+
+        let context =
+            Context::new_from_folder(&PathBuf::from("src/codegen/fixtures/specs")).unwrap();
+        let spec = PathBuf::from("generic.yaml");
+        let ty = Type::reference("UserList");
+        let check = |value: serde_json::Value| {
+            context
+                .validate_value_for_type(&value, &ty, true, &spec)
+                .is_empty()
+        };
+
+        assert!(check(
+            json!({"users": {"items": [{"name": "a"}]}, "pairs": []})
+        ));
+        // type arg of Page is User
+        assert!(!check(json!({"users": {"items": [1]}, "pairs": []})));
+        assert!(!check(
+            json!({"users": {"items": []}, "pairs": [{"first": 1}]})
+        ));
+        assert!(check(
+            json!({"users": {"items": []}, "pairs": [], "counts": {"items": [1]}})
+        ));
+    }
+
+    #[test]
+    fn test_map_key_set_example() {
+        use serde_json::json;
+
+        let context =
+            Context::new_from_folder(&PathBuf::from("src/codegen/fixtures/specs")).unwrap();
+        let spec = PathBuf::from("map_key_set.yaml");
+        let ty = Type::reference("Stats");
+        let check = |value: serde_json::Value| {
+            context
+                .validate_value_for_type(&value, &ty, true, &spec)
+                .is_empty()
+        };
+
+        assert!(check(json!({"names_by_id": {"1": "a"}, "tags": []})));
+        // key of names_by_id is i32
+        assert!(!check(json!({"names_by_id": {"a": "a"}, "tags": []})));
+        assert!(!check(
+            json!({"names_by_id": {"9999999999": "a"}, "tags": []})
+        ));
+        // key of count_by_reason is Reason
+        assert!(check(
+            json!({"names_by_id": {}, "count_by_reason": {"error": 1}, "tags": []})
+        ));
+        assert!(!check(
+            json!({"names_by_id": {}, "count_by_reason": {"unknown": 1}, "tags": []})
+        ));
+        // set items are unique
+        assert!(!check(json!({"names_by_id": {}, "tags": ["a", "a"]})));
+        assert!(!check(
+            json!({"names_by_id": {}, "tags": [], "flags": [256]})
+        ));
+    }
+
+    #[test]
+    fn test_reload() {
+        let folder = std::env::temp_dir().join(format!("tot_spec_reload_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&folder);
+        std::fs::create_dir_all(folder.join("nested")).unwrap();
+        let model = |name: &str| {
+            format!("models:\n  - name: {name}\n    type:\n      name: new_type\n      inner_type: string\n")
+        };
+        std::fs::write(folder.join("a.yaml"), model("A")).unwrap();
+        std::fs::write(folder.join("b.yaml"), model("B")).unwrap();
+
+        let context = Context::new_from_folder(&folder).unwrap();
+
+        std::fs::write(folder.join("a.yaml"), model("A2")).unwrap();
+        std::fs::remove_file(folder.join("b.yaml")).unwrap();
+        std::fs::write(folder.join("nested/c.yaml"), model("C")).unwrap();
+        let context = context
+            .reload(&[
+                folder.join("a.yaml"),
+                folder.join("b.yaml"),
+                folder.join("nested/c.yaml"),
+                folder.join("a.yaml.swp"),
+            ])
+            .unwrap();
+
+        let specs = context
+            .iter_specs()
+            .map(|(spec, def)| (spec.clone(), def.models[0].name.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            specs,
+            vec![
+                (PathBuf::from("a.yaml"), "A2".to_string()),
+                (PathBuf::from("nested/c.yaml"), "C".to_string()),
+            ]
+        );
+
+        // broken spec is reported, previous context is still usable
+        std::fs::write(folder.join("a.yaml"), "models: [").unwrap();
+        assert!(context.reload(&[folder.join("a.yaml")]).is_err());
+        assert_eq!(context.iter_specs().count(), 2);
+
+        std::fs::remove_dir_all(&folder).unwrap();
+    }
+
+    #[test]
+    fn test_targets() {
+        let context =
+            Context::new_from_folder(&PathBuf::from("src/codegen/fixtures/targets")).unwrap();
+        let root = context.root_folder().parent().unwrap();
+        assert_eq!(
+            context.targets(),
+            &[
+                Target {
+                    codegen: "rs_serde".into(),
+                    output: root.join("server/src/model"),
+                },
+                Target {
+                    codegen: "typescript".into(),
+                    output: root.join("web/src/api"),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_specs() {
+        let context = Context::new_from_folder_unvalidated(&PathBuf::from(
+            "src/codegen/fixtures/invalid_specs",
+        ))
+        .unwrap();
+        let violations = context
+            .validate()
+            .into_iter()
+            .map(|d| {
+                assert_eq!(
+                    d.spec,
+                    PathBuf::from("src/codegen/fixtures/invalid_specs/a.yaml")
+                );
+                let location = d.location.unwrap();
+                (d.code, location.line, location.column, d.message)
+            })
+            .collect::<Vec<_>>();
+        let expected = [
+            (
+                "missing-include",
+                5,
+                5,
+                r#"include missing "missing.yaml" not found"#,
+            ),
+            (
+                "include-cycle",
+                3,
+                5,
+                r#"include cycle: "a.yaml" -> "b.yaml" -> "a.yaml""#,
+            ),
+            (
+                "unresolved-reference",
+                21,
+                17,
+                "Child references unknown model Unknown",
+            ),
+            (
+                "unresolved-reference",
+                27,
+                17,
+                "Child references c.Other, namespace c is not included",
+            ),
+            (
+                "unresolved-reference",
+                65,
+                5,
+                "Get references unknown model GetResponse",
+            ),
+            (
+                "duplicate-name",
+                43,
+                5,
+                "model name Empty is used more than once",
+            ),
+            (
+                "duplicate-name",
+                24,
+                11,
+                "Child field name name is used more than once",
+            ),
+            (
+                "duplicate-name",
+                34,
+                11,
+                "Kind variant name A is used more than once",
+            ),
+            (
+                "duplicate-name",
+                56,
+                11,
+                "Reason const name Ok is used more than once",
+            ),
+            (
+                "duplicate-value",
+                54,
+                11,
+                r#"Reason const value "ok" is used more than once"#,
+            ),
+            (
+                "invalid-extend",
+                15,
+                5,
+                "Child extends Base which is not virtual",
+            ),
+            (
+                "invalid-extend",
+                59,
+                5,
+                "Remote extends b.Other which is not in the same spec",
+            ),
+        ]
+        .map(|(code, line, column, message)| (code, line, column, message.to_string()));
+        assert_eq!(violations, expected);
+    }
+}
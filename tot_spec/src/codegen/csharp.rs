@@ -0,0 +1,1025 @@
+use crate::codegen::utils::{indent, to_pascal_case};
+use crate::{
+    ConstType, ConstValueDef, Definition, EnumRepresentation, FieldDef, ModelDef, ModelType,
+    Nullability, StringOrInteger, StructDef, TimestampFormat, Type, TypeReference, VariantDef,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::Path;
+
+use super::context::Context;
+use super::output::{FileTree, OutputFile};
+
+pub struct CSharp {
+    context: Context,
+    config: CSharpConfig,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+pub struct CSharpConfig {
+    /// root namespace, spec `a/b.yaml` is in namespace `{namespace}.A.B`, default `Models`
+    namespace: Option<String>,
+}
+
+/// where a type is rendered
+struct Scope<'a> {
+    /// namespace of the rendered file
+    namespace: &'a str,
+    /// classes nested in the enclosing enum class, they shadow models and system types with
+    /// the same name, so those are qualified
+    nested: BTreeSet<String>,
+}
+
+impl<'a> Scope<'a> {
+    fn new(namespace: &'a str) -> Self {
+        Self {
+            namespace,
+            nested: BTreeSet::new(),
+        }
+    }
+
+    /// name qualified by namespace if it is shadowed
+    fn qualify(&self, namespace: &str, name: &str) -> String {
+        match self.nested.contains(name) {
+            true => format!("global::{namespace}.{name}"),
+            false => name.to_string(),
+        }
+    }
+}
+
+impl super::Codegen for CSharp {
+    fn load_from_context(context: &Context) -> anyhow::Result<Self> {
+        let config = context.load_codegen_config::<CSharpConfig>("csharp")?;
+        Ok(Self {
+            context: context.clone(),
+            config: config.unwrap_or_default(),
+        })
+    }
+
+    fn generate(&self) -> anyhow::Result<FileTree> {
+        let mut tree = FileTree::new();
+        let mut uses_bigint = false;
+        for (spec_path, def) in self.context.iter_specs() {
+            let file_stem = spec_path.file_stem().unwrap_or_default().to_string_lossy();
+            let output_path =
+                spec_path.with_file_name(format!("{}.cs", to_pascal_case(&file_stem)));
+            tree.add(OutputFile::new(output_path, self.render(spec_path)?));
+
+            uses_bigint |= def
+                .models
+                .iter()
+                .flat_map(|m| m.member_types())
+                .any(|(_, ty)| matches!(ty, Type::BigInt));
+        }
+
+        // shared by all specs, it is in the root namespace
+        if uses_bigint {
+            tree.add(OutputFile::new(
+                "BigIntegerConverter.cs",
+                render_bigint_converter(self.root_namespace())?,
+            ));
+        }
+        Ok(tree)
+    }
+}
+
+impl CSharp {
+    fn root_namespace(&self) -> &str {
+        self.config.namespace.as_deref().unwrap_or("Models")
+    }
+
+    /// root namespace, followed by spec's folders and file stem, models of different specs
+    /// may have the same name
+    fn namespace(&self, spec_path: &Path) -> String {
+        let mut namespace = self.root_namespace().to_string();
+        for component in spec_path.with_extension("").iter() {
+            write!(
+                namespace,
+                ".{}",
+                to_pascal_case(&component.to_string_lossy())
+            )
+            .unwrap();
+        }
+        namespace
+    }
+
+    fn render(&self, spec_path: &Path) -> anyhow::Result<String> {
+        let def = self.context.get_definition(spec_path)?;
+        let namespace = self.namespace(spec_path);
+
+        let mut usings = BTreeSet::from(["System.Text.Json.Serialization"]);
+        let mut body = String::new();
+        for model in def.models.iter() {
+            check_bigint(model)?;
+
+            writeln!(body)?;
+            if let Some(desc) = &model.desc {
+                writeln!(body, "{}", xml_doc(desc))?;
+            }
+
+            let scope = Scope::new(&namespace);
+            let code = match &model.type_ {
+                ModelType::Struct(struct_def) => {
+                    self.render_class(model, struct_def, false, spec_path, &scope, &mut usings)?
+                }
+                ModelType::Virtual(struct_def) => {
+                    self.render_class(model, struct_def, true, spec_path, &scope, &mut usings)?
+                }
+                ModelType::NewType { inner_type } => {
+                    usings.insert("System.Text.Json");
+                    let inner = self.csharp_type(inner_type, spec_path, &scope, &mut usings)?;
+                    render_value_type(&model.name, &inner, &[], None)?
+                }
+                ModelType::Const { value_type, values } => {
+                    usings.insert("System.Text.Json");
+                    if !matches!(value_type, ConstType::String) {
+                        usings.insert("System.Globalization");
+                    }
+                    render_const(&model.name, value_type, values)?
+                }
+                ModelType::Enum {
+                    variants,
+                    tag_name,
+                    payload_name,
+                    representation,
+                } => {
+                    if *representation != EnumRepresentation::Adjacent {
+                        usings.insert("System.Text.Json");
+                    }
+                    if *representation == EnumRepresentation::External {
+                        usings.insert("System.Linq");
+                    }
+                    self.render_enum(
+                        model,
+                        variants,
+                        *representation,
+                        tag_name.as_deref().unwrap_or("type"),
+                        payload_name.as_deref().unwrap_or("payload"),
+                        def,
+                        spec_path,
+                        &namespace,
+                        &mut usings,
+                    )?
+                }
+            };
+            write!(body, "{code}")?;
+        }
+
+        let mut result = String::new();
+        writeln!(result, "#nullable enable")?;
+        writeln!(result)?;
+        for using in usings {
+            writeln!(result, "using {using};")?;
+        }
+        writeln!(result)?;
+        writeln!(result, "namespace {namespace};")?;
+        result.push_str(&body);
+        Ok(result)
+    }
+
+    /// type declared in spec_path and rendered in scope
+    fn csharp_type(
+        &self,
+        ty: &Type,
+        spec_path: &Path,
+        scope: &Scope,
+        usings: &mut BTreeSet<&'static str>,
+    ) -> anyhow::Result<String> {
+        let mut generic = |name: &str, args: &[&Type]| -> anyhow::Result<String> {
+            usings.insert("System.Collections.Generic");
+            let args = args
+                .iter()
+                .map(|arg| self.csharp_type(arg, spec_path, scope, usings))
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            Ok(format!(
+                "{}<{}>",
+                scope.qualify("System.Collections.Generic", name),
+                args.join(", ")
+            ))
+        };
+
+        Ok(match ty {
+            Type::Bool => "bool".into(),
+            Type::I8 => "sbyte".into(),
+            Type::I16 => "short".into(),
+            Type::I32 => "int".into(),
+            Type::I64 => "long".into(),
+            Type::U8 => "byte".into(),
+            Type::U16 => "ushort".into(),
+            Type::U32 => "uint".into(),
+            Type::U64 => "ulong".into(),
+            Type::F32 => "float".into(),
+            Type::F64 => "double".into(),
+            Type::Decimal => "decimal".into(),
+            Type::BigInt => {
+                usings.insert("System.Numerics");
+                scope.qualify("System.Numerics", "BigInteger")
+            }
+            // System.Text.Json encodes byte[] as base64 string
+            Type::Bytes => "byte[]".into(),
+            Type::String => "string".into(),
+            Type::Timestamp {
+                format: TimestampFormat::Rfc3339,
+            } => {
+                usings.insert("System");
+                scope.qualify("System", "DateTimeOffset")
+            }
+            Type::Date => {
+                usings.insert("System");
+                scope.qualify("System", "DateOnly")
+            }
+            // millis
+            Type::Timestamp {
+                format: TimestampFormat::EpochMillis,
+            }
+            | Type::Duration => "long".into(),
+            Type::List { item_type } => generic("List", &[item_type])?,
+            Type::Set { item_type } => generic("HashSet", &[item_type])?,
+            Type::Map {
+                key_type,
+                value_type,
+            } => generic("Dictionary", &[key_type, value_type])?,
+            Type::Reference(type_ref) => {
+                self.csharp_type_reference(type_ref, spec_path, scope, usings)?
+            }
+            Type::TypeParam { param } => param.clone(),
+            Type::Json => {
+                usings.insert("System.Text.Json");
+                scope.qualify("System.Text.Json", "JsonElement")
+            }
+        })
+    }
+
+    /// models in other namespaces are qualified
+    fn csharp_type_reference(
+        &self,
+        type_ref: &TypeReference,
+        spec_path: &Path,
+        scope: &Scope,
+        usings: &mut BTreeSet<&'static str>,
+    ) -> anyhow::Result<String> {
+        let target_path = match &type_ref.namespace {
+            Some(namespace) => self.context.get_include_path(namespace, spec_path)?,
+            None => spec_path.to_path_buf(),
+        };
+        let target_namespace = self.namespace(&target_path);
+        let mut name = match target_namespace == scope.namespace {
+            true => scope.qualify(&target_namespace, &type_ref.target),
+            false => format!("global::{target_namespace}.{}", type_ref.target),
+        };
+
+        if !type_ref.args.is_empty() {
+            let args = type_ref
+                .args
+                .iter()
+                .map(|arg| self.csharp_type(arg, spec_path, scope, usings))
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            write!(name, "<{}>", args.join(", "))?;
+        }
+        Ok(name)
+    }
+
+    /// property with `required` if it should be present, nullable if it may be absent or null
+    fn render_property(
+        &self,
+        field: &FieldDef,
+        def: &Definition,
+        spec_path: &Path,
+        scope: &Scope,
+        usings: &mut BTreeSet<&'static str>,
+    ) -> anyhow::Result<String> {
+        let mut result = String::new();
+        if let Some(desc) = &field.desc {
+            writeln!(result, "{}", xml_doc(desc))?;
+        }
+        if field.nullability == Some(Nullability::OptionalNullable) {
+            writeln!(result, "// absent and null are both decoded as null")?;
+        }
+
+        writeln!(
+            result,
+            "[JsonPropertyName({})]",
+            serde_json::to_string(&def.field_wire_name(field))?
+        )?;
+        if field.may_be_absent() {
+            writeln!(
+                result,
+                "[JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]"
+            )?;
+        }
+        if let Some(attribute) = type_attribute(&field.type_) {
+            writeln!(result, "{attribute}")?;
+        }
+
+        let mut ty = self.csharp_type(&field.type_, spec_path, scope, usings)?;
+        if !field.required {
+            ty.push('?');
+        }
+        let required = match field.required || field.nullability == Some(Nullability::Nullable) {
+            true => "required ",
+            false => "",
+        };
+        writeln!(
+            result,
+            "public {required}{ty} {} {{ get; set; }}",
+            to_pascal_case(&field.name)
+        )?;
+        Ok(result)
+    }
+
+    /// class for struct, abstract class for virtual model
+    fn render_class(
+        &self,
+        model: &ModelDef,
+        struct_def: &StructDef,
+        is_virtual: bool,
+        spec_path: &Path,
+        scope: &Scope,
+        usings: &mut BTreeSet<&'static str>,
+    ) -> anyhow::Result<String> {
+        let def = self.context.get_definition(spec_path)?;
+        let modifier = match is_virtual {
+            true => "abstract ",
+            false => "",
+        };
+        let mut header = format!("public {modifier}class {}", model.name);
+        if !model.type_params.is_empty() {
+            write!(header, "<{}>", model.type_params.join(", "))?;
+        }
+        // properties of virtual model are inherited
+        if let Some(extend) = &struct_def.extend {
+            let type_ref = TypeReference::try_parse(extend)
+                .ok_or_else(|| anyhow::anyhow!("invalid extend {extend}"))?;
+            write!(
+                header,
+                " : {}",
+                self.csharp_type_reference(&type_ref, spec_path, scope, usings)?
+            )?;
+        }
+
+        let properties = struct_def
+            .fields
+            .iter()
+            .map(|field| self.render_property(field, def, spec_path, scope, usings))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        render_block(&header, &properties)
+    }
+
+    /// abstract class with a nested class for each variant. adjacent is System.Text.Json's
+    /// own polymorphism with type discriminator, other representations have a converter
+    #[allow(clippy::too_many_arguments)]
+    fn render_enum(
+        &self,
+        model: &ModelDef,
+        variants: &[VariantDef],
+        representation: EnumRepresentation,
+        tag_name: &str,
+        payload_name: &str,
+        def: &Definition,
+        spec_path: &Path,
+        namespace: &str,
+        usings: &mut BTreeSet<&'static str>,
+    ) -> anyhow::Result<String> {
+        let name = &model.name;
+        let adjacent = representation == EnumRepresentation::Adjacent;
+
+        let mut scope = Scope::new(namespace);
+        for variant in variants {
+            scope.nested.insert(variant.name.clone());
+            if variant.payload_fields.is_some() {
+                scope.nested.insert(format!("{}Payload", variant.name));
+            }
+        }
+        if !adjacent {
+            scope.nested.insert("Converter".to_string());
+        }
+
+        let mut members = vec![];
+        for variant in variants {
+            let variant_name = &variant.name;
+            let mut code = String::new();
+            if let Some(desc) = &variant.desc {
+                writeln!(code, "{}", xml_doc(desc))?;
+            }
+
+            let payload_type = match (&variant.payload_type, &variant.payload_fields) {
+                (Some(payload_type), _) => {
+                    Some(self.csharp_type(payload_type, spec_path, &scope, usings)?)
+                }
+                (None, Some(_)) => Some(format!("{variant_name}Payload")),
+                (None, None) => None,
+            };
+            let mut properties = vec![];
+            if let Some(payload_type) = &payload_type {
+                let mut property = String::new();
+                if adjacent {
+                    writeln!(
+                        property,
+                        "[JsonPropertyName({})]",
+                        serde_json::to_string(payload_name)?
+                    )?;
+                }
+                write!(
+                    property,
+                    "public required {payload_type} {} {{ get; set; }}",
+                    to_pascal_case(payload_name)
+                )?;
+                properties.push(property);
+            }
+            let header = format!("public sealed class {variant_name} : {name}");
+            code.push_str(&render_block(&header, &properties)?);
+            members.push(code);
+
+            if let (None, Some(fields)) = (&variant.payload_type, &variant.payload_fields) {
+                let properties = fields
+                    .iter()
+                    .map(|field| self.render_property(field, def, spec_path, &scope, usings))
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+                let header = format!("public sealed class {variant_name}Payload");
+                members.push(render_block(&header, &properties)?);
+            }
+        }
+
+        let mut result = String::new();
+        if adjacent {
+            writeln!(
+                result,
+                "[JsonPolymorphic(TypeDiscriminatorPropertyName = {})]",
+                serde_json::to_string(tag_name)?
+            )?;
+            for variant in variants {
+                writeln!(
+                    result,
+                    "[JsonDerivedType(typeof({name}.{}), {})]",
+                    variant.name,
+                    serde_json::to_string(&def.variant_wire_name(variant))?
+                )?;
+            }
+        } else {
+            writeln!(result, "[JsonConverter(typeof({name}.Converter))]")?;
+            members.push(render_converter(
+                model,
+                variants,
+                representation,
+                tag_name,
+                payload_name,
+                def,
+                &scope,
+                self,
+                spec_path,
+                usings,
+            )?);
+        }
+        result.push_str(&render_block(
+            &format!("public abstract class {name}"),
+            &members,
+        )?);
+        Ok(result)
+    }
+}
+
+/// converter works on JsonElement, for representations System.Text.Json does not support
+#[allow(clippy::too_many_arguments)]
+fn render_converter(
+    model: &ModelDef,
+    variants: &[VariantDef],
+    representation: EnumRepresentation,
+    tag_name: &str,
+    payload_name: &str,
+    def: &Definition,
+    scope: &Scope,
+    codegen: &CSharp,
+    spec_path: &Path,
+    usings: &mut BTreeSet<&'static str>,
+) -> anyhow::Result<String> {
+    let name = &model.name;
+    let tag = serde_json::to_string(tag_name)?;
+    let property = to_pascal_case(payload_name);
+    let has_payload = |v: &VariantDef| v.payload_type.is_some() || v.payload_fields.is_some();
+    let unit_variant = variants.iter().find(|v| !has_payload(v));
+
+    // payload type to deserialize
+    let mut payload_types = vec![];
+    for variant in variants {
+        payload_types.push(match (&variant.payload_type, &variant.payload_fields) {
+            (Some(payload_type), _) => {
+                Some(codegen.csharp_type(payload_type, spec_path, scope, usings)?)
+            }
+            (None, Some(_)) => Some(format!("{}Payload", variant.name)),
+            (None, None) => None,
+        });
+    }
+    // expression creates the variant from payload element
+    let decode = |idx: usize, element: &str| match &payload_types[idx] {
+        Some(payload_type) => format!(
+            "new {} {{ {property} = {element}.Deserialize<{payload_type}>(options)! }}",
+            variants[idx].name
+        ),
+        None => format!("new {}()", variants[idx].name),
+    };
+    let wire_name = |v: &VariantDef| serde_json::to_string(&def.variant_wire_name(v));
+    let not_variant =
+        format!("throw new JsonException($\"{{value.GetType()}} is not a variant of {name}\")");
+    let unknown = format!("throw new JsonException($\"unknown variant {{tag}} of {name}\")");
+
+    let mut read = String::new();
+    writeln!(
+        read,
+        "var element = JsonSerializer.Deserialize<JsonElement>(ref reader, options);"
+    )?;
+    let mut write = String::new();
+    match representation {
+        EnumRepresentation::Internal => {
+            writeln!(
+                read,
+                "var tag = element.TryGetProperty({tag}, out var tagElement) ? tagElement.GetString() : null;"
+            )?;
+            writeln!(read, "return tag switch")?;
+            writeln!(read, "{{")?;
+            for (idx, variant) in variants.iter().enumerate() {
+                writeln!(
+                    read,
+                    "    {} => {},",
+                    wire_name(variant)?,
+                    decode(idx, "element")
+                )?;
+            }
+            writeln!(read, "    _ => {unknown},")?;
+            writeln!(read, "}};")?;
+        }
+        EnumRepresentation::External => {
+            // variant without payload is a string, others are an object with one key
+            if unit_variant.is_some() {
+                writeln!(read, "if (element.ValueKind == JsonValueKind.String)")?;
+                writeln!(read, "{{")?;
+                writeln!(read, "    return element.GetString() switch")?;
+                writeln!(read, "    {{")?;
+                for variant in variants.iter().filter(|v| !has_payload(v)) {
+                    writeln!(
+                        read,
+                        "        {} => new {}(),",
+                        wire_name(variant)?,
+                        variant.name
+                    )?;
+                }
+                writeln!(read, "        var tag => {unknown},")?;
+                writeln!(read, "    }};")?;
+                writeln!(read, "}}")?;
+            }
+            writeln!(
+                read,
+                "if (element.ValueKind != JsonValueKind.Object || element.EnumerateObject().Count() != 1)"
+            )?;
+            writeln!(read, "{{")?;
+            writeln!(
+                read,
+                "    throw new JsonException(\"expect one key for {name}\");"
+            )?;
+            writeln!(read, "}}")?;
+            writeln!(read, "var property = element.EnumerateObject().First();")?;
+            writeln!(read, "return property.Name switch")?;
+            writeln!(read, "{{")?;
+            for (idx, variant) in variants.iter().enumerate() {
+                if has_payload(variant) {
+                    writeln!(
+                        read,
+                        "    {} => {},",
+                        wire_name(variant)?,
+                        decode(idx, "property.Value")
+                    )?;
+                }
+            }
+            writeln!(read, "    var tag => {unknown},")?;
+            writeln!(read, "}};")?;
+        }
+        _ => {
+            // null only matches variant without payload, check it first as it may decode
+            // into a payload type
+            if let Some(variant) = unit_variant {
+                writeln!(read, "if (element.ValueKind == JsonValueKind.Null)")?;
+                writeln!(read, "{{")?;
+                writeln!(read, "    return new {}();", variant.name)?;
+                writeln!(read, "}}")?;
+            }
+            // try variants in order, the first match wins
+            for (idx, variant) in variants.iter().enumerate() {
+                if !has_payload(variant) {
+                    continue;
+                }
+                writeln!(read, "try")?;
+                writeln!(read, "{{")?;
+                writeln!(read, "    return {};", decode(idx, "element"))?;
+                writeln!(read, "}}")?;
+                writeln!(read, "catch (JsonException)")?;
+                writeln!(read, "{{")?;
+                writeln!(read, "    // try next variant")?;
+                writeln!(read, "}}")?;
+            }
+            writeln!(
+                read,
+                "throw new JsonException($\"no variant of {name} matches {{element}}\");"
+            )?;
+        }
+    }
+
+    match representation {
+        EnumRepresentation::Internal | EnumRepresentation::External => {
+            writeln!(
+                write,
+                "(string Tag, JsonElement? Payload) tagged = value switch"
+            )?;
+            writeln!(write, "{{")?;
+            for variant in variants {
+                match has_payload(variant) {
+                    true => writeln!(
+                        write,
+                        "    {} v => ({}, JsonSerializer.SerializeToElement(v.{property}, options)),",
+                        variant.name,
+                        wire_name(variant)?
+                    )?,
+                    false => writeln!(
+                        write,
+                        "    {} => ({}, null),",
+                        variant.name,
+                        wire_name(variant)?
+                    )?,
+                }
+            }
+            writeln!(write, "    _ => {not_variant},")?;
+            writeln!(write, "}};")?;
+            if representation == EnumRepresentation::Internal {
+                writeln!(write, "// tag is merged into payload's fields")?;
+                writeln!(write, "writer.WriteStartObject();")?;
+                writeln!(write, "writer.WriteString({tag}, tagged.Tag);")?;
+                writeln!(write, "if (tagged.Payload is JsonElement fields)")?;
+                writeln!(write, "{{")?;
+                writeln!(write, "    foreach (var field in fields.EnumerateObject())")?;
+                writeln!(write, "    {{")?;
+                writeln!(write, "        field.WriteTo(writer);")?;
+                writeln!(write, "    }}")?;
+                writeln!(write, "}}")?;
+                writeln!(write, "writer.WriteEndObject();")?;
+            } else {
+                writeln!(write, "if (tagged.Payload is not JsonElement payload)")?;
+                writeln!(write, "{{")?;
+                writeln!(write, "    writer.WriteStringValue(tagged.Tag);")?;
+                writeln!(write, "    return;")?;
+                writeln!(write, "}}")?;
+                writeln!(write, "writer.WriteStartObject();")?;
+                writeln!(write, "writer.WritePropertyName(tagged.Tag);")?;
+                writeln!(write, "payload.WriteTo(writer);")?;
+                writeln!(write, "writer.WriteEndObject();")?;
+            }
+        }
+        _ => {
+            writeln!(write, "switch (value)")?;
+            writeln!(write, "{{")?;
+            for variant in variants {
+                match has_payload(variant) {
+                    true => {
+                        writeln!(write, "    case {} v:", variant.name)?;
+                        writeln!(
+                            write,
+                            "        JsonSerializer.Serialize(writer, v.{property}, options);"
+                        )?;
+                    }
+                    false => {
+                        writeln!(write, "    case {}:", variant.name)?;
+                        writeln!(write, "        writer.WriteNullValue();")?;
+                    }
+                }
+                writeln!(write, "        break;")?;
+            }
+            writeln!(write, "    default:")?;
+            writeln!(write, "        {not_variant};")?;
+            writeln!(write, "}}")?;
+        }
+    }
+
+    let mut members = vec![];
+    if representation == EnumRepresentation::Untagged && unit_variant.is_some() {
+        // null is passed to converter only if HandleNull is true
+        members.push("public override bool HandleNull => true;".to_string());
+    }
+    members.push(render_block(
+        &format!("public override {name} Read(ref Utf8JsonReader reader, System.Type typeToConvert, JsonSerializerOptions options)"),
+        &[read.trim_end().to_string()],
+    )?);
+    members.push(render_block(
+        &format!(
+            "public override void Write(Utf8JsonWriter writer, {name} value, JsonSerializerOptions options)"
+        ),
+        &[write.trim_end().to_string()],
+    )?);
+    render_block(
+        &format!("public sealed class Converter : JsonConverter<{name}>"),
+        &members,
+    )
+}
+
+/// readonly record struct wraps a value, it is encoded as the value
+fn render_value_type(
+    name: &str,
+    inner: &str,
+    statics: &[String],
+    property_name: Option<(String, String)>,
+) -> anyhow::Result<String> {
+    let mut converter = vec![
+        format!(
+            "public override {name} Read(ref Utf8JsonReader reader, System.Type typeToConvert, JsonSerializerOptions options) =>\n    new(JsonSerializer.Deserialize<{inner}>(ref reader, options)!);"
+        ),
+        format!(
+            "public override void Write(Utf8JsonWriter writer, {name} value, JsonSerializerOptions options) =>\n    JsonSerializer.Serialize(writer, value.Value, options);"
+        ),
+    ];
+    // used for dictionary keys
+    if let Some((read, write)) = property_name {
+        converter.push(format!(
+            "public override {name} ReadAsPropertyName(ref Utf8JsonReader reader, System.Type typeToConvert, JsonSerializerOptions options) =>\n    new({read});"
+        ));
+        converter.push(format!(
+            "public override void WriteAsPropertyName(Utf8JsonWriter writer, {name} value, JsonSerializerOptions options) =>\n    writer.WritePropertyName({write});"
+        ));
+    }
+
+    let mut members = statics.to_vec();
+    members.push(render_block(
+        &format!("public sealed class Converter : JsonConverter<{name}>"),
+        &converter,
+    )?);
+
+    let mut result = String::new();
+    writeln!(result, "[JsonConverter(typeof({name}.Converter))]")?;
+    result.push_str(&render_block(
+        &format!("public readonly record struct {name}({inner} Value)"),
+        &members,
+    )?);
+    Ok(result)
+}
+
+/// value type with a static field for each value
+fn render_const(
+    model_name: &str,
+    value_type: &ConstType,
+    values: &[ConstValueDef],
+) -> anyhow::Result<String> {
+    let inner = match value_type {
+        ConstType::I8 => "sbyte",
+        ConstType::I16 => "short",
+        ConstType::I32 => "int",
+        ConstType::I64 => "long",
+        ConstType::U8 => "byte",
+        ConstType::U16 => "ushort",
+        ConstType::U32 => "uint",
+        ConstType::U64 => "ulong",
+        ConstType::String => "string",
+    };
+
+    let mut statics = vec![];
+    for value in values {
+        let mut code = String::new();
+        if let Some(desc) = &value.desc {
+            writeln!(code, "{}", xml_doc(desc))?;
+        }
+        let literal = match &value.value {
+            StringOrInteger::String(s) => serde_json::to_string(s)?,
+            StringOrInteger::Integer(i) => i.to_string(),
+        };
+        write!(
+            code,
+            "public static readonly {model_name} {} = new({literal});",
+            to_pascal_case(&value.name)
+        )?;
+        statics.push(code);
+    }
+
+    let property_name = match value_type {
+        ConstType::String => ("reader.GetString()!".to_string(), "value.Value".to_string()),
+        _ => (
+            format!("{inner}.Parse(reader.GetString()!, CultureInfo.InvariantCulture)"),
+            "value.Value.ToString(CultureInfo.InvariantCulture)".to_string(),
+        ),
+    };
+    render_value_type(model_name, inner, &statics, Some(property_name))
+}
+
+fn render_bigint_converter(namespace: &str) -> anyhow::Result<String> {
+    let mut result = String::new();
+    writeln!(result, "#nullable enable")?;
+    writeln!(result)?;
+    for using in [
+        "System.Globalization",
+        "System.Numerics",
+        "System.Text.Json",
+        "System.Text.Json.Serialization",
+    ] {
+        writeln!(result, "using {using};")?;
+    }
+    writeln!(result)?;
+    writeln!(result, "namespace {namespace};")?;
+    writeln!(result)?;
+    writeln!(
+        result,
+        "/// <summary>BigInteger is encoded as json string</summary>"
+    )?;
+    result.push_str(&render_block(
+        "public sealed class BigIntegerConverter : JsonConverter<BigInteger>",
+        &[
+            "public override BigInteger Read(ref Utf8JsonReader reader, System.Type typeToConvert, JsonSerializerOptions options) =>\n    BigInteger.Parse(reader.GetString()!, CultureInfo.InvariantCulture);".to_string(),
+            "public override void Write(Utf8JsonWriter writer, BigInteger value, JsonSerializerOptions options) =>\n    writer.WriteStringValue(value.ToString(CultureInfo.InvariantCulture));".to_string(),
+        ],
+    )?);
+    Ok(result)
+}
+
+/// decimal and bigint are encoded as json string
+fn type_attribute(ty: &Type) -> Option<&'static str> {
+    match ty {
+        Type::BigInt => Some("[JsonConverter(typeof(BigIntegerConverter))]"),
+        _ if contains_type(ty, &|ty| matches!(ty, Type::Decimal)) => Some(
+            "[JsonNumberHandling(JsonNumberHandling.AllowReadingFromString | JsonNumberHandling.WriteAsString)]",
+        ),
+        _ => None,
+    }
+}
+
+/// converter for bigint is set by attribute of property, so it can only be field's type
+fn check_bigint(model: &ModelDef) -> anyhow::Result<()> {
+    let is_field = matches!(model.type_, ModelType::Struct(_) | ModelType::Virtual(_));
+    for (member, ty) in model.member_types() {
+        let direct = is_field && matches!(ty, Type::BigInt);
+        if !direct && contains_type(ty, &|ty| matches!(ty, Type::BigInt)) {
+            anyhow::bail!(
+                "{}.{} csharp only supports bigint as field type",
+                model.name,
+                member.unwrap_or_default()
+            );
+        }
+    }
+    Ok(())
+}
+
+fn contains_type(ty: &Type, pred: &dyn Fn(&Type) -> bool) -> bool {
+    if pred(ty) {
+        return true;
+    }
+    match ty {
+        Type::List { item_type } | Type::Set { item_type } => contains_type(item_type, pred),
+        Type::Map {
+            key_type,
+            value_type,
+        } => contains_type(key_type, pred) || contains_type(value_type, pred),
+        Type::Reference(type_ref) => type_ref.args.iter().any(|arg| contains_type(arg, pred)),
+        _ => false,
+    }
+}
+
+/// header followed by members in braces, members are separated by an empty line
+fn render_block(header: &str, members: &[String]) -> anyhow::Result<String> {
+    let mut result = String::new();
+    writeln!(result, "{header}")?;
+    writeln!(result, "{{")?;
+    for (idx, member) in members.iter().enumerate() {
+        if idx > 0 {
+            writeln!(result)?;
+        }
+        writeln!(result, "{}", indent(member.trim_end(), 1))?;
+    }
+    writeln!(result, "}}")?;
+    Ok(result)
+}
+
+fn xml_doc(desc: &str) -> String {
+    let desc = desc
+        .trim()
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;");
+    match desc.contains('\n') {
+        true => format!(
+            "/// <summary>\n{}\n/// </summary>",
+            desc.lines()
+                .map(|line| format!("/// {line}"))
+                .collect::<Vec<_>>()
+                .join("\n")
+        ),
+        false => format!("/// <summary>{desc}</summary>"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codegen::Codegen;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_render() {
+        fn test_def(spec: &Path, code_path: &str) {
+            let spec = spec.strip_prefix("src/codegen/fixtures/specs/").unwrap();
+
+            let codegen =
+                CSharp::load_from_folder(&PathBuf::from("src/codegen/fixtures/specs/")).unwrap();
+
+            let rendered = codegen.render(spec).unwrap();
+
+            let code = std::fs::read_to_string(code_path).unwrap();
+
+            #[cfg(not(feature = "test_update_spec"))]
+            pretty_assertions::assert_eq!(code.trim(), rendered.trim());
+
+            #[cfg(feature = "test_update_spec")]
+            {
+                if code.trim() != rendered.trim() {
+                    std::fs::write(code_path, rendered).unwrap();
+                }
+            }
+        }
+
+        for (spec, expected) in &[
+            (
+                "src/codegen/fixtures/specs/simple_struct.yaml",
+                "src/codegen/fixtures/csharp/simple_struct.cs",
+            ),
+            (
+                "src/codegen/fixtures/specs/enum.yaml",
+                "src/codegen/fixtures/csharp/enum.cs",
+            ),
+            (
+                "src/codegen/fixtures/specs/enum_custom_tag.yaml",
+                "src/codegen/fixtures/csharp/enum_custom_tag.cs",
+            ),
+            (
+                "src/codegen/fixtures/specs/enum_variant_fields.yaml",
+                "src/codegen/fixtures/csharp/enum_variant_fields.cs",
+            ),
+            (
+                "src/codegen/fixtures/specs/enum_representation.yaml",
+                "src/codegen/fixtures/csharp/enum_representation.cs",
+            ),
+            (
+                "src/codegen/fixtures/specs/extend.yaml",
+                "src/codegen/fixtures/csharp/extend.cs",
+            ),
+            (
+                "src/codegen/fixtures/specs/new_type.yaml",
+                "src/codegen/fixtures/csharp/new_type.cs",
+            ),
+            (
+                "src/codegen/fixtures/specs/const_i8.yaml",
+                "src/codegen/fixtures/csharp/const_i8.cs",
+            ),
+            (
+                "src/codegen/fixtures/specs/const_string.yaml",
+                "src/codegen/fixtures/csharp/const_string.cs",
+            ),
+            (
+                "src/codegen/fixtures/specs/include_test.yaml",
+                "src/codegen/fixtures/csharp/include_test.cs",
+            ),
+            (
+                "src/codegen/fixtures/specs/time.yaml",
+                "src/codegen/fixtures/csharp/time.cs",
+            ),
+            (
+                "src/codegen/fixtures/specs/unsigned.yaml",
+                "src/codegen/fixtures/csharp/unsigned.cs",
+            ),
+            (
+                "src/codegen/fixtures/specs/decimal.yaml",
+                "src/codegen/fixtures/csharp/decimal.cs",
+            ),
+            (
+                "src/codegen/fixtures/specs/bigint.yaml",
+                "src/codegen/fixtures/csharp/bigint.cs",
+            ),
+            (
+                "src/codegen/fixtures/specs/json.yaml",
+                "src/codegen/fixtures/csharp/json.cs",
+            ),
+            (
+                "src/codegen/fixtures/specs/nullability.yaml",
+                "src/codegen/fixtures/csharp/nullability.cs",
+            ),
+            (
+                "src/codegen/fixtures/specs/wire_name.yaml",
+                "src/codegen/fixtures/csharp/wire_name.cs",
+            ),
+            (
+                "src/codegen/fixtures/specs/generic.yaml",
+                "src/codegen/fixtures/csharp/generic.cs",
+            ),
+            (
+                "src/codegen/fixtures/specs/map_key_set.yaml",
+                "src/codegen/fixtures/csharp/map_key_set.cs",
+            ),
+            (
+                "src/codegen/fixtures/specs/empty_struct.yaml",
+                "src/codegen/fixtures/csharp/empty_struct.cs",
+            ),
+        ] {
+            test_def(PathBuf::from(spec).as_path(), expected);
+        }
+    }
+}
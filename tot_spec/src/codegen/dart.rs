@@ -0,0 +1,1007 @@
+use crate::codegen::utils::{indent, multiline_prefix_with};
+use crate::{
+    ConstType, ConstValueDef, Definition, EnumRepresentation, FieldDef, ModelDef, ModelType,
+    Nullability, StringOrInteger, StructDef, TimestampFormat, Type, TypeReference, VariantDef,
+};
+use convert_case::Casing;
+use std::fmt::Write;
+use std::path::Path;
+
+use super::context::Context;
+use super::output::{FileTree, OutputFile};
+
+pub struct Dart {
+    context: Context,
+}
+
+impl super::Codegen for Dart {
+    fn load_from_context(context: &Context) -> anyhow::Result<Self> {
+        Ok(Self {
+            context: context.clone(),
+        })
+    }
+
+    fn generate(&self) -> anyhow::Result<FileTree> {
+        let mut tree = FileTree::new();
+        for (spec_path, _) in self.context.iter_specs() {
+            let mut output = spec_path.clone();
+            output.set_extension("dart");
+            tree.add(OutputFile::new(output, self.render(spec_path)?));
+        }
+        Ok(tree)
+    }
+}
+
+impl Dart {
+    fn render(&self, spec_path: &Path) -> anyhow::Result<String> {
+        let def = self.context.get_definition(spec_path)?;
+
+        let mut result = String::new();
+
+        // includes are imported with namespace as prefix
+        for include in def.includes.iter() {
+            let include_path = self
+                .context
+                .get_include_path(&include.namespace, spec_path)?;
+            let folder = spec_path.parent().unwrap_or(Path::new(""));
+            let mut relative_path = pathdiff::diff_paths(&include_path, folder)
+                .ok_or_else(|| anyhow::anyhow!("failed to import {include_path:?}"))?;
+            relative_path.set_extension("dart");
+            let relative_path = relative_path.to_string_lossy().replace('\\', "/");
+            writeln!(
+                result,
+                "import {} as {};",
+                dart_string(&relative_path),
+                include.namespace
+            )?;
+        }
+
+        for model in def.models.iter() {
+            if !result.is_empty() {
+                writeln!(result)?;
+            }
+            if let Some(desc) = &model.desc {
+                writeln!(result, "{}", multiline_prefix_with(desc, "/// "))?;
+            }
+
+            let code = match &model.type_ {
+                ModelType::Struct(struct_def) => {
+                    self.render_struct(model, struct_def, spec_path)?
+                }
+                ModelType::Virtual(struct_def) => self.render_virtual(model, struct_def)?,
+                ModelType::NewType { inner_type } => {
+                    ensure_no_type_params(model)?;
+                    self.render_new_type(&model.name, inner_type, spec_path)?
+                }
+                ModelType::Const { value_type, values } => {
+                    render_const(&model.name, value_type, values)?
+                }
+                ModelType::Enum {
+                    variants,
+                    tag_name,
+                    payload_name,
+                    representation,
+                } => {
+                    ensure_no_type_params(model)?;
+                    self.render_enum(
+                        model,
+                        variants,
+                        *representation,
+                        tag_name.as_deref().unwrap_or("type"),
+                        payload_name.as_deref().unwrap_or("payload"),
+                        def,
+                        spec_path,
+                    )?
+                }
+            };
+            write!(result, "{code}")?;
+        }
+        Ok(result)
+    }
+
+    /// dart type of field, nullable if it may be absent or null
+    fn dart_field_type(&self, field: &FieldDef) -> String {
+        let ty = dart_type(&field.type_);
+        match field.required || field.default.is_some() || matches!(*field.type_, Type::Json) {
+            true => ty,
+            false => format!("{ty}?"),
+        }
+    }
+
+    /// expression decodes `expr` of json value into ty
+    fn decode(&self, ty: &Type, expr: &str, spec_path: &Path) -> anyhow::Result<String> {
+        if let Some(cast) = simple_cast(ty) {
+            return Ok(format!("{expr} as {cast}"));
+        }
+        Ok(match ty {
+            Type::F32 | Type::F64 => format!("({expr} as num).toDouble()"),
+            Type::BigInt => format!("BigInt.parse({expr} as String)"),
+            Type::Bytes => format!("List<int>.from({expr} as List)"),
+            Type::Timestamp {
+                format: TimestampFormat::Rfc3339,
+            }
+            | Type::Date => format!("DateTime.parse({expr} as String)"),
+            Type::Timestamp {
+                format: TimestampFormat::EpochMillis,
+            } => format!("DateTime.fromMillisecondsSinceEpoch({expr} as int, isUtc: true)"),
+            Type::Duration => format!("Duration(milliseconds: {expr} as int)"),
+            Type::List { item_type } => format!(
+                "({expr} as List).map((e) => {}).toList()",
+                self.decode(item_type, "e", spec_path)?
+            ),
+            Type::Set { item_type } => format!(
+                "({expr} as List).map((e) => {}).toSet()",
+                self.decode(item_type, "e", spec_path)?
+            ),
+            // json object's key is always string
+            Type::Map {
+                key_type,
+                value_type,
+            } => format!(
+                "({expr} as Map<String, dynamic>).map((k, v) => MapEntry({}, {}))",
+                self.decode_key(key_type, "k", spec_path)?,
+                self.decode(value_type, "v", spec_path)?
+            ),
+            Type::Reference(type_ref) => {
+                let model = self
+                    .context
+                    .get_model_def_for_reference(type_ref, spec_path)?;
+                let json = match &model.type_ {
+                    ModelType::Struct(_) => format!("{expr} as Map<String, dynamic>"),
+                    ModelType::Virtual(_) => {
+                        anyhow::bail!("dart can not decode virtual model {}", model.name)
+                    }
+                    _ => expr.to_string(),
+                };
+                // converter for each type arg
+                let mut args = vec![json];
+                for arg in type_ref.args.iter() {
+                    args.push(format!("(e) => {}", self.decode(arg, "e", spec_path)?));
+                }
+                let name = match &type_ref.namespace {
+                    Some(namespace) => format!("{namespace}.{}", type_ref.target),
+                    None => type_ref.target.clone(),
+                };
+                format!("{name}.fromJson({})", args.join(", "))
+            }
+            Type::TypeParam { param } => format!("fromJson{param}({expr})"),
+            Type::Json => expr.to_string(),
+            _ => unreachable!("{ty:?} is decoded by cast"),
+        })
+    }
+
+    /// expression encodes `expr` of ty into json value
+    fn encode(&self, ty: &Type, expr: &str, spec_path: &Path) -> anyhow::Result<String> {
+        Ok(match ty {
+            Type::Bool
+            | Type::I8
+            | Type::I16
+            | Type::I32
+            | Type::I64
+            | Type::U8
+            | Type::U16
+            | Type::U32
+            | Type::U64
+            | Type::F32
+            | Type::F64
+            | Type::Decimal
+            | Type::Bytes
+            | Type::String
+            | Type::Json => expr.to_string(),
+            Type::BigInt => format!("{expr}.toString()"),
+            Type::Timestamp {
+                format: TimestampFormat::Rfc3339,
+            } => format!("{expr}.toUtc().toIso8601String()"),
+            Type::Timestamp {
+                format: TimestampFormat::EpochMillis,
+            } => format!("{expr}.millisecondsSinceEpoch"),
+            Type::Date => format!("{expr}.toIso8601String().substring(0, 10)"),
+            Type::Duration => format!("{expr}.inMilliseconds"),
+            Type::List { item_type } => match self.encode(item_type, "e", spec_path)? {
+                item if item == "e" => expr.to_string(),
+                item => format!("{expr}.map((e) => {item}).toList()"),
+            },
+            // json has no set
+            Type::Set { item_type } => match self.encode(item_type, "e", spec_path)? {
+                item if item == "e" => format!("{expr}.toList()"),
+                item => format!("{expr}.map((e) => {item}).toList()"),
+            },
+            Type::Map {
+                key_type,
+                value_type,
+            } => {
+                let key = self.encode_key(key_type, "k", spec_path)?;
+                let value = self.encode(value_type, "v", spec_path)?;
+                match key == "k" && value == "v" {
+                    true => expr.to_string(),
+                    false => format!("{expr}.map((k, v) => MapEntry({key}, {value}))"),
+                }
+            }
+            Type::Reference(type_ref) => {
+                let mut args = vec![];
+                for arg in type_ref.args.iter() {
+                    args.push(format!("(e) => {}", self.encode(arg, "e", spec_path)?));
+                }
+                format!("{expr}.toJson({})", args.join(", "))
+            }
+            Type::TypeParam { param } => format!("toJson{param}({expr})"),
+        })
+    }
+
+    /// map key is string in json, key type is string, integer or const
+    fn decode_key(&self, ty: &Type, expr: &str, spec_path: &Path) -> anyhow::Result<String> {
+        Ok(match ty {
+            Type::String => expr.to_string(),
+            ty if ty.is_integer() => format!("int.parse({expr})"),
+            Type::Reference(_) if self.context.is_string_const(ty, spec_path) => {
+                self.decode(ty, expr, spec_path)?
+            }
+            Type::Reference(_) => self.decode(ty, &format!("int.parse({expr})"), spec_path)?,
+            _ => anyhow::bail!("dart does not support map key {ty:?}"),
+        })
+    }
+
+    fn encode_key(&self, ty: &Type, expr: &str, spec_path: &Path) -> anyhow::Result<String> {
+        Ok(match ty {
+            Type::String => expr.to_string(),
+            ty if ty.is_integer() => format!("{expr}.toString()"),
+            Type::Reference(_) if self.context.is_string_const(ty, spec_path) => {
+                self.encode(ty, expr, spec_path)?
+            }
+            Type::Reference(_) => format!("{}.toString()", self.encode(ty, expr, spec_path)?),
+            _ => anyhow::bail!("dart does not support map key {ty:?}"),
+        })
+    }
+
+    /// immutable class with fromJson and toJson, fields of virtual model it extends are
+    /// overridden
+    fn render_struct(
+        &self,
+        model: &ModelDef,
+        struct_def: &StructDef,
+        spec_path: &Path,
+    ) -> anyhow::Result<String> {
+        let def = self.context.get_definition(spec_path)?;
+
+        let mut fields = vec![];
+        let mut header = format!("class {}{}", model.name, type_params(model));
+        if let Some(extend) = &struct_def.extend {
+            let type_ref = TypeReference::try_parse(extend)
+                .ok_or_else(|| anyhow::anyhow!("invalid extend {extend}"))?;
+            // fields declared by the virtual model are in base spec
+            let base_path = match &type_ref.namespace {
+                Some(namespace) => self.context.get_include_path(namespace, spec_path)?,
+                None => spec_path.to_path_buf(),
+            };
+            let base_def = self.context.get_definition(&base_path)?;
+            let base = self
+                .context
+                .get_model_def_for_reference(&type_ref, spec_path)?;
+            let ModelType::Virtual(base_struct) = &base.type_ else {
+                anyhow::bail!("{} extends {extend} which is not virtual", model.name);
+            };
+            fields.extend(
+                base_struct
+                    .fields
+                    .iter()
+                    .map(|f| (f, base_def, base_path.clone(), true)),
+            );
+            write!(
+                header,
+                " implements {}",
+                dart_type(&Type::Reference(type_ref))
+            )?;
+        }
+        fields.extend(
+            struct_def
+                .fields
+                .iter()
+                .map(|f| (f, def, spec_path.to_path_buf(), false)),
+        );
+
+        let mut members = vec![];
+        for (field, _, _, is_override) in fields.iter() {
+            let mut code = String::new();
+            if let Some(desc) = &field.desc {
+                writeln!(code, "{}", multiline_prefix_with(desc, "/// "))?;
+            }
+            if field.nullability == Some(Nullability::OptionalNullable) {
+                writeln!(code, "// absent and null are both decoded as null")?;
+            }
+            if *is_override {
+                writeln!(code, "@override")?;
+            }
+            write!(
+                code,
+                "final {} {};",
+                self.dart_field_type(field),
+                field_name(&field.name)
+            )?;
+            members.push(code);
+        }
+
+        // constructor
+        let mut params = vec![];
+        for (field, ..) in fields.iter() {
+            let name = field_name(&field.name);
+            params.push(match &field.default {
+                Some(default) => format!("this.{name} = {},", dart_const_literal(default)),
+                None if field.required || field.nullability == Some(Nullability::Nullable) => {
+                    format!("required this.{name},")
+                }
+                None => format!("this.{name},"),
+            });
+        }
+        members.push(match params.is_empty() {
+            true => format!("const {}();", model.name),
+            false => format!(
+                "const {}({{\n{}\n}});",
+                model.name,
+                indent(params.join("\n"), 1)
+            ),
+        });
+
+        // converter for each type param
+        let mut from_json_params = vec!["Map<String, dynamic> json".to_string()];
+        let mut to_json_params = vec![];
+        for param in model.type_params.iter() {
+            from_json_params.push(format!("{param} Function(Object? json) fromJson{param}"));
+            to_json_params.push(format!("Object? Function({param} value) toJson{param}"));
+        }
+
+        // fromJson
+        let mut args = vec![];
+        for (field, field_def, field_spec_path, _) in fields.iter() {
+            let json = format!("json[{}]", dart_string(&field_def.field_wire_name(field)));
+            let value = self.decode(&field.type_, &json, field_spec_path)?;
+            let value = match &field.default {
+                _ if field.required || matches!(*field.type_, Type::Json) => value,
+                Some(default) => format!("{json} == null ? {} : {value}", dart_literal(default)),
+                None => match simple_cast(&field.type_) {
+                    Some(cast) => format!("{json} as {cast}?"),
+                    None => format!("{json} == null ? null : {value}"),
+                },
+            };
+            args.push(format!("{}: {value},", field_name(&field.name)));
+        }
+        let mut from_json = format!(
+            "factory {}.fromJson({}) {{\n",
+            model.name,
+            from_json_params.join(", ")
+        );
+        match args.is_empty() {
+            true => writeln!(from_json, "    return const {}();", model.name)?,
+            false => writeln!(
+                from_json,
+                "    return {}(\n{}\n    );",
+                model.name,
+                indent(args.join("\n"), 2)
+            )?,
+        }
+        write!(from_json, "}}")?;
+        members.push(from_json);
+
+        // toJson, fields may be absent are omitted when null
+        let mut entries = vec![];
+        for (field, field_def, field_spec_path, _) in fields.iter() {
+            let key = dart_string(&field_def.field_wire_name(field));
+            let name = field_name(&field.name);
+            let value = self.encode(&field.type_, &name, field_spec_path)?;
+            if field.required || field.default.is_some() || matches!(*field.type_, Type::Json) {
+                entries.push(format!("{key}: {value},"));
+                continue;
+            }
+            let value = match value == name {
+                true => value,
+                false => self.encode(&field.type_, &format!("{name}!"), field_spec_path)?,
+            };
+            entries.push(match field.may_be_absent() {
+                true => format!("if ({name} != null) {key}: {value},"),
+                false if value == name => format!("{key}: {value},"),
+                false => format!("{key}: {name} == null ? null : {value},"),
+            });
+        }
+        let mut to_json = format!(
+            "Map<String, dynamic> toJson({}) {{\n",
+            to_json_params.join(", ")
+        );
+        match entries.is_empty() {
+            true => writeln!(to_json, "    return {{}};")?,
+            false => writeln!(
+                to_json,
+                "    return {{\n{}\n    }};",
+                indent(entries.join("\n"), 2)
+            )?,
+        }
+        write!(to_json, "}}")?;
+        members.push(to_json);
+
+        render_class(&header, &members)
+    }
+
+    /// abstract class declares getters for fields
+    fn render_virtual(&self, model: &ModelDef, struct_def: &StructDef) -> anyhow::Result<String> {
+        let mut header = format!("abstract class {}{}", model.name, type_params(model));
+        if let Some(extend) = &struct_def.extend {
+            let type_ref = TypeReference::try_parse(extend)
+                .ok_or_else(|| anyhow::anyhow!("invalid extend {extend}"))?;
+            write!(
+                header,
+                " implements {}",
+                dart_type(&Type::Reference(type_ref))
+            )?;
+        }
+
+        let mut members = vec![];
+        for field in struct_def.fields.iter() {
+            let mut code = String::new();
+            if let Some(desc) = &field.desc {
+                writeln!(code, "{}", multiline_prefix_with(desc, "/// "))?;
+            }
+            write!(
+                code,
+                "{} get {};",
+                self.dart_field_type(field),
+                field_name(&field.name)
+            )?;
+            members.push(code);
+        }
+        render_class(&header, &members)
+    }
+
+    fn render_new_type(
+        &self,
+        name: &str,
+        inner_type: &Type,
+        spec_path: &Path,
+    ) -> anyhow::Result<String> {
+        render_value_class(
+            name,
+            &dart_type(inner_type),
+            vec![],
+            &self.decode(inner_type, "json", spec_path)?,
+            &format!(
+                "Object? toJson() => {};",
+                self.encode(inner_type, "value", spec_path)?
+            ),
+        )
+    }
+
+    /// sealed class with a sub class for each variant, the sub class is named by enum name
+    /// followed by variant name
+    #[allow(clippy::too_many_arguments)]
+    fn render_enum(
+        &self,
+        model: &ModelDef,
+        variants: &[VariantDef],
+        representation: EnumRepresentation,
+        tag_name: &str,
+        payload_name: &str,
+        def: &Definition,
+        spec_path: &Path,
+    ) -> anyhow::Result<String> {
+        let enum_name = &model.name;
+        let tag = dart_string(tag_name);
+        let payload_key = dart_string(payload_name);
+        let payload_field = field_name(payload_name);
+        let invalid_type = "final type => throw FormatException('invalid type: $type'),";
+
+        // payload type of each variant, variant with payload fields has a payload class
+        let mut payload_types = vec![];
+        for variant in variants {
+            payload_types.push(match (&variant.payload_type, &variant.payload_fields) {
+                (Some(payload_type), _) => Some((**payload_type).clone()),
+                (None, Some(_)) => Some(Type::Reference(TypeReference {
+                    namespace: None,
+                    target: format!("{enum_name}{}Payload", variant.name),
+                    args: vec![],
+                })),
+                (None, None) => None,
+            });
+        }
+        let create = |idx: usize, json: &str| -> anyhow::Result<String> {
+            let variant_cls = format!("{enum_name}{}", variants[idx].name);
+            Ok(match &payload_types[idx] {
+                None => format!("const {variant_cls}()"),
+                // payload class is not in def, so decode it here
+                Some(_) if variants[idx].payload_type.is_none() => format!(
+                    "{variant_cls}({variant_cls}Payload.fromJson({json} as Map<String, dynamic>))"
+                ),
+                Some(payload_type) => format!(
+                    "{variant_cls}({})",
+                    self.decode(payload_type, json, spec_path)?
+                ),
+            })
+        };
+
+        let mut from_json = format!("factory {enum_name}.fromJson(Object? json) {{\n");
+        let mut body = String::new();
+        match representation {
+            EnumRepresentation::Adjacent | EnumRepresentation::Internal => {
+                writeln!(body, "final map = json as Map<String, dynamic>;")?;
+                writeln!(body, "return switch (map[{tag}]) {{")?;
+                for (idx, variant) in variants.iter().enumerate() {
+                    let json = match representation {
+                        EnumRepresentation::Adjacent => format!("map[{payload_key}]"),
+                        // the payload's fields are along with the tag
+                        _ => "json".to_string(),
+                    };
+                    writeln!(
+                        body,
+                        "    {} => {},",
+                        dart_string(&def.variant_wire_name(variant)),
+                        create(idx, &json)?
+                    )?;
+                }
+                writeln!(body, "    {invalid_type}")?;
+                writeln!(body, "}};")?;
+            }
+            EnumRepresentation::External => {
+                // variant without payload is a plain string
+                if payload_types.iter().any(|p| p.is_none()) {
+                    writeln!(body, "if (json is String) {{")?;
+                    writeln!(body, "    return switch (json) {{")?;
+                    for (idx, variant) in variants.iter().enumerate() {
+                        if payload_types[idx].is_none() {
+                            writeln!(
+                                body,
+                                "        {} => {},",
+                                dart_string(&def.variant_wire_name(variant)),
+                                create(idx, "json")?
+                            )?;
+                        }
+                    }
+                    writeln!(body, "        {invalid_type}")?;
+                    writeln!(body, "    }};")?;
+                    writeln!(body, "}}")?;
+                }
+                writeln!(
+                    body,
+                    "final entry = (json as Map<String, dynamic>).entries.single;"
+                )?;
+                writeln!(body, "return switch (entry.key) {{")?;
+                for (idx, variant) in variants.iter().enumerate() {
+                    if payload_types[idx].is_some() {
+                        writeln!(
+                            body,
+                            "    {} => {},",
+                            dart_string(&def.variant_wire_name(variant)),
+                            create(idx, "entry.value")?
+                        )?;
+                    }
+                }
+                writeln!(body, "    {invalid_type}")?;
+                writeln!(body, "}};")?;
+            }
+            EnumRepresentation::Untagged => {
+                if let Some(idx) = payload_types.iter().position(|p| p.is_none()) {
+                    writeln!(body, "if (json == null) {{")?;
+                    writeln!(body, "    return {};", create(idx, "json")?)?;
+                    writeln!(body, "}}")?;
+                }
+                // try variants in order, the first match wins
+                for (idx, payload_type) in payload_types.iter().enumerate() {
+                    if payload_type.is_some() {
+                        writeln!(body, "try {{")?;
+                        writeln!(body, "    return {};", create(idx, "json")?)?;
+                        writeln!(body, "}} catch (_) {{}}")?;
+                    }
+                }
+                writeln!(body, "throw FormatException('no variant matches: $json');")?;
+            }
+        }
+        writeln!(from_json, "{}", indent(body.trim_end(), 1))?;
+        write!(from_json, "}}")?;
+
+        let mut result = render_class(
+            &format!("sealed class {enum_name}"),
+            &[
+                format!("const {enum_name}();"),
+                from_json,
+                "Object? toJson();".to_string(),
+            ],
+        )?;
+
+        for (idx, variant) in variants.iter().enumerate() {
+            let variant_cls = format!("{enum_name}{}", variant.name);
+            let wire_name = dart_string(&def.variant_wire_name(variant));
+
+            writeln!(result)?;
+            if let Some(desc) = &variant.desc {
+                writeln!(result, "{}", multiline_prefix_with(desc, "/// "))?;
+            }
+
+            let mut members = vec![];
+            let payload = match &payload_types[idx] {
+                Some(payload_type) => {
+                    members.push(format!(
+                        "final {} {payload_field};",
+                        dart_type(payload_type)
+                    ));
+                    members.push(format!("const {variant_cls}(this.{payload_field});"));
+                    let payload = match variant.payload_type.is_some() {
+                        true => self.encode(payload_type, &payload_field, spec_path)?,
+                        false => format!("{payload_field}.toJson()"),
+                    };
+                    Some(payload)
+                }
+                None => {
+                    members.push(format!("const {variant_cls}();"));
+                    None
+                }
+            };
+
+            let json = match (representation, payload) {
+                (EnumRepresentation::Adjacent, Some(payload)) => {
+                    format!("{{{tag}: {wire_name}, {payload_key}: {payload}}}")
+                }
+                (EnumRepresentation::Internal, Some(payload)) => {
+                    format!("{{{tag}: {wire_name}, ...{payload}}}")
+                }
+                (EnumRepresentation::Adjacent | EnumRepresentation::Internal, None) => {
+                    format!("{{{tag}: {wire_name}}}")
+                }
+                (EnumRepresentation::External, Some(payload)) => {
+                    format!("{{{wire_name}: {payload}}}")
+                }
+                (EnumRepresentation::External, None) => wire_name,
+                (EnumRepresentation::Untagged, Some(payload)) => payload,
+                (EnumRepresentation::Untagged, None) => "null".to_string(),
+            };
+            members.push(format!("@override\nObject? toJson() => {json};"));
+
+            result.push_str(&render_class(
+                &format!("class {variant_cls} extends {enum_name}"),
+                &members,
+            )?);
+
+            if let (None, Some(fields)) = (&variant.payload_type, &variant.payload_fields) {
+                let payload_model = ModelDef {
+                    name: format!("{variant_cls}Payload"),
+                    desc: None,
+                    type_: ModelType::Struct(StructDef {
+                        extend: None,
+                        fields: fields.clone(),
+                    }),
+                    ..model.clone()
+                };
+                let ModelType::Struct(struct_def) = &payload_model.type_ else {
+                    unreachable!()
+                };
+                writeln!(result)?;
+                result.push_str(&self.render_struct(&payload_model, struct_def, spec_path)?);
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+fn dart_type(ty: &Type) -> String {
+    match ty {
+        Type::Bool => "bool".into(),
+        // dart's int is 64 bits
+        Type::I8
+        | Type::I16
+        | Type::I32
+        | Type::I64
+        | Type::U8
+        | Type::U16
+        | Type::U32
+        | Type::U64 => "int".into(),
+        Type::F32 | Type::F64 => "double".into(),
+        // dart has no builtin decimal, keep the string on the wire
+        Type::Decimal => "String".into(),
+        Type::BigInt => "BigInt".into(),
+        Type::Bytes => "List<int>".into(),
+        Type::String => "String".into(),
+        // dart has no date type, date is DateTime at midnight
+        Type::Timestamp { .. } | Type::Date => "DateTime".into(),
+        Type::Duration => "Duration".into(),
+        Type::List { item_type } => format!("List<{}>", dart_type(item_type)),
+        Type::Set { item_type } => format!("Set<{}>", dart_type(item_type)),
+        Type::Map {
+            key_type,
+            value_type,
+        } => format!("Map<{}, {}>", dart_type(key_type), dart_type(value_type)),
+        Type::Reference(type_ref) => {
+            let mut name = match &type_ref.namespace {
+                Some(namespace) => format!("{namespace}.{}", type_ref.target),
+                None => type_ref.target.clone(),
+            };
+            if !type_ref.args.is_empty() {
+                let args = type_ref.args.iter().map(dart_type).collect::<Vec<_>>();
+                write!(name, "<{}>", args.join(", ")).unwrap();
+            }
+            name
+        }
+        Type::TypeParam { param } => param.clone(),
+        Type::Json => "Object?".into(),
+    }
+}
+
+/// class with static const instance for each value
+fn render_const(
+    model_name: &str,
+    value_type: &ConstType,
+    values: &[ConstValueDef],
+) -> anyhow::Result<String> {
+    let value_type = match value_type {
+        ConstType::String => "String",
+        _ => "int",
+    };
+
+    let mut statics = vec![];
+    for value in values {
+        let mut code = String::new();
+        if let Some(desc) = &value.desc {
+            writeln!(code, "{}", multiline_prefix_with(desc, "/// "))?;
+        }
+        let literal = match &value.value {
+            StringOrInteger::String(s) => dart_string(s),
+            StringOrInteger::Integer(i) => i.to_string(),
+        };
+        write!(
+            code,
+            "static const {} = {model_name}({literal});",
+            field_name(&value.name)
+        )?;
+        statics.push(code);
+    }
+
+    render_value_class(
+        model_name,
+        value_type,
+        statics,
+        &format!("json as {value_type}"),
+        &format!("{value_type} toJson() => value;"),
+    )
+}
+
+/// class wraps a value, it is encoded as the value
+fn render_value_class(
+    name: &str,
+    value_type: &str,
+    statics: Vec<String>,
+    from_json: &str,
+    to_json: &str,
+) -> anyhow::Result<String> {
+    let mut members = vec![
+        format!("final {value_type} value;"),
+        format!("const {name}(this.value);"),
+    ];
+    members.extend(statics);
+    members.push(format!(
+        "factory {name}.fromJson(Object? json) => {name}({from_json});"
+    ));
+    members.push(to_json.to_string());
+    members.push(format!(
+        "@override\nbool operator ==(Object other) => other is {name} && other.value == value;"
+    ));
+    members.push("@override\nint get hashCode => value.hashCode;".to_string());
+    render_class(&format!("class {name}"), &members)
+}
+
+/// header followed by members in braces, members are separated by an empty line
+fn render_class(header: &str, members: &[String]) -> anyhow::Result<String> {
+    let mut result = String::new();
+    writeln!(result, "{header} {{")?;
+    for (idx, member) in members.iter().enumerate() {
+        if idx > 0 {
+            writeln!(result)?;
+        }
+        writeln!(result, "{}", indent(member, 1))?;
+    }
+    writeln!(result, "}}")?;
+    Ok(result)
+}
+
+fn ensure_no_type_params(model: &ModelDef) -> anyhow::Result<()> {
+    if !model.type_params.is_empty() {
+        anyhow::bail!("dart only supports type params for struct, {}", model.name);
+    }
+    Ok(())
+}
+
+fn type_params(model: &ModelDef) -> String {
+    match model.type_params.is_empty() {
+        true => String::new(),
+        false => format!("<{}>", model.type_params.join(", ")),
+    }
+}
+
+/// dart type if json value is decoded by a cast
+fn simple_cast(ty: &Type) -> Option<&'static str> {
+    match ty {
+        Type::Bool => Some("bool"),
+        Type::String | Type::Decimal => Some("String"),
+        ty if ty.is_integer() => Some("int"),
+        _ => None,
+    }
+}
+
+fn field_name(name: &str) -> String {
+    let name = name.to_case(convert_case::Case::Camel);
+    match DART_KEYWORDS.contains(&name.as_str()) {
+        true => format!("{name}_"),
+        false => name,
+    }
+}
+
+const DART_KEYWORDS: &[&str] = &[
+    "assert", "break", "case", "catch", "class", "const", "continue", "default", "do", "else",
+    "enum", "extends", "false", "final", "finally", "for", "if", "in", "is", "new", "null",
+    "rethrow", "return", "super", "switch", "this", "throw", "true", "try", "var", "void", "while",
+    "with",
+];
+
+fn dart_string(value: &str) -> String {
+    let mut result = String::from("'");
+    for c in value.chars() {
+        match c {
+            '\\' => result.push_str("\\\\"),
+            '\'' => result.push_str("\\'"),
+            '$' => result.push_str("\\$"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c => result.push(c),
+        }
+    }
+    result.push('\'');
+    result
+}
+
+/// dart literal for json value
+fn dart_literal(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => "null".to_string(),
+        serde_json::Value::Bool(b) => b.to_string(),
+        serde_json::Value::Number(n) => n.to_string(),
+        serde_json::Value::String(s) => dart_string(s),
+        serde_json::Value::Array(items) => format!(
+            "[{}]",
+            items
+                .iter()
+                .map(dart_literal)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        serde_json::Value::Object(items) => format!(
+            "{{{}}}",
+            items
+                .iter()
+                .map(|(k, v)| format!("{}: {}", dart_string(k), dart_literal(v)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+/// default value of constructor param should be const
+fn dart_const_literal(value: &serde_json::Value) -> String {
+    match value.is_array() || value.is_object() {
+        true => format!("const {}", dart_literal(value)),
+        false => dart_literal(value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codegen::Codegen;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_render() {
+        fn test_def(spec: &Path, code_path: &str) {
+            let spec = spec.strip_prefix("src/codegen/fixtures/specs/").unwrap();
+
+            let codegen =
+                Dart::load_from_folder(&PathBuf::from("src/codegen/fixtures/specs/")).unwrap();
+
+            let rendered = codegen.render(spec).unwrap();
+
+            let code = std::fs::read_to_string(code_path).unwrap();
+
+            #[cfg(not(feature = "test_update_spec"))]
+            pretty_assertions::assert_eq!(code.trim(), rendered.trim());
+
+            #[cfg(feature = "test_update_spec")]
+            {
+                if code.trim() != rendered.trim() {
+                    std::fs::write(code_path, rendered).unwrap();
+                }
+            }
+        }
+
+        for (spec, expected) in &[
+            (
+                "src/codegen/fixtures/specs/simple_struct.yaml",
+                "src/codegen/fixtures/dart/simple_struct.dart",
+            ),
+            (
+                "src/codegen/fixtures/specs/enum.yaml",
+                "src/codegen/fixtures/dart/enum.dart",
+            ),
+            (
+                "src/codegen/fixtures/specs/enum_custom_tag.yaml",
+                "src/codegen/fixtures/dart/enum_custom_tag.dart",
+            ),
+            (
+                "src/codegen/fixtures/specs/enum_variant_fields.yaml",
+                "src/codegen/fixtures/dart/enum_variant_fields.dart",
+            ),
+            (
+                "src/codegen/fixtures/specs/enum_representation.yaml",
+                "src/codegen/fixtures/dart/enum_representation.dart",
+            ),
+            (
+                "src/codegen/fixtures/specs/extend.yaml",
+                "src/codegen/fixtures/dart/extend.dart",
+            ),
+            (
+                "src/codegen/fixtures/specs/new_type.yaml",
+                "src/codegen/fixtures/dart/new_type.dart",
+            ),
+            (
+                "src/codegen/fixtures/specs/const_i8.yaml",
+                "src/codegen/fixtures/dart/const_i8.dart",
+            ),
+            (
+                "src/codegen/fixtures/specs/const_string.yaml",
+                "src/codegen/fixtures/dart/const_string.dart",
+            ),
+            (
+                "src/codegen/fixtures/specs/include_test.yaml",
+                "src/codegen/fixtures/dart/include_test.dart",
+            ),
+            (
+                "src/codegen/fixtures/specs/time.yaml",
+                "src/codegen/fixtures/dart/time.dart",
+            ),
+            (
+                "src/codegen/fixtures/specs/unsigned.yaml",
+                "src/codegen/fixtures/dart/unsigned.dart",
+            ),
+            (
+                "src/codegen/fixtures/specs/decimal.yaml",
+                "src/codegen/fixtures/dart/decimal.dart",
+            ),
+            (
+                "src/codegen/fixtures/specs/bigint.yaml",
+                "src/codegen/fixtures/dart/bigint.dart",
+            ),
+            (
+                "src/codegen/fixtures/specs/json.yaml",
+                "src/codegen/fixtures/dart/json.dart",
+            ),
+            (
+                "src/codegen/fixtures/specs/default.yaml",
+                "src/codegen/fixtures/dart/default.dart",
+            ),
+            (
+                "src/codegen/fixtures/specs/nullability.yaml",
+                "src/codegen/fixtures/dart/nullability.dart",
+            ),
+            (
+                "src/codegen/fixtures/specs/wire_name.yaml",
+                "src/codegen/fixtures/dart/wire_name.dart",
+            ),
+            (
+                "src/codegen/fixtures/specs/generic.yaml",
+                "src/codegen/fixtures/dart/generic.dart",
+            ),
+            (
+                "src/codegen/fixtures/specs/map_key_set.yaml",
+                "src/codegen/fixtures/dart/map_key_set.dart",
+            ),
+            (
+                "src/codegen/fixtures/specs/empty_struct.yaml",
+                "src/codegen/fixtures/dart/empty_struct.dart",
+            ),
+        ] {
+            test_def(PathBuf::from(spec).as_path(), expected);
+        }
+    }
+}
@@ -0,0 +1,15 @@
+#nullable enable
+
+using System.Numerics;
+using System.Text.Json.Serialization;
+
+namespace TotSpec.Test.Bigint;
+
+/// <summary>struct for bigint field</summary>
+public class TestBigInt
+{
+    [JsonPropertyName("value")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    [JsonConverter(typeof(BigIntegerConverter))]
+    public BigInteger? Value { get; set; }
+}
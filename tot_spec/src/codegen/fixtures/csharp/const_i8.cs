@@ -0,0 +1,36 @@
+#nullable enable
+
+using System.Globalization;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace TotSpec.Test.ConstI8;
+
+/// <summary>
+/// Const def for i8
+/// Second line of comment
+/// </summary>
+[JsonConverter(typeof(Code.Converter))]
+public readonly record struct Code(sbyte Value)
+{
+    /// <summary>Everything is ok</summary>
+    public static readonly Code Ok = new(0);
+
+    /// <summary>Request is bad</summary>
+    public static readonly Code Error = new(1);
+
+    public sealed class Converter : JsonConverter<Code>
+    {
+        public override Code Read(ref Utf8JsonReader reader, System.Type typeToConvert, JsonSerializerOptions options) =>
+            new(JsonSerializer.Deserialize<sbyte>(ref reader, options)!);
+
+        public override void Write(Utf8JsonWriter writer, Code value, JsonSerializerOptions options) =>
+            JsonSerializer.Serialize(writer, value.Value, options);
+
+        public override Code ReadAsPropertyName(ref Utf8JsonReader reader, System.Type typeToConvert, JsonSerializerOptions options) =>
+            new(sbyte.Parse(reader.GetString()!, CultureInfo.InvariantCulture));
+
+        public override void WriteAsPropertyName(Utf8JsonWriter writer, Code value, JsonSerializerOptions options) =>
+            writer.WritePropertyName(value.Value.ToString(CultureInfo.InvariantCulture));
+    }
+}
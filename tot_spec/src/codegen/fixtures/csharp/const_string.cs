@@ -0,0 +1,32 @@
+#nullable enable
+
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace TotSpec.Test.ConstString;
+
+/// <summary>Const def for string</summary>
+[JsonConverter(typeof(Reason.Converter))]
+public readonly record struct Reason(string Value)
+{
+    /// <summary>Everything is ok</summary>
+    public static readonly Reason Ok = new("ok");
+
+    /// <summary>Request is bad</summary>
+    public static readonly Reason Error = new("error");
+
+    public sealed class Converter : JsonConverter<Reason>
+    {
+        public override Reason Read(ref Utf8JsonReader reader, System.Type typeToConvert, JsonSerializerOptions options) =>
+            new(JsonSerializer.Deserialize<string>(ref reader, options)!);
+
+        public override void Write(Utf8JsonWriter writer, Reason value, JsonSerializerOptions options) =>
+            JsonSerializer.Serialize(writer, value.Value, options);
+
+        public override Reason ReadAsPropertyName(ref Utf8JsonReader reader, System.Type typeToConvert, JsonSerializerOptions options) =>
+            new(reader.GetString()!);
+
+        public override void WriteAsPropertyName(Utf8JsonWriter writer, Reason value, JsonSerializerOptions options) =>
+            writer.WritePropertyName(value.Value);
+    }
+}
@@ -0,0 +1,14 @@
+#nullable enable
+
+using System.Text.Json.Serialization;
+
+namespace TotSpec.Test.Decimal;
+
+/// <summary>struct for decimal field</summary>
+public class TestDecimal
+{
+    [JsonPropertyName("value")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    [JsonNumberHandling(JsonNumberHandling.AllowReadingFromString | JsonNumberHandling.WriteAsString)]
+    public decimal? Value { get; set; }
+}
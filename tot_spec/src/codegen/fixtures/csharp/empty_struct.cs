@@ -0,0 +1,10 @@
+#nullable enable
+
+using System.Text.Json.Serialization;
+
+namespace TotSpec.Test.EmptyStruct;
+
+/// <summary>Empty struct with no fields</summary>
+public class EmptyStruct
+{
+}
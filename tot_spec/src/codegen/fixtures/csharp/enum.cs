@@ -0,0 +1,43 @@
+#nullable enable
+
+using System.Text.Json.Serialization;
+
+namespace TotSpec.Test.Enum;
+
+[JsonPolymorphic(TypeDiscriminatorPropertyName = "type")]
+[JsonDerivedType(typeof(Number.Int64), "Int64")]
+[JsonDerivedType(typeof(Number.Float), "Float")]
+[JsonDerivedType(typeof(Number.RealNumber), "RealNumber")]
+public abstract class Number
+{
+    /// <summary>Variant Int64</summary>
+    public sealed class Int64 : Number
+    {
+        [JsonPropertyName("payload")]
+        public required long Payload { get; set; }
+    }
+
+    /// <summary>Variant Float</summary>
+    public sealed class Float : Number
+    {
+        [JsonPropertyName("payload")]
+        public required double Payload { get; set; }
+    }
+
+    public sealed class RealNumber : Number
+    {
+        [JsonPropertyName("payload")]
+        public required global::TotSpec.Test.Enum.RealNumber Payload { get; set; }
+    }
+}
+
+public class RealNumber
+{
+    [JsonPropertyName("part_0")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public double? Part0 { get; set; }
+
+    [JsonPropertyName("part_1")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public double? Part1 { get; set; }
+}
@@ -0,0 +1,43 @@
+#nullable enable
+
+using System.Text.Json.Serialization;
+
+namespace TotSpec.Test.EnumCustomTag;
+
+[JsonPolymorphic(TypeDiscriminatorPropertyName = "kind")]
+[JsonDerivedType(typeof(Number.Int64), "Int64")]
+[JsonDerivedType(typeof(Number.Float), "Float")]
+[JsonDerivedType(typeof(Number.RealNumber), "RealNumber")]
+public abstract class Number
+{
+    /// <summary>Variant Int64</summary>
+    public sealed class Int64 : Number
+    {
+        [JsonPropertyName("data")]
+        public required long Data { get; set; }
+    }
+
+    /// <summary>Variant Float</summary>
+    public sealed class Float : Number
+    {
+        [JsonPropertyName("data")]
+        public required double Data { get; set; }
+    }
+
+    public sealed class RealNumber : Number
+    {
+        [JsonPropertyName("data")]
+        public required global::TotSpec.Test.EnumCustomTag.RealNumber Data { get; set; }
+    }
+}
+
+public class RealNumber
+{
+    [JsonPropertyName("part_0")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public double? Part0 { get; set; }
+
+    [JsonPropertyName("part_1")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public double? Part1 { get; set; }
+}
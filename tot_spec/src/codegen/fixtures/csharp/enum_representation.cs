@@ -0,0 +1,246 @@
+#nullable enable
+
+using System.Collections.Generic;
+using System.Linq;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace TotSpec.Test.EnumRepresentation;
+
+public class Circle
+{
+    [JsonPropertyName("radius")]
+    public required double Radius { get; set; }
+}
+
+public class Rect
+{
+    [JsonPropertyName("width")]
+    public required double Width { get; set; }
+
+    [JsonPropertyName("height")]
+    public required double Height { get; set; }
+}
+
+/// <summary>internally tagged, tag is along with payload's fields</summary>
+[JsonConverter(typeof(InternalShape.Converter))]
+public abstract class InternalShape
+{
+    public sealed class CircleShape : InternalShape
+    {
+        public required Circle Payload { get; set; }
+    }
+
+    public sealed class RectShape : InternalShape
+    {
+        public required Rect Payload { get; set; }
+    }
+
+    public sealed class Empty : InternalShape
+    {
+    }
+
+    public sealed class Converter : JsonConverter<InternalShape>
+    {
+        public override InternalShape Read(ref Utf8JsonReader reader, System.Type typeToConvert, JsonSerializerOptions options)
+        {
+            var element = JsonSerializer.Deserialize<JsonElement>(ref reader, options);
+            var tag = element.TryGetProperty("kind", out var tagElement) ? tagElement.GetString() : null;
+            return tag switch
+            {
+                "circle" => new CircleShape { Payload = element.Deserialize<Circle>(options)! },
+                "rect" => new RectShape { Payload = element.Deserialize<Rect>(options)! },
+                "Empty" => new Empty(),
+                _ => throw new JsonException($"unknown variant {tag} of InternalShape"),
+            };
+        }
+
+        public override void Write(Utf8JsonWriter writer, InternalShape value, JsonSerializerOptions options)
+        {
+            (string Tag, JsonElement? Payload) tagged = value switch
+            {
+                CircleShape v => ("circle", JsonSerializer.SerializeToElement(v.Payload, options)),
+                RectShape v => ("rect", JsonSerializer.SerializeToElement(v.Payload, options)),
+                Empty => ("Empty", null),
+                _ => throw new JsonException($"{value.GetType()} is not a variant of InternalShape"),
+            };
+            // tag is merged into payload's fields
+            writer.WriteStartObject();
+            writer.WriteString("kind", tagged.Tag);
+            if (tagged.Payload is JsonElement fields)
+            {
+                foreach (var field in fields.EnumerateObject())
+                {
+                    field.WriteTo(writer);
+                }
+            }
+            writer.WriteEndObject();
+        }
+    }
+}
+
+/// <summary>externally tagged, variant name is the key</summary>
+[JsonConverter(typeof(ExternalShape.Converter))]
+public abstract class ExternalShape
+{
+    public sealed class CircleShape : ExternalShape
+    {
+        public required Circle Payload { get; set; }
+    }
+
+    public sealed class Size : ExternalShape
+    {
+        public required double Payload { get; set; }
+    }
+
+    public sealed class Empty : ExternalShape
+    {
+    }
+
+    public sealed class Converter : JsonConverter<ExternalShape>
+    {
+        public override ExternalShape Read(ref Utf8JsonReader reader, System.Type typeToConvert, JsonSerializerOptions options)
+        {
+            var element = JsonSerializer.Deserialize<JsonElement>(ref reader, options);
+            if (element.ValueKind == JsonValueKind.String)
+            {
+                return element.GetString() switch
+                {
+                    "Empty" => new Empty(),
+                    var tag => throw new JsonException($"unknown variant {tag} of ExternalShape"),
+                };
+            }
+            if (element.ValueKind != JsonValueKind.Object || element.EnumerateObject().Count() != 1)
+            {
+                throw new JsonException("expect one key for ExternalShape");
+            }
+            var property = element.EnumerateObject().First();
+            return property.Name switch
+            {
+                "circle" => new CircleShape { Payload = property.Value.Deserialize<Circle>(options)! },
+                "Size" => new Size { Payload = property.Value.Deserialize<double>(options)! },
+                var tag => throw new JsonException($"unknown variant {tag} of ExternalShape"),
+            };
+        }
+
+        public override void Write(Utf8JsonWriter writer, ExternalShape value, JsonSerializerOptions options)
+        {
+            (string Tag, JsonElement? Payload) tagged = value switch
+            {
+                CircleShape v => ("circle", JsonSerializer.SerializeToElement(v.Payload, options)),
+                Size v => ("Size", JsonSerializer.SerializeToElement(v.Payload, options)),
+                Empty => ("Empty", null),
+                _ => throw new JsonException($"{value.GetType()} is not a variant of ExternalShape"),
+            };
+            if (tagged.Payload is not JsonElement payload)
+            {
+                writer.WriteStringValue(tagged.Tag);
+                return;
+            }
+            writer.WriteStartObject();
+            writer.WritePropertyName(tagged.Tag);
+            payload.WriteTo(writer);
+            writer.WriteEndObject();
+        }
+    }
+}
+
+/// <summary>untagged, the first matching variant wins</summary>
+[JsonConverter(typeof(UntaggedValue.Converter))]
+public abstract class UntaggedValue
+{
+    public sealed class Number : UntaggedValue
+    {
+        public required double Payload { get; set; }
+    }
+
+    public sealed class Text : UntaggedValue
+    {
+        public required string Payload { get; set; }
+    }
+
+    public sealed class Texts : UntaggedValue
+    {
+        public required List<string> Payload { get; set; }
+    }
+
+    public sealed class CircleValue : UntaggedValue
+    {
+        public required Circle Payload { get; set; }
+    }
+
+    public sealed class Empty : UntaggedValue
+    {
+    }
+
+    public sealed class Converter : JsonConverter<UntaggedValue>
+    {
+        public override bool HandleNull => true;
+
+        public override UntaggedValue Read(ref Utf8JsonReader reader, System.Type typeToConvert, JsonSerializerOptions options)
+        {
+            var element = JsonSerializer.Deserialize<JsonElement>(ref reader, options);
+            if (element.ValueKind == JsonValueKind.Null)
+            {
+                return new Empty();
+            }
+            try
+            {
+                return new Number { Payload = element.Deserialize<double>(options)! };
+            }
+            catch (JsonException)
+            {
+                // try next variant
+            }
+            try
+            {
+                return new Text { Payload = element.Deserialize<string>(options)! };
+            }
+            catch (JsonException)
+            {
+                // try next variant
+            }
+            try
+            {
+                return new Texts { Payload = element.Deserialize<List<string>>(options)! };
+            }
+            catch (JsonException)
+            {
+                // try next variant
+            }
+            try
+            {
+                return new CircleValue { Payload = element.Deserialize<Circle>(options)! };
+            }
+            catch (JsonException)
+            {
+                // try next variant
+            }
+            throw new JsonException($"no variant of UntaggedValue matches {element}");
+        }
+
+        public override void Write(Utf8JsonWriter writer, UntaggedValue value, JsonSerializerOptions options)
+        {
+            switch (value)
+            {
+                case Number v:
+                    JsonSerializer.Serialize(writer, v.Payload, options);
+                    break;
+                case Text v:
+                    JsonSerializer.Serialize(writer, v.Payload, options);
+                    break;
+                case Texts v:
+                    JsonSerializer.Serialize(writer, v.Payload, options);
+                    break;
+                case CircleValue v:
+                    JsonSerializer.Serialize(writer, v.Payload, options);
+                    break;
+                case Empty:
+                    writer.WriteNullValue();
+                    break;
+                default:
+                    throw new JsonException($"{value.GetType()} is not a variant of UntaggedValue");
+            }
+        }
+    }
+}
@@ -0,0 +1,46 @@
+#nullable enable
+
+using System.Text.Json.Serialization;
+
+namespace TotSpec.Test.EnumVariantFields;
+
+[JsonPolymorphic(TypeDiscriminatorPropertyName = "type")]
+[JsonDerivedType(typeof(Number.Real), "Real")]
+public abstract class Number
+{
+    public sealed class Real : Number
+    {
+        [JsonPropertyName("payload")]
+        public required RealPayload Payload { get; set; }
+    }
+
+    public sealed class RealPayload
+    {
+        [JsonPropertyName("real")]
+        public required long Real { get; set; }
+
+        [JsonPropertyName("imagine")]
+        public required long Imagine { get; set; }
+    }
+}
+
+/// <summary>Number2 with variant with fields</summary>
+[JsonPolymorphic(TypeDiscriminatorPropertyName = "type")]
+[JsonDerivedType(typeof(Number2.Real), "Real")]
+public abstract class Number2
+{
+    public sealed class Real : Number2
+    {
+        [JsonPropertyName("payload")]
+        public required RealPayload Payload { get; set; }
+    }
+
+    public sealed class RealPayload
+    {
+        [JsonPropertyName("real")]
+        public required long Real { get; set; }
+
+        [JsonPropertyName("imagine")]
+        public required long Imagine { get; set; }
+    }
+}
@@ -0,0 +1,17 @@
+#nullable enable
+
+using System.Text.Json.Serialization;
+
+namespace TotSpec.Test.Extend;
+
+public abstract class Base
+{
+    [JsonPropertyName("id")]
+    public required string Id { get; set; }
+}
+
+public class Child : Base
+{
+    [JsonPropertyName("name")]
+    public required string Name { get; set; }
+}
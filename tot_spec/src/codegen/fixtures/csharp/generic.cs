@@ -0,0 +1,50 @@
+#nullable enable
+
+using System.Collections.Generic;
+using System.Text.Json.Serialization;
+
+namespace TotSpec.Test.Generic;
+
+public class User
+{
+    [JsonPropertyName("name")]
+    public required string Name { get; set; }
+}
+
+/// <summary>one page of items</summary>
+public class Page<T>
+{
+    [JsonPropertyName("items")]
+    public required List<T> Items { get; set; }
+
+    [JsonPropertyName("page_size")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public int? PageSize { get; set; }
+
+    [JsonPropertyName("next_cursor")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? NextCursor { get; set; }
+}
+
+public class Pair<A, B>
+{
+    [JsonPropertyName("first")]
+    public required A First { get; set; }
+
+    [JsonPropertyName("second")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public B? Second { get; set; }
+}
+
+public class UserList
+{
+    [JsonPropertyName("users")]
+    public required Page<User> Users { get; set; }
+
+    [JsonPropertyName("pairs")]
+    public required List<Pair<string, Page<User>>> Pairs { get; set; }
+
+    [JsonPropertyName("counts")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public Page<long>? Counts { get; set; }
+}
@@ -0,0 +1,19 @@
+#nullable enable
+
+using System.Text.Json.Serialization;
+
+namespace TotSpec.Test.IncludeTest;
+
+public class TestBase
+{
+    /// <summary>use base's BaseId as the id</summary>
+    [JsonPropertyName("id")]
+    public required global::TotSpec.Test.IncludeBase.Id Id { get; set; }
+
+    /// <summary>use base_dup's BaseId as the id_2, this is just demo</summary>
+    [JsonPropertyName("id_2")]
+    public required global::TotSpec.Test.IncludeBase.Id Id2 { get; set; }
+
+    [JsonPropertyName("common")]
+    public required global::TotSpec.Test.IncludeBase.Common Common { get; set; }
+}
@@ -0,0 +1,14 @@
+#nullable enable
+
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace TotSpec.Test.Json;
+
+/// <summary>Test struct for json field</summary>
+public class TestJsonStruct
+{
+    [JsonPropertyName("json_value")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public JsonElement? JsonValue { get; set; }
+}
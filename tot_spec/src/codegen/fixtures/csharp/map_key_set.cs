@@ -0,0 +1,47 @@
+#nullable enable
+
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace TotSpec.Test.MapKeySet;
+
+[JsonConverter(typeof(Reason.Converter))]
+public readonly record struct Reason(string Value)
+{
+    public static readonly Reason Ok = new("ok");
+
+    public static readonly Reason Error = new("error");
+
+    public sealed class Converter : JsonConverter<Reason>
+    {
+        public override Reason Read(ref Utf8JsonReader reader, System.Type typeToConvert, JsonSerializerOptions options) =>
+            new(JsonSerializer.Deserialize<string>(ref reader, options)!);
+
+        public override void Write(Utf8JsonWriter writer, Reason value, JsonSerializerOptions options) =>
+            JsonSerializer.Serialize(writer, value.Value, options);
+
+        public override Reason ReadAsPropertyName(ref Utf8JsonReader reader, System.Type typeToConvert, JsonSerializerOptions options) =>
+            new(reader.GetString()!);
+
+        public override void WriteAsPropertyName(Utf8JsonWriter writer, Reason value, JsonSerializerOptions options) =>
+            writer.WritePropertyName(value.Value);
+    }
+}
+
+public class Stats
+{
+    [JsonPropertyName("names_by_id")]
+    public required Dictionary<int, string> NamesById { get; set; }
+
+    [JsonPropertyName("count_by_reason")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public Dictionary<Reason, long>? CountByReason { get; set; }
+
+    [JsonPropertyName("tags")]
+    public required HashSet<string> Tags { get; set; }
+
+    [JsonPropertyName("flags")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public HashSet<byte>? Flags { get; set; }
+}
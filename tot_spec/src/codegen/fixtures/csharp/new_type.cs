@@ -0,0 +1,34 @@
+#nullable enable
+
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace TotSpec.Test.NewType;
+
+/// <summary>NewType to i64, and derive Ord macros</summary>
+[JsonConverter(typeof(Id.Converter))]
+public readonly record struct Id(long Value)
+{
+    public sealed class Converter : JsonConverter<Id>
+    {
+        public override Id Read(ref Utf8JsonReader reader, System.Type typeToConvert, JsonSerializerOptions options) =>
+            new(JsonSerializer.Deserialize<long>(ref reader, options)!);
+
+        public override void Write(Utf8JsonWriter writer, Id value, JsonSerializerOptions options) =>
+            JsonSerializer.Serialize(writer, value.Value, options);
+    }
+}
+
+[JsonConverter(typeof(DictNewType.Converter))]
+public readonly record struct DictNewType(Dictionary<string, byte[]> Value)
+{
+    public sealed class Converter : JsonConverter<DictNewType>
+    {
+        public override DictNewType Read(ref Utf8JsonReader reader, System.Type typeToConvert, JsonSerializerOptions options) =>
+            new(JsonSerializer.Deserialize<Dictionary<string, byte[]>>(ref reader, options)!);
+
+        public override void Write(Utf8JsonWriter writer, DictNewType value, JsonSerializerOptions options) =>
+            JsonSerializer.Serialize(writer, value.Value, options);
+    }
+}
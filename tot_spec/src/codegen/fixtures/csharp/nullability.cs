@@ -0,0 +1,40 @@
+#nullable enable
+
+using System.Collections.Generic;
+using System.Text.Json.Serialization;
+
+namespace TotSpec.Test.Nullability;
+
+/// <summary>patch style request, absent means unchanged and null means clear</summary>
+public class UpdateUserRequest
+{
+    [JsonPropertyName("id")]
+    public required string Id { get; set; }
+
+    [JsonPropertyName("name")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Name { get; set; }
+
+    [JsonPropertyName("email")]
+    public required string? Email { get; set; }
+
+    // absent and null are both decoded as null
+    [JsonPropertyName("nickname")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Nickname { get; set; }
+
+    // absent and null are both decoded as null
+    [JsonPropertyName("address")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public Address? Address { get; set; }
+
+    [JsonPropertyName("tags")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public List<string>? Tags { get; set; }
+}
+
+public class Address
+{
+    [JsonPropertyName("city")]
+    public required string City { get; set; }
+}
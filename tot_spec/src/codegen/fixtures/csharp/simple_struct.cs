@@ -0,0 +1,51 @@
+#nullable enable
+
+using System.Collections.Generic;
+using System.Text.Json.Serialization;
+
+namespace TotSpec.Test.SimpleStruct;
+
+/// <summary>Example of simple struct definition</summary>
+public class SimpleStruct
+{
+    /// <summary>bool value</summary>
+    [JsonPropertyName("bool_value")]
+    public required bool BoolValue { get; set; }
+
+    /// <summary>i8 value</summary>
+    [JsonPropertyName("i8_value")]
+    public required sbyte I8Value { get; set; }
+
+    [JsonPropertyName("i16_value")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public short? I16Value { get; set; }
+
+    [JsonPropertyName("i32_value")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public int? I32Value { get; set; }
+
+    [JsonPropertyName("i64_value")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public long? I64Value { get; set; }
+
+    [JsonPropertyName("string_value")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? StringValue { get; set; }
+
+    [JsonPropertyName("bytes_value")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public byte[]? BytesValue { get; set; }
+
+    [JsonPropertyName("string_to_string")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public Dictionary<string, string>? StringToString { get; set; }
+
+    /// <summary>nested self</summary>
+    [JsonPropertyName("children")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public List<SimpleStruct>? Children { get; set; }
+
+    /// <summary>this field is required</summary>
+    [JsonPropertyName("required_str_value")]
+    public required string RequiredStrValue { get; set; }
+}
@@ -0,0 +1,37 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Text.Json.Serialization;
+
+namespace TotSpec.Test.Time;
+
+/// <summary>struct for time fields</summary>
+public class TestTime
+{
+    /// <summary>timestamp in rfc3339 string</summary>
+    [JsonPropertyName("created_at")]
+    public required DateTimeOffset CreatedAt { get; set; }
+
+    /// <summary>timestamp in epoch millis</summary>
+    [JsonPropertyName("updated_at")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public long? UpdatedAt { get; set; }
+
+    [JsonPropertyName("expired_at")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public long? ExpiredAt { get; set; }
+
+    [JsonPropertyName("birthday")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public DateOnly? Birthday { get; set; }
+
+    /// <summary>duration in millis</summary>
+    [JsonPropertyName("timeout")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public long? Timeout { get; set; }
+
+    [JsonPropertyName("history")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public List<DateTimeOffset>? History { get; set; }
+}
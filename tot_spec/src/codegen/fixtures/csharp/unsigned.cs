@@ -0,0 +1,59 @@
+#nullable enable
+
+using System.Collections.Generic;
+using System.Globalization;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace TotSpec.Test.Unsigned;
+
+/// <summary>struct for unsigned and f32 fields</summary>
+public class TestUnsigned
+{
+    [JsonPropertyName("u8_value")]
+    public required byte U8Value { get; set; }
+
+    [JsonPropertyName("u16_value")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public ushort? U16Value { get; set; }
+
+    [JsonPropertyName("u32_value")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public uint? U32Value { get; set; }
+
+    [JsonPropertyName("u64_value")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public ulong? U64Value { get; set; }
+
+    [JsonPropertyName("f32_value")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public float? F32Value { get; set; }
+
+    [JsonPropertyName("u32_list")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public List<uint>? U32List { get; set; }
+}
+
+/// <summary>Const def for u32</summary>
+[JsonConverter(typeof(Level.Converter))]
+public readonly record struct Level(uint Value)
+{
+    public static readonly Level Low = new(1);
+
+    public static readonly Level High = new(3000000000);
+
+    public sealed class Converter : JsonConverter<Level>
+    {
+        public override Level Read(ref Utf8JsonReader reader, System.Type typeToConvert, JsonSerializerOptions options) =>
+            new(JsonSerializer.Deserialize<uint>(ref reader, options)!);
+
+        public override void Write(Utf8JsonWriter writer, Level value, JsonSerializerOptions options) =>
+            JsonSerializer.Serialize(writer, value.Value, options);
+
+        public override Level ReadAsPropertyName(ref Utf8JsonReader reader, System.Type typeToConvert, JsonSerializerOptions options) =>
+            new(uint.Parse(reader.GetString()!, CultureInfo.InvariantCulture));
+
+        public override void WriteAsPropertyName(Utf8JsonWriter writer, Level value, JsonSerializerOptions options) =>
+            writer.WritePropertyName(value.Value.ToString(CultureInfo.InvariantCulture));
+    }
+}
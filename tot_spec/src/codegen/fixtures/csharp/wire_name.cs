@@ -0,0 +1,42 @@
+#nullable enable
+
+using System.Text.Json.Serialization;
+
+namespace TotSpec.Test.WireName;
+
+/// <summary>struct with renamed fields</summary>
+public class TestWireName
+{
+    [JsonPropertyName("userID")]
+    public required string UserId { get; set; }
+
+    [JsonPropertyName("displayName")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? DisplayName { get; set; }
+
+    [JsonPropertyName("createdAt")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public long? CreatedAt { get; set; }
+
+    [JsonPropertyName("content-type")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? ContentType { get; set; }
+}
+
+[JsonPolymorphic(TypeDiscriminatorPropertyName = "type")]
+[JsonDerivedType(typeof(Shape.Circle), "circle")]
+[JsonDerivedType(typeof(Shape.BigSquare), "square")]
+public abstract class Shape
+{
+    public sealed class Circle : Shape
+    {
+        [JsonPropertyName("payload")]
+        public required double Payload { get; set; }
+    }
+
+    public sealed class BigSquare : Shape
+    {
+        [JsonPropertyName("payload")]
+        public required double Payload { get; set; }
+    }
+}
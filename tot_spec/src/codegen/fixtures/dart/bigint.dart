@@ -0,0 +1,20 @@
+/// struct for bigint field
+class TestBigInt {
+    final BigInt? value;
+
+    const TestBigInt({
+        this.value,
+    });
+
+    factory TestBigInt.fromJson(Map<String, dynamic> json) {
+        return TestBigInt(
+            value: json['value'] == null ? null : BigInt.parse(json['value'] as String),
+        );
+    }
+
+    Map<String, dynamic> toJson() {
+        return {
+            if (value != null) 'value': value!.toString(),
+        };
+    }
+}
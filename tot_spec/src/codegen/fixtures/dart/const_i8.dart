@@ -0,0 +1,23 @@
+/// Const def for i8
+/// Second line of comment
+class Code {
+    final int value;
+
+    const Code(this.value);
+
+    /// Everything is ok
+    static const ok = Code(0);
+
+    /// Request is bad
+    static const error = Code(1);
+
+    factory Code.fromJson(Object? json) => Code(json as int);
+
+    int toJson() => value;
+
+    @override
+    bool operator ==(Object other) => other is Code && other.value == value;
+
+    @override
+    int get hashCode => value.hashCode;
+}
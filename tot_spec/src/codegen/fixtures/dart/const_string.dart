@@ -0,0 +1,22 @@
+/// Const def for string
+class Reason {
+    final String value;
+
+    const Reason(this.value);
+
+    /// Everything is ok
+    static const ok = Reason('ok');
+
+    /// Request is bad
+    static const error = Reason('error');
+
+    factory Reason.fromJson(Object? json) => Reason(json as String);
+
+    String toJson() => value;
+
+    @override
+    bool operator ==(Object other) => other is Reason && other.value == value;
+
+    @override
+    int get hashCode => value.hashCode;
+}
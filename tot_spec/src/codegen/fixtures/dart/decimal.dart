@@ -0,0 +1,20 @@
+/// struct for decimal field
+class TestDecimal {
+    final String? value;
+
+    const TestDecimal({
+        this.value,
+    });
+
+    factory TestDecimal.fromJson(Map<String, dynamic> json) {
+        return TestDecimal(
+            value: json['value'] as String?,
+        );
+    }
+
+    Map<String, dynamic> toJson() {
+        return {
+            if (value != null) 'value': value,
+        };
+    }
+}
@@ -0,0 +1,55 @@
+/// struct with field defaults
+class TestDefault {
+    final String id;
+
+    final int pageSize;
+
+    final double ratio;
+
+    final bool enabled;
+
+    final String name;
+
+    final List<String> tags;
+
+    final Map<String, int> labels;
+
+    final String? desc;
+
+    const TestDefault({
+        required this.id,
+        this.pageSize = 10,
+        this.ratio = 1,
+        this.enabled = true,
+        this.name = 'unnamed',
+        this.tags = const ['a', 'b'],
+        this.labels = const {},
+        this.desc,
+    });
+
+    factory TestDefault.fromJson(Map<String, dynamic> json) {
+        return TestDefault(
+            id: json['id'] as String,
+            pageSize: json['page_size'] == null ? 10 : json['page_size'] as int,
+            ratio: json['ratio'] == null ? 1 : (json['ratio'] as num).toDouble(),
+            enabled: json['enabled'] == null ? true : json['enabled'] as bool,
+            name: json['name'] == null ? 'unnamed' : json['name'] as String,
+            tags: json['tags'] == null ? ['a', 'b'] : (json['tags'] as List).map((e) => e as String).toList(),
+            labels: json['labels'] == null ? {} : (json['labels'] as Map<String, dynamic>).map((k, v) => MapEntry(k, v as int)),
+            desc: json['desc'] as String?,
+        );
+    }
+
+    Map<String, dynamic> toJson() {
+        return {
+            'id': id,
+            'page_size': pageSize,
+            'ratio': ratio,
+            'enabled': enabled,
+            'name': name,
+            'tags': tags,
+            'labels': labels,
+            if (desc != null) 'desc': desc,
+        };
+    }
+}
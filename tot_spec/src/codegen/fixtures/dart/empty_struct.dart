@@ -0,0 +1,12 @@
+/// Empty struct with no fields
+class EmptyStruct {
+    const EmptyStruct();
+
+    factory EmptyStruct.fromJson(Map<String, dynamic> json) {
+        return const EmptyStruct();
+    }
+
+    Map<String, dynamic> toJson() {
+        return {};
+    }
+}
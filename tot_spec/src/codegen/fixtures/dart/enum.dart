@@ -0,0 +1,69 @@
+sealed class Number {
+    const Number();
+
+    factory Number.fromJson(Object? json) {
+        final map = json as Map<String, dynamic>;
+        return switch (map['type']) {
+            'Int64' => NumberInt64(map['payload'] as int),
+            'Float' => NumberFloat((map['payload'] as num).toDouble()),
+            'RealNumber' => NumberRealNumber(RealNumber.fromJson(map['payload'] as Map<String, dynamic>)),
+            final type => throw FormatException('invalid type: $type'),
+        };
+    }
+
+    Object? toJson();
+}
+
+/// Variant Int64
+class NumberInt64 extends Number {
+    final int payload;
+
+    const NumberInt64(this.payload);
+
+    @override
+    Object? toJson() => {'type': 'Int64', 'payload': payload};
+}
+
+/// Variant Float
+class NumberFloat extends Number {
+    final double payload;
+
+    const NumberFloat(this.payload);
+
+    @override
+    Object? toJson() => {'type': 'Float', 'payload': payload};
+}
+
+class NumberRealNumber extends Number {
+    final RealNumber payload;
+
+    const NumberRealNumber(this.payload);
+
+    @override
+    Object? toJson() => {'type': 'RealNumber', 'payload': payload.toJson()};
+}
+
+class RealNumber {
+    final double? part0;
+
+    final double? part1;
+
+    const RealNumber({
+        this.part0,
+        this.part1,
+    });
+
+    factory RealNumber.fromJson(Map<String, dynamic> json) {
+        return RealNumber(
+            part0: json['part_0'] == null ? null : (json['part_0'] as num).toDouble(),
+            part1: json['part_1'] == null ? null : (json['part_1'] as num).toDouble(),
+        );
+    }
+
+    Map<String, dynamic> toJson() {
+        return {
+            if (part0 != null) 'part_0': part0,
+            if (part1 != null) 'part_1': part1,
+        };
+    }
+}
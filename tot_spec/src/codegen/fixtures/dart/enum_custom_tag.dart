@@ -0,0 +1,69 @@
+sealed class Number {
+    const Number();
+
+    factory Number.fromJson(Object? json) {
+        final map = json as Map<String, dynamic>;
+        return switch (map['kind']) {
+            'Int64' => NumberInt64(map['data'] as int),
+            'Float' => NumberFloat((map['data'] as num).toDouble()),
+            'RealNumber' => NumberRealNumber(RealNumber.fromJson(map['data'] as Map<String, dynamic>)),
+            final type => throw FormatException('invalid type: $type'),
+        };
+    }
+
+    Object? toJson();
+}
+
+/// Variant Int64
+class NumberInt64 extends Number {
+    final int data;
+
+    const NumberInt64(this.data);
+
+    @override
+    Object? toJson() => {'kind': 'Int64', 'data': data};
+}
+
+/// Variant Float
+class NumberFloat extends Number {
+    final double data;
+
+    const NumberFloat(this.data);
+
+    @override
+    Object? toJson() => {'kind': 'Float', 'data': data};
+}
+
+class NumberRealNumber extends Number {
+    final RealNumber data;
+
+    const NumberRealNumber(this.data);
+
+    @override
+    Object? toJson() => {'kind': 'RealNumber', 'data': data.toJson()};
+}
+
+class RealNumber {
+    final double? part0;
+
+    final double? part1;
+
+    const RealNumber({
+        this.part0,
+        this.part1,
+    });
+
+    factory RealNumber.fromJson(Map<String, dynamic> json) {
+        return RealNumber(
+            part0: json['part_0'] == null ? null : (json['part_0'] as num).toDouble(),
+            part1: json['part_1'] == null ? null : (json['part_1'] as num).toDouble(),
+        );
+    }
+
+    Map<String, dynamic> toJson() {
+        return {
+            if (part0 != null) 'part_0': part0,
+            if (part1 != null) 'part_1': part1,
+        };
+    }
+}
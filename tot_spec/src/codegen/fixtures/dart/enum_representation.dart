@@ -0,0 +1,202 @@
+class Circle {
+    final double radius;
+
+    const Circle({
+        required this.radius,
+    });
+
+    factory Circle.fromJson(Map<String, dynamic> json) {
+        return Circle(
+            radius: (json['radius'] as num).toDouble(),
+        );
+    }
+
+    Map<String, dynamic> toJson() {
+        return {
+            'radius': radius,
+        };
+    }
+}
+
+class Rect {
+    final double width;
+
+    final double height;
+
+    const Rect({
+        required this.width,
+        required this.height,
+    });
+
+    factory Rect.fromJson(Map<String, dynamic> json) {
+        return Rect(
+            width: (json['width'] as num).toDouble(),
+            height: (json['height'] as num).toDouble(),
+        );
+    }
+
+    Map<String, dynamic> toJson() {
+        return {
+            'width': width,
+            'height': height,
+        };
+    }
+}
+
+/// internally tagged, tag is along with payload's fields
+sealed class InternalShape {
+    const InternalShape();
+
+    factory InternalShape.fromJson(Object? json) {
+        final map = json as Map<String, dynamic>;
+        return switch (map['kind']) {
+            'circle' => InternalShapeCircleShape(Circle.fromJson(json as Map<String, dynamic>)),
+            'rect' => InternalShapeRectShape(Rect.fromJson(json as Map<String, dynamic>)),
+            'Empty' => const InternalShapeEmpty(),
+            final type => throw FormatException('invalid type: $type'),
+        };
+    }
+
+    Object? toJson();
+}
+
+class InternalShapeCircleShape extends InternalShape {
+    final Circle payload;
+
+    const InternalShapeCircleShape(this.payload);
+
+    @override
+    Object? toJson() => {'kind': 'circle', ...payload.toJson()};
+}
+
+class InternalShapeRectShape extends InternalShape {
+    final Rect payload;
+
+    const InternalShapeRectShape(this.payload);
+
+    @override
+    Object? toJson() => {'kind': 'rect', ...payload.toJson()};
+}
+
+class InternalShapeEmpty extends InternalShape {
+    const InternalShapeEmpty();
+
+    @override
+    Object? toJson() => {'kind': 'Empty'};
+}
+
+/// externally tagged, variant name is the key
+sealed class ExternalShape {
+    const ExternalShape();
+
+    factory ExternalShape.fromJson(Object? json) {
+        if (json is String) {
+            return switch (json) {
+                'Empty' => const ExternalShapeEmpty(),
+                final type => throw FormatException('invalid type: $type'),
+            };
+        }
+        final entry = (json as Map<String, dynamic>).entries.single;
+        return switch (entry.key) {
+            'circle' => ExternalShapeCircleShape(Circle.fromJson(entry.value as Map<String, dynamic>)),
+            'Size' => ExternalShapeSize((entry.value as num).toDouble()),
+            final type => throw FormatException('invalid type: $type'),
+        };
+    }
+
+    Object? toJson();
+}
+
+class ExternalShapeCircleShape extends ExternalShape {
+    final Circle payload;
+
+    const ExternalShapeCircleShape(this.payload);
+
+    @override
+    Object? toJson() => {'circle': payload.toJson()};
+}
+
+class ExternalShapeSize extends ExternalShape {
+    final double payload;
+
+    const ExternalShapeSize(this.payload);
+
+    @override
+    Object? toJson() => {'Size': payload};
+}
+
+class ExternalShapeEmpty extends ExternalShape {
+    const ExternalShapeEmpty();
+
+    @override
+    Object? toJson() => 'Empty';
+}
+
+/// untagged, the first matching variant wins
+sealed class UntaggedValue {
+    const UntaggedValue();
+
+    factory UntaggedValue.fromJson(Object? json) {
+        if (json == null) {
+            return const UntaggedValueEmpty();
+        }
+        try {
+            return UntaggedValueNumber((json as num).toDouble());
+        } catch (_) {}
+        try {
+            return UntaggedValueText(json as String);
+        } catch (_) {}
+        try {
+            return UntaggedValueTexts((json as List).map((e) => e as String).toList());
+        } catch (_) {}
+        try {
+            return UntaggedValueCircleValue(Circle.fromJson(json as Map<String, dynamic>));
+        } catch (_) {}
+        throw FormatException('no variant matches: $json');
+    }
+
+    Object? toJson();
+}
+
+class UntaggedValueNumber extends UntaggedValue {
+    final double payload;
+
+    const UntaggedValueNumber(this.payload);
+
+    @override
+    Object? toJson() => payload;
+}
+
+class UntaggedValueText extends UntaggedValue {
+    final String payload;
+
+    const UntaggedValueText(this.payload);
+
+    @override
+    Object? toJson() => payload;
+}
+
+class UntaggedValueTexts extends UntaggedValue {
+    final List<String> payload;
+
+    const UntaggedValueTexts(this.payload);
+
+    @override
+    Object? toJson() => payload;
+}
+
+class UntaggedValueCircleValue extends UntaggedValue {
+    final Circle payload;
+
+    const UntaggedValueCircleValue(this.payload);
+
+    @override
+    Object? toJson() => payload.toJson();
+}
+
+class UntaggedValueEmpty extends UntaggedValue {
+    const UntaggedValueEmpty();
+
+    @override
+    Object? toJson() => null;
+}
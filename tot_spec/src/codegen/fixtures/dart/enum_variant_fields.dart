@@ -0,0 +1,96 @@
+sealed class Number {
+    const Number();
+
+    factory Number.fromJson(Object? json) {
+        final map = json as Map<String, dynamic>;
+        return switch (map['type']) {
+            'Real' => NumberReal(NumberRealPayload.fromJson(map['payload'] as Map<String, dynamic>)),
+            final type => throw FormatException('invalid type: $type'),
+        };
+    }
+
+    Object? toJson();
+}
+
+class NumberReal extends Number {
+    final NumberRealPayload payload;
+
+    const NumberReal(this.payload);
+
+    @override
+    Object? toJson() => {'type': 'Real', 'payload': payload.toJson()};
+}
+
+class NumberRealPayload {
+    final int real;
+
+    final int imagine;
+
+    const NumberRealPayload({
+        required this.real,
+        required this.imagine,
+    });
+
+    factory NumberRealPayload.fromJson(Map<String, dynamic> json) {
+        return NumberRealPayload(
+            real: json['real'] as int,
+            imagine: json['imagine'] as int,
+        );
+    }
+
+    Map<String, dynamic> toJson() {
+        return {
+            'real': real,
+            'imagine': imagine,
+        };
+    }
+}
+
+/// Number2 with variant with fields
+sealed class Number2 {
+    const Number2();
+
+    factory Number2.fromJson(Object? json) {
+        final map = json as Map<String, dynamic>;
+        return switch (map['type']) {
+            'Real' => Number2Real(Number2RealPayload.fromJson(map['payload'] as Map<String, dynamic>)),
+            final type => throw FormatException('invalid type: $type'),
+        };
+    }
+
+    Object? toJson();
+}
+
+class Number2Real extends Number2 {
+    final Number2RealPayload payload;
+
+    const Number2Real(this.payload);
+
+    @override
+    Object? toJson() => {'type': 'Real', 'payload': payload.toJson()};
+}
+
+class Number2RealPayload {
+    final int real;
+
+    final int imagine;
+
+    const Number2RealPayload({
+        required this.real,
+        required this.imagine,
+    });
+
+    factory Number2RealPayload.fromJson(Map<String, dynamic> json) {
+        return Number2RealPayload(
+            real: json['real'] as int,
+            imagine: json['imagine'] as int,
+        );
+    }
+
+    Map<String, dynamic> toJson() {
+        return {
+            'real': real,
+            'imagine': imagine,
+        };
+    }
+}
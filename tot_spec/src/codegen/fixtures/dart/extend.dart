@@ -0,0 +1,29 @@
+abstract class Base {
+    String get id;
+}
+
+class Child implements Base {
+    @override
+    final String id;
+
+    final String name;
+
+    const Child({
+        required this.id,
+        required this.name,
+    });
+
+    factory Child.fromJson(Map<String, dynamic> json) {
+        return Child(
+            id: json['id'] as String,
+            name: json['name'] as String,
+        );
+    }
+
+    Map<String, dynamic> toJson() {
+        return {
+            'id': id,
+            'name': name,
+        };
+    }
+}
@@ -0,0 +1,105 @@
+class User {
+    final String name;
+
+    const User({
+        required this.name,
+    });
+
+    factory User.fromJson(Map<String, dynamic> json) {
+        return User(
+            name: json['name'] as String,
+        );
+    }
+
+    Map<String, dynamic> toJson() {
+        return {
+            'name': name,
+        };
+    }
+}
+
+/// one page of items
+class Page<T> {
+    final List<T> items;
+
+    final int pageSize;
+
+    final String? nextCursor;
+
+    const Page({
+        required this.items,
+        this.pageSize = 20,
+        this.nextCursor,
+    });
+
+    factory Page.fromJson(Map<String, dynamic> json, T Function(Object? json) fromJsonT) {
+        return Page(
+            items: (json['items'] as List).map((e) => fromJsonT(e)).toList(),
+            pageSize: json['page_size'] == null ? 20 : json['page_size'] as int,
+            nextCursor: json['next_cursor'] as String?,
+        );
+    }
+
+    Map<String, dynamic> toJson(Object? Function(T value) toJsonT) {
+        return {
+            'items': items.map((e) => toJsonT(e)).toList(),
+            'page_size': pageSize,
+            if (nextCursor != null) 'next_cursor': nextCursor,
+        };
+    }
+}
+
+class Pair<A, B> {
+    final A first;
+
+    final B? second;
+
+    const Pair({
+        required this.first,
+        this.second,
+    });
+
+    factory Pair.fromJson(Map<String, dynamic> json, A Function(Object? json) fromJsonA, B Function(Object? json) fromJsonB) {
+        return Pair(
+            first: fromJsonA(json['first']),
+            second: json['second'] == null ? null : fromJsonB(json['second']),
+        );
+    }
+
+    Map<String, dynamic> toJson(Object? Function(A value) toJsonA, Object? Function(B value) toJsonB) {
+        return {
+            'first': toJsonA(first),
+            if (second != null) 'second': toJsonB(second!),
+        };
+    }
+}
+
+class UserList {
+    final Page<User> users;
+
+    final List<Pair<String, Page<User>>> pairs;
+
+    final Page<int>? counts;
+
+    const UserList({
+        required this.users,
+        required this.pairs,
+        this.counts,
+    });
+
+    factory UserList.fromJson(Map<String, dynamic> json) {
+        return UserList(
+            users: Page.fromJson(json['users'] as Map<String, dynamic>, (e) => User.fromJson(e as Map<String, dynamic>)),
+            pairs: (json['pairs'] as List).map((e) => Pair.fromJson(e as Map<String, dynamic>, (e) => e as String, (e) => Page.fromJson(e as Map<String, dynamic>, (e) => User.fromJson(e as Map<String, dynamic>)))).toList(),
+            counts: json['counts'] == null ? null : Page.fromJson(json['counts'] as Map<String, dynamic>, (e) => e as int),
+        );
+    }
+
+    Map<String, dynamic> toJson() {
+        return {
+            'users': users.toJson((e) => e.toJson()),
+            'pairs': pairs.map((e) => e.toJson((e) => e, (e) => e.toJson((e) => e.toJson()))).toList(),
+            if (counts != null) 'counts': counts!.toJson((e) => e),
+        };
+    }
+}
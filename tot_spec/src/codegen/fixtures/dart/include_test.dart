@@ -0,0 +1,34 @@
+import 'include_base.dart' as base;
+import 'include_base.dart' as base_dup;
+
+class TestBase {
+    /// use base's BaseId as the id
+    final base.Id id;
+
+    /// use base_dup's BaseId as the id_2, this is just demo
+    final base_dup.Id id2;
+
+    final base.Common common;
+
+    const TestBase({
+        required this.id,
+        required this.id2,
+        required this.common,
+    });
+
+    factory TestBase.fromJson(Map<String, dynamic> json) {
+        return TestBase(
+            id: base.Id.fromJson(json['id']),
+            id2: base_dup.Id.fromJson(json['id_2']),
+            common: base.Common.fromJson(json['common'] as Map<String, dynamic>),
+        );
+    }
+
+    Map<String, dynamic> toJson() {
+        return {
+            'id': id.toJson(),
+            'id_2': id2.toJson(),
+            'common': common.toJson(),
+        };
+    }
+}
@@ -0,0 +1,20 @@
+/// Test struct for json field
+class TestJsonStruct {
+    final Object? jsonValue;
+
+    const TestJsonStruct({
+        this.jsonValue,
+    });
+
+    factory TestJsonStruct.fromJson(Map<String, dynamic> json) {
+        return TestJsonStruct(
+            jsonValue: json['json_value'],
+        );
+    }
+
+    Map<String, dynamic> toJson() {
+        return {
+            'json_value': jsonValue,
+        };
+    }
+}
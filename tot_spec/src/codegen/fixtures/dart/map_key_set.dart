@@ -0,0 +1,54 @@
+class Reason {
+    final String value;
+
+    const Reason(this.value);
+
+    static const ok = Reason('ok');
+
+    static const error = Reason('error');
+
+    factory Reason.fromJson(Object? json) => Reason(json as String);
+
+    String toJson() => value;
+
+    @override
+    bool operator ==(Object other) => other is Reason && other.value == value;
+
+    @override
+    int get hashCode => value.hashCode;
+}
+
+class Stats {
+    final Map<int, String> namesById;
+
+    final Map<Reason, int>? countByReason;
+
+    final Set<String> tags;
+
+    final Set<int>? flags;
+
+    const Stats({
+        required this.namesById,
+        this.countByReason,
+        required this.tags,
+        this.flags,
+    });
+
+    factory Stats.fromJson(Map<String, dynamic> json) {
+        return Stats(
+            namesById: (json['names_by_id'] as Map<String, dynamic>).map((k, v) => MapEntry(int.parse(k), v as String)),
+            countByReason: json['count_by_reason'] == null ? null : (json['count_by_reason'] as Map<String, dynamic>).map((k, v) => MapEntry(Reason.fromJson(k), v as int)),
+            tags: (json['tags'] as List).map((e) => e as String).toSet(),
+            flags: json['flags'] == null ? null : (json['flags'] as List).map((e) => e as int).toSet(),
+        );
+    }
+
+    Map<String, dynamic> toJson() {
+        return {
+            'names_by_id': namesById.map((k, v) => MapEntry(k.toString(), v)),
+            if (countByReason != null) 'count_by_reason': countByReason!.map((k, v) => MapEntry(k.toJson(), v)),
+            'tags': tags.toList(),
+            if (flags != null) 'flags': flags!.toList(),
+        };
+    }
+}
@@ -0,0 +1,32 @@
+/// NewType to i64, and derive Ord macros
+class Id {
+    final int value;
+
+    const Id(this.value);
+
+    factory Id.fromJson(Object? json) => Id(json as int);
+
+    Object? toJson() => value;
+
+    @override
+    bool operator ==(Object other) => other is Id && other.value == value;
+
+    @override
+    int get hashCode => value.hashCode;
+}
+
+class DictNewType {
+    final Map<String, List<int>> value;
+
+    const DictNewType(this.value);
+
+    factory DictNewType.fromJson(Object? json) => DictNewType((json as Map<String, dynamic>).map((k, v) => MapEntry(k, List<int>.from(v as List))));
+
+    Object? toJson() => value;
+
+    @override
+    bool operator ==(Object other) => other is DictNewType && other.value == value;
+
+    @override
+    int get hashCode => value.hashCode;
+}
@@ -0,0 +1,67 @@
+/// patch style request, absent means unchanged and null means clear
+class UpdateUserRequest {
+    final String id;
+
+    final String? name;
+
+    final String? email;
+
+    // absent and null are both decoded as null
+    final String? nickname;
+
+    // absent and null are both decoded as null
+    final Address? address;
+
+    final List<String>? tags;
+
+    const UpdateUserRequest({
+        required this.id,
+        this.name,
+        required this.email,
+        this.nickname,
+        this.address,
+        this.tags,
+    });
+
+    factory UpdateUserRequest.fromJson(Map<String, dynamic> json) {
+        return UpdateUserRequest(
+            id: json['id'] as String,
+            name: json['name'] as String?,
+            email: json['email'] as String?,
+            nickname: json['nickname'] as String?,
+            address: json['address'] == null ? null : Address.fromJson(json['address'] as Map<String, dynamic>),
+            tags: json['tags'] == null ? null : (json['tags'] as List).map((e) => e as String).toList(),
+        );
+    }
+
+    Map<String, dynamic> toJson() {
+        return {
+            'id': id,
+            if (name != null) 'name': name,
+            'email': email,
+            if (nickname != null) 'nickname': nickname,
+            if (address != null) 'address': address!.toJson(),
+            if (tags != null) 'tags': tags,
+        };
+    }
+}
+
+class Address {
+    final String city;
+
+    const Address({
+        required this.city,
+    });
+
+    factory Address.fromJson(Map<String, dynamic> json) {
+        return Address(
+            city: json['city'] as String,
+        );
+    }
+
+    Map<String, dynamic> toJson() {
+        return {
+            'city': city,
+        };
+    }
+}
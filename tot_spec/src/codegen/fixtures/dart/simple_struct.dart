@@ -0,0 +1,69 @@
+/// Example of simple struct definition
+class SimpleStruct {
+    /// bool value
+    final bool boolValue;
+
+    /// i8 value
+    final int i8Value;
+
+    final int? i16Value;
+
+    final int? i32Value;
+
+    final int? i64Value;
+
+    final String? stringValue;
+
+    final List<int>? bytesValue;
+
+    final Map<String, String>? stringToString;
+
+    /// nested self
+    final List<SimpleStruct>? children;
+
+    /// this field is required
+    final String requiredStrValue;
+
+    const SimpleStruct({
+        required this.boolValue,
+        required this.i8Value,
+        this.i16Value,
+        this.i32Value,
+        this.i64Value,
+        this.stringValue,
+        this.bytesValue,
+        this.stringToString,
+        this.children,
+        required this.requiredStrValue,
+    });
+
+    factory SimpleStruct.fromJson(Map<String, dynamic> json) {
+        return SimpleStruct(
+            boolValue: json['bool_value'] as bool,
+            i8Value: json['i8_value'] as int,
+            i16Value: json['i16_value'] as int?,
+            i32Value: json['i32_value'] as int?,
+            i64Value: json['i64_value'] as int?,
+            stringValue: json['string_value'] as String?,
+            bytesValue: json['bytes_value'] == null ? null : List<int>.from(json['bytes_value'] as List),
+            stringToString: json['string_to_string'] == null ? null : (json['string_to_string'] as Map<String, dynamic>).map((k, v) => MapEntry(k, v as String)),
+            children: json['children'] == null ? null : (json['children'] as List).map((e) => SimpleStruct.fromJson(e as Map<String, dynamic>)).toList(),
+            requiredStrValue: json['required_str_value'] as String,
+        );
+    }
+
+    Map<String, dynamic> toJson() {
+        return {
+            'bool_value': boolValue,
+            'i8_value': i8Value,
+            if (i16Value != null) 'i16_value': i16Value,
+            if (i32Value != null) 'i32_value': i32Value,
+            if (i64Value != null) 'i64_value': i64Value,
+            if (stringValue != null) 'string_value': stringValue,
+            if (bytesValue != null) 'bytes_value': bytesValue,
+            if (stringToString != null) 'string_to_string': stringToString,
+            if (children != null) 'children': children!.map((e) => e.toJson()).toList(),
+            'required_str_value': requiredStrValue,
+        };
+    }
+}
@@ -0,0 +1,48 @@
+/// struct for time fields
+class TestTime {
+    /// timestamp in rfc3339 string
+    final DateTime createdAt;
+
+    /// timestamp in epoch millis
+    final DateTime? updatedAt;
+
+    final DateTime? expiredAt;
+
+    final DateTime? birthday;
+
+    /// duration in millis
+    final Duration? timeout;
+
+    final List<DateTime>? history;
+
+    const TestTime({
+        required this.createdAt,
+        this.updatedAt,
+        this.expiredAt,
+        this.birthday,
+        this.timeout,
+        this.history,
+    });
+
+    factory TestTime.fromJson(Map<String, dynamic> json) {
+        return TestTime(
+            createdAt: DateTime.parse(json['created_at'] as String),
+            updatedAt: json['updated_at'] == null ? null : DateTime.fromMillisecondsSinceEpoch(json['updated_at'] as int, isUtc: true),
+            expiredAt: json['expired_at'] == null ? null : DateTime.fromMillisecondsSinceEpoch(json['expired_at'] as int, isUtc: true),
+            birthday: json['birthday'] == null ? null : DateTime.parse(json['birthday'] as String),
+            timeout: json['timeout'] == null ? null : Duration(milliseconds: json['timeout'] as int),
+            history: json['history'] == null ? null : (json['history'] as List).map((e) => DateTime.parse(e as String)).toList(),
+        );
+    }
+
+    Map<String, dynamic> toJson() {
+        return {
+            'created_at': createdAt.toUtc().toIso8601String(),
+            if (updatedAt != null) 'updated_at': updatedAt!.millisecondsSinceEpoch,
+            if (expiredAt != null) 'expired_at': expiredAt!.millisecondsSinceEpoch,
+            if (birthday != null) 'birthday': birthday!.toIso8601String().substring(0, 10),
+            if (timeout != null) 'timeout': timeout!.inMilliseconds,
+            if (history != null) 'history': history!.map((e) => e.toUtc().toIso8601String()).toList(),
+        };
+    }
+}
@@ -0,0 +1,66 @@
+/// struct for unsigned and f32 fields
+class TestUnsigned {
+    final int u8Value;
+
+    final int? u16Value;
+
+    final int? u32Value;
+
+    final int? u64Value;
+
+    final double? f32Value;
+
+    final List<int>? u32List;
+
+    const TestUnsigned({
+        required this.u8Value,
+        this.u16Value,
+        this.u32Value,
+        this.u64Value,
+        this.f32Value,
+        this.u32List,
+    });
+
+    factory TestUnsigned.fromJson(Map<String, dynamic> json) {
+        return TestUnsigned(
+            u8Value: json['u8_value'] as int,
+            u16Value: json['u16_value'] as int?,
+            u32Value: json['u32_value'] as int?,
+            u64Value: json['u64_value'] as int?,
+            f32Value: json['f32_value'] == null ? null : (json['f32_value'] as num).toDouble(),
+            u32List: json['u32_list'] == null ? null : (json['u32_list'] as List).map((e) => e as int).toList(),
+        );
+    }
+
+    Map<String, dynamic> toJson() {
+        return {
+            'u8_value': u8Value,
+            if (u16Value != null) 'u16_value': u16Value,
+            if (u32Value != null) 'u32_value': u32Value,
+            if (u64Value != null) 'u64_value': u64Value,
+            if (f32Value != null) 'f32_value': f32Value,
+            if (u32List != null) 'u32_list': u32List,
+        };
+    }
+}
+
+/// Const def for u32
+class Level {
+    final int value;
+
+    const Level(this.value);
+
+    static const low = Level(1);
+
+    static const high = Level(3000000000);
+
+    factory Level.fromJson(Object? json) => Level(json as int);
+
+    int toJson() => value;
+
+    @override
+    bool operator ==(Object other) => other is Level && other.value == value;
+
+    @override
+    int get hashCode => value.hashCode;
+}
@@ -0,0 +1,68 @@
+/// struct with renamed fields
+class TestWireName {
+    final String userId;
+
+    final String? displayName;
+
+    final int? createdAt;
+
+    final String? contentType;
+
+    const TestWireName({
+        required this.userId,
+        this.displayName,
+        this.createdAt,
+        this.contentType,
+    });
+
+    factory TestWireName.fromJson(Map<String, dynamic> json) {
+        return TestWireName(
+            userId: json['userID'] as String,
+            displayName: json['displayName'] as String?,
+            createdAt: json['createdAt'] as int?,
+            contentType: json['content-type'] as String?,
+        );
+    }
+
+    Map<String, dynamic> toJson() {
+        return {
+            'userID': userId,
+            if (displayName != null) 'displayName': displayName,
+            if (createdAt != null) 'createdAt': createdAt,
+            if (contentType != null) 'content-type': contentType,
+        };
+    }
+}
+
+sealed class Shape {
+    const Shape();
+
+    factory Shape.fromJson(Object? json) {
+        final map = json as Map<String, dynamic>;
+        return switch (map['type']) {
+            'circle' => ShapeCircle((map['payload'] as num).toDouble()),
+            'square' => ShapeBigSquare((map['payload'] as num).toDouble()),
+            final type => throw FormatException('invalid type: $type'),
+        };
+    }
+
+    Object? toJson();
+}
+
+class ShapeCircle extends Shape {
+    final double payload;
+
+    const ShapeCircle(this.payload);
+
+    @override
+    Object? toJson() => {'type': 'circle', 'payload': payload};
+}
+
+class ShapeBigSquare extends Shape {
+    final double payload;
+
+    const ShapeBigSquare(this.payload);
+
+    @override
+    Object? toJson() => {'type': 'square', 'payload': payload};
+}
@@ -0,0 +1,12 @@
+package consti8
+
+// Const def for i8
+// Second line of comment
+type Code int8
+
+const (
+	// Everything is ok
+	CodeOk    Code = 0
+	// Request is bad
+	CodeError Code = 1
+)
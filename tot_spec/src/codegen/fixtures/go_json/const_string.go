@@ -0,0 +1,11 @@
+package conststring
+
+// Const def for string
+type Reason string
+
+const (
+	// Everything is ok
+	ReasonOk    Reason = "ok"
+	// Request is bad
+	ReasonError Reason = "error"
+)
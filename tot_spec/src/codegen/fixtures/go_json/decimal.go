@@ -0,0 +1,6 @@
+package decimal
+
+// struct for decimal field
+type TestDecimal struct {
+	Value *string `json:"value,omitempty"`
+}
@@ -0,0 +1,4 @@
+package emptystruct
+
+// Empty struct with no fields
+type EmptyStruct struct{}
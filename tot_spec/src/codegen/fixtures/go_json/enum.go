@@ -0,0 +1,86 @@
+package enum
+
+import (
+	"encoding/json"
+	"fmt"
+)
+
+type Number struct {
+	Variant NumberVariant
+}
+
+// NumberVariant is implemented by variants of Number
+type NumberVariant interface {
+	isNumber()
+}
+
+// Variant Int64
+type NumberInt64 int64
+
+func (NumberInt64) isNumber() {}
+
+// Variant Float
+type NumberFloat float64
+
+func (NumberFloat) isNumber() {}
+
+type NumberRealNumber RealNumber
+
+func (NumberRealNumber) isNumber() {}
+
+func (v Number) MarshalJSON() ([]byte, error) {
+	var tag string
+	var payload any
+	switch variant := v.Variant.(type) {
+	case NumberInt64:
+		tag, payload = "Int64", int64(variant)
+	case NumberFloat:
+		tag, payload = "Float", float64(variant)
+	case NumberRealNumber:
+		tag, payload = "RealNumber", RealNumber(variant)
+	default:
+		return nil, fmt.Errorf("%T is not a variant of Number", v.Variant)
+	}
+	return json.Marshal(struct {
+		Tag     string `json:"type"`
+		Payload any    `json:"payload,omitempty"`
+	}{tag, payload})
+}
+
+func (v *Number) UnmarshalJSON(data []byte) error {
+	var raw struct {
+		Tag     string          `json:"type"`
+		Payload json.RawMessage `json:"payload"`
+	}
+	if err := json.Unmarshal(data, &raw); err != nil {
+		return err
+	}
+	switch raw.Tag {
+	case "Int64":
+		var payload int64
+		if err := json.Unmarshal(raw.Payload, &payload); err != nil {
+			return err
+		}
+		v.Variant = NumberInt64(payload)
+	case "Float":
+		var payload float64
+		if err := json.Unmarshal(raw.Payload, &payload); err != nil {
+			return err
+		}
+		v.Variant = NumberFloat(payload)
+	case "RealNumber":
+		var payload RealNumber
+		if err := json.Unmarshal(raw.Payload, &payload); err != nil {
+			return err
+		}
+		v.Variant = NumberRealNumber(payload)
+	default:
+		return fmt.Errorf("unknown variant %q of Number", raw.Tag)
+	}
+	return nil
+}
+
+type RealNumber struct {
+	Part0 *float64 `json:"part_0,omitempty"`
+	Part1 *float64 `json:"part_1,omitempty"`
+}
@@ -0,0 +1,245 @@
+package enumrepresentation
+
+import (
+	"encoding/json"
+	"fmt"
+)
+
+type Circle struct {
+	Radius float64 `json:"radius"`
+}
+
+type Rect struct {
+	Width  float64 `json:"width"`
+	Height float64 `json:"height"`
+}
+
+// internally tagged, tag is along with payload's fields
+type InternalShape struct {
+	Variant InternalShapeVariant
+}
+
+// InternalShapeVariant is implemented by variants of InternalShape
+type InternalShapeVariant interface {
+	isInternalShape()
+}
+
+type InternalShapeCircleShape Circle
+
+func (InternalShapeCircleShape) isInternalShape() {}
+
+type InternalShapeRectShape Rect
+
+func (InternalShapeRectShape) isInternalShape() {}
+
+type InternalShapeEmpty struct{}
+
+func (InternalShapeEmpty) isInternalShape() {}
+
+func (v InternalShape) MarshalJSON() ([]byte, error) {
+	var tag string
+	var payload any
+	switch variant := v.Variant.(type) {
+	case InternalShapeCircleShape:
+		tag, payload = "circle", Circle(variant)
+	case InternalShapeRectShape:
+		tag, payload = "rect", Rect(variant)
+	case InternalShapeEmpty:
+		tag = "Empty"
+	default:
+		return nil, fmt.Errorf("%T is not a variant of InternalShape", v.Variant)
+	}
+	// tag is merged into payload's fields
+	fields := map[string]json.RawMessage{}
+	if payload != nil {
+		data, err := json.Marshal(payload)
+		if err != nil {
+			return nil, err
+		}
+		if err := json.Unmarshal(data, &fields); err != nil {
+			return nil, err
+		}
+	}
+	fields["kind"], _ = json.Marshal(tag)
+	return json.Marshal(fields)
+}
+
+func (v *InternalShape) UnmarshalJSON(data []byte) error {
+	var raw struct {
+		Tag string `json:"kind"`
+	}
+	if err := json.Unmarshal(data, &raw); err != nil {
+		return err
+	}
+	switch raw.Tag {
+	case "circle":
+		var payload Circle
+		if err := json.Unmarshal(data, &payload); err != nil {
+			return err
+		}
+		v.Variant = InternalShapeCircleShape(payload)
+	case "rect":
+		var payload Rect
+		if err := json.Unmarshal(data, &payload); err != nil {
+			return err
+		}
+		v.Variant = InternalShapeRectShape(payload)
+	case "Empty":
+		v.Variant = InternalShapeEmpty{}
+	default:
+		return fmt.Errorf("unknown variant %q of InternalShape", raw.Tag)
+	}
+	return nil
+}
+
+// externally tagged, variant name is the key
+type ExternalShape struct {
+	Variant ExternalShapeVariant
+}
+
+// ExternalShapeVariant is implemented by variants of ExternalShape
+type ExternalShapeVariant interface {
+	isExternalShape()
+}
+
+type ExternalShapeCircleShape Circle
+
+func (ExternalShapeCircleShape) isExternalShape() {}
+
+type ExternalShapeSize float64
+
+func (ExternalShapeSize) isExternalShape() {}
+
+type ExternalShapeEmpty struct{}
+
+func (ExternalShapeEmpty) isExternalShape() {}
+
+func (v ExternalShape) MarshalJSON() ([]byte, error) {
+	var tag string
+	var payload any
+	switch variant := v.Variant.(type) {
+	case ExternalShapeCircleShape:
+		tag, payload = "circle", Circle(variant)
+	case ExternalShapeSize:
+		tag, payload = "Size", float64(variant)
+	case ExternalShapeEmpty:
+		tag = "Empty"
+	default:
+		return nil, fmt.Errorf("%T is not a variant of ExternalShape", v.Variant)
+	}
+	if payload == nil {
+		return json.Marshal(tag)
+	}
+	return json.Marshal(map[string]any{tag: payload})
+}
+
+func (v *ExternalShape) UnmarshalJSON(data []byte) error {
+	// variant without payload is a string, others are an object with one key
+	var tag string
+	var raw map[string]json.RawMessage
+	if err := json.Unmarshal(data, &tag); err != nil {
+		if err := json.Unmarshal(data, &raw); err != nil {
+			return err
+		}
+		if len(raw) != 1 {
+			return fmt.Errorf("expect one key for ExternalShape, got %d", len(raw))
+		}
+		for key := range raw {
+			tag = key
+		}
+	}
+	switch tag {
+	case "circle":
+		var payload Circle
+		if err := json.Unmarshal(raw[tag], &payload); err != nil {
+			return err
+		}
+		v.Variant = ExternalShapeCircleShape(payload)
+	case "Size":
+		var payload float64
+		if err := json.Unmarshal(raw[tag], &payload); err != nil {
+			return err
+		}
+		v.Variant = ExternalShapeSize(payload)
+	case "Empty":
+		v.Variant = ExternalShapeEmpty{}
+	default:
+		return fmt.Errorf("unknown variant %q of ExternalShape", tag)
+	}
+	return nil
+}
+
+// untagged, the first matching variant wins
+type UntaggedValue struct {
+	Variant UntaggedValueVariant
+}
+
+// UntaggedValueVariant is implemented by variants of UntaggedValue
+type UntaggedValueVariant interface {
+	isUntaggedValue()
+}
+
+type UntaggedValueNumber float64
+
+func (UntaggedValueNumber) isUntaggedValue() {}
+
+type UntaggedValueText string
+
+func (UntaggedValueText) isUntaggedValue() {}
+
+type UntaggedValueTexts []string
+
+func (UntaggedValueTexts) isUntaggedValue() {}
+
+type UntaggedValueCircleValue Circle
+
+func (UntaggedValueCircleValue) isUntaggedValue() {}
+
+type UntaggedValueEmpty struct{}
+
+func (UntaggedValueEmpty) isUntaggedValue() {}
+
+func (v UntaggedValue) MarshalJSON() ([]byte, error) {
+	switch variant := v.Variant.(type) {
+	case UntaggedValueNumber:
+		return json.Marshal(float64(variant))
+	case UntaggedValueText:
+		return json.Marshal(string(variant))
+	case UntaggedValueTexts:
+		return json.Marshal([]string(variant))
+	case UntaggedValueCircleValue:
+		return json.Marshal(Circle(variant))
+	case UntaggedValueEmpty:
+		return []byte("null"), nil
+	default:
+		return nil, fmt.Errorf("%T is not a variant of UntaggedValue", v.Variant)
+	}
+}
+
+func (v *UntaggedValue) UnmarshalJSON(data []byte) error {
+	if string(data) == "null" {
+		v.Variant = UntaggedValueEmpty{}
+		return nil
+	}
+	var numberPayload float64
+	if err := json.Unmarshal(data, &numberPayload); err == nil {
+		v.Variant = UntaggedValueNumber(numberPayload)
+		return nil
+	}
+	var textPayload string
+	if err := json.Unmarshal(data, &textPayload); err == nil {
+		v.Variant = UntaggedValueText(textPayload)
+		return nil
+	}
+	var textsPayload []string
+	if err := json.Unmarshal(data, &textsPayload); err == nil {
+		v.Variant = UntaggedValueTexts(textsPayload)
+		return nil
+	}
+	var circleValuePayload Circle
+	if err := json.Unmarshal(data, &circleValuePayload); err == nil {
+		v.Variant = UntaggedValueCircleValue(circleValuePayload)
+		return nil
+	}
+	return fmt.Errorf("no variant of UntaggedValue matches %s", data)
+}
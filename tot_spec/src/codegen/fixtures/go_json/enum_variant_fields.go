@@ -0,0 +1,111 @@
+package enumvariantfields
+
+import (
+	"encoding/json"
+	"fmt"
+)
+
+type Number struct {
+	Variant NumberVariant
+}
+
+// NumberVariant is implemented by variants of Number
+type NumberVariant interface {
+	isNumber()
+}
+
+type NumberReal struct {
+	Real    int64 `json:"real"`
+	Imagine int64 `json:"imagine"`
+}
+
+func (NumberReal) isNumber() {}
+
+func (v Number) MarshalJSON() ([]byte, error) {
+	var tag string
+	var payload any
+	switch variant := v.Variant.(type) {
+	case NumberReal:
+		tag, payload = "Real", variant
+	default:
+		return nil, fmt.Errorf("%T is not a variant of Number", v.Variant)
+	}
+	return json.Marshal(struct {
+		Tag     string `json:"type"`
+		Payload any    `json:"payload,omitempty"`
+	}{tag, payload})
+}
+
+func (v *Number) UnmarshalJSON(data []byte) error {
+	var raw struct {
+		Tag     string          `json:"type"`
+		Payload json.RawMessage `json:"payload"`
+	}
+	if err := json.Unmarshal(data, &raw); err != nil {
+		return err
+	}
+	switch raw.Tag {
+	case "Real":
+		var payload NumberReal
+		if err := json.Unmarshal(raw.Payload, &payload); err != nil {
+			return err
+		}
+		v.Variant = payload
+	default:
+		return fmt.Errorf("unknown variant %q of Number", raw.Tag)
+	}
+	return nil
+}
+
+// Number2 with variant with fields
+type Number2 struct {
+	Variant Number2Variant
+}
+
+// Number2Variant is implemented by variants of Number2
+type Number2Variant interface {
+	isNumber2()
+}
+
+type Number2Real struct {
+	Real    int64 `json:"real"`
+	Imagine int64 `json:"imagine"`
+}
+
+func (Number2Real) isNumber2() {}
+
+func (v Number2) MarshalJSON() ([]byte, error) {
+	var tag string
+	var payload any
+	switch variant := v.Variant.(type) {
+	case Number2Real:
+		tag, payload = "Real", variant
+	default:
+		return nil, fmt.Errorf("%T is not a variant of Number2", v.Variant)
+	}
+	return json.Marshal(struct {
+		Tag     string `json:"type"`
+		Payload any    `json:"payload,omitempty"`
+	}{tag, payload})
+}
+
+func (v *Number2) UnmarshalJSON(data []byte) error {
+	var raw struct {
+		Tag     string          `json:"type"`
+		Payload json.RawMessage `json:"payload"`
+	}
+	if err := json.Unmarshal(data, &raw); err != nil {
+		return err
+	}
+	switch raw.Tag {
+	case "Real":
+		var payload Number2Real
+		if err := json.Unmarshal(raw.Payload, &payload); err != nil {
+			return err
+		}
+		v.Variant = payload
+	default:
+		return fmt.Errorf("unknown variant %q of Number2", raw.Tag)
+	}
+	return nil
+}
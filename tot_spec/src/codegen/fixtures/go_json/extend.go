@@ -0,0 +1,11 @@
+package extend
+
+type Base struct {
+	Id string `json:"id"`
+}
+
+type Child struct {
+	Base
+
+	Name string `json:"name"`
+}
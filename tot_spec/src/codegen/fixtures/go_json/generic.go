@@ -0,0 +1,23 @@
+package generic
+
+type User struct {
+	Name string `json:"name"`
+}
+
+// one page of items
+type Page[T any] struct {
+	Items      []T     `json:"items"`
+	PageSize   *int32  `json:"page_size,omitempty"`
+	NextCursor *string `json:"next_cursor,omitempty"`
+}
+
+type Pair[A, B any] struct {
+	First  A  `json:"first"`
+	Second *B `json:"second,omitempty"`
+}
+
+type UserList struct {
+	Users  Page[User]                 `json:"users"`
+	Pairs  []Pair[string, Page[User]] `json:"pairs"`
+	Counts *Page[int64]               `json:"counts,omitempty"`
+}
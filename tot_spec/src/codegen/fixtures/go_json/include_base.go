@@ -0,0 +1,7 @@
+package includebase
+
+type Id int64
+
+type Common struct {
+	Id int64 `json:"id"`
+}
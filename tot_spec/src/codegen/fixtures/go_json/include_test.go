@@ -0,0 +1,14 @@
+package includetest
+
+import (
+	base "github.com/tot-spec/test/include_base"
+	base_dup "github.com/tot-spec/test/include_base"
+)
+
+type TestBase struct {
+	// use base's BaseId as the id
+	Id     base.Id     `json:"id"`
+	// use base_dup's BaseId as the id_2, this is just demo
+	Id2    base_dup.Id `json:"id_2"`
+	Common base.Common `json:"common"`
+}
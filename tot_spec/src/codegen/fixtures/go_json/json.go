@@ -0,0 +1,10 @@
+package json
+
+import (
+	"encoding/json"
+)
+
+// Test struct for json field
+type TestJsonStruct struct {
+	JsonValue json.RawMessage `json:"json_value,omitempty"`
+}
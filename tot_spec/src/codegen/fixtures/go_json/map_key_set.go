@@ -0,0 +1,15 @@
+package mapkeyset
+
+type Reason string
+
+const (
+	ReasonOk    Reason = "ok"
+	ReasonError Reason = "error"
+)
+
+type Stats struct {
+	NamesById     map[int32]string `json:"names_by_id"`
+	CountByReason map[Reason]int64 `json:"count_by_reason,omitempty"`
+	Tags          []string         `json:"tags"`
+	Flags         []uint8          `json:"flags,omitempty"`
+}
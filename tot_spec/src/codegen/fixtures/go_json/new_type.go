@@ -0,0 +1,6 @@
+package newtype
+
+// NewType to i64, and derive Ord macros
+type Id int64
+
+type DictNewType map[string][]byte
@@ -0,0 +1,17 @@
+package nullability
+
+// patch style request, absent means unchanged and null means clear
+type UpdateUserRequest struct {
+	Id       string   `json:"id"`
+	Name     *string  `json:"name,omitempty"`
+	Email    *string  `json:"email"`
+	// absent and null are both decoded as nil
+	Nickname *string  `json:"nickname,omitempty"`
+	// absent and null are both decoded as nil
+	Address  *Address `json:"address,omitempty"`
+	Tags     []string `json:"tags,omitempty"`
+}
+
+type Address struct {
+	City string `json:"city"`
+}
@@ -0,0 +1,19 @@
+package simplestruct
+
+// Example of simple struct definition
+type SimpleStruct struct {
+	// bool value
+	BoolValue        bool              `json:"bool_value"`
+	// i8 value
+	I8Value          int8              `json:"i8_value"`
+	I16Value         *int16            `json:"i16_value,omitempty"`
+	I32Value         *int32            `json:"i32_value,omitempty"`
+	I64Value         *int64            `json:"i64_value,omitempty"`
+	StringValue      *string           `json:"string_value,omitempty"`
+	BytesValue       []byte            `json:"bytes_value,omitempty"`
+	StringToString   map[string]string `json:"string_to_string,omitempty"`
+	// nested self
+	Children         []SimpleStruct    `json:"children,omitempty"`
+	// this field is required
+	RequiredStrValue string            `json:"required_str_value"`
+}
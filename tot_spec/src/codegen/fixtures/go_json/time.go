@@ -0,0 +1,18 @@
+package time
+
+import (
+	"time"
+)
+
+// struct for time fields
+type TestTime struct {
+	// timestamp in rfc3339 string
+	CreatedAt time.Time   `json:"created_at"`
+	// timestamp in epoch millis
+	UpdatedAt *int64      `json:"updated_at,omitempty"`
+	ExpiredAt *int64      `json:"expired_at,omitempty"`
+	Birthday  *string     `json:"birthday,omitempty"`
+	// duration in millis
+	Timeout   *int64      `json:"timeout,omitempty"`
+	History   []time.Time `json:"history,omitempty"`
+}
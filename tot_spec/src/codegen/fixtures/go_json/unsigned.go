@@ -0,0 +1,19 @@
+package unsigned
+
+// struct for unsigned and f32 fields
+type TestUnsigned struct {
+	U8Value  uint8    `json:"u8_value"`
+	U16Value *uint16  `json:"u16_value,omitempty"`
+	U32Value *uint32  `json:"u32_value,omitempty"`
+	U64Value *uint64  `json:"u64_value,omitempty"`
+	F32Value *float32 `json:"f32_value,omitempty"`
+	U32List  []uint32 `json:"u32_list,omitempty"`
+}
+
+// Const def for u32
+type Level uint32
+
+const (
+	LevelLow  Level = 1
+	LevelHigh Level = 3000000000
+)
@@ -0,0 +1,75 @@
+package wirename
+
+import (
+	"encoding/json"
+	"fmt"
+)
+
+// struct with renamed fields
+type TestWireName struct {
+	UserId      string  `json:"userID"`
+	DisplayName *string `json:"displayName,omitempty"`
+	CreatedAt   *int64  `json:"createdAt,omitempty"`
+	ContentType *string `json:"content-type,omitempty"`
+}
+
+type Shape struct {
+	Variant ShapeVariant
+}
+
+// ShapeVariant is implemented by variants of Shape
+type ShapeVariant interface {
+	isShape()
+}
+
+type ShapeCircle float64
+
+func (ShapeCircle) isShape() {}
+
+type ShapeBigSquare float64
+
+func (ShapeBigSquare) isShape() {}
+
+func (v Shape) MarshalJSON() ([]byte, error) {
+	var tag string
+	var payload any
+	switch variant := v.Variant.(type) {
+	case ShapeCircle:
+		tag, payload = "circle", float64(variant)
+	case ShapeBigSquare:
+		tag, payload = "square", float64(variant)
+	default:
+		return nil, fmt.Errorf("%T is not a variant of Shape", v.Variant)
+	}
+	return json.Marshal(struct {
+		Tag     string `json:"type"`
+		Payload any    `json:"payload,omitempty"`
+	}{tag, payload})
+}
+
+func (v *Shape) UnmarshalJSON(data []byte) error {
+	var raw struct {
+		Tag     string          `json:"type"`
+		Payload json.RawMessage `json:"payload"`
+	}
+	if err := json.Unmarshal(data, &raw); err != nil {
+		return err
+	}
+	switch raw.Tag {
+	case "circle":
+		var payload float64
+		if err := json.Unmarshal(raw.Payload, &payload); err != nil {
+			return err
+		}
+		v.Variant = ShapeCircle(payload)
+	case "square":
+		var payload float64
+		if err := json.Unmarshal(raw.Payload, &payload); err != nil {
+			return err
+		}
+		v.Variant = ShapeBigSquare(payload)
+	default:
+		return fmt.Errorf("unknown variant %q of Shape", raw.Tag)
+	}
+	return nil
+}
@@ -0,0 +1,12 @@
+package com.tot_spec.test.const_u64;
+import lombok.*;
+import java.util.*;
+
+// Const def for u64
+public class Code {
+    // Everything is ok
+    public static final java.math.BigInteger OK = java.math.BigInteger.valueOf(0L);
+
+    // Largest value fits in i64
+    public static final java.math.BigInteger MAX = java.math.BigInteger.valueOf(9223372036854775807L);
+}
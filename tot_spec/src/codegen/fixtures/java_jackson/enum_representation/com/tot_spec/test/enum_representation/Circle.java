@@ -0,0 +1,11 @@
+package com.tot_spec.test.enum_representation;
+import lombok.*;
+import java.util.*;
+
+@Data
+@Builder
+@AllArgsConstructor
+@NoArgsConstructor
+public class Circle {
+    private Double radius;
+}
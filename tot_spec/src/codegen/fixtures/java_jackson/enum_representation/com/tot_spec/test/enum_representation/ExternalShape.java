@@ -0,0 +1,71 @@
+package com.tot_spec.test.enum_representation;
+import lombok.*;
+import java.util.*;
+
+// externally tagged, variant name is the key
+@com.fasterxml.jackson.databind.annotation.JsonSerialize(using = ExternalShape.Serializer.class)
+@com.fasterxml.jackson.databind.annotation.JsonDeserialize(using = ExternalShape.Deserializer.class)
+public abstract class ExternalShape {
+    @Data
+    @Builder
+    @AllArgsConstructor
+    @NoArgsConstructor
+    public static class CircleShape extends ExternalShape {
+        private com.tot_spec.test.enum_representation.Circle payload;
+    }
+
+    @Data
+    @Builder
+    @AllArgsConstructor
+    @NoArgsConstructor
+    public static class Size extends ExternalShape {
+        private Double payload;
+    }
+
+    @Data
+    @Builder
+    @AllArgsConstructor
+    @NoArgsConstructor
+    public static class Empty extends ExternalShape {
+    }
+
+    public static class Serializer extends com.fasterxml.jackson.databind.JsonSerializer<ExternalShape> {
+        @Override
+        public void serialize(ExternalShape value, com.fasterxml.jackson.core.JsonGenerator gen, com.fasterxml.jackson.databind.SerializerProvider provider) throws java.io.IOException {
+            if (value instanceof ExternalShape.CircleShape) {
+                gen.writeStartObject();
+                gen.writeObjectField("circle", ((ExternalShape.CircleShape) value).getPayload());
+                gen.writeEndObject();
+            } else if (value instanceof ExternalShape.Size) {
+                gen.writeStartObject();
+                gen.writeObjectField("Size", ((ExternalShape.Size) value).getPayload());
+                gen.writeEndObject();
+            } else if (value instanceof ExternalShape.Empty) {
+                gen.writeString("Empty");
+            }
+        }
+    }
+
+    public static class Deserializer extends com.fasterxml.jackson.databind.JsonDeserializer<ExternalShape> {
+        @Override
+        public ExternalShape deserialize(com.fasterxml.jackson.core.JsonParser p, com.fasterxml.jackson.databind.DeserializationContext ctxt) throws java.io.IOException {
+            com.fasterxml.jackson.core.ObjectCodec codec = p.getCodec();
+            com.fasterxml.jackson.databind.JsonNode node = codec.readTree(p);
+            if (node.isTextual()) {
+                switch (node.asText()) {
+                    case "Empty":
+                        return new ExternalShape.Empty();
+                }
+            } else if (node.isObject() && node.size() == 1) {
+                java.util.Map.Entry<String, com.fasterxml.jackson.databind.JsonNode> entry = node.fields().next();
+                switch (entry.getKey()) {
+                    case "circle":
+                        return new ExternalShape.CircleShape(codec.readValue(codec.treeAsTokens(entry.getValue()), new com.fasterxml.jackson.core.type.TypeReference<com.tot_spec.test.enum_representation.Circle>() {}));
+                    case "Size":
+                        return new ExternalShape.Size(codec.readValue(codec.treeAsTokens(entry.getValue()), new com.fasterxml.jackson.core.type.TypeReference<Double>() {}));
+                }
+            }
+            throw com.fasterxml.jackson.databind.JsonMappingException.from(p, "invalid ExternalShape");
+        }
+    }
+}
@@ -0,0 +1,37 @@
+package com.tot_spec.test.enum_representation;
+import lombok.*;
+import java.util.*;
+
+// internally tagged, tag is along with payload's fields
+@com.fasterxml.jackson.annotation.JsonTypeInfo(use = com.fasterxml.jackson.annotation.JsonTypeInfo.Id.NAME, property = "kind")
+@com.fasterxml.jackson.annotation.JsonSubTypes({
+    @com.fasterxml.jackson.annotation.JsonSubTypes.Type(value = InternalShape.CircleShape.class, name = "circle"),
+    @com.fasterxml.jackson.annotation.JsonSubTypes.Type(value = InternalShape.RectShape.class, name = "rect"),
+    @com.fasterxml.jackson.annotation.JsonSubTypes.Type(value = InternalShape.Empty.class, name = "Empty"),
+})
+public abstract class InternalShape {
+    @Data
+    @Builder
+    @AllArgsConstructor
+    @NoArgsConstructor
+    public static class CircleShape extends InternalShape {
+        @com.fasterxml.jackson.annotation.JsonUnwrapped
+        private com.tot_spec.test.enum_representation.Circle payload;
+    }
+
+    @Data
+    @Builder
+    @AllArgsConstructor
+    @NoArgsConstructor
+    public static class RectShape extends InternalShape {
+        @com.fasterxml.jackson.annotation.JsonUnwrapped
+        private com.tot_spec.test.enum_representation.Rect payload;
+    }
+
+    @Data
+    @Builder
+    @AllArgsConstructor
+    @NoArgsConstructor
+    public static class Empty extends InternalShape {
+    }
+}
@@ -0,0 +1,12 @@
+package com.tot_spec.test.enum_representation;
+import lombok.*;
+import java.util.*;
+
+@Data
+@Builder
+@AllArgsConstructor
+@NoArgsConstructor
+public class Rect {
+    private Double width;
+    private Double height;
+}
@@ -0,0 +1,101 @@
+package com.tot_spec.test.enum_representation;
+import lombok.*;
+import java.util.*;
+
+// untagged, the first matching variant wins
+@com.fasterxml.jackson.databind.annotation.JsonSerialize(using = UntaggedValue.Serializer.class)
+@com.fasterxml.jackson.databind.annotation.JsonDeserialize(using = UntaggedValue.Deserializer.class)
+public abstract class UntaggedValue {
+    @Data
+    @Builder
+    @AllArgsConstructor
+    @NoArgsConstructor
+    public static class Number extends UntaggedValue {
+        private Double payload;
+    }
+
+    @Data
+    @Builder
+    @AllArgsConstructor
+    @NoArgsConstructor
+    public static class Text extends UntaggedValue {
+        private String payload;
+    }
+
+    @Data
+    @Builder
+    @AllArgsConstructor
+    @NoArgsConstructor
+    public static class Texts extends UntaggedValue {
+        private List<String> payload;
+    }
+
+    @Data
+    @Builder
+    @AllArgsConstructor
+    @NoArgsConstructor
+    public static class CircleValue extends UntaggedValue {
+        private com.tot_spec.test.enum_representation.Circle payload;
+    }
+
+    @Data
+    @Builder
+    @AllArgsConstructor
+    @NoArgsConstructor
+    public static class Empty extends UntaggedValue {
+    }
+
+    public static class Serializer extends com.fasterxml.jackson.databind.JsonSerializer<UntaggedValue> {
+        @Override
+        public void serialize(UntaggedValue value, com.fasterxml.jackson.core.JsonGenerator gen, com.fasterxml.jackson.databind.SerializerProvider provider) throws java.io.IOException {
+            if (value instanceof UntaggedValue.Number) {
+                gen.writeObject(((UntaggedValue.Number) value).getPayload());
+            } else if (value instanceof UntaggedValue.Text) {
+                gen.writeObject(((UntaggedValue.Text) value).getPayload());
+            } else if (value instanceof UntaggedValue.Texts) {
+                gen.writeObject(((UntaggedValue.Texts) value).getPayload());
+            } else if (value instanceof UntaggedValue.CircleValue) {
+                gen.writeObject(((UntaggedValue.CircleValue) value).getPayload());
+            } else if (value instanceof UntaggedValue.Empty) {
+                gen.writeNull();
+            }
+        }
+    }
+
+    public static class Deserializer extends com.fasterxml.jackson.databind.JsonDeserializer<UntaggedValue> {
+        @Override
+        public UntaggedValue deserialize(com.fasterxml.jackson.core.JsonParser p, com.fasterxml.jackson.databind.DeserializationContext ctxt) throws java.io.IOException {
+            com.fasterxml.jackson.core.ObjectCodec codec = p.getCodec();
+            com.fasterxml.jackson.databind.JsonNode node = codec.readTree(p);
+            if (node.isNull()) {
+                return new UntaggedValue.Empty();
+            }
+            try {
+                return new UntaggedValue.Number(codec.readValue(codec.treeAsTokens(node), new com.fasterxml.jackson.core.type.TypeReference<Double>() {}));
+            } catch (java.io.IOException e) {
+                // try next variant
+            }
+            try {
+                return new UntaggedValue.Text(codec.readValue(codec.treeAsTokens(node), new com.fasterxml.jackson.core.type.TypeReference<String>() {}));
+            } catch (java.io.IOException e) {
+                // try next variant
+            }
+            try {
+                return new UntaggedValue.Texts(codec.readValue(codec.treeAsTokens(node), new com.fasterxml.jackson.core.type.TypeReference<List<String>>() {}));
+            } catch (java.io.IOException e) {
+                // try next variant
+            }
+            try {
+                return new UntaggedValue.CircleValue(codec.readValue(codec.treeAsTokens(node), new com.fasterxml.jackson.core.type.TypeReference<com.tot_spec.test.enum_representation.Circle>() {}));
+            } catch (java.io.IOException e) {
+                // try next variant
+            }
+            throw com.fasterxml.jackson.databind.JsonMappingException.from(p, "invalid UntaggedValue");
+        }
+
+        @Override
+        public UntaggedValue getNullValue(com.fasterxml.jackson.databind.DeserializationContext ctxt) {
+            return new UntaggedValue.Empty();
+        }
+    }
+}
@@ -0,0 +1,16 @@
+package com.tot_spec.test.generic;
+import lombok.*;
+import java.util.*;
+
+// one page of items
+@Data
+@Builder
+@AllArgsConstructor
+@NoArgsConstructor
+public class Page<T> {
+    private List<T> items;
+    @com.fasterxml.jackson.annotation.JsonProperty("page_size")
+    private Integer pageSize;
+    @com.fasterxml.jackson.annotation.JsonProperty("next_cursor")
+    private String nextCursor;
+}
@@ -0,0 +1,12 @@
+package com.tot_spec.test.generic;
+import lombok.*;
+import java.util.*;
+
+@Data
+@Builder
+@AllArgsConstructor
+@NoArgsConstructor
+public class Pair<A, B> {
+    private A first;
+    private B second;
+}
@@ -0,0 +1,11 @@
+package com.tot_spec.test.generic;
+import lombok.*;
+import java.util.*;
+
+@Data
+@Builder
+@AllArgsConstructor
+@NoArgsConstructor
+public class User {
+    private String name;
+}
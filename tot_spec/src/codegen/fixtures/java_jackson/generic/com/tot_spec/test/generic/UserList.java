@@ -0,0 +1,13 @@
+package com.tot_spec.test.generic;
+import lombok.*;
+import java.util.*;
+
+@Data
+@Builder
+@AllArgsConstructor
+@NoArgsConstructor
+public class UserList {
+    private com.tot_spec.test.generic.Page<com.tot_spec.test.generic.User> users;
+    private List<com.tot_spec.test.generic.Pair<String, com.tot_spec.test.generic.Page<com.tot_spec.test.generic.User>>> pairs;
+    private com.tot_spec.test.generic.Page<Integer> counts;
+}
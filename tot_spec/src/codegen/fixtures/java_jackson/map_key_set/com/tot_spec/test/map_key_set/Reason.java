@@ -0,0 +1,9 @@
+package com.tot_spec.test.map_key_set;
+import lombok.*;
+import java.util.*;
+
+public class Reason {
+    public static final String OK = "ok";
+
+    public static final String ERROR = "error";
+}
@@ -0,0 +1,16 @@
+package com.tot_spec.test.map_key_set;
+import lombok.*;
+import java.util.*;
+
+@Data
+@Builder
+@AllArgsConstructor
+@NoArgsConstructor
+public class Stats {
+    @com.fasterxml.jackson.annotation.JsonProperty("names_by_id")
+    private Map<Integer, String> namesById;
+    @com.fasterxml.jackson.annotation.JsonProperty("count_by_reason")
+    private Map<String, Integer> countByReason;
+    private Set<String> tags;
+    private Set<Integer> flags;
+}
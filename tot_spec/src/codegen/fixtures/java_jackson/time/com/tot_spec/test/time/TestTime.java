@@ -0,0 +1,28 @@
+package com.tot_spec.test.time;
+import lombok.*;
+import java.util.*;
+
+// struct for time fields
+@Data
+@Builder
+@AllArgsConstructor
+@NoArgsConstructor
+public class TestTime {
+    // timestamp in rfc3339 string
+    @com.fasterxml.jackson.annotation.JsonProperty("created_at")
+    @com.fasterxml.jackson.annotation.JsonFormat(shape = com.fasterxml.jackson.annotation.JsonFormat.Shape.STRING)
+    private java.time.Instant createdAt;
+    // timestamp in epoch millis
+    @com.fasterxml.jackson.annotation.JsonProperty("updated_at")
+    @com.fasterxml.jackson.annotation.JsonFormat(shape = com.fasterxml.jackson.annotation.JsonFormat.Shape.NUMBER_INT, without = {com.fasterxml.jackson.annotation.JsonFormat.Feature.READ_DATE_TIMESTAMPS_AS_NANOSECONDS, com.fasterxml.jackson.annotation.JsonFormat.Feature.WRITE_DATE_TIMESTAMPS_AS_NANOSECONDS})
+    private java.time.Instant updatedAt;
+    @com.fasterxml.jackson.annotation.JsonProperty("expired_at")
+    @com.fasterxml.jackson.annotation.JsonFormat(shape = com.fasterxml.jackson.annotation.JsonFormat.Shape.NUMBER_INT, without = {com.fasterxml.jackson.annotation.JsonFormat.Feature.READ_DATE_TIMESTAMPS_AS_NANOSECONDS, com.fasterxml.jackson.annotation.JsonFormat.Feature.WRITE_DATE_TIMESTAMPS_AS_NANOSECONDS})
+    private java.time.Instant expiredAt;
+    @com.fasterxml.jackson.annotation.JsonFormat(shape = com.fasterxml.jackson.annotation.JsonFormat.Shape.STRING)
+    private java.time.LocalDate birthday;
+    // duration in millis
+    @com.fasterxml.jackson.annotation.JsonFormat(shape = com.fasterxml.jackson.annotation.JsonFormat.Shape.NUMBER_INT, without = {com.fasterxml.jackson.annotation.JsonFormat.Feature.READ_DATE_TIMESTAMPS_AS_NANOSECONDS, com.fasterxml.jackson.annotation.JsonFormat.Feature.WRITE_DATE_TIMESTAMPS_AS_NANOSECONDS})
+    private java.time.Duration timeout;
+    private List<java.time.Instant> history;
+}
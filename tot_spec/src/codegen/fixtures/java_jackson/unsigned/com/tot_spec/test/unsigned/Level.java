@@ -0,0 +1,10 @@
+package com.tot_spec.test.unsigned;
+import lombok.*;
+import java.util.*;
+
+// Const def for u32
+public class Level {
+    public static final Long LOW = 1L;
+
+    public static final Long HIGH = 3000000000L;
+}
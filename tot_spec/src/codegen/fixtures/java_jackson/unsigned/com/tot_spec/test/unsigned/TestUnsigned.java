@@ -0,0 +1,23 @@
+package com.tot_spec.test.unsigned;
+import lombok.*;
+import java.util.*;
+
+// struct for unsigned and f32 fields
+@Data
+@Builder
+@AllArgsConstructor
+@NoArgsConstructor
+public class TestUnsigned {
+    @com.fasterxml.jackson.annotation.JsonProperty("u8_value")
+    private Integer u8Value;
+    @com.fasterxml.jackson.annotation.JsonProperty("u16_value")
+    private Integer u16Value;
+    @com.fasterxml.jackson.annotation.JsonProperty("u32_value")
+    private Long u32Value;
+    @com.fasterxml.jackson.annotation.JsonProperty("u64_value")
+    private java.math.BigInteger u64Value;
+    @com.fasterxml.jackson.annotation.JsonProperty("f32_value")
+    private Float f32Value;
+    @com.fasterxml.jackson.annotation.JsonProperty("u32_list")
+    private List<Long> u32List;
+}
@@ -0,0 +1,26 @@
+package com.tot_spec.test.wire_name;
+import lombok.*;
+import java.util.*;
+
+@com.fasterxml.jackson.annotation.JsonTypeInfo(use = com.fasterxml.jackson.annotation.JsonTypeInfo.Id.NAME, property = "type")
+@com.fasterxml.jackson.annotation.JsonSubTypes({
+    @com.fasterxml.jackson.annotation.JsonSubTypes.Type(value = Shape.Circle.class, name = "circle"),
+    @com.fasterxml.jackson.annotation.JsonSubTypes.Type(value = Shape.BigSquare.class, name = "square"),
+})
+public abstract class Shape {
+    @Data
+    @Builder
+    @AllArgsConstructor
+    @NoArgsConstructor
+    public static class Circle extends Shape {
+        private Double payload;
+    }
+
+    @Data
+    @Builder
+    @AllArgsConstructor
+    @NoArgsConstructor
+    public static class BigSquare extends Shape {
+        private Double payload;
+    }
+}
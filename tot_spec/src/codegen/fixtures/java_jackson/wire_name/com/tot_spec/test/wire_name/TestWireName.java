@@ -0,0 +1,17 @@
+package com.tot_spec.test.wire_name;
+import lombok.*;
+import java.util.*;
+
+// struct with renamed fields
+@Data
+@Builder
+@AllArgsConstructor
+@NoArgsConstructor
+public class TestWireName {
+    @com.fasterxml.jackson.annotation.JsonProperty("userID")
+    private String userId;
+    private String displayName;
+    private Integer createdAt;
+    @com.fasterxml.jackson.annotation.JsonProperty("content-type")
+    private String contentType;
+}
@@ -0,0 +1,19 @@
+package com.tot_spec.test.const_i8
+
+import kotlinx.serialization.*
+
+/**
+ * Const def for i8
+ * Second line of comment
+ */
+@Serializable
+@JvmInline
+value class Code(val value: Byte) {
+    companion object {
+        /** Everything is ok */
+        val OK = Code(0)
+
+        /** Request is bad */
+        val ERROR = Code(1)
+    }
+}
@@ -0,0 +1,16 @@
+package com.tot_spec.test.const_string
+
+import kotlinx.serialization.*
+
+/** Const def for string */
+@Serializable
+@JvmInline
+value class Reason(val value: String) {
+    companion object {
+        /** Everything is ok */
+        val OK = Reason("ok")
+
+        /** Request is bad */
+        val ERROR = Reason("error")
+    }
+}
@@ -0,0 +1,9 @@
+package com.tot_spec.test.decimal
+
+import kotlinx.serialization.*
+
+/** struct for decimal field */
+@Serializable
+data class TestDecimal(
+    val value: String? = null,
+)
@@ -0,0 +1,7 @@
+package com.tot_spec.test.empty_struct
+
+import kotlinx.serialization.*
+
+/** Empty struct with no fields */
+@Serializable
+class EmptyStruct
@@ -0,0 +1,29 @@
+package com.tot_spec.test.enum_custom_tag
+
+import kotlinx.serialization.*
+import kotlinx.serialization.json.*
+
+@OptIn(ExperimentalSerializationApi::class)
+@Serializable
+@JsonClassDiscriminator("kind")
+sealed class Number {
+    /** Variant Int64 */
+    @Serializable
+    @SerialName("Int64")
+    data class Int64(val data: Long) : Number()
+
+    /** Variant Float */
+    @Serializable
+    @SerialName("Float")
+    data class Float(val data: Double) : Number()
+
+    @Serializable
+    @SerialName("RealNumber")
+    data class RealNumber(val data: com.tot_spec.test.enum_custom_tag.RealNumber) : Number()
+}
+
+@Serializable
+data class RealNumber(
+    @SerialName("part_0") val part0: Double? = null,
+    @SerialName("part_1") val part1: Double? = null,
+)
@@ -0,0 +1,138 @@
+package com.tot_spec.test.enum_representation
+
+import kotlinx.serialization.*
+import kotlinx.serialization.descriptors.*
+import kotlinx.serialization.encoding.*
+import kotlinx.serialization.json.*
+
+@Serializable
+data class Circle(
+    val radius: Double,
+)
+
+@Serializable
+data class Rect(
+    val width: Double,
+    val height: Double,
+)
+
+/** internally tagged, tag is along with payload's fields */
+@Serializable(with = InternalShape.Serializer::class)
+sealed class InternalShape {
+    data class CircleShape(val payload: Circle) : InternalShape()
+
+    data class RectShape(val payload: Rect) : InternalShape()
+
+    data object Empty : InternalShape()
+
+    object Serializer : KSerializer<InternalShape> {
+        override val descriptor: SerialDescriptor = buildClassSerialDescriptor("com.tot_spec.test.enum_representation.InternalShape")
+
+        override fun serialize(encoder: Encoder, value: InternalShape) {
+            val json = (encoder as JsonEncoder).json
+            val element = when (value) {
+                is CircleShape -> JsonObject(mapOf("kind" to JsonPrimitive("circle")) + json.encodeToJsonElement(value.payload).jsonObject)
+                is RectShape -> JsonObject(mapOf("kind" to JsonPrimitive("rect")) + json.encodeToJsonElement(value.payload).jsonObject)
+                is Empty -> JsonObject(mapOf("kind" to JsonPrimitive("Empty")))
+            }
+            encoder.encodeJsonElement(element)
+        }
+
+        override fun deserialize(decoder: Decoder): InternalShape {
+            val json = (decoder as JsonDecoder).json
+            val element = decoder.decodeJsonElement()
+            val fields = element.jsonObject
+            return when (val tag = fields["kind"]?.jsonPrimitive?.content) {
+                "circle" -> CircleShape(json.decodeFromJsonElement(JsonObject(fields - "kind")))
+                "rect" -> RectShape(json.decodeFromJsonElement(JsonObject(fields - "kind")))
+                "Empty" -> Empty
+                else -> throw SerializationException("unknown variant $tag of InternalShape")
+            }
+        }
+    }
+}
+
+/** externally tagged, variant name is the key */
+@Serializable(with = ExternalShape.Serializer::class)
+sealed class ExternalShape {
+    data class CircleShape(val payload: Circle) : ExternalShape()
+
+    data class Size(val payload: Double) : ExternalShape()
+
+    data object Empty : ExternalShape()
+
+    object Serializer : KSerializer<ExternalShape> {
+        override val descriptor: SerialDescriptor = buildClassSerialDescriptor("com.tot_spec.test.enum_representation.ExternalShape")
+
+        override fun serialize(encoder: Encoder, value: ExternalShape) {
+            val json = (encoder as JsonEncoder).json
+            val element = when (value) {
+                is CircleShape -> JsonObject(mapOf("circle" to json.encodeToJsonElement(value.payload)))
+                is Size -> JsonObject(mapOf("Size" to json.encodeToJsonElement(value.payload)))
+                is Empty -> JsonPrimitive("Empty")
+            }
+            encoder.encodeJsonElement(element)
+        }
+
+        override fun deserialize(decoder: Decoder): ExternalShape {
+            val json = (decoder as JsonDecoder).json
+            val element = decoder.decodeJsonElement()
+            if (element is JsonPrimitive) {
+                return when (val tag = element.content) {
+                    "Empty" -> Empty
+                    else -> throw SerializationException("unknown variant $tag of ExternalShape")
+                }
+            }
+            val (tag, payload) = element.jsonObject.entries.singleOrNull()
+                ?: throw SerializationException("expect one key for ExternalShape")
+            return when (tag) {
+                "circle" -> CircleShape(json.decodeFromJsonElement(payload))
+                "Size" -> Size(json.decodeFromJsonElement(payload))
+                else -> throw SerializationException("unknown variant $tag of ExternalShape")
+            }
+        }
+    }
+}
+
+/** untagged, the first matching variant wins */
+@Serializable(with = UntaggedValue.Serializer::class)
+sealed class UntaggedValue {
+    data class Number(val payload: Double) : UntaggedValue()
+
+    data class Text(val payload: String) : UntaggedValue()
+
+    data class Texts(val payload: List<String>) : UntaggedValue()
+
+    data class CircleValue(val payload: Circle) : UntaggedValue()
+
+    data object Empty : UntaggedValue()
+
+    object Serializer : KSerializer<UntaggedValue> {
+        override val descriptor: SerialDescriptor = buildClassSerialDescriptor("com.tot_spec.test.enum_representation.UntaggedValue")
+
+        override fun serialize(encoder: Encoder, value: UntaggedValue) {
+            val json = (encoder as JsonEncoder).json
+            val element = when (value) {
+                is Number -> json.encodeToJsonElement(value.payload)
+                is Text -> json.encodeToJsonElement(value.payload)
+                is Texts -> json.encodeToJsonElement(value.payload)
+                is CircleValue -> json.encodeToJsonElement(value.payload)
+                is Empty -> JsonNull
+            }
+            encoder.encodeJsonElement(element)
+        }
+
+        override fun deserialize(decoder: Decoder): UntaggedValue {
+            val json = (decoder as JsonDecoder).json
+            val element = decoder.decodeJsonElement()
+            if (element is JsonNull) {
+                return Empty
+            }
+            runCatching { return Number(json.decodeFromJsonElement(element)) }
+            runCatching { return Text(json.decodeFromJsonElement(element)) }
+            runCatching { return Texts(json.decodeFromJsonElement(element)) }
+            runCatching { return CircleValue(json.decodeFromJsonElement(element)) }
+            throw SerializationException("no variant of UntaggedValue matches $element")
+        }
+    }
+}
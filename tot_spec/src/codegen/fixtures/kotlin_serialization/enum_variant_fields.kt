@@ -0,0 +1,35 @@
+package com.tot_spec.test.enum_variant_fields
+
+import kotlinx.serialization.*
+import kotlinx.serialization.json.*
+
+@OptIn(ExperimentalSerializationApi::class)
+@Serializable
+@JsonClassDiscriminator("type")
+sealed class Number {
+    @Serializable
+    @SerialName("Real")
+    data class Real(val payload: Payload) : Number() {
+        @Serializable
+        data class Payload(
+            val real: Long,
+            val imagine: Long,
+        )
+    }
+}
+
+/** Number2 with variant with fields */
+@OptIn(ExperimentalSerializationApi::class)
+@Serializable
+@JsonClassDiscriminator("type")
+sealed class Number2 {
+    @Serializable
+    @SerialName("Real")
+    data class Real(val payload: Payload) : Number2() {
+        @Serializable
+        data class Payload(
+            val real: Long,
+            val imagine: Long,
+        )
+    }
+}
@@ -0,0 +1,13 @@
+package com.tot_spec.test.extend
+
+import kotlinx.serialization.*
+
+interface Base {
+    val id: String
+}
+
+@Serializable
+data class Child(
+    override val id: String,
+    val name: String,
+) : Base
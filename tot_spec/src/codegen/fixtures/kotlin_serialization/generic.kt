@@ -0,0 +1,29 @@
+package com.tot_spec.test.generic
+
+import kotlinx.serialization.*
+
+@Serializable
+data class User(
+    val name: String,
+)
+
+/** one page of items */
+@Serializable
+data class Page<T>(
+    val items: List<T>,
+    @SerialName("page_size") val pageSize: Int? = null,
+    @SerialName("next_cursor") val nextCursor: String? = null,
+)
+
+@Serializable
+data class Pair<A, B>(
+    val first: A,
+    val second: B? = null,
+)
+
+@Serializable
+data class UserList(
+    val users: Page<User>,
+    val pairs: List<Pair<String, Page<User>>>,
+    val counts: Page<Long>? = null,
+)
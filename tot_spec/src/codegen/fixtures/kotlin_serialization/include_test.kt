@@ -0,0 +1,12 @@
+package com.tot_spec.test.include_test
+
+import kotlinx.serialization.*
+
+@Serializable
+data class TestBase(
+    /** use base's BaseId as the id */
+    val id: com.tot_spec.test.base.Id,
+    /** use base_dup's BaseId as the id_2, this is just demo */
+    @SerialName("id_2") val id2: com.tot_spec.test.base.Id,
+    val common: com.tot_spec.test.base.Common,
+)
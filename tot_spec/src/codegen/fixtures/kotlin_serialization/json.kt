@@ -0,0 +1,9 @@
+package com.tot_spec.test.json
+
+import kotlinx.serialization.*
+
+/** Test struct for json field */
+@Serializable
+data class TestJsonStruct(
+    @SerialName("json_value") val jsonValue: kotlinx.serialization.json.JsonElement? = null,
+)
@@ -0,0 +1,21 @@
+package com.tot_spec.test.map_key_set
+
+import kotlinx.serialization.*
+
+@Serializable
+@JvmInline
+value class Reason(val value: String) {
+    companion object {
+        val OK = Reason("ok")
+
+        val ERROR = Reason("error")
+    }
+}
+
+@Serializable
+data class Stats(
+    @SerialName("names_by_id") val namesById: Map<Int, String>,
+    @SerialName("count_by_reason") val countByReason: Map<Reason, Long>? = null,
+    val tags: Set<String>,
+    val flags: Set<UByte>? = null,
+)
@@ -0,0 +1,12 @@
+package com.tot_spec.test.new_type
+
+import kotlinx.serialization.*
+
+/** NewType to i64, and derive Ord macros */
+@Serializable
+@JvmInline
+value class Id(val value: Long)
+
+@Serializable
+@JvmInline
+value class DictNewType(val value: Map<String, ByteArray>)
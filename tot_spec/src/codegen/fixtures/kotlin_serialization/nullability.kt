@@ -0,0 +1,21 @@
+package com.tot_spec.test.nullability
+
+import kotlinx.serialization.*
+
+/** patch style request, absent means unchanged and null means clear */
+@Serializable
+data class UpdateUserRequest(
+    val id: String,
+    val name: String? = null,
+    val email: String?,
+    // absent and null are both decoded as null
+    val nickname: String? = null,
+    // absent and null are both decoded as null
+    val address: Address? = null,
+    val tags: List<String>? = null,
+)
+
+@Serializable
+data class Address(
+    val city: String,
+)
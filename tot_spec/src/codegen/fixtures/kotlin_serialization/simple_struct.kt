@@ -0,0 +1,22 @@
+package com.tot_spec.test.simple_struct
+
+import kotlinx.serialization.*
+
+/** Example of simple struct definition */
+@Serializable
+data class SimpleStruct(
+    /** bool value */
+    @SerialName("bool_value") val boolValue: Boolean,
+    /** i8 value */
+    @SerialName("i8_value") val i8Value: Byte,
+    @SerialName("i16_value") val i16Value: Short? = null,
+    @SerialName("i32_value") val i32Value: Int? = null,
+    @SerialName("i64_value") val i64Value: Long? = null,
+    @SerialName("string_value") val stringValue: String? = null,
+    @SerialName("bytes_value") val bytesValue: ByteArray? = null,
+    @SerialName("string_to_string") val stringToString: Map<String, String>? = null,
+    /** nested self */
+    val children: List<SimpleStruct>? = null,
+    /** this field is required */
+    @SerialName("required_str_value") val requiredStrValue: String,
+)
@@ -0,0 +1,17 @@
+package com.tot_spec.test.time
+
+import kotlinx.serialization.*
+
+/** struct for time fields */
+@Serializable
+data class TestTime(
+    /** timestamp in rfc3339 string */
+    @SerialName("created_at") val createdAt: kotlinx.datetime.Instant,
+    /** timestamp in epoch millis */
+    @SerialName("updated_at") val updatedAt: Long? = null,
+    @SerialName("expired_at") val expiredAt: Long? = null,
+    val birthday: kotlinx.datetime.LocalDate? = null,
+    /** duration in millis */
+    val timeout: Long? = null,
+    val history: List<kotlinx.datetime.Instant>? = null,
+)
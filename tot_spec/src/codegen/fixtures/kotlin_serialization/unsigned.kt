@@ -0,0 +1,25 @@
+package com.tot_spec.test.unsigned
+
+import kotlinx.serialization.*
+
+/** struct for unsigned and f32 fields */
+@Serializable
+data class TestUnsigned(
+    @SerialName("u8_value") val u8Value: UByte,
+    @SerialName("u16_value") val u16Value: UShort? = null,
+    @SerialName("u32_value") val u32Value: UInt? = null,
+    @SerialName("u64_value") val u64Value: ULong? = null,
+    @SerialName("f32_value") val f32Value: Float? = null,
+    @SerialName("u32_list") val u32List: List<UInt>? = null,
+)
+
+/** Const def for u32 */
+@Serializable
+@JvmInline
+value class Level(val value: UInt) {
+    companion object {
+        val LOW = Level(1u)
+
+        val HIGH = Level(3000000000u)
+    }
+}
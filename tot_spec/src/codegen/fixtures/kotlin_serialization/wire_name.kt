@@ -0,0 +1,26 @@
+package com.tot_spec.test.wire_name
+
+import kotlinx.serialization.*
+import kotlinx.serialization.json.*
+
+/** struct with renamed fields */
+@Serializable
+data class TestWireName(
+    @SerialName("userID") val userId: String,
+    val displayName: String? = null,
+    val createdAt: Long? = null,
+    @SerialName("content-type") val contentType: String? = null,
+)
+
+@OptIn(ExperimentalSerializationApi::class)
+@Serializable
+@JsonClassDiscriminator("type")
+sealed class Shape {
+    @Serializable
+    @SerialName("circle")
+    data class Circle(val payload: Double) : Shape()
+
+    @Serializable
+    @SerialName("square")
+    data class BigSquare(val payload: Double) : Shape()
+}
@@ -1,9 +1,12 @@
 # import annotations to enable forward declaration
 from __future__ import annotations
 from dataclasses import dataclass
+import dataclasses
 import abc
 import typing
 import decimal
+import datetime
+import re
 
 
 # Const def for string
@@ -0,0 +1,150 @@
+# import annotations to enable forward declaration
+from __future__ import annotations
+from dataclasses import dataclass
+import dataclasses
+import abc
+import typing
+import decimal
+import datetime
+import re
+
+
+# struct with field constraints
+@dataclass
+class TestConstraints:
+    name: str
+    age: typing.Optional[int] = None
+    score: typing.Optional[float] = None
+    tags: typing.Optional[typing.List[str]] = None
+    labels: typing.Optional[typing.Dict[str, str]] = None
+    desc: typing.Optional[str] = None
+
+    def to_dict(self):
+        result = {}
+
+        # name
+        result["name"] = self.name
+
+        # age
+        if self.age is None:
+            result["age"] = None
+        else:
+            age_tmp = self.age
+            result["age"] = age_tmp
+
+        # score
+        result["score"] = self.score
+
+        # tags
+        if self.tags is None:
+            result["tags"] = None
+        else:
+            tags_tmp = []
+            for item in self.tags:
+                item_tmp = item
+                tags_tmp.append(item_tmp)
+
+            result["tags"] = tags_tmp
+
+        # labels
+        if self.labels is None:
+            result["labels"] = None
+        else:
+            labels_tmp = {}
+            for key, item in self.labels.items():
+                item_tmp = item
+                labels_tmp[key] = item_tmp
+
+            result["labels"] = labels_tmp
+
+        # desc
+        result["desc"] = self.desc
+        return result
+
+
+    @staticmethod
+    def from_dict(d):
+
+        # name
+        name_tmp = d["name"]
+
+        # age
+        age_tmp = None
+        if (item := d.get("age")) is not None:
+            age_tmp = int(item)
+
+        # score
+        score_tmp = d.get("score", None)
+
+        # tags
+        tags_tmp = None
+        if (item := d.get("tags")) is not None:
+            tags_tmp = []
+            for item in item:
+                item_tmp = item
+                tags_tmp.append(item_tmp)
+
+
+        # labels
+        labels_tmp = None
+        if (item := d.get("labels")) is not None:
+            labels_tmp = {}
+            for key, item in item.items():
+                item_tmp = item
+                labels_tmp[key] = item_tmp
+
+
+        # desc
+        desc_tmp = d.get("desc", None)
+        return TestConstraints(
+            name = name_tmp,
+            age = age_tmp,
+            score = score_tmp,
+            tags = tags_tmp,
+            labels = labels_tmp,
+            desc = desc_tmp,
+        )
+
+
+
+    def validate(self):
+
+        # name
+        value = self.name
+        if len(value) == 0:
+            raise ValueError("field:name expect non empty value")
+        if len(value) > 64:
+            raise ValueError("field:name expect length <= 64")
+        if re.search("^[a-z_]+$", value) is None:
+            raise ValueError("field:name expect value to match \"^[a-z_]+$\"")
+
+        # age
+        value = self.age
+        if value is not None:
+            if value < 1:
+                raise ValueError("field:age expect value >= 1")
+            if value > 150:
+                raise ValueError("field:age expect value <= 150")
+
+        # score
+        value = self.score
+        if value is not None:
+            if value < 0:
+                raise ValueError("field:score expect value >= 0")
+            if value > 1.5:
+                raise ValueError("field:score expect value <= 1.5")
+
+        # tags
+        value = self.tags
+        if value is not None:
+            if len(value) < 1:
+                raise ValueError("field:tags expect length >= 1")
+            if len(value) > 10:
+                raise ValueError("field:tags expect length <= 10")
+
+        # labels
+        value = self.labels
+        if value is not None:
+            if len(value) == 0:
+                raise ValueError("field:labels expect non empty value")
+
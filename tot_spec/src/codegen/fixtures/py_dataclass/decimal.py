@@ -1,9 +1,12 @@
 # import annotations to enable forward declaration
 from __future__ import annotations
 from dataclasses import dataclass
+import dataclasses
 import abc
 import typing
 import decimal
+import datetime
+import re
 
 
 # struct for decimal field
@@ -28,7 +31,7 @@ class TestDecimal:
 
         # value
         value_tmp = None
-        if item := d.get("value"):
+        if (item := d.get("value")) is not None:
             value_tmp = decimal.Decimal(item)
         return TestDecimal(
             value = value_tmp,
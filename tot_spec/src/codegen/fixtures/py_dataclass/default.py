@@ -0,0 +1,138 @@
+# import annotations to enable forward declaration
+from __future__ import annotations
+from dataclasses import dataclass
+import dataclasses
+import abc
+import typing
+import decimal
+import datetime
+import re
+
+
+# struct with field defaults
+@dataclass
+class TestDefault:
+    id: str
+    page_size: int = 10
+    ratio: float = 1
+    enabled: bool = True
+    name: str = "unnamed"
+    tags: typing.List[str] = dataclasses.field(default_factory=lambda: ["a", "b"])
+    labels: typing.Dict[str, int] = dataclasses.field(default_factory=lambda: {})
+    desc: typing.Optional[str] = None
+
+    def to_dict(self):
+        result = {}
+
+        # id
+        result["id"] = self.id
+
+        # page_size
+        if self.page_size is None:
+            result["page_size"] = None
+        else:
+            page_size_tmp = self.page_size
+            result["page_size"] = page_size_tmp
+
+        # ratio
+        result["ratio"] = self.ratio
+
+        # enabled
+        result["enabled"] = self.enabled
+
+        # name
+        result["name"] = self.name
+
+        # tags
+        if self.tags is None:
+            result["tags"] = None
+        else:
+            tags_tmp = []
+            for item in self.tags:
+                item_tmp = item
+                tags_tmp.append(item_tmp)
+
+            result["tags"] = tags_tmp
+
+        # labels
+        if self.labels is None:
+            result["labels"] = None
+        else:
+            labels_tmp = {}
+            for key, item in self.labels.items():
+                item_tmp = item
+                labels_tmp[key] = item_tmp
+
+            result["labels"] = labels_tmp
+
+        # desc
+        result["desc"] = self.desc
+        return result
+
+
+    @staticmethod
+    def from_dict(d):
+
+        # id
+        id_tmp = d["id"]
+
+        # page_size
+        page_size_tmp = 10
+        if (item := d.get("page_size")) is not None:
+            page_size_tmp = int(item)
+
+        # ratio
+        ratio_tmp = 1
+        if (item := d.get("ratio")) is not None:
+            ratio_tmp = item
+
+        # enabled
+        enabled_tmp = True
+        if (item := d.get("enabled")) is not None:
+            enabled_tmp = item
+
+        # name
+        name_tmp = "unnamed"
+        if (item := d.get("name")) is not None:
+            name_tmp = item
+
+        # tags
+        tags_tmp = ["a", "b"]
+        if (item := d.get("tags")) is not None:
+            tags_tmp = []
+            for item in item:
+                item_tmp = item
+                tags_tmp.append(item_tmp)
+
+
+        # labels
+        labels_tmp = {}
+        if (item := d.get("labels")) is not None:
+            labels_tmp = {}
+            for key, item in item.items():
+                item_tmp = int(item)
+                labels_tmp[key] = item_tmp
+
+
+        # desc
+        desc_tmp = d.get("desc", None)
+        return TestDefault(
+            id = id_tmp,
+            page_size = page_size_tmp,
+            ratio = ratio_tmp,
+            enabled = enabled_tmp,
+            name = name_tmp,
+            tags = tags_tmp,
+            labels = labels_tmp,
+            desc = desc_tmp,
+        )
+
+
+
+    def validate(self):
+
+        # page_size
+        value = self.page_size
+        if value < 1:
+            raise ValueError("field:page_size expect value >= 1")
+
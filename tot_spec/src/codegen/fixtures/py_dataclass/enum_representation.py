@@ -0,0 +1,283 @@
+# import annotations to enable forward declaration
+from __future__ import annotations
+from dataclasses import dataclass
+import dataclasses
+import abc
+import typing
+import decimal
+import datetime
+import re
+
+
+@dataclass
+class Circle:
+    radius: float
+
+    def to_dict(self):
+        result = {}
+
+        # radius
+        result["radius"] = self.radius
+        return result
+
+
+    @staticmethod
+    def from_dict(d):
+
+        # radius
+        radius_tmp = d["radius"]
+        return Circle(
+            radius = radius_tmp,
+        )
+
+
+
+@dataclass
+class Rect:
+    width: float
+    height: float
+
+    def to_dict(self):
+        result = {}
+
+        # width
+        result["width"] = self.width
+
+        # height
+        result["height"] = self.height
+        return result
+
+
+    @staticmethod
+    def from_dict(d):
+
+        # width
+        width_tmp = d["width"]
+
+        # height
+        height_tmp = d["height"]
+        return Rect(
+            width = width_tmp,
+            height = height_tmp,
+        )
+
+
+
+# internally tagged, tag is along with payload's fields
+class InternalShape(abc.ABC):
+    pass
+
+    @abc.abstractmethod
+    def to_dict(self):
+        pass
+
+    @staticmethod
+    def from_dict(d):
+        type_ = d["kind"]
+        if type_ == "circle":
+            payload_tmp = Circle.from_dict(d)
+            return InternalShape_CircleShape(payload=payload_tmp)
+        elif type_ == "rect":
+            payload_tmp = Rect.from_dict(d)
+            return InternalShape_RectShape(payload=payload_tmp)
+        elif type_ == "Empty":
+            return InternalShape_Empty()
+        else:
+            raise ValueError(f"invalid type: {type_}")
+
+
+# variant CircleShape for InternalShape
+@dataclass
+class InternalShape_CircleShape(InternalShape):
+    payload: Circle
+
+    def to_dict(self):
+        type_ = "circle"
+        payload_tmp = self.payload.to_dict()
+        return {
+            "kind": type_,
+            **payload_tmp,
+        }
+
+
+# variant RectShape for InternalShape
+@dataclass
+class InternalShape_RectShape(InternalShape):
+    payload: Rect
+
+    def to_dict(self):
+        type_ = "rect"
+        payload_tmp = self.payload.to_dict()
+        return {
+            "kind": type_,
+            **payload_tmp,
+        }
+
+
+# variant Empty for InternalShape
+@dataclass
+class InternalShape_Empty(InternalShape):
+    pass
+
+    def to_dict(self):
+        type_ = "Empty"
+        return {
+            "kind": type_,
+        }
+
+
+# externally tagged, variant name is the key
+class ExternalShape(abc.ABC):
+    pass
+
+    @abc.abstractmethod
+    def to_dict(self):
+        pass
+
+    @staticmethod
+    def from_dict(d):
+        if isinstance(d, str):
+            type_, payload = d, None
+        else:
+            (type_, payload), = d.items()
+        if type_ == "circle":
+            payload_tmp = Circle.from_dict(payload)
+            return ExternalShape_CircleShape(payload=payload_tmp)
+        elif type_ == "Size":
+            payload_tmp = payload
+            return ExternalShape_Size(payload=payload_tmp)
+        elif type_ == "Empty":
+            return ExternalShape_Empty()
+        else:
+            raise ValueError(f"invalid type: {type_}")
+
+
+# variant CircleShape for ExternalShape
+@dataclass
+class ExternalShape_CircleShape(ExternalShape):
+    payload: Circle
+
+    def to_dict(self):
+        type_ = "circle"
+        payload_tmp = self.payload.to_dict()
+        return {type_: payload_tmp}
+
+
+# variant Size for ExternalShape
+@dataclass
+class ExternalShape_Size(ExternalShape):
+    payload: float
+
+    def to_dict(self):
+        type_ = "Size"
+        payload_tmp = self.payload
+        return {type_: payload_tmp}
+
+
+# variant Empty for ExternalShape
+@dataclass
+class ExternalShape_Empty(ExternalShape):
+    pass
+
+    def to_dict(self):
+        type_ = "Empty"
+        return type_
+
+
+# untagged, the first matching variant wins
+class UntaggedValue(abc.ABC):
+    pass
+
+    @abc.abstractmethod
+    def to_dict(self):
+        pass
+
+    @staticmethod
+    def from_dict(d):
+        try:
+            if not isinstance(d, (int, float)):
+                raise TypeError("expect (int, float)")
+            payload_tmp = d
+            return UntaggedValue_Number(payload=payload_tmp)
+        except (KeyError, TypeError, ValueError):
+            pass
+        try:
+            if not isinstance(d, str):
+                raise TypeError("expect str")
+            payload_tmp = d
+            return UntaggedValue_Text(payload=payload_tmp)
+        except (KeyError, TypeError, ValueError):
+            pass
+        try:
+            if not isinstance(d, list):
+                raise TypeError("expect list")
+            payload_tmp = []
+            for item in d:
+                item_tmp = item
+                payload_tmp.append(item_tmp)
+
+            return UntaggedValue_Texts(payload=payload_tmp)
+        except (KeyError, TypeError, ValueError):
+            pass
+        try:
+            payload_tmp = Circle.from_dict(d)
+            return UntaggedValue_CircleValue(payload=payload_tmp)
+        except (KeyError, TypeError, ValueError):
+            pass
+        if d is None:
+            return UntaggedValue_Empty()
+        raise ValueError(f"no variant matches: {d}")
+
+
+# variant Number for UntaggedValue
+@dataclass
+class UntaggedValue_Number(UntaggedValue):
+    payload: float
+
+    def to_dict(self):
+        payload_tmp = self.payload
+        return payload_tmp
+
+
+# variant Text for UntaggedValue
+@dataclass
+class UntaggedValue_Text(UntaggedValue):
+    payload: str
+
+    def to_dict(self):
+        payload_tmp = self.payload
+        return payload_tmp
+
+
+# variant Texts for UntaggedValue
+@dataclass
+class UntaggedValue_Texts(UntaggedValue):
+    payload: typing.List[str]
+
+    def to_dict(self):
+        payload_tmp = []
+        for item in self.payload:
+            item_tmp = item
+            payload_tmp.append(item_tmp)
+
+        return payload_tmp
+
+
+# variant CircleValue for UntaggedValue
+@dataclass
+class UntaggedValue_CircleValue(UntaggedValue):
+    payload: Circle
+
+    def to_dict(self):
+        payload_tmp = self.payload.to_dict()
+        return payload_tmp
+
+
+# variant Empty for UntaggedValue
+@dataclass
+class UntaggedValue_Empty(UntaggedValue):
+    pass
+
+    def to_dict(self):
+        return None
+
@@ -0,0 +1,181 @@
+# import annotations to enable forward declaration
+from __future__ import annotations
+from dataclasses import dataclass
+import dataclasses
+import abc
+import typing
+import decimal
+import datetime
+import re
+
+
+@dataclass
+class User:
+    name: str
+
+    def to_dict(self):
+        result = {}
+
+        # name
+        result["name"] = self.name
+        return result
+
+
+    @staticmethod
+    def from_dict(d):
+
+        # name
+        name_tmp = d["name"]
+        return User(
+            name = name_tmp,
+        )
+
+
+
+# one page of items
+@dataclass
+class Page:
+    items: typing.List[typing.Any]
+    page_size: int = 20
+    next_cursor: typing.Optional[str] = None
+
+    def to_dict(self):
+        result = {}
+
+        # items
+        items_tmp = []
+        for item in self.items:
+            item_tmp = item.to_dict() if hasattr(item, "to_dict") else item
+            items_tmp.append(item_tmp)
+
+        result["items"] = items_tmp
+
+        # page_size
+        if self.page_size is None:
+            result["page_size"] = None
+        else:
+            page_size_tmp = self.page_size
+            result["page_size"] = page_size_tmp
+
+        # next_cursor
+        result["next_cursor"] = self.next_cursor
+        return result
+
+
+    @staticmethod
+    def from_dict(d):
+
+        # items
+        items_tmp = []
+        for item in d["items"]:
+            item_tmp = item
+            items_tmp.append(item_tmp)
+
+
+        # page_size
+        page_size_tmp = 20
+        if (item := d.get("page_size")) is not None:
+            page_size_tmp = int(item)
+
+        # next_cursor
+        next_cursor_tmp = d.get("next_cursor", None)
+        return Page(
+            items = items_tmp,
+            page_size = page_size_tmp,
+            next_cursor = next_cursor_tmp,
+        )
+
+
+
+@dataclass
+class Pair:
+    first: typing.Any
+    second: typing.Optional[typing.Any] = None
+
+    def to_dict(self):
+        result = {}
+
+        # first
+        first_tmp = self.first.to_dict() if hasattr(self.first, "to_dict") else self.first
+        result["first"] = first_tmp
+
+        # second
+        if self.second is None:
+            result["second"] = None
+        else:
+            second_tmp = self.second.to_dict() if hasattr(self.second, "to_dict") else self.second
+            result["second"] = second_tmp
+        return result
+
+
+    @staticmethod
+    def from_dict(d):
+
+        # first
+        first_tmp = d["first"]
+
+        # second
+        second_tmp = None
+        if (item := d.get("second")) is not None:
+            second_tmp = item
+        return Pair(
+            first = first_tmp,
+            second = second_tmp,
+        )
+
+
+
+@dataclass
+class UserList:
+    users: Page
+    pairs: typing.List[Pair]
+    counts: typing.Optional[Page] = None
+
+    def to_dict(self):
+        result = {}
+
+        # users
+        users_tmp = self.users.to_dict()
+        result["users"] = users_tmp
+
+        # pairs
+        pairs_tmp = []
+        for item in self.pairs:
+            item_tmp = item.to_dict()
+            pairs_tmp.append(item_tmp)
+
+        result["pairs"] = pairs_tmp
+
+        # counts
+        if self.counts is None:
+            result["counts"] = None
+        else:
+            counts_tmp = self.counts.to_dict()
+            result["counts"] = counts_tmp
+        return result
+
+
+    @staticmethod
+    def from_dict(d):
+
+        # users
+        users_tmp = Page.from_dict(d["users"])
+
+        # pairs
+        pairs_tmp = []
+        for item in d["pairs"]:
+            item_tmp = Pair.from_dict(item)
+            pairs_tmp.append(item_tmp)
+
+
+        # counts
+        counts_tmp = None
+        if (item := d.get("counts")) is not None:
+            counts_tmp = Page.from_dict(item)
+        return UserList(
+            users = users_tmp,
+            pairs = pairs_tmp,
+            counts = counts_tmp,
+        )
+
+
@@ -1,9 +1,12 @@
 # import annotations to enable forward declaration
 from __future__ import annotations
 from dataclasses import dataclass
+import dataclasses
 import abc
 import typing
 import decimal
+import datetime
+import re
 
 
 # Test struct for json field
@@ -24,7 +27,7 @@ class TestJsonStruct:
 
         # json_value
         json_value_tmp = None
-        if item := d.get("json_value"):
+        if (item := d.get("json_value")) is not None:
             json_value_tmp = item
         return TestJsonStruct(
             json_value = json_value_tmp,
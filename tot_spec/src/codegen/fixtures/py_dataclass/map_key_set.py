@@ -0,0 +1,99 @@
+# import annotations to enable forward declaration
+from __future__ import annotations
+from dataclasses import dataclass
+import dataclasses
+import abc
+import typing
+import decimal
+import datetime
+import re
+
+
+class Reason(abc.ABC):
+    Ok: str = "ok"
+    Error: str = "error"
+
+@dataclass
+class Stats:
+    names_by_id: typing.Dict[int, str]
+    tags: typing.Set[str]
+    count_by_reason: typing.Optional[typing.Dict[str, int]] = None
+    flags: typing.Optional[typing.Set[int]] = None
+
+    def to_dict(self):
+        result = {}
+
+        # names_by_id
+        names_by_id_tmp = {}
+        for key, item in self.names_by_id.items():
+            item_tmp = item
+            names_by_id_tmp[key] = item_tmp
+
+        result["names_by_id"] = names_by_id_tmp
+
+        # tags
+        tags_tmp = list(self.tags)
+        result["tags"] = tags_tmp
+
+        # count_by_reason
+        if self.count_by_reason is None:
+            result["count_by_reason"] = None
+        else:
+            count_by_reason_tmp = {}
+            for key, item in self.count_by_reason.items():
+                item_tmp = item
+                count_by_reason_tmp[key] = item_tmp
+
+            result["count_by_reason"] = count_by_reason_tmp
+
+        # flags
+        if self.flags is None:
+            result["flags"] = None
+        else:
+            flags_tmp = list(self.flags)
+            result["flags"] = flags_tmp
+        return result
+
+
+    @staticmethod
+    def from_dict(d):
+
+        # names_by_id
+        names_by_id_tmp = {}
+        for key, item in d["names_by_id"].items():
+            item_tmp = item
+            names_by_id_tmp[int(key)] = item_tmp
+
+
+        # tags
+        tags_tmp = set(d["tags"])
+
+        # count_by_reason
+        count_by_reason_tmp = None
+        if (item := d.get("count_by_reason")) is not None:
+            count_by_reason_tmp = {}
+            for key, item in item.items():
+                item_tmp = int(item)
+                count_by_reason_tmp[key] = item_tmp
+
+
+        # flags
+        flags_tmp = None
+        if (item := d.get("flags")) is not None:
+            flags_tmp = set(item)
+        return Stats(
+            names_by_id = names_by_id_tmp,
+            tags = tags_tmp,
+            count_by_reason = count_by_reason_tmp,
+            flags = flags_tmp,
+        )
+
+
+
+    def validate(self):
+
+        # tags
+        value = self.tags
+        if len(value) > 10:
+            raise ValueError("field:tags expect length <= 10")
+
@@ -1,9 +1,12 @@
 # import annotations to enable forward declaration
 from __future__ import annotations
 from dataclasses import dataclass
+import dataclasses
 import abc
 import typing
 import decimal
+import datetime
+import re
 
 
 # NewType to i64, and derive Ord macros
@@ -39,3 +42,4 @@ class DictNewType:
             value_tmp[key] = item_tmp
 
         return DictNewType(value_tmp)
+
@@ -1,9 +1,12 @@
 # import annotations to enable forward declaration
 from __future__ import annotations
 from dataclasses import dataclass
+import dataclasses
 import abc
 import typing
 import decimal
+import datetime
+import re
 
 
 # Example of simple struct definition
@@ -97,12 +100,12 @@ class SimpleStruct:
 
         # i16_value
         i16_value_tmp = None
-        if item := d.get("i16_value"):
+        if (item := d.get("i16_value")) is not None:
             i16_value_tmp = int(item)
 
         # i32_value
         i32_value_tmp = None
-        if item := d.get("i32_value"):
+        if (item := d.get("i32_value")) is not None:
             i32_value_tmp = int(item)
 
         # i64_value
@@ -118,7 +121,7 @@ class SimpleStruct:
 
         # string_to_string
         string_to_string_tmp = None
-        if item := d.get("string_to_string"):
+        if (item := d.get("string_to_string")) is not None:
             string_to_string_tmp = {}
             for key, item in item.items():
                 item_tmp = item
@@ -127,7 +130,7 @@ class SimpleStruct:
 
         # children
         children_tmp = None
-        if item := d.get("children"):
+        if (item := d.get("children")) is not None:
             children_tmp = []
             for item in item:
                 item_tmp = SimpleStruct.from_dict(item)
@@ -0,0 +1,116 @@
+# import annotations to enable forward declaration
+from __future__ import annotations
+from dataclasses import dataclass
+import dataclasses
+import abc
+import typing
+import decimal
+import datetime
+import re
+
+
+# struct for time fields
+@dataclass
+class TestTime:
+    # timestamp in rfc3339 string
+    created_at: datetime.datetime
+    # timestamp in epoch millis
+    updated_at: typing.Optional[datetime.datetime] = None
+    expired_at: typing.Optional[datetime.datetime] = None
+    birthday: typing.Optional[datetime.date] = None
+    # duration in millis
+    timeout: typing.Optional[datetime.timedelta] = None
+    history: typing.Optional[typing.List[datetime.datetime]] = None
+
+    def to_dict(self):
+        result = {}
+
+        # created_at
+        created_at_tmp = self.created_at.isoformat()
+        result["created_at"] = created_at_tmp
+
+        # updated_at
+        if self.updated_at is None:
+            result["updated_at"] = None
+        else:
+            updated_at_tmp = round(self.updated_at.timestamp() * 1000)
+            result["updated_at"] = updated_at_tmp
+
+        # expired_at
+        if self.expired_at is None:
+            result["expired_at"] = None
+        else:
+            expired_at_tmp = round(self.expired_at.timestamp() * 1000)
+            result["expired_at"] = expired_at_tmp
+
+        # birthday
+        if self.birthday is None:
+            result["birthday"] = None
+        else:
+            birthday_tmp = self.birthday.isoformat()
+            result["birthday"] = birthday_tmp
+
+        # timeout
+        if self.timeout is None:
+            result["timeout"] = None
+        else:
+            timeout_tmp = self.timeout // datetime.timedelta(milliseconds=1)
+            result["timeout"] = timeout_tmp
+
+        # history
+        if self.history is None:
+            result["history"] = None
+        else:
+            history_tmp = []
+            for item in self.history:
+                item_tmp = item.isoformat()
+                history_tmp.append(item_tmp)
+
+            result["history"] = history_tmp
+        return result
+
+
+    @staticmethod
+    def from_dict(d):
+
+        # created_at
+        created_at_tmp = datetime.datetime.fromisoformat(d["created_at"].replace("Z", "+00:00"))
+
+        # updated_at
+        updated_at_tmp = None
+        if (item := d.get("updated_at")) is not None:
+            updated_at_tmp = datetime.datetime.fromtimestamp(item / 1000, tz=datetime.timezone.utc)
+
+        # expired_at
+        expired_at_tmp = None
+        if (item := d.get("expired_at")) is not None:
+            expired_at_tmp = datetime.datetime.fromtimestamp(item / 1000, tz=datetime.timezone.utc)
+
+        # birthday
+        birthday_tmp = None
+        if (item := d.get("birthday")) is not None:
+            birthday_tmp = datetime.date.fromisoformat(item)
+
+        # timeout
+        timeout_tmp = None
+        if (item := d.get("timeout")) is not None:
+            timeout_tmp = datetime.timedelta(milliseconds=item)
+
+        # history
+        history_tmp = None
+        if (item := d.get("history")) is not None:
+            history_tmp = []
+            for item in item:
+                item_tmp = datetime.datetime.fromisoformat(item.replace("Z", "+00:00"))
+                history_tmp.append(item_tmp)
+
+        return TestTime(
+            created_at = created_at_tmp,
+            updated_at = updated_at_tmp,
+            expired_at = expired_at_tmp,
+            birthday = birthday_tmp,
+            timeout = timeout_tmp,
+            history = history_tmp,
+        )
+
+
@@ -0,0 +1,118 @@
+# import annotations to enable forward declaration
+from __future__ import annotations
+from dataclasses import dataclass
+import dataclasses
+import abc
+import typing
+import decimal
+import datetime
+import re
+
+
+# struct for unsigned and f32 fields
+@dataclass
+class TestUnsigned:
+    u8_value: int
+    u16_value: typing.Optional[int] = None
+    u32_value: typing.Optional[int] = None
+    u64_value: typing.Optional[int] = None
+    f32_value: typing.Optional[float] = None
+    u32_list: typing.Optional[typing.List[int]] = None
+
+    def to_dict(self):
+        result = {}
+
+        # u8_value
+        u8_value_tmp = self.u8_value
+        result["u8_value"] = u8_value_tmp
+
+        # u16_value
+        if self.u16_value is None:
+            result["u16_value"] = None
+        else:
+            u16_value_tmp = self.u16_value
+            result["u16_value"] = u16_value_tmp
+
+        # u32_value
+        if self.u32_value is None:
+            result["u32_value"] = None
+        else:
+            u32_value_tmp = self.u32_value
+            result["u32_value"] = u32_value_tmp
+
+        # u64_value
+        if self.u64_value is None:
+            result["u64_value"] = None
+        else:
+            u64_value_tmp = self.u64_value
+            result["u64_value"] = u64_value_tmp
+
+        # f32_value
+        if self.f32_value is None:
+            result["f32_value"] = None
+        else:
+            f32_value_tmp = self.f32_value
+            result["f32_value"] = f32_value_tmp
+
+        # u32_list
+        if self.u32_list is None:
+            result["u32_list"] = None
+        else:
+            u32_list_tmp = []
+            for item in self.u32_list:
+                item_tmp = item
+                u32_list_tmp.append(item_tmp)
+
+            result["u32_list"] = u32_list_tmp
+        return result
+
+
+    @staticmethod
+    def from_dict(d):
+
+        # u8_value
+        u8_value_tmp = int(d["u8_value"])
+
+        # u16_value
+        u16_value_tmp = None
+        if (item := d.get("u16_value")) is not None:
+            u16_value_tmp = int(item)
+
+        # u32_value
+        u32_value_tmp = None
+        if (item := d.get("u32_value")) is not None:
+            u32_value_tmp = int(item)
+
+        # u64_value
+        u64_value_tmp = None
+        if (item := d.get("u64_value")) is not None:
+            u64_value_tmp = int(item)
+
+        # f32_value
+        f32_value_tmp = None
+        if (item := d.get("f32_value")) is not None:
+            f32_value_tmp = item
+
+        # u32_list
+        u32_list_tmp = None
+        if (item := d.get("u32_list")) is not None:
+            u32_list_tmp = []
+            for item in item:
+                item_tmp = int(item)
+                u32_list_tmp.append(item_tmp)
+
+        return TestUnsigned(
+            u8_value = u8_value_tmp,
+            u16_value = u16_value_tmp,
+            u32_value = u32_value_tmp,
+            u64_value = u64_value_tmp,
+            f32_value = f32_value_tmp,
+            u32_list = u32_list_tmp,
+        )
+
+
+
+# Const def for u32
+class Level(abc.ABC):
+    Low: int = 1
+    High: int = 3000000000
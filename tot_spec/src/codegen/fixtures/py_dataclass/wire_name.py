@@ -0,0 +1,108 @@
+# import annotations to enable forward declaration
+from __future__ import annotations
+from dataclasses import dataclass
+import dataclasses
+import abc
+import typing
+import decimal
+import datetime
+import re
+
+
+# struct with renamed fields
+@dataclass
+class TestWireName:
+    user_id: str
+    display_name: typing.Optional[str] = None
+    created_at: typing.Optional[int] = None
+    content_type: typing.Optional[str] = None
+
+    def to_dict(self):
+        result = {}
+
+        # user_id
+        result["userID"] = self.user_id
+
+        # display_name
+        result["displayName"] = self.display_name
+
+        # created_at
+        result["createdAt"] = self.created_at
+
+        # content_type
+        result["content-type"] = self.content_type
+        return result
+
+
+    @staticmethod
+    def from_dict(d):
+
+        # user_id
+        user_id_tmp = d["userID"]
+
+        # display_name
+        display_name_tmp = d.get("displayName", None)
+
+        # created_at
+        created_at_tmp = d.get("createdAt", None)
+
+        # content_type
+        content_type_tmp = d.get("content-type", None)
+        return TestWireName(
+            user_id = user_id_tmp,
+            display_name = display_name_tmp,
+            created_at = created_at_tmp,
+            content_type = content_type_tmp,
+        )
+
+
+
+class Shape(abc.ABC):
+    pass
+
+    @abc.abstractmethod
+    def to_dict(self):
+        pass
+
+    @staticmethod
+    def from_dict(d):
+        type_ = d["type"]
+        if type_ == "circle":
+            payload = d["payload"]
+            payload_tmp = payload
+            return Shape_Circle(payload=payload_tmp)
+        elif type_ == "square":
+            payload = d["payload"]
+            payload_tmp = payload
+            return Shape_BigSquare(payload=payload_tmp)
+        else:
+            raise ValueError(f"invalid type: {type_}")
+
+
+# variant Circle for Shape
+@dataclass
+class Shape_Circle(Shape):
+    payload: float
+
+    def to_dict(self):
+        type_ = "circle"
+        payload_tmp = self.payload
+        return {
+            "type": type_,
+            "payload": payload_tmp,
+        }
+
+
+# variant BigSquare for Shape
+@dataclass
+class Shape_BigSquare(Shape):
+    payload: float
+
+    def to_dict(self):
+        type_ = "square"
+        payload_tmp = self.payload
+        return {
+            "type": type_,
+            "payload": payload_tmp,
+        }
+
@@ -0,0 +1,63 @@
+/// struct with field constraints
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TestConstraints {
+    pub name: std::string::String,
+    pub age: std::option::Option<u8>,
+    pub score: std::option::Option<f64>,
+    pub tags: std::option::Option<std::vec::Vec<std::string::String>>,
+    pub labels: std::option::Option<
+        std::collections::HashMap<std::string::String, std::string::String>,
+    >,
+    pub desc: std::option::Option<std::string::String>,
+}
+impl TestConstraints {
+    /// validate fields against constraints defined in spec
+    pub fn validate(&self) -> Result<(), String> {
+        {
+            let value = &self.name;
+            if value.is_empty() {
+                return Err("field:name expect non empty value".to_string());
+            }
+            if value.chars().count() > 64 {
+                return Err("field:name expect length <= 64".to_string());
+            }
+            static PATTERN: std::sync::LazyLock<regex::Regex> = std::sync::LazyLock::new(||
+            regex::Regex::new("^[a-z_]+$").unwrap());
+            if !PATTERN.is_match(value) {
+                return Err(
+                    format!("field:name expect value to match {:?}", PATTERN.as_str()),
+                );
+            }
+        }
+        if let Some(value) = &self.age {
+            if *value < 1 {
+                return Err("field:age expect value >= 1".to_string());
+            }
+            if *value > 150 {
+                return Err("field:age expect value <= 150".to_string());
+            }
+        }
+        if let Some(value) = &self.score {
+            if *value < 0.0 {
+                return Err("field:score expect value >= 0".to_string());
+            }
+            if *value > 1.5 {
+                return Err("field:score expect value <= 1.5".to_string());
+            }
+        }
+        if let Some(value) = &self.tags {
+            if value.len() < 1 {
+                return Err("field:tags expect length >= 1".to_string());
+            }
+            if value.len() > 10 {
+                return Err("field:tags expect length <= 10".to_string());
+            }
+        }
+        if let Some(value) = &self.labels {
+            if value.is_empty() {
+                return Err("field:labels expect non empty value".to_string());
+            }
+        }
+        Ok(())
+    }
+}
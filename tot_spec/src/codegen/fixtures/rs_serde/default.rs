@@ -0,0 +1,50 @@
+/// struct with field defaults
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TestDefault {
+    pub id: std::string::String,
+    #[serde(default = "TestDefault::default_page_size")]
+    pub page_size: i32,
+    #[serde(default = "TestDefault::default_ratio")]
+    pub ratio: f64,
+    #[serde(default = "TestDefault::default_enabled")]
+    pub enabled: bool,
+    #[serde(default = "TestDefault::default_name")]
+    pub name: std::string::String,
+    #[serde(default = "TestDefault::default_tags")]
+    pub tags: std::vec::Vec<std::string::String>,
+    #[serde(default = "TestDefault::default_labels")]
+    pub labels: std::collections::HashMap<std::string::String, i64>,
+    pub desc: std::option::Option<std::string::String>,
+}
+impl TestDefault {
+    fn default_page_size() -> i32 {
+        10
+    }
+    fn default_ratio() -> f64 {
+        1.0
+    }
+    fn default_enabled() -> bool {
+        true
+    }
+    fn default_name() -> std::string::String {
+        "unnamed".to_string()
+    }
+    fn default_tags() -> std::vec::Vec<std::string::String> {
+        vec!["a".to_string(), "b".to_string()]
+    }
+    fn default_labels() -> std::collections::HashMap<std::string::String, i64> {
+        Default::default()
+    }
+}
+impl TestDefault {
+    /// validate fields against constraints defined in spec
+    pub fn validate(&self) -> Result<(), String> {
+        {
+            let value = &self.page_size;
+            if *value < 1 {
+                return Err("field:page_size expect value >= 1".to_string());
+            }
+        }
+        Ok(())
+    }
+}
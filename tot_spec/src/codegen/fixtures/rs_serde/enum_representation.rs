@@ -0,0 +1,39 @@
+/// Circle
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Circle {
+    pub radius: f64,
+}
+/// Rect
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Rect {
+    pub width: f64,
+    pub height: f64,
+}
+/// internally tagged, tag is along with payload's fields
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "kind")]
+pub enum InternalShape {
+    #[serde(rename = "circle")]
+    CircleShape(Circle),
+    #[serde(rename = "rect")]
+    RectShape(Rect),
+    Empty,
+}
+/// externally tagged, variant name is the key
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub enum ExternalShape {
+    #[serde(rename = "circle")]
+    CircleShape(Circle),
+    Size(f64),
+    Empty,
+}
+/// untagged, the first matching variant wins
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+pub enum UntaggedValue {
+    Number(f64),
+    Text(std::string::String),
+    Texts(std::vec::Vec<std::string::String>),
+    CircleValue(Circle),
+    Empty,
+}
@@ -0,0 +1,31 @@
+/// User
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct User {
+    pub name: std::string::String,
+}
+/// one page of items
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Page<T> {
+    pub items: std::vec::Vec<T>,
+    #[serde(default = "Page::<T>::default_page_size")]
+    pub page_size: i32,
+    pub next_cursor: std::option::Option<std::string::String>,
+}
+impl<T> Page<T> {
+    fn default_page_size() -> i32 {
+        20
+    }
+}
+/// Pair
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Pair<A, B> {
+    pub first: A,
+    pub second: std::option::Option<B>,
+}
+/// UserList
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct UserList {
+    pub users: Page<User>,
+    pub pairs: std::vec::Vec<Pair<std::string::String, Page<User>>>,
+    pub counts: std::option::Option<Page<i64>>,
+}
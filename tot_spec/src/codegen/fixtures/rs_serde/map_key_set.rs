@@ -0,0 +1,52 @@
+/// Reason
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    Copy,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+)]
+pub struct Reason(pub &'static str);
+impl Reason {
+    pub fn from_value(val: &str) -> Option<Self> {
+        match val {
+            "ok" => Some(Self::OK),
+            "error" => Some(Self::ERROR),
+            _ => None,
+        }
+    }
+    pub fn to_value(self) -> &'static str {
+        self.0
+    }
+}
+impl Reason {
+    pub const OK: Reason = Reason("ok");
+    pub const ERROR: Reason = Reason("error");
+}
+/// Stats
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Stats {
+    pub names_by_id: std::collections::HashMap<i32, std::string::String>,
+    pub count_by_reason: std::option::Option<
+        std::collections::HashMap<std::string::String, i64>,
+    >,
+    pub tags: std::collections::HashSet<std::string::String>,
+    pub flags: std::option::Option<std::collections::HashSet<u8>>,
+}
+impl Stats {
+    /// validate fields against constraints defined in spec
+    pub fn validate(&self) -> Result<(), String> {
+        {
+            let value = &self.tags;
+            if value.len() > 10 {
+                return Err("field:tags expect length <= 10".to_string());
+            }
+        }
+        Ok(())
+    }
+}
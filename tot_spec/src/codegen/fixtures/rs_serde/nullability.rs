@@ -0,0 +1,36 @@
+/// patch style request, absent means unchanged and null means clear
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct UpdateUserRequest {
+    pub id: std::string::String,
+    #[serde(default, skip_serializing_if = "std::option::Option::is_none")]
+    pub name: std::option::Option<std::string::String>,
+    #[serde(deserialize_with = "std::option::Option::deserialize")]
+    pub email: std::option::Option<std::string::String>,
+    #[serde(
+        default,
+        skip_serializing_if = "tot_spec_util::tri_state::TriState::is_absent"
+    )]
+    pub nickname: tot_spec_util::tri_state::TriState<std::string::String>,
+    #[serde(
+        default,
+        skip_serializing_if = "tot_spec_util::tri_state::TriState::is_absent"
+    )]
+    pub address: tot_spec_util::tri_state::TriState<Address>,
+    pub tags: std::option::Option<std::vec::Vec<std::string::String>>,
+}
+impl UpdateUserRequest {
+    /// validate fields against constraints defined in spec
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(value) = self.nickname.value() {
+            if value.chars().count() > 10 {
+                return Err("field:nickname expect length <= 10".to_string());
+            }
+        }
+        Ok(())
+    }
+}
+/// Address
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Address {
+    pub city: std::string::String,
+}
@@ -0,0 +1,13 @@
+/// struct for time fields
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TestTime {
+    /// timestamp in rfc3339 string
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// timestamp in epoch millis
+    pub updated_at: std::option::Option<tot_spec_util::time::EpochMillis>,
+    pub expired_at: std::option::Option<tot_spec_util::time::EpochMillis>,
+    pub birthday: std::option::Option<chrono::NaiveDate>,
+    /// duration in millis
+    pub timeout: std::option::Option<tot_spec_util::time::DurationMillis>,
+    pub history: std::option::Option<std::vec::Vec<chrono::DateTime<chrono::Utc>>>,
+}
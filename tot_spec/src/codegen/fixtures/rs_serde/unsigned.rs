@@ -0,0 +1,40 @@
+/// struct for unsigned and f32 fields
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TestUnsigned {
+    pub u8_value: u8,
+    pub u16_value: std::option::Option<u16>,
+    pub u32_value: std::option::Option<u32>,
+    pub u64_value: std::option::Option<u64>,
+    pub f32_value: std::option::Option<f32>,
+    pub u32_list: std::option::Option<std::vec::Vec<u32>>,
+}
+/// Const def for u32
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    Copy,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+)]
+pub struct Level(pub u32);
+impl Level {
+    pub fn from_value(val: u32) -> Option<Self> {
+        match val {
+            1 => Some(Self::LOW),
+            3000000000 => Some(Self::HIGH),
+            _ => None,
+        }
+    }
+    pub fn to_value(self) -> u32 {
+        self.0
+    }
+}
+impl Level {
+    pub const LOW: Level = Level(1);
+    pub const HIGH: Level = Level(3000000000);
+}
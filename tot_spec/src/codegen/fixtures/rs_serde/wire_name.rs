@@ -0,0 +1,21 @@
+/// struct with renamed fields
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TestWireName {
+    #[serde(rename = "userID")]
+    pub user_id: std::string::String,
+    #[serde(rename = "displayName")]
+    pub display_name: std::option::Option<std::string::String>,
+    #[serde(rename = "createdAt")]
+    pub created_at: std::option::Option<i64>,
+    #[serde(rename = "content-type")]
+    pub content_type: std::option::Option<std::string::String>,
+}
+/// Shape
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", content = "payload")]
+pub enum Shape {
+    #[serde(rename = "circle")]
+    Circle(f64),
+    #[serde(rename = "square")]
+    BigSquare(f64),
+}
@@ -0,0 +1,83 @@
+import Foundation
+
+// timestamp in rfc3339 string
+public struct Rfc3339Timestamp: Codable, Hashable {
+    public var date: Date
+
+    public init(_ date: Date) {
+        self.date = date
+    }
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        let value = try container.decode(String.self)
+        let formatter = ISO8601DateFormatter()
+        // fractional seconds are optional
+        for options: ISO8601DateFormatter.Options in [[.withInternetDateTime, .withFractionalSeconds], [.withInternetDateTime]] {
+            formatter.formatOptions = options
+            if let date = formatter.date(from: value) {
+                self.date = date
+                return
+            }
+        }
+        throw DecodingError.dataCorruptedError(in: container, debugDescription: "invalid rfc3339 timestamp \(value)")
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        let formatter = ISO8601DateFormatter()
+        formatter.formatOptions = [.withInternetDateTime, .withFractionalSeconds]
+        var container = encoder.singleValueContainer()
+        try container.encode(formatter.string(from: date))
+    }
+}
+
+// timestamp in millis since unix epoch
+public struct EpochMillisTimestamp: Codable, Hashable {
+    public var date: Date
+
+    public init(_ date: Date) {
+        self.date = date
+    }
+
+    public init(from decoder: Decoder) throws {
+        let millis = try decoder.singleValueContainer().decode(Int64.self)
+        self.date = Date(timeIntervalSince1970: Double(millis) / 1000)
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        try container.encode(Int64((date.timeIntervalSince1970 * 1000).rounded()))
+    }
+}
+
+// date in YYYY-MM-DD, the date is at midnight in utc
+public struct LocalDate: Codable, Hashable {
+    public var date: Date
+
+    public init(_ date: Date) {
+        self.date = date
+    }
+
+    private static func formatter() -> DateFormatter {
+        let formatter = DateFormatter()
+        formatter.calendar = Calendar(identifier: .iso8601)
+        formatter.locale = Locale(identifier: "en_US_POSIX")
+        formatter.timeZone = TimeZone(identifier: "UTC")
+        formatter.dateFormat = "yyyy-MM-dd"
+        return formatter
+    }
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        let value = try container.decode(String.self)
+        guard let date = LocalDate.formatter().date(from: value) else {
+            throw DecodingError.dataCorruptedError(in: container, debugDescription: "invalid date \(value)")
+        }
+        self.date = date
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        try container.encode(LocalDate.formatter().string(from: date))
+    }
+}
@@ -0,0 +1,40 @@
+import Foundation
+
+public enum ModelError: Error {
+    case Error
+}
+
+// struct with field defaults
+public struct TestDefault: Codable {
+    public var id: String
+    public var page_size: Int32
+    public var ratio: Float64
+    public var enabled: Bool
+    public var name: String
+    public var tags: [String]
+    public var labels: [String:Int64]
+    public var desc: String?
+
+    public init(id: String, page_size: Int32 = 10, ratio: Float64 = 1, enabled: Bool = true, name: String = "unnamed", tags: [String] = ["a", "b"], labels: [String:Int64] = [:], desc: String? = nil) {
+        self.id = id
+        self.page_size = page_size
+        self.ratio = ratio
+        self.enabled = enabled
+        self.name = name
+        self.tags = tags
+        self.labels = labels
+        self.desc = desc
+    }
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        self.id = try container.decode(String.self, forKey: .id)
+        self.page_size = try container.decodeIfPresent(Int32.self, forKey: .page_size) ?? 10
+        self.ratio = try container.decodeIfPresent(Float64.self, forKey: .ratio) ?? 1
+        self.enabled = try container.decodeIfPresent(Bool.self, forKey: .enabled) ?? true
+        self.name = try container.decodeIfPresent(String.self, forKey: .name) ?? "unnamed"
+        self.tags = try container.decodeIfPresent([String].self, forKey: .tags) ?? ["a", "b"]
+        self.labels = try container.decodeIfPresent([String:Int64].self, forKey: .labels) ?? [:]
+        self.desc = try container.decodeIfPresent(String.self, forKey: .desc)
+    }
+}
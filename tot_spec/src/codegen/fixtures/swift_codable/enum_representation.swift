@@ -0,0 +1,170 @@
+import Foundation
+
+public enum ModelError: Error {
+    case Error
+}
+
+public struct Circle: Codable {
+    public var radius: Float64
+
+    public init(radius: Float64) {
+        self.radius = radius
+    }
+}
+
+public struct Rect: Codable {
+    public var width: Float64
+    public var height: Float64
+
+    public init(width: Float64, height: Float64) {
+        self.width = width
+        self.height = height
+    }
+}
+
+// internally tagged, tag is along with payload's fields
+public enum InternalShape: Codable {
+    case CircleShape(PACKAGE.Circle)
+    case RectShape(PACKAGE.Rect)
+    case Empty
+
+    // coding keys
+    enum CodingKeys: String, CodingKey {
+        case kind
+    }
+
+    // decoder
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        let kind = try container.decode(String.self, forKey: .kind)
+        switch kind {
+            case "circle":
+                self = .CircleShape(try PACKAGE.Circle(from: decoder))
+            case "rect":
+                self = .RectShape(try PACKAGE.Rect(from: decoder))
+            case "Empty":
+                self = .Empty
+            default:
+                throw ModelError.Error
+        }
+    }
+
+    // encoder
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        switch self {
+            case let .CircleShape(payload):
+                try container.encode("circle", forKey: .kind)
+                try payload.encode(to: encoder)
+            case let .RectShape(payload):
+                try container.encode("rect", forKey: .kind)
+                try payload.encode(to: encoder)
+            case .Empty:
+                try container.encode("Empty", forKey: .kind)
+        }
+    }
+}
+
+// externally tagged, variant name is the key
+public enum ExternalShape: Codable {
+    case CircleShape(PACKAGE.Circle)
+    case Size(Float64)
+    case Empty
+
+    // coding keys
+    enum CodingKeys: String, CodingKey {
+        case CircleShape = "circle"
+        case Size = "Size"
+    }
+
+    // decoder
+    public init(from decoder: Decoder) throws {
+        // variant without payload is a plain string
+        if let tag = try? decoder.singleValueContainer().decode(String.self) {
+            switch tag {
+                case "Empty":
+                    self = .Empty
+                default:
+                    throw ModelError.Error
+            }
+            return
+        }
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        guard let key = container.allKeys.first else {
+            throw ModelError.Error
+        }
+        switch key {
+            case .CircleShape:
+                self = .CircleShape(try container.decode(PACKAGE.Circle.self, forKey: .CircleShape))
+            case .Size:
+                self = .Size(try container.decode(Float64.self, forKey: .Size))
+        }
+    }
+
+    // encoder
+    public func encode(to encoder: Encoder) throws {
+        switch self {
+            case let .CircleShape(payload):
+                var container = encoder.container(keyedBy: CodingKeys.self)
+                try container.encode(payload, forKey: .CircleShape)
+            case let .Size(payload):
+                var container = encoder.container(keyedBy: CodingKeys.self)
+                try container.encode(payload, forKey: .Size)
+            case .Empty:
+                var container = encoder.singleValueContainer()
+                try container.encode("Empty")
+        }
+    }
+}
+
+// untagged, the first matching variant wins
+public enum UntaggedValue: Codable {
+    case Number(Float64)
+    case Text(String)
+    case Texts([String])
+    case CircleValue(PACKAGE.Circle)
+    case Empty
+
+    // decoder
+    public init(from decoder: Decoder) throws {
+        // try variants in order, the first match wins
+        if let payload = try? Float64(from: decoder) {
+            self = .Number(payload)
+            return
+        }
+        if let payload = try? String(from: decoder) {
+            self = .Text(payload)
+            return
+        }
+        if let payload = try? [String](from: decoder) {
+            self = .Texts(payload)
+            return
+        }
+        if let payload = try? PACKAGE.Circle(from: decoder) {
+            self = .CircleValue(payload)
+            return
+        }
+        if let container = try? decoder.singleValueContainer(), container.decodeNil() {
+            self = .Empty
+            return
+        }
+        throw ModelError.Error
+    }
+
+    // encoder
+    public func encode(to encoder: Encoder) throws {
+        switch self {
+            case let .Number(payload):
+                try payload.encode(to: encoder)
+            case let .Text(payload):
+                try payload.encode(to: encoder)
+            case let .Texts(payload):
+                try payload.encode(to: encoder)
+            case let .CircleValue(payload):
+                try payload.encode(to: encoder)
+            case .Empty:
+                var container = encoder.singleValueContainer()
+                try container.encodeNil()
+        }
+    }
+}
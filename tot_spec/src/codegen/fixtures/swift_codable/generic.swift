@@ -0,0 +1,55 @@
+import Foundation
+
+public enum ModelError: Error {
+    case Error
+}
+
+public struct User: Codable {
+    public var name: String
+
+    public init(name: String) {
+        self.name = name
+    }
+}
+
+// one page of items
+public struct Page<T: Codable>: Codable {
+    public var items: [T]
+    public var page_size: Int32
+    public var next_cursor: String?
+
+    public init(items: [T], page_size: Int32 = 20, next_cursor: String? = nil) {
+        self.items = items
+        self.page_size = page_size
+        self.next_cursor = next_cursor
+    }
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        self.items = try container.decode([T].self, forKey: .items)
+        self.page_size = try container.decodeIfPresent(Int32.self, forKey: .page_size) ?? 20
+        self.next_cursor = try container.decodeIfPresent(String.self, forKey: .next_cursor)
+    }
+}
+
+public struct Pair<A: Codable, B: Codable>: Codable {
+    public var first: A
+    public var second: B?
+
+    public init(first: A, second: B? = nil) {
+        self.first = first
+        self.second = second
+    }
+}
+
+public struct UserList: Codable {
+    public var users: PACKAGE.Page<PACKAGE.User>
+    public var pairs: [PACKAGE.Pair<String, PACKAGE.Page<PACKAGE.User>>]
+    public var counts: PACKAGE.Page<Int64>?
+
+    public init(users: PACKAGE.Page<PACKAGE.User>, pairs: [PACKAGE.Pair<String, PACKAGE.Page<PACKAGE.User>>], counts: PACKAGE.Page<Int64>? = nil) {
+        self.users = users
+        self.pairs = pairs
+        self.counts = counts
+    }
+}
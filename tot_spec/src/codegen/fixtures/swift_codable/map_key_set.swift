@@ -0,0 +1,24 @@
+import Foundation
+
+public enum ModelError: Error {
+    case Error
+}
+
+public enum Reason: String {
+    case Ok = "ok"
+    case Error = "error"
+}
+
+public struct Stats: Codable {
+    public var names_by_id: [Int:String]
+    public var count_by_reason: [String:Int64]?
+    public var tags: Set<String>
+    public var flags: Set<UInt8>?
+
+    public init(names_by_id: [Int:String], count_by_reason: [String:Int64]? = nil, tags: Set<String>, flags: Set<UInt8>? = nil) {
+        self.names_by_id = names_by_id
+        self.count_by_reason = count_by_reason
+        self.tags = tags
+        self.flags = flags
+    }
+}
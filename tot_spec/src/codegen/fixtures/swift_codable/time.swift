@@ -0,0 +1,24 @@
+import Foundation
+
+public enum ModelError: Error {
+    case Error
+}
+
+// struct for time fields
+public struct TestTime: Codable {
+    public var created_at: Rfc3339Timestamp
+    public var updated_at: EpochMillisTimestamp?
+    public var expired_at: EpochMillisTimestamp?
+    public var birthday: LocalDate?
+    public var timeout: Int64?
+    public var history: [Rfc3339Timestamp]?
+
+    public init(created_at: Rfc3339Timestamp, updated_at: EpochMillisTimestamp? = nil, expired_at: EpochMillisTimestamp? = nil, birthday: LocalDate? = nil, timeout: Int64? = nil, history: [Rfc3339Timestamp]? = nil) {
+        self.created_at = created_at
+        self.updated_at = updated_at
+        self.expired_at = expired_at
+        self.birthday = birthday
+        self.timeout = timeout
+        self.history = history
+    }
+}
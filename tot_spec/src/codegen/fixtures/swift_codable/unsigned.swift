@@ -0,0 +1,30 @@
+import Foundation
+
+public enum ModelError: Error {
+    case Error
+}
+
+// struct for unsigned and f32 fields
+public struct TestUnsigned: Codable {
+    public var u8_value: UInt8
+    public var u16_value: UInt16?
+    public var u32_value: UInt32?
+    public var u64_value: UInt64?
+    public var f32_value: Float32?
+    public var u32_list: [UInt32]?
+
+    public init(u8_value: UInt8, u16_value: UInt16? = nil, u32_value: UInt32? = nil, u64_value: UInt64? = nil, f32_value: Float32? = nil, u32_list: [UInt32]? = nil) {
+        self.u8_value = u8_value
+        self.u16_value = u16_value
+        self.u32_value = u32_value
+        self.u64_value = u64_value
+        self.f32_value = f32_value
+        self.u32_list = u32_list
+    }
+}
+
+// Const def for u32
+public enum Level: UInt32 {
+    case Low = 1
+    case High = 3000000000
+}
@@ -0,0 +1,66 @@
+import Foundation
+
+public enum ModelError: Error {
+    case Error
+}
+
+// struct with renamed fields
+public struct TestWireName: Codable {
+    public var user_id: String
+    public var display_name: String?
+    public var created_at: Int64?
+    public var content_type: String?
+
+    public init(user_id: String, display_name: String? = nil, created_at: Int64? = nil, content_type: String? = nil) {
+        self.user_id = user_id
+        self.display_name = display_name
+        self.created_at = created_at
+        self.content_type = content_type
+    }
+
+    enum CodingKeys: String, CodingKey {
+        case user_id = "userID"
+        case display_name = "displayName"
+        case created_at = "createdAt"
+        case content_type = "content-type"
+    }
+}
+
+public enum Shape: Codable {
+    case Circle(Float64)
+    case BigSquare(Float64)
+
+    // coding keys
+    enum CodingKeys: String, CodingKey {
+        case type, payload
+    }
+
+    // decoder
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        let type = try container.decode(String.self, forKey: .type)
+        switch type {
+            case "circle":
+                let payload = try container.decode(Float64.self, forKey:.payload)
+                self = .Circle(payload)
+            case "square":
+                let payload = try container.decode(Float64.self, forKey:.payload)
+                self = .BigSquare(payload)
+            default:
+                throw ModelError.Error
+        }
+    }
+
+    // encoder
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        switch self {
+            case let .Circle(payload):
+                try container.encode("circle", forKey: .type)
+                try container.encode(payload, forKey: .payload)
+            case let .BigSquare(payload):
+                try container.encode("square", forKey: .type)
+                try container.encode(payload, forKey: .payload)
+        }
+    }
+}
@@ -0,0 +1,102 @@
+
+// struct with field constraints
+export class TestConstraints {
+    name!: string;
+    age: number | undefined;
+    score: number | undefined;
+    tags: string[] | undefined;
+    labels: Record<string, string> | undefined;
+    desc: string | undefined;
+
+    constructor(data: Partial<TestConstraints>) {
+        Object.assign(this, data);
+    }
+
+    toJSON(): any {
+        return {
+            name: this.name,
+            age: this.age,
+            score: this.score,
+            tags: this.tags,
+            labels: this.labels,
+            desc: this.desc,
+        };
+    }
+
+    static fromJSON(json: any): TestConstraints {
+        return new TestConstraints({
+            name: json.name,
+            age: json.age,
+            score: json.score,
+            tags: json.tags,
+            labels: json.labels,
+            desc: json.desc,
+        });
+    }
+
+    validate(): void {
+        {
+            const value = this.name;
+            if (value.length === 0) {
+                throw new Error("field:name expect non empty value");
+            }
+            if (value.length > 64) {
+                throw new Error("field:name expect length <= 64");
+            }
+            if (!new RegExp("^[a-z_]+$").test(value)) {
+                throw new Error("field:name expect value to match \"^[a-z_]+$\"");
+            }
+        }
+        {
+            const value = this.age;
+            if (value !== undefined && value !== null) {
+                if (value < 1) {
+                    throw new Error("field:age expect value >= 1");
+                }
+                if (value > 150) {
+                    throw new Error("field:age expect value <= 150");
+                }
+            }
+        }
+        {
+            const value = this.score;
+            if (value !== undefined && value !== null) {
+                if (value < 0) {
+                    throw new Error("field:score expect value >= 0");
+                }
+                if (value > 1.5) {
+                    throw new Error("field:score expect value <= 1.5");
+                }
+            }
+        }
+        {
+            const value = this.tags;
+            if (value !== undefined && value !== null) {
+                if (value.length < 1) {
+                    throw new Error("field:tags expect length >= 1");
+                }
+                if (value.length > 10) {
+                    throw new Error("field:tags expect length <= 10");
+                }
+            }
+        }
+        {
+            const value = this.labels;
+            if (value !== undefined && value !== null) {
+                if (Object.keys(value).length === 0) {
+                    throw new Error("field:labels expect non empty value");
+                }
+            }
+        }
+    }
+}
+
+export type TestConstraintsJSON = {
+    name: string;
+    age: number | undefined;
+    score: number | undefined;
+    tags: string[] | undefined;
+    labels: Record<string, string> | undefined;
+    desc: string | undefined;
+}
+
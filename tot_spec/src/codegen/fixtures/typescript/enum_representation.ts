@@ -0,0 +1,88 @@
+
+export class Circle {
+    radius!: number;
+
+    constructor(data: Partial<Circle>) {
+        Object.assign(this, data);
+    }
+
+    toJSON(): any {
+        return {
+            radius: this.radius,
+        };
+    }
+
+    static fromJSON(json: any): Circle {
+        return new Circle({
+            radius: json.radius,
+        });
+    }
+}
+
+export type CircleJSON = {
+    radius: number;
+}
+
+
+export class Rect {
+    width!: number;
+    height!: number;
+
+    constructor(data: Partial<Rect>) {
+        Object.assign(this, data);
+    }
+
+    toJSON(): any {
+        return {
+            width: this.width,
+            height: this.height,
+        };
+    }
+
+    static fromJSON(json: any): Rect {
+        return new Rect({
+            width: json.width,
+            height: json.height,
+        });
+    }
+}
+
+export type RectJSON = {
+    width: number;
+    height: number;
+}
+
+
+// internally tagged, tag is along with payload's fields
+export type InternalShape =
+    ({ kind: "circle" } & Circle)
+
+    | ({ kind: "rect" } & Rect)
+
+    | { kind: "Empty" }
+;
+
+
+// externally tagged, variant name is the key
+export type ExternalShape =
+    { circle: Circle }
+
+    | { Size: number }
+
+    | "Empty"
+;
+
+
+// untagged, the first matching variant wins
+export type UntaggedValue =
+    number
+
+    | string
+
+    | string[]
+
+    | Circle
+
+    | null
+;
+
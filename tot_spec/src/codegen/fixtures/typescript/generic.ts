@@ -0,0 +1,121 @@
+
+export class User {
+    name!: string;
+
+    constructor(data: Partial<User>) {
+        Object.assign(this, data);
+    }
+
+    toJSON(): any {
+        return {
+            name: this.name,
+        };
+    }
+
+    static fromJSON(json: any): User {
+        return new User({
+            name: json.name,
+        });
+    }
+}
+
+export type UserJSON = {
+    name: string;
+}
+
+
+// one page of items
+export class Page<T> {
+    items!: T[];
+    pageSize: number | undefined;
+    nextCursor: string | undefined;
+
+    constructor(data: Partial<Page<T>>) {
+        Object.assign(this, data);
+    }
+
+    toJSON(): any {
+        return {
+            items: this.items,
+            page_size: this.pageSize,
+            next_cursor: this.nextCursor,
+        };
+    }
+
+    static fromJSON<T>(json: any, fromJSONT: (json: any) => T): Page<T> {
+        return new Page<T>({
+            items: json.items.map((e: any) => fromJSONT(e)),
+            pageSize: json.page_size,
+            nextCursor: json.next_cursor,
+        });
+    }
+}
+
+export type PageJSON<T> = {
+    items: T[];
+    page_size: number | undefined;
+    next_cursor: string | undefined;
+}
+
+
+export class Pair<A, B> {
+    first!: A;
+    second: B | undefined;
+
+    constructor(data: Partial<Pair<A, B>>) {
+        Object.assign(this, data);
+    }
+
+    toJSON(): any {
+        return {
+            first: this.first,
+            second: this.second,
+        };
+    }
+
+    static fromJSON<A, B>(json: any, fromJSONA: (json: any) => A, fromJSONB: (json: any) => B): Pair<A, B> {
+        return new Pair<A, B>({
+            first: fromJSONA(json.first),
+            second: json.second == null ? json.second : fromJSONB(json.second),
+        });
+    }
+}
+
+export type PairJSON<A, B> = {
+    first: A;
+    second: B | undefined;
+}
+
+
+export class UserList {
+    users!: Page<User>;
+    pairs!: Pair<string, Page<User>>[];
+    counts: Page<bigint> | undefined;
+
+    constructor(data: Partial<UserList>) {
+        Object.assign(this, data);
+    }
+
+    toJSON(): any {
+        return {
+            users: this.users.toJSON(),
+            pairs: this.pairs.map((e) => e.toJSON()),
+            counts: this.counts?.toJSON(),
+        };
+    }
+
+    static fromJSON(json: any): UserList {
+        return new UserList({
+            users: Page.fromJSON(json.users, (e: any) => User.fromJSON(e)),
+            pairs: json.pairs.map((e: any) => Pair.fromJSON(e, (e: any) => e, (e: any) => Page.fromJSON(e, (e: any) => User.fromJSON(e)))),
+            counts: json.counts == null ? json.counts : Page.fromJSON(json.counts, (e: any) => e),
+        });
+    }
+}
+
+export type UserListJSON = {
+    users: Page<User>;
+    pairs: Pair<string, Page<User>>[];
+    counts: Page<bigint> | undefined;
+}
+
@@ -0,0 +1,49 @@
+
+export type Reason = "ok" | "error";
+
+
+export class Stats {
+    namesById!: Record<number, string>;
+    countByReason: Partial<Record<Reason, bigint>> | undefined;
+    tags!: Set<string>;
+    flags: Set<number> | undefined;
+
+    constructor(data: Partial<Stats>) {
+        Object.assign(this, data);
+    }
+
+    toJSON(): any {
+        return {
+            names_by_id: this.namesById,
+            count_by_reason: this.countByReason,
+            tags: Array.from(this.tags),
+            flags: this.flags && Array.from(this.flags),
+        };
+    }
+
+    static fromJSON(json: any): Stats {
+        return new Stats({
+            namesById: json.names_by_id,
+            countByReason: json.count_by_reason,
+            tags: new Set(json.tags),
+            flags: json.flags == null ? json.flags : new Set(json.flags),
+        });
+    }
+
+    validate(): void {
+        {
+            const value = this.tags;
+            if (value.size > 10) {
+                throw new Error("field:tags expect length <= 10");
+            }
+        }
+    }
+}
+
+export type StatsJSON = {
+    names_by_id: Record<number, string>;
+    count_by_reason: Partial<Record<Reason, bigint>> | undefined;
+    tags: string[];
+    flags: number[] | undefined;
+}
+
@@ -0,0 +1,82 @@
+
+// patch style request, absent means unchanged and null means clear
+export class UpdateUserRequest {
+    id!: string;
+    name?: string;
+    email!: string | null;
+    nickname?: string | null;
+    address?: Address | null;
+    tags: string[] | undefined;
+
+    constructor(data: Partial<UpdateUserRequest>) {
+        Object.assign(this, data);
+    }
+
+    toJSON(): any {
+        return {
+            id: this.id,
+            name: this.name,
+            email: this.email,
+            nickname: this.nickname,
+            address: this.address && this.address.toJSON(),
+            tags: this.tags,
+        };
+    }
+
+    static fromJSON(json: any): UpdateUserRequest {
+        return new UpdateUserRequest({
+            id: json.id,
+            name: json.name,
+            email: json.email,
+            nickname: json.nickname,
+            address: json.address && Address.fromJSON(json.address),
+            tags: json.tags,
+        });
+    }
+
+    validate(): void {
+        {
+            const value = this.nickname;
+            if (value !== undefined && value !== null) {
+                if (value.length > 10) {
+                    throw new Error("field:nickname expect length <= 10");
+                }
+            }
+        }
+    }
+}
+
+export type UpdateUserRequestJSON = {
+    id: string;
+    name?: string;
+    email: string | null;
+    nickname?: string | null;
+    address?: Address | null;
+    tags: string[] | undefined;
+}
+
+
+export class Address {
+    city!: string;
+
+    constructor(data: Partial<Address>) {
+        Object.assign(this, data);
+    }
+
+    toJSON(): any {
+        return {
+            city: this.city,
+        };
+    }
+
+    static fromJSON(json: any): Address {
+        return new Address({
+            city: json.city,
+        });
+    }
+}
+
+export type AddressJSON = {
+    city: string;
+}
+
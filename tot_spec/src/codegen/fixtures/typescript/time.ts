@@ -0,0 +1,49 @@
+
+// struct for time fields
+export class TestTime {
+    /// timestamp in rfc3339 string
+    createdAt!: string;
+    /// timestamp in epoch millis
+    updatedAt: number | undefined;
+    expiredAt: number | undefined;
+    birthday: string | undefined;
+    /// duration in millis
+    timeout: number | undefined;
+    history: string[] | undefined;
+
+    constructor(data: Partial<TestTime>) {
+        Object.assign(this, data);
+    }
+
+    toJSON(): any {
+        return {
+            created_at: this.createdAt,
+            updated_at: this.updatedAt,
+            expired_at: this.expiredAt,
+            birthday: this.birthday,
+            timeout: this.timeout,
+            history: this.history,
+        };
+    }
+
+    static fromJSON(json: any): TestTime {
+        return new TestTime({
+            createdAt: json.created_at,
+            updatedAt: json.updated_at,
+            expiredAt: json.expired_at,
+            birthday: json.birthday,
+            timeout: json.timeout,
+            history: json.history,
+        });
+    }
+}
+
+export type TestTimeJSON = {
+    created_at: string;
+    updated_at: number | undefined;
+    expired_at: number | undefined;
+    birthday: string | undefined;
+    timeout: number | undefined;
+    history: string[] | undefined;
+}
+
@@ -0,0 +1,50 @@
+
+// struct for unsigned and f32 fields
+export class TestUnsigned {
+    u8Value!: number;
+    u16Value: number | undefined;
+    u32Value: number | undefined;
+    u64Value: bigint | undefined;
+    f32Value: number | undefined;
+    u32List: number[] | undefined;
+
+    constructor(data: Partial<TestUnsigned>) {
+        Object.assign(this, data);
+    }
+
+    toJSON(): any {
+        return {
+            u8_value: this.u8Value,
+            u16_value: this.u16Value,
+            u32_value: this.u32Value,
+            u64_value: this.u64Value,
+            f32_value: this.f32Value,
+            u32_list: this.u32List,
+        };
+    }
+
+    static fromJSON(json: any): TestUnsigned {
+        return new TestUnsigned({
+            u8Value: json.u8_value,
+            u16Value: json.u16_value,
+            u32Value: json.u32_value,
+            u64Value: json.u64_value,
+            f32Value: json.f32_value,
+            u32List: json.u32_list,
+        });
+    }
+}
+
+export type TestUnsignedJSON = {
+    u8_value: number;
+    u16_value: number | undefined;
+    u32_value: number | undefined;
+    u64_value: bigint | undefined;
+    f32_value: number | undefined;
+    u32_list: number[] | undefined;
+}
+
+
+// Const def for u32
+export type Level = 1 | 3000000000;
+
@@ -0,0 +1,45 @@
+
+// struct with renamed fields
+export class TestWireName {
+    userId!: string;
+    displayName: string | undefined;
+    createdAt: bigint | undefined;
+    contentType: string | undefined;
+
+    constructor(data: Partial<TestWireName>) {
+        Object.assign(this, data);
+    }
+
+    toJSON(): any {
+        return {
+            userID: this.userId,
+            displayName: this.displayName,
+            createdAt: this.createdAt,
+            "content-type": this.contentType,
+        };
+    }
+
+    static fromJSON(json: any): TestWireName {
+        return new TestWireName({
+            userId: json.userID,
+            displayName: json.displayName,
+            createdAt: json.createdAt,
+            contentType: json["content-type"],
+        });
+    }
+}
+
+export type TestWireNameJSON = {
+    userID: string;
+    displayName: string | undefined;
+    createdAt: bigint | undefined;
+    "content-type": string | undefined;
+}
+
+
+export type Shape =
+    { type: "circle", payload: number }
+
+    | { type: "square", payload: number }
+;
+
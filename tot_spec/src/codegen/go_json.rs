@@ -0,0 +1,960 @@
+use crate::codegen::utils::{multiline_prefix_with, to_pascal_case};
+use crate::{
+    ConstType, ConstValueDef, Definition, EnumRepresentation, FieldDef, ModelDef, ModelType,
+    Nullability, StringOrInteger, StructDef, TimestampFormat, Type, TypeReference, VariantDef,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::path::Path;
+
+use super::context::Context;
+use super::output::{FileTree, OutputFile};
+
+pub struct GoJson {
+    context: Context,
+    config: GoJsonConfig,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+pub struct GoJsonConfig {
+    /// Type mapping for decimal types, default `string`
+    decimal_type: Option<String>,
+    /// Type mapping for bigint types, default `string`
+    bigint_type: Option<String>,
+    /// import path of the output folder, included spec without `meta.go_json.package`
+    /// is imported from its folder under the module
+    module: Option<String>,
+}
+
+/// imports used by a go file, std packages and included specs' packages by alias
+#[derive(Default)]
+struct Imports {
+    std: BTreeSet<&'static str>,
+    packages: BTreeMap<String, String>,
+}
+
+impl super::Codegen for GoJson {
+    fn load_from_context(context: &Context) -> anyhow::Result<Self> {
+        let config = context.load_codegen_config::<GoJsonConfig>("go_json")?;
+        Ok(Self {
+            context: context.clone(),
+            config: config.unwrap_or_default(),
+        })
+    }
+
+    fn generate(&self) -> anyhow::Result<FileTree> {
+        let mut tree = FileTree::new();
+        // each spec is a go package in its own folder, so models of different specs
+        // may have the same name. file name is fixed, go treats suffixes like `_test` and
+        // `_linux` specially
+        for (spec_path, _) in self.context.iter_specs() {
+            let output_path = spec_path.with_extension("").join("models.go");
+            tree.add(OutputFile::new(output_path, self.render(spec_path)?));
+        }
+        Ok(tree)
+    }
+}
+
+impl GoJson {
+    fn render(&self, spec_path: &Path) -> anyhow::Result<String> {
+        let def = self.context.get_definition(spec_path)?;
+
+        // imports are collected while rendering models, only used ones are imported
+        let mut imports = Imports::default();
+        let mut body = String::new();
+        for model in def.models.iter() {
+            writeln!(body)?;
+            if let Some(desc) = &model.desc {
+                writeln!(body, "{}", multiline_prefix_with(desc, "// "))?;
+            }
+
+            let code = match &model.type_ {
+                ModelType::Struct(struct_def) | ModelType::Virtual(struct_def) => {
+                    self.render_struct(model, struct_def, spec_path, &mut imports)?
+                }
+                ModelType::NewType { inner_type } => format!(
+                    "type {} {}\n",
+                    to_pascal_case(&model.name),
+                    self.go_type(inner_type, spec_path, &mut imports)?
+                ),
+                ModelType::Const { value_type, values } => {
+                    self.render_const(&model.name, value_type, values)?
+                }
+                ModelType::Enum {
+                    variants,
+                    tag_name,
+                    payload_name,
+                    representation,
+                } => {
+                    imports.std.extend(["encoding/json", "fmt"]);
+                    let tag_name = tag_name.as_deref().unwrap_or("type");
+                    let payload_name = payload_name.as_deref().unwrap_or("payload");
+                    let go_variants =
+                        self.go_variants(model, variants, def, spec_path, &mut imports)?;
+                    let mut code = render_variants(model, &go_variants)?;
+                    code.push_str(&render_marshal(
+                        model,
+                        &go_variants,
+                        *representation,
+                        tag_name,
+                        payload_name,
+                    )?);
+                    code.push_str(&render_unmarshal(
+                        model,
+                        &go_variants,
+                        *representation,
+                        tag_name,
+                        payload_name,
+                    )?);
+                    code
+                }
+            };
+            write!(body, "{code}")?;
+        }
+
+        let mut result = String::new();
+        writeln!(result, "package {}", go_package_name(def, spec_path))?;
+        if !imports.std.is_empty() || !imports.packages.is_empty() {
+            writeln!(result)?;
+            writeln!(result, "import (")?;
+            for path in imports.std.iter() {
+                writeln!(result, "\t\"{path}\"")?;
+            }
+            if !imports.std.is_empty() && !imports.packages.is_empty() {
+                writeln!(result)?;
+            }
+            for (alias, path) in imports.packages.iter() {
+                writeln!(result, "\t{alias} \"{path}\"")?;
+            }
+            writeln!(result, ")")?;
+        }
+        result.push_str(&body);
+        Ok(result)
+    }
+
+    fn go_type(
+        &self,
+        ty: &Type,
+        spec_path: &Path,
+        imports: &mut Imports,
+    ) -> anyhow::Result<String> {
+        Ok(match ty {
+            Type::Bool => "bool".into(),
+            Type::I8 => "int8".into(),
+            Type::I16 => "int16".into(),
+            Type::I32 => "int32".into(),
+            Type::I64 => "int64".into(),
+            Type::U8 => "uint8".into(),
+            Type::U16 => "uint16".into(),
+            Type::U32 => "uint32".into(),
+            Type::U64 => "uint64".into(),
+            Type::F32 => "float32".into(),
+            Type::F64 => "float64".into(),
+            // both are encoded as json string
+            Type::Decimal => self.config.decimal_type.clone().unwrap_or("string".into()),
+            Type::BigInt => self.config.bigint_type.clone().unwrap_or("string".into()),
+            // encoding/json encodes []byte as base64 string
+            Type::Bytes => "[]byte".into(),
+            Type::String | Type::Date => "string".into(),
+            Type::Timestamp {
+                format: TimestampFormat::Rfc3339,
+            } => {
+                imports.std.insert("time");
+                "time.Time".into()
+            }
+            // millis, time.Duration is nanos so it is not used
+            Type::Timestamp {
+                format: TimestampFormat::EpochMillis,
+            }
+            | Type::Duration => "int64".into(),
+            Type::List { item_type } | Type::Set { item_type } => {
+                format!("[]{}", self.go_type(item_type, spec_path, imports)?)
+            }
+            Type::Map {
+                key_type,
+                value_type,
+            } => format!(
+                "map[{}]{}",
+                self.go_type(key_type, spec_path, imports)?,
+                self.go_type(value_type, spec_path, imports)?
+            ),
+            Type::Reference(type_ref) => self.go_type_reference(type_ref, spec_path, imports)?,
+            Type::TypeParam { param } => param.clone(),
+            Type::Json => {
+                imports.std.insert("encoding/json");
+                "json.RawMessage".into()
+            }
+        })
+    }
+
+    fn go_type_reference(
+        &self,
+        type_ref: &TypeReference,
+        spec_path: &Path,
+        imports: &mut Imports,
+    ) -> anyhow::Result<String> {
+        let mut name = to_pascal_case(&type_ref.target);
+        if let Some(namespace) = &type_ref.namespace {
+            let package = self.include_package(namespace, spec_path)?;
+            imports.packages.insert(namespace.clone(), package);
+            name = format!("{namespace}.{name}");
+        }
+
+        if !type_ref.args.is_empty() {
+            let args = type_ref
+                .args
+                .iter()
+                .map(|arg| self.go_type(arg, spec_path, imports))
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            write!(name, "[{}]", args.join(", "))?;
+        }
+        Ok(name)
+    }
+
+    /// import path of the included spec, from its `meta.go_json.package`, or the package's
+    /// folder under the module
+    fn include_package(&self, namespace: &str, spec_path: &Path) -> anyhow::Result<String> {
+        let include_path = self.context.get_include_path(namespace, spec_path)?;
+        let include_def = self.context.get_definition(&include_path)?;
+        if let Some(package) = include_def.get_meta("go_json").get("package") {
+            return Ok(package.clone());
+        }
+        let Some(module) = &self.config.module else {
+            anyhow::bail!(
+                "{spec_path:?} includes {include_path:?} from another go package, \
+                 it requires meta.go_json.package or codegen.go_json.module"
+            );
+        };
+        let folder = include_path.with_extension("");
+        let folder = folder
+            .iter()
+            .map(|c| c.to_string_lossy())
+            .collect::<Vec<_>>();
+        Ok(format!(
+            "{}/{}",
+            module.trim_end_matches('/'),
+            folder.join("/")
+        ))
+    }
+
+    /// field's type, fields may be absent or null are pointers except slices and maps
+    fn go_field_type(
+        &self,
+        field: &FieldDef,
+        spec_path: &Path,
+        imports: &mut Imports,
+    ) -> anyhow::Result<String> {
+        let ty = self.go_type(&field.type_, spec_path, imports)?;
+        let nilable = matches!(
+            &*field.type_,
+            Type::List { .. } | Type::Set { .. } | Type::Map { .. } | Type::Bytes | Type::Json
+        );
+        if field.required || nilable {
+            Ok(ty)
+        } else {
+            Ok(format!("*{ty}"))
+        }
+    }
+
+    /// struct fields aligned as gofmt does
+    fn render_fields(
+        &self,
+        fields: &[FieldDef],
+        def: &Definition,
+        spec_path: &Path,
+        imports: &mut Imports,
+    ) -> anyhow::Result<String> {
+        let mut rows = vec![];
+        for field in fields {
+            let omitempty = match field.nullability {
+                _ if field.required => "",
+                // null is encoded from nil, so it should not be omitted
+                Some(Nullability::Nullable) => "",
+                _ => ",omitempty",
+            };
+            let tag = format!("`json:\"{}{omitempty}\"`", def.field_wire_name(field));
+            let ty = self.go_field_type(field, spec_path, imports)?;
+            rows.push((field, to_pascal_case(&field.name), ty, tag));
+        }
+
+        let name_width = rows.iter().map(|r| r.1.len()).max().unwrap_or_default();
+        let type_width = rows.iter().map(|r| r.2.len()).max().unwrap_or_default();
+        let mut result = String::new();
+        for (field, name, ty, tag) in rows {
+            if let Some(desc) = &field.desc {
+                writeln!(
+                    result,
+                    "\t{}",
+                    multiline_prefix_with(desc, "// ").replace('\n', "\n\t")
+                )?;
+            }
+            if field.nullability == Some(Nullability::OptionalNullable) {
+                writeln!(result, "\t// absent and null are both decoded as nil")?;
+            }
+            writeln!(result, "\t{name:name_width$} {ty:type_width$} {tag}")?;
+        }
+        Ok(result)
+    }
+
+    fn render_struct(
+        &self,
+        model: &ModelDef,
+        struct_def: &StructDef,
+        spec_path: &Path,
+        imports: &mut Imports,
+    ) -> anyhow::Result<String> {
+        let def = self.context.get_definition(spec_path)?;
+        let type_params = match model.type_params.is_empty() {
+            true => "".to_string(),
+            false => format!("[{} any]", model.type_params.join(", ")),
+        };
+        let name = to_pascal_case(&model.name);
+
+        let mut result = String::new();
+        if struct_def.fields.is_empty() && struct_def.extend.is_none() {
+            writeln!(result, "type {name}{type_params} struct{{}}")?;
+            return Ok(result);
+        }
+
+        writeln!(result, "type {name}{type_params} struct {{")?;
+        // fields of virtual model are flattened into json by embedding
+        if let Some(extend) = &struct_def.extend {
+            let type_ref = TypeReference::try_parse(extend)
+                .ok_or_else(|| anyhow::anyhow!("invalid extend {extend}"))?;
+            writeln!(
+                result,
+                "\t{}",
+                self.go_type_reference(&type_ref, spec_path, imports)?
+            )?;
+            if !struct_def.fields.is_empty() {
+                writeln!(result)?;
+            }
+        }
+        result.push_str(&self.render_fields(&struct_def.fields, def, spec_path, imports)?);
+        writeln!(result, "}}")?;
+        Ok(result)
+    }
+
+    fn render_const(
+        &self,
+        model_name: &str,
+        value_type: &ConstType,
+        values: &[ConstValueDef],
+    ) -> anyhow::Result<String> {
+        let name = to_pascal_case(model_name);
+        let go_type = match value_type {
+            ConstType::I8 => "int8",
+            ConstType::I16 => "int16",
+            ConstType::I32 => "int32",
+            ConstType::I64 => "int64",
+            ConstType::U8 => "uint8",
+            ConstType::U16 => "uint16",
+            ConstType::U32 => "uint32",
+            ConstType::U64 => "uint64",
+            ConstType::String => "string",
+        };
+
+        let mut result = String::new();
+        writeln!(result, "type {name} {go_type}")?;
+        if values.is_empty() {
+            return Ok(result);
+        }
+
+        let value_names = values
+            .iter()
+            .map(|value| format!("{name}{}", to_pascal_case(&value.name)))
+            .collect::<Vec<_>>();
+        let width = value_names
+            .iter()
+            .map(|n| n.len())
+            .max()
+            .unwrap_or_default();
+        writeln!(result)?;
+        writeln!(result, "const (")?;
+        for (value, value_name) in values.iter().zip(value_names) {
+            if let Some(desc) = &value.desc {
+                writeln!(
+                    result,
+                    "\t{}",
+                    multiline_prefix_with(desc, "// ").replace('\n', "\n\t")
+                )?;
+            }
+            let literal = match &value.value {
+                StringOrInteger::String(s) => serde_json::to_string(s)?,
+                StringOrInteger::Integer(i) => i.to_string(),
+            };
+            writeln!(result, "\t{value_name:width$} {name} = {literal}")?;
+        }
+        writeln!(result, ")")?;
+        Ok(result)
+    }
+
+    /// variants with wire name and payload resolved
+    fn go_variants(
+        &self,
+        model: &ModelDef,
+        variants: &[VariantDef],
+        def: &Definition,
+        spec_path: &Path,
+        imports: &mut Imports,
+    ) -> anyhow::Result<Vec<GoVariant>> {
+        let mut go_variants = vec![];
+        for variant in variants {
+            let payload = match (&variant.payload_type, &variant.payload_fields) {
+                (Some(payload_type), _) => {
+                    Payload::Type(self.go_type(payload_type, spec_path, imports)?)
+                }
+                (None, Some(fields)) if !fields.is_empty() => {
+                    Payload::Fields(self.render_fields(fields, def, spec_path, imports)?)
+                }
+                (None, _) => Payload::None,
+            };
+            go_variants.push(GoVariant {
+                type_name: format!(
+                    "{}{}",
+                    to_pascal_case(&model.name),
+                    to_pascal_case(&variant.name)
+                ),
+                wire_name: def.variant_wire_name(variant).into_owned(),
+                desc: variant.desc.clone(),
+                payload,
+            });
+        }
+        Ok(go_variants)
+    }
+}
+
+/// variant of enum, rendered as a type implements the enum's variant interface
+struct GoVariant {
+    type_name: String,
+    wire_name: String,
+    desc: Option<String>,
+    payload: Payload,
+}
+
+enum Payload {
+    None,
+    /// payload type, variant is a named type of it
+    Type(String),
+    /// rendered payload fields, variant is a struct of them
+    Fields(String),
+}
+
+impl GoVariant {
+    /// expression of payload to encode, None if no payload
+    fn payload_expr(&self) -> Option<String> {
+        match &self.payload {
+            Payload::None => None,
+            // convert back, so methods of payload type like MarshalJSON are used
+            Payload::Type(ty) => Some(format!("{ty}(variant)")),
+            Payload::Fields(_) => Some("variant".to_string()),
+        }
+    }
+
+    /// statements decode payload from data expr into variant
+    fn decode(&self, data: &str, var: &str) -> String {
+        let type_name = &self.type_name;
+        let (payload_type, value) = match &self.payload {
+            Payload::None => return format!("v.Variant = {type_name}{{}}"),
+            Payload::Type(ty) => (ty.as_str(), format!("{type_name}({var})")),
+            Payload::Fields(_) => (type_name.as_str(), var.to_string()),
+        };
+        format!(
+            "var {var} {payload_type}\nif err := json.Unmarshal({data}, &{var}); err != nil {{\n\treturn err\n}}\nv.Variant = {value}"
+        )
+    }
+}
+
+/// wrapper struct, the interface implemented by variants, and a type for each variant
+fn render_variants(model: &ModelDef, variants: &[GoVariant]) -> anyhow::Result<String> {
+    let name = to_pascal_case(&model.name);
+    let marker = format!("is{name}");
+
+    let mut result = String::new();
+    writeln!(result, "type {name} struct {{")?;
+    writeln!(result, "\tVariant {name}Variant")?;
+    writeln!(result, "}}")?;
+    writeln!(result)?;
+    writeln!(
+        result,
+        "// {name}Variant is implemented by variants of {name}"
+    )?;
+    writeln!(result, "type {name}Variant interface {{")?;
+    writeln!(result, "\t{marker}()")?;
+    writeln!(result, "}}")?;
+
+    for variant in variants {
+        let type_name = &variant.type_name;
+        writeln!(result)?;
+        if let Some(desc) = &variant.desc {
+            writeln!(result, "{}", multiline_prefix_with(desc, "// "))?;
+        }
+        match &variant.payload {
+            Payload::None => writeln!(result, "type {type_name} struct{{}}")?,
+            Payload::Type(ty) => writeln!(result, "type {type_name} {ty}")?,
+            Payload::Fields(fields) => {
+                writeln!(result, "type {type_name} struct {{")?;
+                write!(result, "{fields}")?;
+                writeln!(result, "}}")?;
+            }
+        }
+        writeln!(result)?;
+        writeln!(result, "func ({type_name}) {marker}() {{}}")?;
+    }
+    Ok(result)
+}
+
+/// `switch` on variant's type, `variant` is only bound when used
+fn type_switch(variants: &[GoVariant]) -> &'static str {
+    if variants.iter().all(|v| matches!(v.payload, Payload::None)) {
+        "switch v.Variant.(type) {"
+    } else {
+        "switch variant := v.Variant.(type) {"
+    }
+}
+
+fn render_marshal(
+    model: &ModelDef,
+    variants: &[GoVariant],
+    representation: EnumRepresentation,
+    tag_name: &str,
+    payload_name: &str,
+) -> anyhow::Result<String> {
+    let name = to_pascal_case(&model.name);
+    let unknown = format!("return nil, fmt.Errorf(\"%T is not a variant of {name}\", v.Variant)");
+
+    let mut result = String::new();
+    writeln!(result)?;
+    writeln!(result, "func (v {name}) MarshalJSON() ([]byte, error) {{")?;
+
+    if representation == EnumRepresentation::Untagged {
+        writeln!(result, "\t{}", type_switch(variants))?;
+        for variant in variants {
+            writeln!(result, "\tcase {}:", variant.type_name)?;
+            match variant.payload_expr() {
+                Some(payload) => writeln!(result, "\t\treturn json.Marshal({payload})")?,
+                None => writeln!(result, "\t\treturn []byte(\"null\"), nil")?,
+            }
+        }
+        writeln!(result, "\tdefault:")?;
+        writeln!(result, "\t\t{unknown}")?;
+        writeln!(result, "\t}}")?;
+        writeln!(result, "}}")?;
+        return Ok(result);
+    }
+
+    writeln!(result, "\tvar tag string")?;
+    writeln!(result, "\tvar payload any")?;
+    writeln!(result, "\t{}", type_switch(variants))?;
+    for variant in variants {
+        let wire_name = serde_json::to_string(&variant.wire_name)?;
+        writeln!(result, "\tcase {}:", variant.type_name)?;
+        match variant.payload_expr() {
+            Some(payload) => writeln!(result, "\t\ttag, payload = {wire_name}, {payload}")?,
+            None => writeln!(result, "\t\ttag = {wire_name}")?,
+        }
+    }
+    writeln!(result, "\tdefault:")?;
+    writeln!(result, "\t\t{unknown}")?;
+    writeln!(result, "\t}}")?;
+
+    let tail = match representation {
+        EnumRepresentation::Adjacent => {
+            let tag_field = format!("`json:\"{tag_name}\"`");
+            let payload_field = format!("`json:\"{payload_name},omitempty\"`");
+            format!(
+                "return json.Marshal(struct {{
+\tTag     string {tag_field}
+\tPayload any    {payload_field}
+}}{{tag, payload}})"
+            )
+        }
+        EnumRepresentation::Internal => {
+            let tag_name = serde_json::to_string(tag_name)?;
+            format!(
+                "// tag is merged into payload's fields
+fields := map[string]json.RawMessage{{}}
+if payload != nil {{
+\tdata, err := json.Marshal(payload)
+\tif err != nil {{
+\t\treturn nil, err
+\t}}
+\tif err := json.Unmarshal(data, &fields); err != nil {{
+\t\treturn nil, err
+\t}}
+}}
+fields[{tag_name}], _ = json.Marshal(tag)
+return json.Marshal(fields)"
+            )
+        }
+        EnumRepresentation::External => "if payload == nil {
+\treturn json.Marshal(tag)
+}
+return json.Marshal(map[string]any{tag: payload})"
+            .to_string(),
+        EnumRepresentation::Untagged => unreachable!(),
+    };
+    writeln!(result, "{}", indent_tabs(&tail, 1))?;
+    writeln!(result, "}}")?;
+    Ok(result)
+}
+
+fn render_unmarshal(
+    model: &ModelDef,
+    variants: &[GoVariant],
+    representation: EnumRepresentation,
+    tag_name: &str,
+    payload_name: &str,
+) -> anyhow::Result<String> {
+    let name = to_pascal_case(&model.name);
+
+    let mut result = String::new();
+    writeln!(result)?;
+    writeln!(
+        result,
+        "func (v *{name}) UnmarshalJSON(data []byte) error {{"
+    )?;
+
+    if representation == EnumRepresentation::Untagged {
+        // null only matches variant without payload, check it first as null decodes into anything
+        if let Some(variant) = variants.iter().find(|v| matches!(v.payload, Payload::None)) {
+            writeln!(result, "\tif string(data) == \"null\" {{")?;
+            writeln!(result, "\t\tv.Variant = {}{{}}", variant.type_name)?;
+            writeln!(result, "\t\treturn nil")?;
+            writeln!(result, "\t}}")?;
+        }
+        for variant in variants {
+            let payload_type = match &variant.payload {
+                Payload::None => continue,
+                Payload::Type(ty) => ty.as_str(),
+                Payload::Fields(_) => variant.type_name.as_str(),
+            };
+            let var = format!("{}Payload", lower_first(&variant.type_name[name.len()..]));
+            let value = match &variant.payload {
+                Payload::Type(_) => format!("{}({var})", variant.type_name),
+                _ => var.clone(),
+            };
+            writeln!(result, "\tvar {var} {payload_type}")?;
+            writeln!(
+                result,
+                "\tif err := json.Unmarshal(data, &{var}); err == nil {{"
+            )?;
+            writeln!(result, "\t\tv.Variant = {value}")?;
+            writeln!(result, "\t\treturn nil")?;
+            writeln!(result, "\t}}")?;
+        }
+        writeln!(
+            result,
+            "\treturn fmt.Errorf(\"no variant of {name} matches %s\", data)"
+        )?;
+        writeln!(result, "}}")?;
+        return Ok(result);
+    }
+
+    let (head, tag, payload) = match representation {
+        EnumRepresentation::Adjacent => {
+            let tag_field = format!("`json:\"{tag_name}\"`");
+            let payload_field = format!("`json:\"{payload_name}\"`");
+            (
+                format!(
+                    "var raw struct {{
+\tTag     string          {tag_field}
+\tPayload json.RawMessage {payload_field}
+}}
+if err := json.Unmarshal(data, &raw); err != nil {{
+\treturn err
+}}"
+                ),
+                "raw.Tag",
+                "raw.Payload",
+            )
+        }
+        EnumRepresentation::Internal => (
+            format!(
+                "var raw struct {{
+\tTag string `json:\"{tag_name}\"`
+}}
+if err := json.Unmarshal(data, &raw); err != nil {{
+\treturn err
+}}"
+            ),
+            "raw.Tag",
+            "data",
+        ),
+        EnumRepresentation::External => (
+            format!(
+                "// variant without payload is a string, others are an object with one key
+var tag string
+var raw map[string]json.RawMessage
+if err := json.Unmarshal(data, &tag); err != nil {{
+\tif err := json.Unmarshal(data, &raw); err != nil {{
+\t\treturn err
+\t}}
+\tif len(raw) != 1 {{
+\t\treturn fmt.Errorf(\"expect one key for {name}, got %d\", len(raw))
+\t}}
+\tfor key := range raw {{
+\t\ttag = key
+\t}}
+}}"
+            ),
+            "tag",
+            "raw[tag]",
+        ),
+        EnumRepresentation::Untagged => unreachable!(),
+    };
+    writeln!(result, "{}", indent_tabs(&head, 1))?;
+    writeln!(result, "\tswitch {tag} {{")?;
+    for variant in variants {
+        writeln!(
+            result,
+            "\tcase {}:",
+            serde_json::to_string(&variant.wire_name)?
+        )?;
+        writeln!(
+            result,
+            "{}",
+            indent_tabs(&variant.decode(payload, "payload"), 2)
+        )?;
+    }
+    writeln!(result, "\tdefault:")?;
+    writeln!(
+        result,
+        "\t\treturn fmt.Errorf(\"unknown variant %q of {name}\", {tag})"
+    )?;
+    writeln!(result, "\t}}")?;
+    writeln!(result, "\treturn nil")?;
+    writeln!(result, "}}")?;
+    Ok(result)
+}
+
+/// indent each non empty line with tabs
+fn indent_tabs(content: &str, level: usize) -> String {
+    let prefix = "\t".repeat(level);
+    content
+        .lines()
+        .map(|line| match line.is_empty() {
+            true => line.to_string(),
+            false => format!("{prefix}{line}"),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn lower_first(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// package is named by last segment of `meta.go_json.package` or spec's file stem, in lower
+/// case without underscores as go suggests
+fn go_package_name(def: &Definition, spec_path: &Path) -> String {
+    let meta = def.get_meta("go_json");
+    let name = match meta.get("package") {
+        // major version suffix like `/v2` is not part of the name
+        Some(package) => package
+            .rsplit('/')
+            .find(|segment| {
+                !(segment.len() > 1
+                    && segment.starts_with('v')
+                    && segment[1..].chars().all(|c| c.is_ascii_digit()))
+            })
+            .unwrap_or_default()
+            .to_string(),
+        None => spec_path
+            .file_stem()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_string(),
+    };
+    let mut package = name
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect::<String>()
+        .to_lowercase();
+    if !package.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        package.insert_str(0, "spec");
+    }
+    match package.as_str() {
+        "break" | "case" | "chan" | "const" | "continue" | "default" | "defer" | "else"
+        | "fallthrough" | "for" | "func" | "go" | "goto" | "if" | "import" | "interface"
+        | "map" | "package" | "range" | "return" | "select" | "struct" | "switch" | "type"
+        | "var" => format!("{package}_"),
+        _ => package,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codegen::Codegen;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_render() {
+        fn test_def(spec: &Path, code_path: &str) {
+            let spec = spec.strip_prefix("src/codegen/fixtures/specs/").unwrap();
+
+            let codegen =
+                GoJson::load_from_folder(&PathBuf::from("src/codegen/fixtures/specs/")).unwrap();
+
+            let rendered = codegen.render(spec).unwrap();
+
+            let code = std::fs::read_to_string(code_path).unwrap();
+
+            #[cfg(not(feature = "test_update_spec"))]
+            pretty_assertions::assert_eq!(code.trim(), rendered.trim());
+
+            #[cfg(feature = "test_update_spec")]
+            {
+                if code.trim() != rendered.trim() {
+                    std::fs::write(code_path, rendered).unwrap();
+                }
+            }
+        }
+
+        for (spec, expected) in &[
+            (
+                "src/codegen/fixtures/specs/simple_struct.yaml",
+                "src/codegen/fixtures/go_json/simple_struct.go",
+            ),
+            (
+                "src/codegen/fixtures/specs/enum.yaml",
+                "src/codegen/fixtures/go_json/enum.go",
+            ),
+            (
+                "src/codegen/fixtures/specs/enum_custom_tag.yaml",
+                "src/codegen/fixtures/go_json/enum_custom_tag.go",
+            ),
+            (
+                "src/codegen/fixtures/specs/enum_variant_fields.yaml",
+                "src/codegen/fixtures/go_json/enum_variant_fields.go",
+            ),
+            (
+                "src/codegen/fixtures/specs/enum_representation.yaml",
+                "src/codegen/fixtures/go_json/enum_representation.go",
+            ),
+            (
+                "src/codegen/fixtures/specs/extend.yaml",
+                "src/codegen/fixtures/go_json/extend.go",
+            ),
+            (
+                "src/codegen/fixtures/specs/new_type.yaml",
+                "src/codegen/fixtures/go_json/new_type.go",
+            ),
+            (
+                "src/codegen/fixtures/specs/const_i8.yaml",
+                "src/codegen/fixtures/go_json/const_i8.go",
+            ),
+            (
+                "src/codegen/fixtures/specs/const_string.yaml",
+                "src/codegen/fixtures/go_json/const_string.go",
+            ),
+            (
+                "src/codegen/fixtures/specs/include_base.yaml",
+                "src/codegen/fixtures/go_json/include_base.go",
+            ),
+            (
+                "src/codegen/fixtures/specs/include_test.yaml",
+                "src/codegen/fixtures/go_json/include_test.go",
+            ),
+            (
+                "src/codegen/fixtures/specs/time.yaml",
+                "src/codegen/fixtures/go_json/time.go",
+            ),
+            (
+                "src/codegen/fixtures/specs/unsigned.yaml",
+                "src/codegen/fixtures/go_json/unsigned.go",
+            ),
+            (
+                "src/codegen/fixtures/specs/decimal.yaml",
+                "src/codegen/fixtures/go_json/decimal.go",
+            ),
+            (
+                "src/codegen/fixtures/specs/json.yaml",
+                "src/codegen/fixtures/go_json/json.go",
+            ),
+            (
+                "src/codegen/fixtures/specs/nullability.yaml",
+                "src/codegen/fixtures/go_json/nullability.go",
+            ),
+            (
+                "src/codegen/fixtures/specs/wire_name.yaml",
+                "src/codegen/fixtures/go_json/wire_name.go",
+            ),
+            (
+                "src/codegen/fixtures/specs/generic.yaml",
+                "src/codegen/fixtures/go_json/generic.go",
+            ),
+            (
+                "src/codegen/fixtures/specs/map_key_set.yaml",
+                "src/codegen/fixtures/go_json/map_key_set.go",
+            ),
+            (
+                "src/codegen/fixtures/specs/empty_struct.yaml",
+                "src/codegen/fixtures/go_json/empty_struct.go",
+            ),
+        ] {
+            test_def(PathBuf::from(spec).as_path(), expected);
+        }
+    }
+
+    #[test]
+    fn test_generate_tree() {
+        // the whole tree is one go module, each folder is a package
+        let codegen =
+            GoJson::load_from_folder(&PathBuf::from("src/codegen/fixtures/specs/")).unwrap();
+        let tree = codegen.generate().unwrap();
+
+        let mut packages = BTreeMap::<PathBuf, (String, BTreeSet<String>)>::new();
+        let mut imports = vec![];
+        for file in tree.files() {
+            let folder = file.path.parent().unwrap().to_path_buf();
+            let package = file.content.lines().next().unwrap().to_string();
+            let (folder_package, types) = packages
+                .entry(folder.clone())
+                .or_insert_with(|| (package.clone(), BTreeSet::new()));
+            assert_eq!(
+                *folder_package, package,
+                "{folder:?} has more than one package"
+            );
+
+            for line in file.content.lines() {
+                if let Some(name) = line.strip_prefix("type ") {
+                    let name = name.split([' ', '[']).next().unwrap().to_string();
+                    assert!(
+                        types.insert(name.clone()),
+                        "{folder:?} declares {name} twice"
+                    );
+                }
+                if let Some((_, path)) = line.split_once("\"github.com/tot-spec/test/") {
+                    imports.push(PathBuf::from(path.trim_end_matches('"')));
+                }
+            }
+        }
+
+        assert!(!imports.is_empty());
+        for import in imports {
+            assert!(
+                packages.contains_key(&import),
+                "{import:?} is not generated"
+            );
+        }
+    }
+
+    #[test]
+    fn test_go_package_name() {
+        let def = serde_yaml::from_str::<Definition>(
+            "meta:\n  go_json:\n    package: github.com/acme/api-base/v2\nmodels: []",
+        )
+        .unwrap();
+        assert_eq!(go_package_name(&def, Path::new("a/b_c.yaml")), "apibase");
+
+        let def = serde_yaml::from_str::<Definition>("models: []").unwrap();
+        assert_eq!(go_package_name(&def, Path::new("a/b_c.yaml")), "bc");
+    }
+}
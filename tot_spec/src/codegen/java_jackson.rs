@@ -1,36 +1,44 @@
 use convert_case::Casing;
 
 use super::context::Context;
+use super::output::{FileTree, OutputFile};
 use super::utils;
-use crate::{ConstType, Definition, FieldDef, ModelDef, StringOrInteger, Type, TypeReference};
+use crate::{
+    ConstType, Definition, EnumRepresentation, FieldDef, ModelDef, StringOrInteger,
+    TimestampFormat, Type, TypeReference, VariantDef,
+};
 use std::path::Path;
 use std::{borrow::Cow, fmt::Write, path::PathBuf};
 
-#[derive(Default)]
-pub struct JavaJackson {}
+pub struct JavaJackson {
+    context: Context,
+}
 
 impl super::Codegen for JavaJackson {
-    fn load_from_folder(_folder: &PathBuf) -> anyhow::Result<Self> {
-        Ok(Self::default())
+    fn load_from_context(context: &Context) -> anyhow::Result<Self> {
+        Ok(Self {
+            context: context.clone(),
+        })
     }
 
-    fn generate_for_folder(&self, folder: &PathBuf, output: &PathBuf) -> anyhow::Result<()> {
-        let context = Context::new_from_folder(folder)?;
+    fn generate(&self) -> anyhow::Result<FileTree> {
+        let context = &self.context;
 
+        let mut tree = FileTree::new();
         for (spec_path, _) in context.iter_specs() {
-            render(&spec_path, &context, &output)?;
+            for file in render(spec_path, context)? {
+                tree.add(file);
+            }
         }
 
-        Ok(())
+        Ok(tree)
     }
 }
 
-/// java does not export to a file, instead, it exports to a folder
-fn render(spec_path: &Path, context: &Context, target_folder: &PathBuf) -> anyhow::Result<()> {
+/// java does not export to a file, instead, it exports to a folder of package
+fn render(spec_path: &Path, context: &Context) -> anyhow::Result<Vec<OutputFile>> {
     let def = context.get_definition(spec_path)?;
 
-    std::fs::create_dir_all(target_folder)?;
-
     let package_name = def
         .get_meta("java_jackson")
         .get("package")
@@ -40,10 +48,10 @@ fn render(spec_path: &Path, context: &Context, target_folder: &PathBuf) -> anyho
     // if namespace_cls exists, then all models will be put into the namespace_cls
     let namespace_cls = def.get_meta("java_jackson").get("namespace_class").cloned();
 
-    let mut package_folder = target_folder.to_owned();
-
+    let mut package_folder = PathBuf::new();
     package_name.split('.').for_each(|c| package_folder.push(c));
-    std::fs::create_dir_all(&package_folder)?;
+
+    let mut files = vec![];
 
     match namespace_cls {
         None => {
@@ -63,7 +71,7 @@ fn render(spec_path: &Path, context: &Context, target_folder: &PathBuf) -> anyho
 
                 writeln!(result, "{}", model_code.trim_end())?;
 
-                std::fs::write(file_path, result)?;
+                files.push(OutputFile::new(file_path, result));
             }
         }
 
@@ -95,11 +103,11 @@ fn render(spec_path: &Path, context: &Context, target_folder: &PathBuf) -> anyho
 
             writeln!(result, "}}")?;
 
-            std::fs::write(file_path, result)?;
+            files.push(OutputFile::new(file_path, result));
         }
     }
 
-    Ok(())
+    Ok(files)
 }
 
 pub fn render_model(
@@ -134,6 +142,11 @@ pub fn render_model(
 
     match &model.type_ {
         crate::ModelType::Struct(st) => {
+            let generics = match model.type_params.is_empty() {
+                true => "".to_string(),
+                false => format!("<{}>", model.type_params.join(", ")),
+            };
+
             // Data annotation makes the class a pojo
             writeln!(result, "@Data")?;
             writeln!(result, "@Builder")?;
@@ -147,14 +160,18 @@ pub fn render_model(
                             java_type_for_type_reference(&type_ref, def, spec_path, context)?;
                         writeln!(
                             result,
-                            "public {class_modifier}class {name} extends {base} {{",
+                            "public {class_modifier}class {name}{generics} extends {base} {{",
                             name = model.name,
                             base = java_type
                         )?;
                     }
                 }
                 None => {
-                    writeln!(result, "public {class_modifier}class {} {{", model.name)?;
+                    writeln!(
+                        result,
+                        "public {class_modifier}class {}{generics} {{",
+                        model.name
+                    )?;
                 }
             }
 
@@ -168,29 +185,44 @@ pub fn render_model(
             variants,
             tag_name,
             payload_name,
+            representation,
         } => {
             let tag_name = tag_name.clone().unwrap_or_else(|| "type".to_string());
             let payload_name = payload_name
                 .clone()
                 .unwrap_or_else(|| "payload".to_string());
-            // Data annotation makes the class a pojo
-            writeln!(
-                result,
-                "@com.fasterxml.jackson.annotation.JsonTypeInfo(use = com.fasterxml.jackson.annotation.JsonTypeInfo.Id.NAME, property = \"{}\")",
-                tag_name
-            )?;
-            {
-                writeln!(result, "@com.fasterxml.jackson.annotation.JsonSubTypes({{")?;
-
-                for v in variants {
+            match representation {
+                EnumRepresentation::Adjacent | EnumRepresentation::Internal => {
+                    // Data annotation makes the class a pojo
                     writeln!(
+                        result,
+                        "@com.fasterxml.jackson.annotation.JsonTypeInfo(use = com.fasterxml.jackson.annotation.JsonTypeInfo.Id.NAME, property = \"{}\")",
+                        tag_name
+                    )?;
+                    writeln!(result, "@com.fasterxml.jackson.annotation.JsonSubTypes({{")?;
+
+                    for v in variants {
+                        writeln!(
                             result,
-                            "    @com.fasterxml.jackson.annotation.JsonSubTypes.Type(value = {model_name}.{name}.class, name = \"{name}\"),",
-                            name = v.name
+                            "    @com.fasterxml.jackson.annotation.JsonSubTypes.Type(value = {model_name}.{name}.class, name = \"{wire_name}\"),",
+                            name = v.name,
+                            wire_name = def.variant_wire_name(v)
                         )?;
-                }
+                    }
 
-                writeln!(result, "}})")?;
+                    writeln!(result, "}})")?;
+                }
+                EnumRepresentation::External | EnumRepresentation::Untagged => {
+                    // jackson has no built in support, so (de)serializer is generated
+                    writeln!(
+                        result,
+                        "@com.fasterxml.jackson.databind.annotation.JsonSerialize(using = {model_name}.Serializer.class)"
+                    )?;
+                    writeln!(
+                        result,
+                        "@com.fasterxml.jackson.databind.annotation.JsonDeserialize(using = {model_name}.Deserializer.class)"
+                    )?;
+                }
             }
             writeln!(result, "public abstract class {} {{", model.name)?;
 
@@ -206,16 +238,23 @@ pub fn render_model(
                     "    public static class {variant_name} extends {model_name} {{"
                 )?;
 
-                match v.payload_type.as_ref() {
-                    Some(payload_type) => {
+                if v.payload_fields.is_some() {
+                    anyhow::bail!("java_jackson enum now only support payload_type");
+                }
+                if let Some(payload_type) = v.payload_type.as_ref() {
+                    if *representation == EnumRepresentation::Internal {
+                        // payload's fields are along with the tag
                         writeln!(
                             result,
-                            "        private {} {};",
-                            java_type(payload_type, def, spec_path, context)?,
-                            payload_name
+                            "        @com.fasterxml.jackson.annotation.JsonUnwrapped"
                         )?;
                     }
-                    None => todo!(),
+                    writeln!(
+                        result,
+                        "        private {} {};",
+                        java_type(payload_type, def, spec_path, context)?,
+                        payload_name
+                    )?;
                 }
 
                 writeln!(result, "    }}")?;
@@ -225,6 +264,23 @@ pub fn render_model(
                 }
             }
 
+            if matches!(
+                representation,
+                EnumRepresentation::External | EnumRepresentation::Untagged
+            ) {
+                writeln!(result)?;
+                let code = render_enum_serde(
+                    model_name,
+                    variants,
+                    *representation,
+                    &payload_name,
+                    def,
+                    spec_path,
+                    context,
+                )?;
+                writeln!(result, "{}", utils::indent(code.trim(), 1))?;
+            }
+
             writeln!(result, "}}")?;
         }
         crate::ModelType::Virtual(st) => {
@@ -281,7 +337,7 @@ pub fn render_model(
                     result,
                     "    public static final {java_type} {} = {};",
                     value.name.to_case(convert_case::Case::UpperSnake),
-                    java_literal(&value.value)
+                    java_literal(&value.value, value_type)
                 )?;
 
                 if idx + 1 < values.len() {
@@ -304,35 +360,70 @@ fn java_type(
 ) -> anyhow::Result<String> {
     Ok(match ty {
         Type::Bool => "Boolean".into(),
-        Type::I8 | Type::I16 | Type::I32 | Type::I64 => "Integer".into(),
+        Type::I8 | Type::I16 | Type::I32 | Type::I64 | Type::U8 | Type::U16 => "Integer".into(),
+        // java has no unsigned types, widen to hold the full range
+        Type::U32 => "Long".into(),
+        Type::U64 => "java.math.BigInteger".into(),
+        Type::F32 => "Float".into(),
         Type::F64 => "Double".into(),
         Type::Bytes => "byte[]".into(),
         Type::String => "String".into(),
+        Type::Timestamp { .. } => "java.time.Instant".into(),
+        Type::Date => "java.time.LocalDate".into(),
+        Type::Duration => "java.time.Duration".into(),
         Type::List { item_type } => {
             format!("List<{}>", java_type(item_type, def, spec_path, context)?)
         }
-        Type::Map { value_type } => {
+        Type::Set { item_type } => {
             format!(
-                "Map<String, {}>",
+                "Set<{}>",
+                java_key_type(item_type, def, spec_path, context)?
+            )
+        }
+        Type::Map {
+            key_type,
+            value_type,
+        } => {
+            format!(
+                "Map<{}, {}>",
+                java_key_type(key_type, def, spec_path, context)?,
                 java_type(value_type, def, spec_path, context)?
             )
         }
         Type::Reference(type_ref) => {
             java_type_for_type_reference(type_ref, def, spec_path, context)?
         }
+        Type::TypeParam { param } => param.clone(),
         Type::Json => "com.fasterxml.jackson.databind.JsonNode".to_string(),
         Type::Decimal => "java.math.BigDecimal".into(),
         Type::BigInt => "java.math.BigInteger".into(),
     })
 }
 
+/// type for map key and set item, const class only holds values so string const is String
+fn java_key_type(
+    ty: &Type,
+    def: &Definition,
+    spec_path: &Path,
+    context: &Context,
+) -> anyhow::Result<String> {
+    if context.is_string_const(ty, spec_path) {
+        return Ok("String".into());
+    }
+    java_type(ty, def, spec_path, context)
+}
+
 fn java_type_for_type_reference(
     type_ref: &TypeReference,
     def: &Definition,
     spec_path: &Path,
     context: &Context,
 ) -> anyhow::Result<String> {
-    let TypeReference { namespace, target } = type_ref;
+    let TypeReference {
+        namespace,
+        target,
+        args,
+    } = type_ref;
     let fqdn_target = match namespace {
         Some(namespace) => {
             let include_def = context.load_include_def(namespace, spec_path)?;
@@ -345,20 +436,58 @@ fn java_type_for_type_reference(
         }
     };
 
-    Ok(fqdn_target)
+    if args.is_empty() {
+        return Ok(fqdn_target);
+    }
+    let args = args
+        .iter()
+        .map(|a| java_type(a, def, spec_path, context))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    Ok(format!("{fqdn_target}<{}>", args.join(", ")))
+}
+
+/// time types need JsonFormat to control the wire format, jackson-datatype-jsr310
+/// is required to be registered. jsr310 reads and writes integers as nanos by default,
+/// the features are disabled so integers are millis
+fn java_json_format(ty: &Type) -> Option<&'static str> {
+    match ty {
+        Type::Timestamp {
+            format: TimestampFormat::Rfc3339,
+        }
+        | Type::Date => Some("shape = com.fasterxml.jackson.annotation.JsonFormat.Shape.STRING"),
+        Type::Timestamp {
+            format: TimestampFormat::EpochMillis,
+        }
+        | Type::Duration => Some(
+            "shape = com.fasterxml.jackson.annotation.JsonFormat.Shape.NUMBER_INT, without = {com.fasterxml.jackson.annotation.JsonFormat.Feature.READ_DATE_TIMESTAMPS_AS_NANOSECONDS, com.fasterxml.jackson.annotation.JsonFormat.Feature.WRITE_DATE_TIMESTAMPS_AS_NANOSECONDS}",
+        ),
+        _ => None,
+    }
 }
 
 fn java_type_for_const(ty: &ConstType) -> &'static str {
     match ty {
-        ConstType::I8 | ConstType::I16 | ConstType::I32 | ConstType::I64 => "Integer",
+        ConstType::I8
+        | ConstType::I16
+        | ConstType::I32
+        | ConstType::I64
+        | ConstType::U8
+        | ConstType::U16 => "Integer",
+        // same as field types, java has no unsigned types
+        ConstType::U32 => "Long",
+        ConstType::U64 => "java.math.BigInteger",
         ConstType::String => "String",
     }
 }
 
-fn java_literal(val: &StringOrInteger) -> String {
-    match val {
-        StringOrInteger::String(val) => format!("\"{}\"", val.replace('"', "\"")),
-        StringOrInteger::Integer(val) => format!("{val}"),
+fn java_literal(val: &StringOrInteger, ty: &ConstType) -> String {
+    match (val, ty) {
+        (StringOrInteger::String(val), _) => format!("\"{}\"", val.replace('"', "\"")),
+        (StringOrInteger::Integer(val), ConstType::U32) => format!("{val}L"),
+        (StringOrInteger::Integer(val), ConstType::U64) => {
+            format!("java.math.BigInteger.valueOf({val}L)")
+        }
+        (StringOrInteger::Integer(val), _) => format!("{val}"),
     }
 }
 
@@ -377,6 +506,179 @@ fn java_package_for_def(def: &Definition) -> String {
     }
 }
 
+/// jackson (de)serializer for external and untagged enum
+fn render_enum_serde(
+    model_name: &str,
+    variants: &[VariantDef],
+    representation: EnumRepresentation,
+    payload_name: &str,
+    def: &Definition,
+    spec_path: &Path,
+    context: &Context,
+) -> anyhow::Result<String> {
+    let mut result = "".to_string();
+    let getter = format!("get{}", payload_name.to_case(convert_case::Case::Pascal));
+
+    writeln!(
+        result,
+        "public static class Serializer extends com.fasterxml.jackson.databind.JsonSerializer<{model_name}> {{"
+    )?;
+    writeln!(result, "    @Override")?;
+    writeln!(
+        result,
+        "    public void serialize({model_name} value, com.fasterxml.jackson.core.JsonGenerator gen, com.fasterxml.jackson.databind.SerializerProvider provider) throws java.io.IOException {{"
+    )?;
+    for (idx, v) in variants.iter().enumerate() {
+        let variant_cls = format!("{model_name}.{}", v.name);
+        let wire_name = def.variant_wire_name(v);
+        let keyword = if idx == 0 { "if" } else { "} else if" };
+        writeln!(
+            result,
+            "        {keyword} (value instanceof {variant_cls}) {{"
+        )?;
+        match (representation, v.payload_type.is_some()) {
+            (EnumRepresentation::External, true) => {
+                writeln!(result, "            gen.writeStartObject();")?;
+                writeln!(
+                    result,
+                    "            gen.writeObjectField(\"{wire_name}\", (({variant_cls}) value).{getter}());"
+                )?;
+                writeln!(result, "            gen.writeEndObject();")?;
+            }
+            (EnumRepresentation::External, false) => {
+                writeln!(result, "            gen.writeString(\"{wire_name}\");")?;
+            }
+            (_, true) => {
+                writeln!(
+                    result,
+                    "            gen.writeObject((({variant_cls}) value).{getter}());"
+                )?;
+            }
+            (_, false) => {
+                writeln!(result, "            gen.writeNull();")?;
+            }
+        }
+    }
+    if !variants.is_empty() {
+        writeln!(result, "        }}")?;
+    }
+    writeln!(result, "    }}")?;
+    writeln!(result, "}}")?;
+    writeln!(result)?;
+
+    writeln!(
+        result,
+        "public static class Deserializer extends com.fasterxml.jackson.databind.JsonDeserializer<{model_name}> {{"
+    )?;
+    writeln!(result, "    @Override")?;
+    writeln!(
+        result,
+        "    public {model_name} deserialize(com.fasterxml.jackson.core.JsonParser p, com.fasterxml.jackson.databind.DeserializationContext ctxt) throws java.io.IOException {{"
+    )?;
+    writeln!(
+        result,
+        "        com.fasterxml.jackson.core.ObjectCodec codec = p.getCodec();"
+    )?;
+    writeln!(
+        result,
+        "        com.fasterxml.jackson.databind.JsonNode node = codec.readTree(p);"
+    )?;
+
+    // untagged variant without payload is null
+    let unit_variant = match representation {
+        EnumRepresentation::Untagged => variants.iter().find(|v| v.payload_type.is_none()),
+        _ => None,
+    };
+
+    // read payload from node with codec, TypeReference keeps generic type
+    let read_payload = |v: &VariantDef, node: &str| -> anyhow::Result<String> {
+        let java_type = java_type(v.payload_type.as_ref().unwrap(), def, spec_path, context)?;
+        Ok(format!(
+            "new {model_name}.{}(codec.readValue(codec.treeAsTokens({node}), new com.fasterxml.jackson.core.type.TypeReference<{java_type}>() {{}}))",
+            v.name
+        ))
+    };
+
+    match representation {
+        EnumRepresentation::External => {
+            writeln!(result, "        if (node.isTextual()) {{")?;
+            writeln!(result, "            switch (node.asText()) {{")?;
+            for v in variants.iter().filter(|v| v.payload_type.is_none()) {
+                writeln!(
+                    result,
+                    "                case \"{}\":",
+                    def.variant_wire_name(v)
+                )?;
+                writeln!(
+                    result,
+                    "                    return new {model_name}.{}();",
+                    v.name
+                )?;
+            }
+            writeln!(result, "            }}")?;
+            writeln!(
+                result,
+                "        }} else if (node.isObject() && node.size() == 1) {{"
+            )?;
+            writeln!(
+                result,
+                "            java.util.Map.Entry<String, com.fasterxml.jackson.databind.JsonNode> entry = node.fields().next();"
+            )?;
+            writeln!(result, "            switch (entry.getKey()) {{")?;
+            for v in variants.iter().filter(|v| v.payload_type.is_some()) {
+                writeln!(
+                    result,
+                    "                case \"{}\":",
+                    def.variant_wire_name(v)
+                )?;
+                writeln!(
+                    result,
+                    "                    return {};",
+                    read_payload(v, "entry.getValue()")?
+                )?;
+            }
+            writeln!(result, "            }}")?;
+            writeln!(result, "        }}")?;
+        }
+        _ => {
+            // jackson reads null into any payload type, so variant without payload goes first
+            if let Some(v) = unit_variant {
+                writeln!(result, "        if (node.isNull()) {{")?;
+                writeln!(result, "            return new {model_name}.{}();", v.name)?;
+                writeln!(result, "        }}")?;
+            }
+            // try variants in order, the first match wins
+            for v in variants.iter().filter(|v| v.payload_type.is_some()) {
+                writeln!(result, "        try {{")?;
+                writeln!(result, "            return {};", read_payload(v, "node")?)?;
+                writeln!(result, "        }} catch (java.io.IOException e) {{")?;
+                writeln!(result, "            // try next variant")?;
+                writeln!(result, "        }}")?;
+            }
+        }
+    }
+    writeln!(
+        result,
+        "        throw com.fasterxml.jackson.databind.JsonMappingException.from(p, \"invalid {model_name}\");"
+    )?;
+    writeln!(result, "    }}")?;
+
+    if let Some(v) = unit_variant {
+        // null property does not reach deserialize
+        writeln!(result)?;
+        writeln!(result, "    @Override")?;
+        writeln!(
+            result,
+            "    public {model_name} getNullValue(com.fasterxml.jackson.databind.DeserializationContext ctxt) {{"
+        )?;
+        writeln!(result, "        return new {model_name}.{}();", v.name)?;
+        writeln!(result, "    }}")?;
+    }
+    writeln!(result, "}}")?;
+
+    Ok(result)
+}
+
 fn render_field(
     field: &FieldDef,
     def: &Definition,
@@ -389,13 +691,21 @@ fn render_field(
     }
 
     let java_field_name = field.name.to_case(convert_case::Case::Camel);
-    let need_rename = java_field_name.ne(&field.name);
+    let wire_name = def.field_wire_name(field);
+    let need_rename = java_field_name.ne(&wire_name);
 
     if need_rename {
         writeln!(
             result,
             "    @com.fasterxml.jackson.annotation.JsonProperty(\"{}\")",
-            field.name
+            wire_name
+        )?;
+    }
+
+    if let Some(format) = java_json_format(&field.type_) {
+        writeln!(
+            result,
+            "    @com.fasterxml.jackson.annotation.JsonFormat({format})"
         )?;
     }
 
@@ -451,6 +761,10 @@ mod tests {
                 "src/codegen/fixtures/specs/const_i64.yaml",
                 "src/codegen/fixtures/java_jackson/const_i64",
             ),
+            (
+                "src/codegen/fixtures/specs/const_u64.yaml",
+                "src/codegen/fixtures/java_jackson/const_u64",
+            ),
             (
                 "src/codegen/fixtures/specs/decimal.yaml",
                 "src/codegen/fixtures/java_jackson/decimal",
@@ -463,6 +777,30 @@ mod tests {
                 "src/codegen/fixtures/specs/java_namespace.yaml",
                 "src/codegen/fixtures/java_jackson/namespace",
             ),
+            (
+                "src/codegen/fixtures/specs/time.yaml",
+                "src/codegen/fixtures/java_jackson/time",
+            ),
+            (
+                "src/codegen/fixtures/specs/unsigned.yaml",
+                "src/codegen/fixtures/java_jackson/unsigned",
+            ),
+            (
+                "src/codegen/fixtures/specs/wire_name.yaml",
+                "src/codegen/fixtures/java_jackson/wire_name",
+            ),
+            (
+                "src/codegen/fixtures/specs/enum_representation.yaml",
+                "src/codegen/fixtures/java_jackson/enum_representation",
+            ),
+            (
+                "src/codegen/fixtures/specs/generic.yaml",
+                "src/codegen/fixtures/java_jackson/generic",
+            ),
+            (
+                "src/codegen/fixtures/specs/map_key_set.yaml",
+                "src/codegen/fixtures/java_jackson/map_key_set",
+            ),
         ];
 
         for (spec, package_folder) in specs.iter() {
@@ -470,12 +808,11 @@ mod tests {
             let context =
                 Context::new_from_folder(&PathBuf::from("src/codegen/fixtures/specs")).unwrap();
 
-            render(
-                &PathBuf::from(spec),
-                &context,
-                &std::path::PathBuf::from(package_folder),
-            )
-            .unwrap();
+            for file in render(&PathBuf::from(spec), &context).unwrap() {
+                let path = PathBuf::from(package_folder).join(file.path);
+                std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+                std::fs::write(path, file.content).unwrap();
+            }
         }
     }
 }
@@ -0,0 +1,849 @@
+use crate::codegen::utils::{indent, multiline_prefix_with, to_pascal_case};
+use crate::{
+    ConstType, ConstValueDef, Definition, EnumRepresentation, FieldDef, ModelDef, ModelType,
+    Nullability, StringOrInteger, StructDef, TimestampFormat, Type, TypeReference, VariantDef,
+};
+use convert_case::Casing;
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use super::context::Context;
+use super::output::{FileTree, OutputFile};
+
+pub struct KotlinSerialization {
+    context: Context,
+}
+
+/// where a type is rendered
+struct Scope<'a> {
+    /// package of the rendered file
+    package: &'a str,
+    /// classes nested in the enclosing sealed class, they shadow models and builtins with
+    /// the same name, so those are qualified
+    nested: BTreeSet<String>,
+}
+
+impl<'a> Scope<'a> {
+    fn new(package: &'a str) -> Self {
+        Self {
+            package,
+            nested: BTreeSet::new(),
+        }
+    }
+
+    /// name qualified by package if it is shadowed
+    fn qualify(&self, package: &str, name: &str) -> String {
+        match self.nested.contains(name) {
+            true => format!("{package}.{name}"),
+            false => name.to_string(),
+        }
+    }
+}
+
+impl super::Codegen for KotlinSerialization {
+    fn load_from_context(context: &Context) -> anyhow::Result<Self> {
+        Ok(Self {
+            context: context.clone(),
+        })
+    }
+
+    fn generate(&self) -> anyhow::Result<FileTree> {
+        let mut tree = FileTree::new();
+        for (spec_path, def) in self.context.iter_specs() {
+            // one file for each spec, in the package's folder
+            let mut output_path = PathBuf::new();
+            kotlin_package(def, spec_path)?
+                .split('.')
+                .for_each(|c| output_path.push(c));
+            let file_stem = spec_path.file_stem().unwrap_or_default().to_string_lossy();
+            output_path.push(format!("{}.kt", to_pascal_case(&file_stem)));
+
+            tree.add(OutputFile::new(output_path, self.render(spec_path)?));
+        }
+        Ok(tree)
+    }
+}
+
+impl KotlinSerialization {
+    fn render(&self, spec_path: &Path) -> anyhow::Result<String> {
+        let def = self.context.get_definition(spec_path)?;
+        let package = kotlin_package(def, spec_path)?;
+
+        let mut imports = BTreeSet::from(["kotlinx.serialization.*"]);
+        let mut body = String::new();
+        for model in def.models.iter() {
+            writeln!(body)?;
+            if let Some(desc) = &model.desc {
+                writeln!(body, "{}", kdoc(desc))?;
+            }
+
+            let scope = Scope::new(&package);
+            let code = match &model.type_ {
+                ModelType::Struct(struct_def) => {
+                    self.render_struct(model, struct_def, spec_path, &scope)?
+                }
+                ModelType::Virtual(struct_def) => {
+                    self.render_interface(model, struct_def, spec_path, &scope)?
+                }
+                ModelType::NewType { inner_type } => format!(
+                    "@Serializable\n@JvmInline\nvalue class {}(val value: {})\n",
+                    model.name,
+                    self.kotlin_type(inner_type, spec_path, &scope)?
+                ),
+                ModelType::Const { value_type, values } => {
+                    render_const(&model.name, value_type, values)?
+                }
+                ModelType::Enum {
+                    variants,
+                    tag_name,
+                    payload_name,
+                    representation,
+                } => {
+                    imports.insert("kotlinx.serialization.json.*");
+                    if *representation != EnumRepresentation::Adjacent {
+                        imports.extend([
+                            "kotlinx.serialization.descriptors.*",
+                            "kotlinx.serialization.encoding.*",
+                        ]);
+                    }
+                    self.render_enum(
+                        model,
+                        variants,
+                        *representation,
+                        tag_name.as_deref().unwrap_or("type"),
+                        payload_name.as_deref().unwrap_or("payload"),
+                        def,
+                        spec_path,
+                        &package,
+                    )?
+                }
+            };
+            write!(body, "{code}")?;
+        }
+
+        let mut result = String::new();
+        writeln!(result, "package {package}")?;
+        writeln!(result)?;
+        for import in imports {
+            writeln!(result, "import {import}")?;
+        }
+        result.push_str(&body);
+        Ok(result)
+    }
+
+    /// type declared in spec_path and rendered in scope
+    fn kotlin_type(&self, ty: &Type, spec_path: &Path, scope: &Scope) -> anyhow::Result<String> {
+        let builtin = |name: &str| scope.qualify("kotlin", name);
+        Ok(match ty {
+            Type::Bool => builtin("Boolean"),
+            Type::I8 => builtin("Byte"),
+            Type::I16 => builtin("Short"),
+            Type::I32 => builtin("Int"),
+            Type::I64 => builtin("Long"),
+            Type::U8 => builtin("UByte"),
+            Type::U16 => builtin("UShort"),
+            Type::U32 => builtin("UInt"),
+            Type::U64 => builtin("ULong"),
+            Type::F32 => builtin("Float"),
+            Type::F64 => builtin("Double"),
+            // both are encoded as json string
+            Type::Decimal | Type::BigInt => builtin("String"),
+            Type::Bytes => builtin("ByteArray"),
+            Type::String => builtin("String"),
+            // kotlinx-datetime types are encoded as ISO 8601 string
+            Type::Timestamp {
+                format: TimestampFormat::Rfc3339,
+            } => "kotlinx.datetime.Instant".into(),
+            Type::Date => "kotlinx.datetime.LocalDate".into(),
+            // millis
+            Type::Timestamp {
+                format: TimestampFormat::EpochMillis,
+            }
+            | Type::Duration => builtin("Long"),
+            Type::List { item_type } => format!(
+                "{}<{}>",
+                scope.qualify("kotlin.collections", "List"),
+                self.kotlin_type(item_type, spec_path, scope)?
+            ),
+            Type::Set { item_type } => format!(
+                "{}<{}>",
+                scope.qualify("kotlin.collections", "Set"),
+                self.kotlin_type(item_type, spec_path, scope)?
+            ),
+            Type::Map {
+                key_type,
+                value_type,
+            } => format!(
+                "{}<{}, {}>",
+                scope.qualify("kotlin.collections", "Map"),
+                self.kotlin_type(key_type, spec_path, scope)?,
+                self.kotlin_type(value_type, spec_path, scope)?
+            ),
+            Type::Reference(type_ref) => self.kotlin_type_reference(type_ref, spec_path, scope)?,
+            Type::TypeParam { param } => param.clone(),
+            Type::Json => "kotlinx.serialization.json.JsonElement".into(),
+        })
+    }
+
+    /// models in other packages are qualified
+    fn kotlin_type_reference(
+        &self,
+        type_ref: &TypeReference,
+        spec_path: &Path,
+        scope: &Scope,
+    ) -> anyhow::Result<String> {
+        let target_path = match &type_ref.namespace {
+            Some(namespace) => self.context.get_include_path(namespace, spec_path)?,
+            None => spec_path.to_path_buf(),
+        };
+        let target_package =
+            kotlin_package(self.context.get_definition(&target_path)?, &target_path)?;
+        let mut name = match target_package == scope.package {
+            true => scope.qualify(&target_package, &type_ref.target),
+            false => format!("{target_package}.{}", type_ref.target),
+        };
+
+        if !type_ref.args.is_empty() {
+            let args = type_ref
+                .args
+                .iter()
+                .map(|arg| self.kotlin_type(arg, spec_path, scope))
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            write!(name, "<{}>", args.join(", "))?;
+        }
+        Ok(name)
+    }
+
+    /// primary constructor property, fields may be absent default to null
+    fn render_property(
+        &self,
+        field: &FieldDef,
+        def: &Definition,
+        spec_path: &Path,
+        scope: &Scope,
+        modifier: &str,
+    ) -> anyhow::Result<String> {
+        let mut result = String::new();
+        if let Some(desc) = &field.desc {
+            writeln!(result, "{}", kdoc(desc))?;
+        }
+        if field.nullability == Some(Nullability::OptionalNullable) {
+            writeln!(result, "// absent and null are both decoded as null")?;
+        }
+
+        let name = field.name.to_case(convert_case::Case::Camel);
+        let wire_name = def.field_wire_name(field);
+        if wire_name != name {
+            write!(result, "@SerialName({}) ", string_literal(&wire_name)?)?;
+        }
+        let mut ty = self.kotlin_type(&field.type_, spec_path, scope)?;
+        if !field.required {
+            ty.push('?');
+        }
+        write!(result, "{modifier}val {}: {ty}", escape_keyword(&name))?;
+        if field.may_be_absent() {
+            write!(result, " = null")?;
+        }
+        writeln!(result, ",")?;
+        Ok(result)
+    }
+
+    /// `data class` with properties, or `class` if there is none
+    fn render_class(
+        &self,
+        header: &str,
+        properties: &str,
+        supertype: &str,
+    ) -> anyhow::Result<String> {
+        let mut result = String::new();
+        writeln!(result, "@Serializable")?;
+        if properties.is_empty() {
+            writeln!(result, "class {header}{supertype}")?;
+        } else {
+            writeln!(result, "data class {header}(")?;
+            writeln!(result, "{}", indent(properties.trim_end(), 1))?;
+            writeln!(result, "){supertype}")?;
+        }
+        Ok(result)
+    }
+
+    fn render_struct(
+        &self,
+        model: &ModelDef,
+        struct_def: &StructDef,
+        spec_path: &Path,
+        scope: &Scope,
+    ) -> anyhow::Result<String> {
+        let def = self.context.get_definition(spec_path)?;
+
+        let mut properties = String::new();
+        let mut supertype = String::new();
+        if let Some(extend) = &struct_def.extend {
+            let type_ref = TypeReference::try_parse(extend)
+                .ok_or_else(|| anyhow::anyhow!("invalid extend {extend}"))?;
+            // properties declared by the interface are overridden, they are declared in base spec
+            let base_path = match &type_ref.namespace {
+                Some(namespace) => self.context.get_include_path(namespace, spec_path)?,
+                None => spec_path.to_path_buf(),
+            };
+            let base_def = self.context.get_definition(&base_path)?;
+            let base = self
+                .context
+                .get_model_def_for_reference(&type_ref, spec_path)?;
+            let ModelType::Virtual(base_struct) = &base.type_ else {
+                anyhow::bail!("{} extends {extend} which is not virtual", model.name);
+            };
+            for field in base_struct.fields.iter() {
+                properties.push_str(&self.render_property(
+                    field,
+                    base_def,
+                    &base_path,
+                    scope,
+                    "override ",
+                )?);
+            }
+            supertype = format!(
+                " : {}",
+                self.kotlin_type_reference(&type_ref, spec_path, scope)?
+            );
+        }
+        for field in struct_def.fields.iter() {
+            properties.push_str(&self.render_property(field, def, spec_path, scope, "")?);
+        }
+
+        let header = format!("{}{}", model.name, type_params(model));
+        self.render_class(&header, &properties, &supertype)
+    }
+
+    fn render_interface(
+        &self,
+        model: &ModelDef,
+        struct_def: &StructDef,
+        spec_path: &Path,
+        scope: &Scope,
+    ) -> anyhow::Result<String> {
+        let mut result = String::new();
+        write!(result, "interface {}{}", model.name, type_params(model))?;
+        if let Some(extend) = &struct_def.extend {
+            let type_ref = TypeReference::try_parse(extend)
+                .ok_or_else(|| anyhow::anyhow!("invalid extend {extend}"))?;
+            write!(
+                result,
+                " : {}",
+                self.kotlin_type_reference(&type_ref, spec_path, scope)?
+            )?;
+        }
+        if struct_def.fields.is_empty() {
+            writeln!(result)?;
+            return Ok(result);
+        }
+
+        writeln!(result, " {{")?;
+        for field in struct_def.fields.iter() {
+            if let Some(desc) = &field.desc {
+                writeln!(result, "{}", indent(kdoc(desc), 1))?;
+            }
+            let mut ty = self.kotlin_type(&field.type_, spec_path, scope)?;
+            if !field.required {
+                ty.push('?');
+            }
+            let name = field.name.to_case(convert_case::Case::Camel);
+            writeln!(result, "    val {}: {ty}", escape_keyword(&name))?;
+        }
+        writeln!(result, "}}")?;
+        Ok(result)
+    }
+
+    /// sealed class with a nested class for each variant. adjacent is kotlinx's own
+    /// polymorphism with class discriminator, other representations have a serializer
+    #[allow(clippy::too_many_arguments)]
+    fn render_enum(
+        &self,
+        model: &ModelDef,
+        variants: &[VariantDef],
+        representation: EnumRepresentation,
+        tag_name: &str,
+        payload_name: &str,
+        def: &Definition,
+        spec_path: &Path,
+        package: &str,
+    ) -> anyhow::Result<String> {
+        let name = &model.name;
+        let adjacent = representation == EnumRepresentation::Adjacent;
+
+        let mut scope = Scope::new(package);
+        scope.nested.extend(variants.iter().map(|v| v.name.clone()));
+        if variants.iter().any(|v| v.payload_fields.is_some()) {
+            scope.nested.insert("Payload".to_string());
+        }
+        if !adjacent {
+            scope.nested.insert("Serializer".to_string());
+        }
+
+        let property = payload_name.to_case(convert_case::Case::Camel);
+        let property_decl = match adjacent && property != payload_name {
+            true => format!(
+                "@SerialName({}) val {}",
+                string_literal(payload_name)?,
+                escape_keyword(&property)
+            ),
+            false => format!("val {}", escape_keyword(&property)),
+        };
+
+        let mut result = String::new();
+        if adjacent {
+            writeln!(result, "@OptIn(ExperimentalSerializationApi::class)")?;
+            writeln!(result, "@Serializable")?;
+            writeln!(
+                result,
+                "@JsonClassDiscriminator({})",
+                string_literal(tag_name)?
+            )?;
+        } else {
+            writeln!(result, "@Serializable(with = {name}.Serializer::class)")?;
+        }
+        writeln!(result, "sealed class {name} {{")?;
+
+        for (idx, variant) in variants.iter().enumerate() {
+            if idx > 0 {
+                writeln!(result)?;
+            }
+            let mut code = String::new();
+            if let Some(desc) = &variant.desc {
+                writeln!(code, "{}", kdoc(desc))?;
+            }
+            if adjacent {
+                writeln!(code, "@Serializable")?;
+                writeln!(
+                    code,
+                    "@SerialName({})",
+                    string_literal(&def.variant_wire_name(variant))?
+                )?;
+            }
+
+            let variant_name = &variant.name;
+            match (&variant.payload_type, &variant.payload_fields) {
+                (Some(payload_type), _) => writeln!(
+                    code,
+                    "data class {variant_name}({property_decl}: {}) : {name}()",
+                    self.kotlin_type(payload_type, spec_path, &scope)?
+                )?,
+                (None, Some(fields)) => {
+                    let mut properties = String::new();
+                    for field in fields {
+                        properties
+                            .push_str(&self.render_property(field, def, spec_path, &scope, "")?);
+                    }
+                    writeln!(
+                        code,
+                        "data class {variant_name}({property_decl}: Payload) : {name}() {{"
+                    )?;
+                    let payload_class = self.render_class("Payload", &properties, "")?;
+                    writeln!(code, "{}", indent(payload_class.trim_end(), 1))?;
+                    writeln!(code, "}}")?;
+                }
+                (None, None) => writeln!(code, "data object {variant_name} : {name}()")?,
+            }
+            writeln!(result, "{}", indent(code.trim_end(), 1))?;
+        }
+
+        if !adjacent {
+            writeln!(result)?;
+            let serializer = render_serializer(
+                model,
+                variants,
+                representation,
+                tag_name,
+                &property,
+                def,
+                package,
+            )?;
+            writeln!(result, "{}", indent(serializer.trim_end(), 1))?;
+        }
+        writeln!(result, "}}")?;
+        Ok(result)
+    }
+}
+
+/// serializer works on json element, for representations kotlinx does not support
+fn render_serializer(
+    model: &ModelDef,
+    variants: &[VariantDef],
+    representation: EnumRepresentation,
+    tag_name: &str,
+    property: &str,
+    def: &Definition,
+    package: &str,
+) -> anyhow::Result<String> {
+    let name = &model.name;
+    let property = escape_keyword(property);
+    let tag = string_literal(tag_name)?;
+    let has_payload = |v: &VariantDef| v.payload_type.is_some() || v.payload_fields.is_some();
+
+    let mut result = String::new();
+    writeln!(result, "object Serializer : KSerializer<{name}> {{")?;
+    writeln!(
+        result,
+        "    override val descriptor: SerialDescriptor = buildClassSerialDescriptor(\"{package}.{name}\")"
+    )?;
+    writeln!(result)?;
+
+    writeln!(
+        result,
+        "    override fun serialize(encoder: Encoder, value: {name}) {{"
+    )?;
+    writeln!(result, "        val json = (encoder as JsonEncoder).json")?;
+    writeln!(result, "        val element = when (value) {{")?;
+    for variant in variants {
+        let wire_name = string_literal(&def.variant_wire_name(variant))?;
+        let payload = format!("json.encodeToJsonElement(value.{property})");
+        let element = match (representation, has_payload(variant)) {
+            (EnumRepresentation::Internal, true) => {
+                format!(
+                    "JsonObject(mapOf({tag} to JsonPrimitive({wire_name})) + {payload}.jsonObject)"
+                )
+            }
+            (EnumRepresentation::Internal, false) => {
+                format!("JsonObject(mapOf({tag} to JsonPrimitive({wire_name})))")
+            }
+            (EnumRepresentation::External, true) => {
+                format!("JsonObject(mapOf({wire_name} to {payload}))")
+            }
+            (EnumRepresentation::External, false) => format!("JsonPrimitive({wire_name})"),
+            (_, true) => payload,
+            (_, false) => "JsonNull".to_string(),
+        };
+        writeln!(result, "            is {} -> {element}", variant.name)?;
+    }
+    writeln!(result, "        }}")?;
+    writeln!(result, "        encoder.encodeJsonElement(element)")?;
+    writeln!(result, "    }}")?;
+    writeln!(result)?;
+
+    writeln!(
+        result,
+        "    override fun deserialize(decoder: Decoder): {name} {{"
+    )?;
+    writeln!(result, "        val json = (decoder as JsonDecoder).json")?;
+    writeln!(result, "        val element = decoder.decodeJsonElement()")?;
+
+    let unknown = format!("throw SerializationException(\"unknown variant $tag of {name}\")");
+    // decode variant from payload element
+    let decode = |variant: &VariantDef, payload: &str| match has_payload(variant) {
+        true => format!("{}(json.decodeFromJsonElement({payload}))", variant.name),
+        false => variant.name.clone(),
+    };
+    let mut code = String::new();
+    match representation {
+        EnumRepresentation::Internal => {
+            writeln!(code, "val fields = element.jsonObject")?;
+            writeln!(
+                code,
+                "return when (val tag = fields[{tag}]?.jsonPrimitive?.content) {{"
+            )?;
+            for variant in variants {
+                writeln!(
+                    code,
+                    "    {} -> {}",
+                    string_literal(&def.variant_wire_name(variant))?,
+                    decode(variant, &format!("JsonObject(fields - {tag})"))
+                )?;
+            }
+            writeln!(code, "    else -> {unknown}")?;
+            writeln!(code, "}}")?;
+        }
+        EnumRepresentation::External => {
+            // variant without payload is a string, others are an object with one key
+            if variants.iter().any(|v| !has_payload(v)) {
+                writeln!(code, "if (element is JsonPrimitive) {{")?;
+                writeln!(code, "    return when (val tag = element.content) {{")?;
+                for variant in variants.iter().filter(|v| !has_payload(v)) {
+                    writeln!(
+                        code,
+                        "        {} -> {}",
+                        string_literal(&def.variant_wire_name(variant))?,
+                        variant.name
+                    )?;
+                }
+                writeln!(code, "        else -> {unknown}")?;
+                writeln!(code, "    }}")?;
+                writeln!(code, "}}")?;
+            }
+            writeln!(
+                code,
+                "val (tag, payload) = element.jsonObject.entries.singleOrNull()"
+            )?;
+            writeln!(
+                code,
+                "    ?: throw SerializationException(\"expect one key for {name}\")"
+            )?;
+            writeln!(code, "return when (tag) {{")?;
+            for variant in variants.iter().filter(|v| has_payload(v)) {
+                writeln!(
+                    code,
+                    "    {} -> {}",
+                    string_literal(&def.variant_wire_name(variant))?,
+                    decode(variant, "payload")
+                )?;
+            }
+            writeln!(code, "    else -> {unknown}")?;
+            writeln!(code, "}}")?;
+        }
+        _ => {
+            // null only matches variant without payload, check it first as it may decode
+            // into a payload type
+            if let Some(variant) = variants.iter().find(|v| !has_payload(v)) {
+                writeln!(code, "if (element is JsonNull) {{")?;
+                writeln!(code, "    return {}", variant.name)?;
+                writeln!(code, "}}")?;
+            }
+            // try variants in order, the first match wins
+            for variant in variants.iter().filter(|v| has_payload(v)) {
+                writeln!(
+                    code,
+                    "runCatching {{ return {} }}",
+                    decode(variant, "element")
+                )?;
+            }
+            writeln!(
+                code,
+                "throw SerializationException(\"no variant of {name} matches $element\")"
+            )?;
+        }
+    }
+    writeln!(result, "{}", indent(code.trim_end(), 2))?;
+    writeln!(result, "    }}")?;
+    writeln!(result, "}}")?;
+    Ok(result)
+}
+
+/// value class with a companion object holding the values
+fn render_const(
+    model_name: &str,
+    value_type: &ConstType,
+    values: &[ConstValueDef],
+) -> anyhow::Result<String> {
+    let (kotlin_type, unsigned) = match value_type {
+        ConstType::I8 => ("Byte", false),
+        ConstType::I16 => ("Short", false),
+        ConstType::I32 => ("Int", false),
+        ConstType::I64 => ("Long", false),
+        ConstType::U8 => ("UByte", true),
+        ConstType::U16 => ("UShort", true),
+        ConstType::U32 => ("UInt", true),
+        ConstType::U64 => ("ULong", true),
+        ConstType::String => ("String", false),
+    };
+
+    let mut result = String::new();
+    writeln!(result, "@Serializable")?;
+    writeln!(result, "@JvmInline")?;
+    write!(result, "value class {model_name}(val value: {kotlin_type})")?;
+    if values.is_empty() {
+        writeln!(result)?;
+        return Ok(result);
+    }
+
+    writeln!(result, " {{")?;
+    writeln!(result, "    companion object {{")?;
+    for (idx, value) in values.iter().enumerate() {
+        if idx > 0 {
+            writeln!(result)?;
+        }
+        if let Some(desc) = &value.desc {
+            writeln!(result, "{}", indent(kdoc(desc), 2))?;
+        }
+        let literal = match &value.value {
+            StringOrInteger::String(s) => string_literal(s)?,
+            StringOrInteger::Integer(i) if unsigned => format!("{i}u"),
+            StringOrInteger::Integer(i) => i.to_string(),
+        };
+        writeln!(
+            result,
+            "        val {} = {model_name}({literal})",
+            value.name.to_case(convert_case::Case::UpperSnake)
+        )?;
+    }
+    writeln!(result, "    }}")?;
+    writeln!(result, "}}")?;
+    Ok(result)
+}
+
+fn type_params(model: &ModelDef) -> String {
+    match model.type_params.is_empty() {
+        true => "".to_string(),
+        false => format!("<{}>", model.type_params.join(", ")),
+    }
+}
+
+/// package from `meta.kotlin_serialization.package`, it is required
+fn kotlin_package(def: &Definition, spec_path: &Path) -> anyhow::Result<String> {
+    def.get_meta("kotlin_serialization")
+        .get("package")
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("{spec_path:?} requires meta.kotlin_serialization.package"))
+}
+
+fn kdoc(desc: &str) -> String {
+    match desc.trim().contains('\n') {
+        true => format!("/**\n{}\n */", multiline_prefix_with(desc, " * ")),
+        false => format!("/** {} */", desc.trim()),
+    }
+}
+
+/// kotlin string literal, `$` starts a template so it is escaped
+fn string_literal(value: &str) -> anyhow::Result<String> {
+    Ok(serde_json::to_string(value)?.replace('$', "\\$"))
+}
+
+/// hard keywords can only be identifiers in backticks
+fn escape_keyword(name: &str) -> String {
+    const KEYWORDS: &[&str] = &[
+        "as",
+        "break",
+        "class",
+        "continue",
+        "do",
+        "else",
+        "false",
+        "for",
+        "fun",
+        "if",
+        "in",
+        "interface",
+        "is",
+        "null",
+        "object",
+        "package",
+        "return",
+        "super",
+        "this",
+        "throw",
+        "true",
+        "try",
+        "typealias",
+        "typeof",
+        "val",
+        "var",
+        "when",
+        "while",
+    ];
+    match KEYWORDS.contains(&name) {
+        true => format!("`{name}`"),
+        false => name.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codegen::Codegen;
+
+    #[test]
+    fn test_render() {
+        fn test_def(spec: &Path, code_path: &str) {
+            let spec = spec.strip_prefix("src/codegen/fixtures/specs/").unwrap();
+
+            let codegen = KotlinSerialization::load_from_folder(&PathBuf::from(
+                "src/codegen/fixtures/specs/",
+            ))
+            .unwrap();
+
+            let rendered = codegen.render(spec).unwrap();
+
+            let code = std::fs::read_to_string(code_path).unwrap();
+
+            #[cfg(not(feature = "test_update_spec"))]
+            pretty_assertions::assert_eq!(code.trim(), rendered.trim());
+
+            #[cfg(feature = "test_update_spec")]
+            {
+                if code.trim() != rendered.trim() {
+                    std::fs::write(code_path, rendered).unwrap();
+                }
+            }
+        }
+
+        for (spec, expected) in &[
+            (
+                "src/codegen/fixtures/specs/simple_struct.yaml",
+                "src/codegen/fixtures/kotlin_serialization/simple_struct.kt",
+            ),
+            (
+                "src/codegen/fixtures/specs/enum.yaml",
+                "src/codegen/fixtures/kotlin_serialization/enum.kt",
+            ),
+            (
+                "src/codegen/fixtures/specs/enum_custom_tag.yaml",
+                "src/codegen/fixtures/kotlin_serialization/enum_custom_tag.kt",
+            ),
+            (
+                "src/codegen/fixtures/specs/enum_variant_fields.yaml",
+                "src/codegen/fixtures/kotlin_serialization/enum_variant_fields.kt",
+            ),
+            (
+                "src/codegen/fixtures/specs/enum_representation.yaml",
+                "src/codegen/fixtures/kotlin_serialization/enum_representation.kt",
+            ),
+            (
+                "src/codegen/fixtures/specs/extend.yaml",
+                "src/codegen/fixtures/kotlin_serialization/extend.kt",
+            ),
+            (
+                "src/codegen/fixtures/specs/new_type.yaml",
+                "src/codegen/fixtures/kotlin_serialization/new_type.kt",
+            ),
+            (
+                "src/codegen/fixtures/specs/const_i8.yaml",
+                "src/codegen/fixtures/kotlin_serialization/const_i8.kt",
+            ),
+            (
+                "src/codegen/fixtures/specs/const_string.yaml",
+                "src/codegen/fixtures/kotlin_serialization/const_string.kt",
+            ),
+            (
+                "src/codegen/fixtures/specs/include_test.yaml",
+                "src/codegen/fixtures/kotlin_serialization/include_test.kt",
+            ),
+            (
+                "src/codegen/fixtures/specs/time.yaml",
+                "src/codegen/fixtures/kotlin_serialization/time.kt",
+            ),
+            (
+                "src/codegen/fixtures/specs/unsigned.yaml",
+                "src/codegen/fixtures/kotlin_serialization/unsigned.kt",
+            ),
+            (
+                "src/codegen/fixtures/specs/decimal.yaml",
+                "src/codegen/fixtures/kotlin_serialization/decimal.kt",
+            ),
+            (
+                "src/codegen/fixtures/specs/json.yaml",
+                "src/codegen/fixtures/kotlin_serialization/json.kt",
+            ),
+            (
+                "src/codegen/fixtures/specs/nullability.yaml",
+                "src/codegen/fixtures/kotlin_serialization/nullability.kt",
+            ),
+            (
+                "src/codegen/fixtures/specs/wire_name.yaml",
+                "src/codegen/fixtures/kotlin_serialization/wire_name.kt",
+            ),
+            (
+                "src/codegen/fixtures/specs/generic.yaml",
+                "src/codegen/fixtures/kotlin_serialization/generic.kt",
+            ),
+            (
+                "src/codegen/fixtures/specs/map_key_set.yaml",
+                "src/codegen/fixtures/kotlin_serialization/map_key_set.kt",
+            ),
+            (
+                "src/codegen/fixtures/specs/empty_struct.yaml",
+                "src/codegen/fixtures/kotlin_serialization/empty_struct.kt",
+            ),
+        ] {
+            test_def(PathBuf::from(spec).as_path(), expected);
+        }
+    }
+}
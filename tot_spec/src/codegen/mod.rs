@@ -1,7 +1,16 @@
+use output::{FileTree, Writer};
 use std::path::PathBuf;
 
-mod context;
+pub use context::{Context, Target};
+
+pub(crate) mod context;
+pub mod csharp;
+pub mod dart;
+pub mod go_json;
 pub mod java_jackson;
+pub mod kotlin_serialization;
+pub mod output;
+pub mod protobuf;
 pub mod py_dataclass;
 pub mod rs_serde;
 pub mod style;
@@ -11,9 +20,52 @@ pub mod typescript;
 pub mod utils;
 
 pub trait Codegen {
-    fn load_from_folder(folder: &PathBuf) -> anyhow::Result<Self>
+    /// create codegen from loaded specs, so one `Context` can be shared by many codegens
+    fn load_from_context(context: &Context) -> anyhow::Result<Self>
     where
         Self: Sized;
 
-    fn generate_for_folder(&self, folder: &PathBuf, output: &PathBuf) -> anyhow::Result<()>;
+    fn load_from_folder(folder: &PathBuf) -> anyhow::Result<Self>
+    where
+        Self: Sized,
+    {
+        Self::load_from_context(&Context::new_from_folder(folder)?)
+    }
+
+    /// render all output files in memory without touching the output folder
+    fn generate(&self) -> anyhow::Result<FileTree>;
+
+    /// generate and write all files into output with default `Writer`,
+    /// specs are already loaded, so folder is not used
+    fn generate_for_folder(&self, _folder: &PathBuf, output: &PathBuf) -> anyhow::Result<()> {
+        let tree = self.generate()?;
+        Writer::new(output).write(&tree)?;
+        Ok(())
+    }
+}
+
+/// create codegen by name, e.g: `rs_serde`
+pub fn load_codegen(name: &str, context: &Context) -> anyhow::Result<Box<dyn Codegen>> {
+    Ok(match name {
+        "rs_serde" => Box::new(rs_serde::RsSerde::load_from_context(context)?),
+        "java_jackson" => Box::new(java_jackson::JavaJackson::load_from_context(context)?),
+        "swift_codable" => Box::new(swift_codable::SwiftCodable::load_from_context(context)?),
+        "py_dataclass" => Box::new(py_dataclass::PyDataclass::load_from_context(context)?),
+        "swagger" => Box::new(swagger::Swagger::load_from_context(context)?),
+        "typescript" => Box::new(typescript::TypeScript::load_from_context(context)?),
+        "go_json" => Box::new(go_json::GoJson::load_from_context(context)?),
+        "kotlin_serialization" => {
+            Box::new(kotlin_serialization::KotlinSerialization::load_from_context(context)?)
+        }
+        "csharp" => Box::new(csharp::CSharp::load_from_context(context)?),
+        "dart" => Box::new(dart::Dart::load_from_context(context)?),
+        "protobuf" => Box::new(protobuf::Protobuf::load_from_context(context)?),
+        _ => anyhow::bail!("unknown codegen name {name:?}"),
+    })
+}
+
+/// load and validate all specs in folder without generating code, returns number of specs
+pub fn validate_folder(folder: &PathBuf) -> anyhow::Result<usize> {
+    let context = Context::new_from_folder(folder)?;
+    Ok(context.iter_specs().count())
 }
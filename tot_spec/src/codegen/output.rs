@@ -0,0 +1,409 @@
+use crate::diagnostic::Diagnostic;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// A file rendered by codegen
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputFile {
+    /// path relative to output folder, absolute path is written as is and never recorded in
+    /// manifest, e.g: protobuf's lockfile which is kept next to specs
+    pub path: PathBuf,
+    pub content: String,
+    /// only written when missing, e.g: python's `__init__.py` which may be edited by hand
+    pub keep_existing: bool,
+}
+
+impl OutputFile {
+    pub fn new(path: impl Into<PathBuf>, content: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            content: content.into(),
+            keep_existing: false,
+        }
+    }
+}
+
+/// Files rendered by codegen in memory, plus problems found while rendering
+#[derive(Debug, Clone, Default)]
+pub struct FileTree {
+    files: BTreeMap<PathBuf, OutputFile>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl FileTree {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// add file, replace the previous one with the same path
+    pub fn add(&mut self, file: OutputFile) {
+        self.files.insert(file.path.clone(), file);
+    }
+
+    pub fn get(&self, path: impl AsRef<Path>) -> Option<&OutputFile> {
+        self.files.get(path.as_ref())
+    }
+
+    /// files ordered by path
+    pub fn files(&self) -> impl Iterator<Item = &OutputFile> {
+        self.files.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+}
+
+impl FromIterator<OutputFile> for FileTree {
+    fn from_iter<T: IntoIterator<Item = OutputFile>>(iter: T) -> Self {
+        let mut tree = Self::new();
+        iter.into_iter().for_each(|file| tree.add(file));
+        tree
+    }
+}
+
+/// How writer handles files already exist in output folder
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OverwritePolicy {
+    /// always write, this is the default
+    #[default]
+    Always,
+    /// skip files whose content is unchanged, so their mtime is kept
+    IfChanged,
+    /// never touch existing files, only create missing ones
+    Never,
+}
+
+/// A generated file differs from the one in output folder
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    /// path relative to output folder, or absolute path of file outside it
+    pub path: PathBuf,
+    /// None if file is missing
+    pub old: Option<String>,
+    pub new: String,
+}
+
+/// Writes `FileTree` into output folder
+#[derive(Debug, Clone)]
+pub struct Writer {
+    output: PathBuf,
+    overwrite: OverwritePolicy,
+    log: bool,
+    manifest: Option<String>,
+    prune: bool,
+}
+
+impl Writer {
+    pub fn new(output: impl Into<PathBuf>) -> Self {
+        Self {
+            output: output.into(),
+            overwrite: OverwritePolicy::default(),
+            log: true,
+            manifest: None,
+            prune: false,
+        }
+    }
+
+    pub fn overwrite(mut self, overwrite: OverwritePolicy) -> Self {
+        self.overwrite = overwrite;
+        self
+    }
+
+    /// whether to log each written file to stderr, default true
+    pub fn log(mut self, log: bool) -> Self {
+        self.log = log;
+        self
+    }
+
+    /// record generated files in manifest `.tot_spec.{name}.manifest` in output folder,
+    /// name should be unique if several codegens share one output folder
+    pub fn manifest(mut self, name: impl Into<String>) -> Self {
+        self.manifest = Some(name.into());
+        self
+    }
+
+    /// delete files in manifest which are no longer generated, files not in manifest or
+    /// edited after generation are never deleted. requires `manifest`
+    pub fn prune(mut self, prune: bool) -> Self {
+        self.prune = prune;
+        self
+    }
+
+    pub fn output(&self) -> &PathBuf {
+        &self.output
+    }
+
+    pub fn manifest_path(&self) -> Option<PathBuf> {
+        let name = self.manifest.as_ref()?;
+        Some(self.output.join(format!(".tot_spec.{name}.manifest")))
+    }
+
+    /// write files and create folders as needed, returns paths of written files
+    pub fn write(&self, tree: &FileTree) -> anyhow::Result<Vec<PathBuf>> {
+        if self.prune && self.manifest.is_none() {
+            anyhow::bail!("prune requires manifest");
+        }
+
+        let mut written = vec![];
+        for file in tree.files() {
+            let path = self.output.join(&file.path);
+            if path.exists() {
+                let skip = match self.overwrite {
+                    _ if file.keep_existing => true,
+                    OverwritePolicy::Always => false,
+                    OverwritePolicy::IfChanged => {
+                        std::fs::read_to_string(&path).ok().as_deref() == Some(&file.content)
+                    }
+                    OverwritePolicy::Never => true,
+                };
+                if skip {
+                    continue;
+                }
+            }
+
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(&path, &file.content)?;
+            if self.log {
+                eprintln!("write output to {:?}", path);
+            }
+            written.push(path);
+        }
+
+        if let Some(manifest_path) = self.manifest_path() {
+            self.update_manifest(&manifest_path, tree)?;
+        }
+        Ok(written)
+    }
+
+    /// record files in tree, and prune files only in previous manifest
+    fn update_manifest(&self, manifest_path: &Path, tree: &FileTree) -> anyhow::Result<()> {
+        let previous = Manifest::load(manifest_path);
+
+        // files kept when existing may be edited by hand, and files outside output folder
+        // are not owned by it, so they are never pruned
+        let mut manifest = Manifest::default();
+        for file in tree
+            .files()
+            .filter(|file| !file.keep_existing && file.path.is_relative())
+        {
+            manifest
+                .files
+                .insert(file.path.clone(), content_hash(file.content.as_bytes()));
+        }
+
+        for (path, hash) in previous.files {
+            if manifest.files.contains_key(&path) {
+                continue;
+            }
+            let full_path = self.output.join(&path);
+            let Ok(content) = std::fs::read(&full_path) else {
+                // already removed
+                continue;
+            };
+            if !self.prune {
+                // kept, so a later prune still knows the file
+                manifest.files.insert(path, hash);
+                continue;
+            }
+            if content_hash(&content) != hash {
+                eprintln!("keep stale output {full_path:?}, it is edited after generation");
+                continue;
+            }
+
+            std::fs::remove_file(&full_path)?;
+            if self.log {
+                eprintln!("remove stale output {full_path:?}");
+            }
+            // remove folders left empty, remove_dir fails on non empty folder
+            for folder in full_path.ancestors().skip(1) {
+                if folder == self.output || std::fs::remove_dir(folder).is_err() {
+                    break;
+                }
+            }
+        }
+
+        std::fs::create_dir_all(&self.output)?;
+        std::fs::write(manifest_path, manifest.to_string())?;
+        Ok(())
+    }
+
+    /// files which would be changed by `write` with `OverwritePolicy::Always`, nothing is written
+    pub fn changes(&self, tree: &FileTree) -> Vec<Change> {
+        tree.files()
+            .filter_map(|file| {
+                let old = std::fs::read_to_string(self.output.join(&file.path)).ok();
+                match &old {
+                    Some(old) if file.keep_existing || old == &file.content => None,
+                    _ => Some(Change {
+                        path: file.path.clone(),
+                        old,
+                        new: file.content.clone(),
+                    }),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Files generated into output folder, with content hash to detect files edited after generation
+#[derive(Debug, Default)]
+struct Manifest {
+    files: BTreeMap<PathBuf, u64>,
+}
+
+impl Manifest {
+    const HEADER: &'static str = "# generated by tot_spec, lists generated files, do not edit";
+
+    /// empty if missing, malformed lines and absolute paths are ignored
+    fn load(path: &Path) -> Self {
+        let content = std::fs::read_to_string(path).unwrap_or_default();
+        let files = content
+            .lines()
+            .filter(|line| !line.starts_with('#'))
+            .filter_map(|line| {
+                let (hash, path) = line.split_once(' ')?;
+                let hash = u64::from_str_radix(hash, 16).ok()?;
+                Some((PathBuf::from(path), hash))
+            })
+            .filter(|(path, _)| path.is_relative())
+            .collect();
+        Self { files }
+    }
+}
+
+impl std::fmt::Display for Manifest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", Self::HEADER)?;
+        for (path, hash) in self.files.iter() {
+            let path = path.to_string_lossy().replace('\\', "/");
+            writeln!(f, "{hash:016x} {path}")?;
+        }
+        Ok(())
+    }
+}
+
+/// FNV-1a, stable across platforms and rust versions
+fn content_hash(content: &[u8]) -> u64 {
+    content.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_writer() {
+        let output = std::env::temp_dir().join(format!("tot_spec_writer_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&output);
+
+        let tree = FileTree::from_iter([
+            OutputFile::new("a.rs", "a"),
+            OutputFile::new("nested/b.rs", "b"),
+            OutputFile {
+                keep_existing: true,
+                ..OutputFile::new("nested/__init__.py", "")
+            },
+        ]);
+
+        let writer = Writer::new(&output).log(false);
+        assert_eq!(writer.changes(&tree).len(), 3);
+        assert_eq!(writer.write(&tree).unwrap().len(), 3);
+        assert!(writer.changes(&tree).is_empty());
+
+        std::fs::write(output.join("a.rs"), "edited").unwrap();
+        std::fs::write(output.join("nested/__init__.py"), "edited").unwrap();
+        assert_eq!(
+            writer.changes(&tree),
+            vec![Change {
+                path: "a.rs".into(),
+                old: Some("edited".into()),
+                new: "a".into(),
+            }]
+        );
+
+        let written = writer
+            .clone()
+            .overwrite(OverwritePolicy::Never)
+            .write(&tree)
+            .unwrap();
+        assert!(written.is_empty());
+
+        let written = writer
+            .clone()
+            .overwrite(OverwritePolicy::IfChanged)
+            .write(&tree)
+            .unwrap();
+        assert_eq!(written, vec![output.join("a.rs")]);
+        assert_eq!(
+            std::fs::read_to_string(output.join("nested/__init__.py")).unwrap(),
+            "edited"
+        );
+
+        std::fs::remove_dir_all(&output).unwrap();
+    }
+
+    #[test]
+    fn test_prune() {
+        let output = std::env::temp_dir().join(format!("tot_spec_prune_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&output);
+        std::fs::create_dir_all(&output).unwrap();
+        std::fs::write(output.join("hand_written.rs"), "hand").unwrap();
+
+        let writer = Writer::new(&output).log(false).manifest("rs_serde");
+        writer
+            .write(&FileTree::from_iter([
+                OutputFile::new("a.rs", "a"),
+                OutputFile::new("b.rs", "b"),
+                OutputFile::new("nested/c.rs", "c"),
+            ]))
+            .unwrap();
+        let manifest = std::fs::read_to_string(writer.manifest_path().unwrap()).unwrap();
+        assert!(manifest.contains(" nested/c.rs\n"));
+
+        // without prune, stale files are kept and still recorded
+        let tree = FileTree::from_iter([OutputFile::new("a.rs", "a")]);
+        writer.write(&tree).unwrap();
+        assert!(output.join("nested/c.rs").exists());
+
+        std::fs::write(output.join("b.rs"), "edited").unwrap();
+        writer.clone().prune(true).write(&tree).unwrap();
+        assert!(output.join("a.rs").exists());
+        assert!(!output.join("nested").exists());
+        assert_eq!(
+            std::fs::read_to_string(output.join("b.rs")).unwrap(),
+            "edited"
+        );
+        assert!(output.join("hand_written.rs").exists());
+
+        // other codegen in the same folder has its own manifest
+        Writer::new(&output)
+            .log(false)
+            .manifest("py_dataclass")
+            .prune(true)
+            .write(&FileTree::new())
+            .unwrap();
+        assert!(output.join("a.rs").exists());
+
+        // file outside output folder is written, but never recorded or pruned
+        let lock = output.with_extension("lock");
+        writer
+            .write(&FileTree::from_iter([OutputFile::new(&lock, "lock")]))
+            .unwrap();
+        let manifest = std::fs::read_to_string(writer.manifest_path().unwrap()).unwrap();
+        assert!(!manifest.contains("lock"));
+        writer.clone().prune(true).write(&FileTree::new()).unwrap();
+        assert_eq!(std::fs::read_to_string(&lock).unwrap(), "lock");
+
+        std::fs::remove_file(&lock).unwrap();
+        std::fs::remove_dir_all(&output).unwrap();
+    }
+}
@@ -0,0 +1,811 @@
+use crate::codegen::utils::{indent, multiline_prefix_with, to_pascal_case, to_snake_case};
+use crate::{
+    ConstType, Definition, FieldDef, ModelDef, ModelType, StringOrInteger, StructDef, Type,
+    TypeReference, VariantDef,
+};
+use convert_case::Casing;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use super::context::Context;
+use super::output::{FileTree, OutputFile};
+
+pub struct Protobuf {
+    context: Context,
+    config: ProtobufConfig,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+pub struct ProtobufConfig {
+    /// package prefix, spec `a/b.yaml` is in package `{package}.a.b`, default `tot_spec`
+    package: Option<String>,
+    /// file records field numbers, relative to spec folder, default `protobuf.lock`
+    lockfile: Option<String>,
+}
+
+/// Field numbers assigned to members of each message, members removed from spec are kept,
+/// so their numbers are never reused
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct Lock {
+    /// (spec path, message) to number of each member
+    messages: BTreeMap<(String, String), BTreeMap<String, u32>>,
+}
+
+impl Lock {
+    const HEADER: &'static str =
+        "# generated by tot_spec, field numbers of protobuf messages, commit it along with specs";
+
+    /// empty if missing, malformed lines are ignored
+    fn load(path: &Path) -> Self {
+        let content = std::fs::read_to_string(path).unwrap_or_default();
+        let mut lock = Self::default();
+        for line in content.lines().filter(|line| !line.starts_with('#')) {
+            let parts = line.split_whitespace().collect::<Vec<_>>();
+            let [spec, message, member, number] = parts[..] else {
+                continue;
+            };
+            let Ok(number) = number.parse::<u32>() else {
+                continue;
+            };
+            lock.messages
+                .entry((spec.to_string(), message.to_string()))
+                .or_default()
+                .insert(member.to_string(), number);
+        }
+        lock
+    }
+
+    /// number for each member in order, explicit number or the locked one, others get numbers
+    /// above all numbers ever used by the message. explicit number can not change a locked one
+    fn assign(
+        &mut self,
+        spec: &str,
+        message: &str,
+        members: &[(String, Option<u32>)],
+    ) -> anyhow::Result<Assigned> {
+        let locked = self
+            .messages
+            .entry((spec.to_string(), message.to_string()))
+            .or_default();
+
+        let mut owners = BTreeMap::<u32, &str>::new();
+        let mut numbers = vec![None; members.len()];
+        // explicit numbers first, so locked numbers can not take them
+        for (idx, (name, explicit)) in members.iter().enumerate() {
+            let Some(number) = explicit.or_else(|| locked.get(name).copied()) else {
+                continue;
+            };
+            if let Some(old) = locked.get(name).filter(|old| **old != number) {
+                anyhow::bail!("{spec}: {message}.{name} changes field number {old} to {number}");
+            }
+            if number == 0 || (19000..20000).contains(&number) {
+                anyhow::bail!("{spec}: {message}.{name} uses invalid field number {number}");
+            }
+            let owner = locked
+                .iter()
+                .find(|(member, n)| **n == number && *member != name)
+                .map(|(member, _)| member.as_str())
+                .or_else(|| owners.get(&number).copied());
+            if let Some(owner) = owner {
+                anyhow::bail!(
+                    "{spec}: {message}.{name} reuses field number {number} of {message}.{owner}"
+                );
+            }
+            owners.insert(number, name);
+            numbers[idx] = Some(number);
+        }
+
+        let mut next = locked
+            .values()
+            .chain(owners.keys())
+            .max()
+            .copied()
+            .unwrap_or_default();
+        let numbers = numbers
+            .into_iter()
+            .map(|number| {
+                number.unwrap_or_else(|| {
+                    next += 1;
+                    // reserved by protobuf implementation
+                    if (19000..20000).contains(&next) {
+                        next = 20000;
+                    }
+                    next
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let removed = locked
+            .iter()
+            .filter(|(member, _)| !members.iter().any(|(name, _)| name == *member))
+            .map(|(member, number)| (member.clone(), *number))
+            .collect::<Vec<_>>();
+        for ((name, _), number) in members.iter().zip(numbers.iter()) {
+            locked.insert(name.clone(), *number);
+        }
+        Ok(Assigned { numbers, removed })
+    }
+}
+
+#[derive(Debug)]
+struct Assigned {
+    /// number of each member
+    numbers: Vec<u32>,
+    /// members in lock but no longer in spec, their numbers are reserved
+    removed: Vec<(String, u32)>,
+}
+
+impl std::fmt::Display for Lock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", Self::HEADER)?;
+        for ((spec, message), members) in self.messages.iter() {
+            let mut members = members.iter().collect::<Vec<_>>();
+            members.sort_by_key(|(_, number)| **number);
+            for (member, number) in members {
+                writeln!(f, "{spec} {message} {member} {number}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// what a rendered spec needs
+struct Scope<'a> {
+    spec_path: &'a Path,
+    /// spec path in lock
+    spec: String,
+    imports: BTreeSet<String>,
+}
+
+impl super::Codegen for Protobuf {
+    fn load_from_context(context: &Context) -> anyhow::Result<Self> {
+        let config = context.load_codegen_config::<ProtobufConfig>("protobuf")?;
+        Ok(Self {
+            context: context.clone(),
+            config: config.unwrap_or_default(),
+        })
+    }
+
+    fn generate(&self) -> anyhow::Result<FileTree> {
+        let lock_path = self.lock_path();
+        let mut lock = Lock::load(&lock_path);
+
+        let mut tree = FileTree::new();
+        for (spec_path, _) in self.context.iter_specs() {
+            let mut output = spec_path.clone();
+            output.set_extension("proto");
+            tree.add(OutputFile::new(output, self.render(spec_path, &mut lock)?));
+        }
+
+        // lock is kept next to specs, so it is written by absolute path
+        tree.add(OutputFile::new(lock_path, lock.to_string()));
+        Ok(tree)
+    }
+}
+
+impl Protobuf {
+    fn lock_path(&self) -> PathBuf {
+        let lockfile = self.config.lockfile.as_deref().unwrap_or("protobuf.lock");
+        self.context.root_folder().join(lockfile)
+    }
+
+    /// root package, followed by spec's folders and file stem
+    fn package(&self, spec_path: &Path) -> String {
+        let mut package = self
+            .config
+            .package
+            .clone()
+            .unwrap_or_else(|| "tot_spec".to_string());
+        for component in spec_path.with_extension("").iter() {
+            write!(
+                package,
+                ".{}",
+                component.to_string_lossy().replace('-', "_")
+            )
+            .unwrap();
+        }
+        package
+    }
+
+    fn render(&self, spec_path: &Path, lock: &mut Lock) -> anyhow::Result<String> {
+        let def = self.context.get_definition(spec_path)?;
+        let mut scope = Scope {
+            spec_path,
+            spec: spec_path.to_string_lossy().replace('\\', "/"),
+            imports: BTreeSet::new(),
+        };
+
+        let mut body = String::new();
+        for model in def.models.iter() {
+            if !model.type_params.is_empty() {
+                anyhow::bail!("protobuf does not support type params, {}", model.name);
+            }
+
+            let code = match &model.type_ {
+                ModelType::Struct(struct_def) => {
+                    let fields = self.struct_fields(model, struct_def, spec_path)?;
+                    self.render_message(&model.name, &model.name, &fields, &mut scope, lock)?
+                }
+                // fields of virtual model are in structs extend it
+                ModelType::Virtual(_) => continue,
+                ModelType::NewType { inner_type } => {
+                    let ty = self.proto_type(inner_type, &mut scope)?;
+                    format!("message {} {{\n    {ty} value = 1;\n}}\n", model.name)
+                }
+                ModelType::Const { value_type, values } => {
+                    // string const is string in protobuf
+                    if matches!(value_type, ConstType::String) {
+                        continue;
+                    }
+                    let mut values = values
+                        .iter()
+                        .map(|value| {
+                            let StringOrInteger::Integer(number) = value.value else {
+                                anyhow::bail!("{}.{} should be integer", model.name, value.name);
+                            };
+                            let number = i32::try_from(number).map_err(|_| {
+                                anyhow::anyhow!("{}.{} exceeds int32", model.name, value.name)
+                            })?;
+                            Ok((value.name.as_str(), number, value.desc.as_deref()))
+                        })
+                        .collect::<anyhow::Result<Vec<_>>>()?;
+                    // first value of proto3 enum is 0, it is the default
+                    if !values.iter().any(|(_, number, _)| *number == 0) {
+                        values.insert(0, ("Unspecified", 0, None));
+                    }
+                    render_enum(&model.name, &values, &[])
+                }
+                ModelType::Enum {
+                    variants, tag_name, ..
+                } => self.render_oneof(
+                    model,
+                    variants,
+                    tag_name.as_deref().unwrap_or("type"),
+                    &mut scope,
+                    lock,
+                )?,
+            };
+
+            writeln!(body)?;
+            if let Some(desc) = &model.desc {
+                writeln!(body, "{}", multiline_prefix_with(desc, "// "))?;
+            }
+            write!(body, "{code}")?;
+        }
+
+        if !def.methods.is_empty() {
+            let file_stem = spec_path.file_stem().unwrap_or_default().to_string_lossy();
+            let mut rpcs = vec![];
+            for method in def.methods.iter() {
+                let mut rpc = String::new();
+                if let Some(desc) = &method.desc {
+                    writeln!(rpc, "{}", multiline_prefix_with(desc, "// "))?;
+                }
+                write!(
+                    rpc,
+                    "rpc {}({}) returns ({});",
+                    to_pascal_case(&method.name),
+                    self.message_name(&method.request, &mut scope)?,
+                    self.message_name(&method.response, &mut scope)?
+                )?;
+                rpcs.push(rpc);
+            }
+            writeln!(body)?;
+            write!(
+                body,
+                "{}",
+                render_block(
+                    &format!("service {}Service", to_pascal_case(&file_stem)),
+                    &rpcs
+                )
+            )?;
+        }
+
+        let mut result = String::new();
+        writeln!(result, "syntax = \"proto3\";")?;
+        writeln!(result)?;
+        writeln!(result, "package {};", self.package(spec_path))?;
+        if !scope.imports.is_empty() {
+            writeln!(result)?;
+        }
+        for import in scope.imports.iter() {
+            writeln!(result, "import \"{import}\";")?;
+        }
+        result.push_str(&body);
+        Ok(result)
+    }
+
+    /// fields of struct, fields of virtual model it extends go first
+    fn struct_fields<'a>(
+        &'a self,
+        model: &ModelDef,
+        struct_def: &'a StructDef,
+        spec_path: &Path,
+    ) -> anyhow::Result<Vec<(&'a FieldDef, &'a Definition)>> {
+        let def = self.context.get_definition(spec_path)?;
+        let mut fields = vec![];
+        if let Some(extend) = &struct_def.extend {
+            let type_ref = TypeReference::try_parse(extend)
+                .ok_or_else(|| anyhow::anyhow!("invalid extend {extend}"))?;
+            let base_def = match &type_ref.namespace {
+                Some(namespace) => self.context.load_include_def(namespace, spec_path)?,
+                None => def,
+            };
+            let base = self
+                .context
+                .get_model_def_for_reference(&type_ref, spec_path)?;
+            let ModelType::Virtual(base_struct) = &base.type_ else {
+                anyhow::bail!("{} extends {extend} which is not virtual", model.name);
+            };
+            fields.extend(base_struct.fields.iter().map(|f| (f, base_def)));
+        }
+        fields.extend(struct_def.fields.iter().map(|f| (f, def)));
+        Ok(fields)
+    }
+
+    /// message for fields, numbers are from `proto_field_number` attribute or lock
+    fn render_message(
+        &self,
+        name: &str,
+        lock_name: &str,
+        fields: &[(&FieldDef, &Definition)],
+        scope: &mut Scope,
+        lock: &mut Lock,
+    ) -> anyhow::Result<String> {
+        let mut members = vec![];
+        for (field, _) in fields.iter() {
+            let explicit = match field.attribute("proto_field_number") {
+                Some(number) => Some(number.parse::<u32>().map_err(|_| {
+                    anyhow::anyhow!("{name}.{} has invalid proto_field_number", field.name)
+                })?),
+                None => None,
+            };
+            members.push((field.name.clone(), explicit));
+        }
+        let Assigned { numbers, removed } = lock.assign(&scope.spec, lock_name, &members)?;
+
+        let mut lines = vec![];
+        for ((field, def), number) in fields.iter().zip(numbers) {
+            let mut line = String::new();
+            if let Some(desc) = &field.desc {
+                writeln!(line, "{}", multiline_prefix_with(desc, "// "))?;
+            }
+            let ty = self.proto_type(&field.type_, scope)?;
+            // optional tracks presence, repeated and map have no presence
+            let label = match field.required || is_container(&field.type_) {
+                true => "",
+                false => "optional ",
+            };
+            write!(line, "{label}{ty} {} = {number}", field.name)?;
+            let wire_name = def.field_wire_name(field);
+            if wire_name != field.name {
+                write!(
+                    line,
+                    " [json_name = {}]",
+                    serde_json::to_string(&wire_name)?
+                )?;
+            }
+            line.push(';');
+            lines.push(line);
+        }
+        lines.extend(render_reserved(&removed));
+        Ok(render_block(&format!("message {name}"), &lines))
+    }
+
+    /// enum without payload is proto enum, others are message with oneof named by tag
+    fn render_oneof(
+        &self,
+        model: &ModelDef,
+        variants: &[VariantDef],
+        tag_name: &str,
+        scope: &mut Scope,
+        lock: &mut Lock,
+    ) -> anyhow::Result<String> {
+        let members = variants
+            .iter()
+            .map(|v| (to_snake_case(&v.name), None))
+            .collect::<Vec<_>>();
+        let Assigned { numbers, removed } = lock.assign(&scope.spec, &model.name, &members)?;
+
+        let has_payload = |v: &VariantDef| v.payload_type.is_some() || v.payload_fields.is_some();
+        if !variants.iter().any(has_payload) {
+            let mut values = vec![("Unspecified", 0, None)];
+            for (variant, number) in variants.iter().zip(numbers) {
+                values.push((
+                    variant.name.as_str(),
+                    number as i32,
+                    variant.desc.as_deref(),
+                ));
+            }
+            return Ok(render_enum(&model.name, &values, &removed));
+        }
+
+        let def = self.context.get_definition(scope.spec_path)?;
+        let mut nested = vec![];
+        let mut oneof = vec![];
+        for ((variant, number), (member, _)) in variants.iter().zip(numbers).zip(members.iter()) {
+            let payload_name = format!("{}Payload", variant.name);
+            let ty = match (&variant.payload_type, &variant.payload_fields) {
+                // oneof can not hold repeated or map, it is wrapped
+                (Some(payload_type), _) if is_container(payload_type) => {
+                    let ty = self.proto_type(payload_type, scope)?;
+                    nested.push(format!(
+                        "message {payload_name} {{\n    {ty} value = 1;\n}}"
+                    ));
+                    payload_name
+                }
+                (Some(payload_type), _) => self.proto_type(payload_type, scope)?,
+                (None, Some(fields)) => {
+                    let fields = fields.iter().map(|f| (f, def)).collect::<Vec<_>>();
+                    let lock_name = format!("{}.{payload_name}", model.name);
+                    nested.push(self.render_message(
+                        &payload_name,
+                        &lock_name,
+                        &fields,
+                        scope,
+                        lock,
+                    )?);
+                    payload_name
+                }
+                (None, None) => {
+                    scope
+                        .imports
+                        .insert("google/protobuf/empty.proto".to_string());
+                    "google.protobuf.Empty".to_string()
+                }
+            };
+
+            let mut line = String::new();
+            if let Some(desc) = &variant.desc {
+                writeln!(line, "{}", multiline_prefix_with(desc, "// "))?;
+            }
+            write!(line, "{ty} {member} = {number};")?;
+            oneof.push(line);
+        }
+
+        let mut members = nested;
+        members.push(render_block(
+            &format!("oneof {}", to_snake_case(tag_name)),
+            &oneof,
+        ));
+        members.extend(render_reserved(&removed));
+        Ok(render_block(&format!("message {}", model.name), &members))
+    }
+
+    fn proto_type(&self, ty: &Type, scope: &mut Scope) -> anyhow::Result<String> {
+        let mut element = |ty: &Type| -> anyhow::Result<String> {
+            if is_container(ty) {
+                anyhow::bail!("protobuf does not support nested list, set or map");
+            }
+            self.proto_type(ty, scope)
+        };
+
+        Ok(match ty {
+            Type::List { item_type } | Type::Set { item_type } => {
+                format!("repeated {}", element(item_type)?)
+            }
+            Type::Map {
+                key_type,
+                value_type,
+            } => {
+                let value = element(value_type)?;
+                format!("map<{}, {value}>", self.proto_key_type(key_type, scope)?)
+            }
+            Type::Reference(type_ref) => self.message_name(type_ref, scope)?,
+            Type::Timestamp { .. } => {
+                scope
+                    .imports
+                    .insert("google/protobuf/timestamp.proto".to_string());
+                "google.protobuf.Timestamp".into()
+            }
+            Type::Duration => {
+                scope
+                    .imports
+                    .insert("google/protobuf/duration.proto".to_string());
+                "google.protobuf.Duration".into()
+            }
+            Type::Json => {
+                scope
+                    .imports
+                    .insert("google/protobuf/struct.proto".to_string());
+                "google.protobuf.Value".into()
+            }
+            Type::TypeParam { param } => {
+                anyhow::bail!("protobuf does not support type param {param}")
+            }
+            ty => scalar_type(ty).to_string(),
+        })
+    }
+
+    /// map key is string or integer, const is its value type
+    fn proto_key_type(&self, ty: &Type, scope: &Scope) -> anyhow::Result<String> {
+        Ok(match ty {
+            Type::Reference(type_ref) => {
+                let model = self
+                    .context
+                    .get_model_def_for_reference(type_ref, scope.spec_path)?;
+                match &model.type_ {
+                    ModelType::Const {
+                        value_type: ConstType::String,
+                        ..
+                    } => "string".into(),
+                    ModelType::Const { .. } => "int32".into(),
+                    _ => anyhow::bail!("protobuf does not support map key {}", model.name),
+                }
+            }
+            ty if ty.is_integer() || matches!(ty, Type::String) => scalar_type(ty).to_string(),
+            _ => anyhow::bail!("protobuf does not support map key {ty:?}"),
+        })
+    }
+
+    /// name of referenced model, qualified by package if it is in other spec
+    fn message_name(&self, type_ref: &TypeReference, scope: &mut Scope) -> anyhow::Result<String> {
+        let model = self
+            .context
+            .get_model_def_for_reference(type_ref, scope.spec_path)?;
+        match &model.type_ {
+            ModelType::Const {
+                value_type: ConstType::String,
+                ..
+            } => return Ok("string".into()),
+            ModelType::Virtual(_) => {
+                anyhow::bail!(
+                    "protobuf does not support reference to virtual model {}",
+                    model.name
+                )
+            }
+            _ => {}
+        }
+
+        let Some(namespace) = &type_ref.namespace else {
+            return Ok(type_ref.target.clone());
+        };
+        let include_path = self.context.get_include_path(namespace, scope.spec_path)?;
+        // import path is relative to output folder
+        let mut import = include_path.clone();
+        import.set_extension("proto");
+        scope
+            .imports
+            .insert(import.to_string_lossy().replace('\\', "/"));
+        Ok(format!(
+            "{}.{}",
+            self.package(&include_path),
+            type_ref.target
+        ))
+    }
+}
+
+fn scalar_type(ty: &Type) -> &'static str {
+    match ty {
+        Type::Bool => "bool",
+        Type::I8 | Type::I16 | Type::I32 => "int32",
+        Type::I64 => "int64",
+        Type::U8 | Type::U16 | Type::U32 => "uint32",
+        Type::U64 => "uint64",
+        Type::F32 => "float",
+        Type::F64 => "double",
+        // same as the json wire
+        Type::Decimal | Type::BigInt => "string",
+        Type::Bytes => "bytes",
+        Type::String => "string",
+        // iso date, protobuf has no well known date type
+        Type::Date => "string",
+        _ => unreachable!("{ty:?} is not scalar"),
+    }
+}
+
+fn is_container(ty: &Type) -> bool {
+    matches!(ty, Type::List { .. } | Type::Set { .. } | Type::Map { .. })
+}
+
+/// proto enum, values are prefixed by enum name as values share the package's scope
+fn render_enum(
+    name: &str,
+    values: &[(&str, i32, Option<&str>)],
+    removed: &[(String, u32)],
+) -> String {
+    let prefix = name.to_case(convert_case::Case::UpperSnake);
+    let value_name =
+        |value: &str| format!("{prefix}_{}", value.to_case(convert_case::Case::UpperSnake));
+    let mut lines = values
+        .iter()
+        .map(|(value, number, desc)| {
+            let mut line = String::new();
+            if let Some(desc) = desc {
+                writeln!(line, "{}", multiline_prefix_with(desc, "// ")).unwrap();
+            }
+            write!(line, "{} = {number};", value_name(value)).unwrap();
+            line
+        })
+        .collect::<Vec<_>>();
+    let removed = removed
+        .iter()
+        .map(|(value, number)| (value_name(value), *number))
+        .collect::<Vec<_>>();
+    lines.extend(render_reserved(&removed));
+    render_block(&format!("enum {name}"), &lines)
+}
+
+/// numbers and names of removed members are reserved
+fn render_reserved(removed: &[(String, u32)]) -> Vec<String> {
+    removed
+        .iter()
+        .map(|(name, number)| format!("reserved {number};\nreserved \"{name}\";"))
+        .collect()
+}
+
+fn render_block(header: &str, lines: &[String]) -> String {
+    let mut result = String::new();
+    writeln!(result, "{header} {{").unwrap();
+    for line in lines {
+        writeln!(result, "{}", indent(line.trim_end(), 1)).unwrap();
+    }
+    writeln!(result, "}}").unwrap();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codegen::Codegen;
+
+    #[test]
+    fn test_render() {
+        fn test_def(spec: &Path, code_path: &str) {
+            let spec = spec.strip_prefix("src/codegen/fixtures/specs/").unwrap();
+
+            let codegen =
+                Protobuf::load_from_folder(&PathBuf::from("src/codegen/fixtures/specs/")).unwrap();
+
+            let mut lock = Lock::load(&codegen.lock_path());
+            let rendered = codegen.render(spec, &mut lock).unwrap();
+
+            let code = std::fs::read_to_string(code_path).unwrap();
+
+            #[cfg(not(feature = "test_update_spec"))]
+            pretty_assertions::assert_eq!(code.trim(), rendered.trim());
+
+            #[cfg(feature = "test_update_spec")]
+            {
+                if code.trim() != rendered.trim() {
+                    std::fs::write(code_path, rendered).unwrap();
+                }
+            }
+        }
+
+        for (spec, expected) in &[
+            (
+                "src/codegen/fixtures/specs/simple_struct.yaml",
+                "src/codegen/fixtures/protobuf/simple_struct.proto",
+            ),
+            (
+                "src/codegen/fixtures/specs/enum.yaml",
+                "src/codegen/fixtures/protobuf/enum.proto",
+            ),
+            (
+                "src/codegen/fixtures/specs/enum_variant_fields.yaml",
+                "src/codegen/fixtures/protobuf/enum_variant_fields.proto",
+            ),
+            (
+                "src/codegen/fixtures/specs/enum_representation.yaml",
+                "src/codegen/fixtures/protobuf/enum_representation.proto",
+            ),
+            (
+                "src/codegen/fixtures/specs/extend.yaml",
+                "src/codegen/fixtures/protobuf/extend.proto",
+            ),
+            (
+                "src/codegen/fixtures/specs/new_type.yaml",
+                "src/codegen/fixtures/protobuf/new_type.proto",
+            ),
+            (
+                "src/codegen/fixtures/specs/const_i8.yaml",
+                "src/codegen/fixtures/protobuf/const_i8.proto",
+            ),
+            (
+                "src/codegen/fixtures/specs/include_test.yaml",
+                "src/codegen/fixtures/protobuf/include_test.proto",
+            ),
+            (
+                "src/codegen/fixtures/specs/time.yaml",
+                "src/codegen/fixtures/protobuf/time.proto",
+            ),
+            (
+                "src/codegen/fixtures/specs/json.yaml",
+                "src/codegen/fixtures/protobuf/json.proto",
+            ),
+            (
+                "src/codegen/fixtures/specs/wire_name.yaml",
+                "src/codegen/fixtures/protobuf/wire_name.proto",
+            ),
+            (
+                "src/codegen/fixtures/specs/map_key_set.yaml",
+                "src/codegen/fixtures/protobuf/map_key_set.proto",
+            ),
+            (
+                "src/codegen/fixtures/specs/empty_struct.yaml",
+                "src/codegen/fixtures/protobuf/empty_struct.proto",
+            ),
+            (
+                "src/codegen/fixtures/specs/examples.yaml",
+                "src/codegen/fixtures/protobuf/examples.proto",
+            ),
+        ] {
+            test_def(PathBuf::from(spec).as_path(), expected);
+        }
+    }
+
+    #[test]
+    fn test_const_exceeds_int32() {
+        let codegen =
+            Protobuf::load_from_folder(&PathBuf::from("src/codegen/fixtures/specs/")).unwrap();
+        let err = codegen
+            .render(Path::new("unsigned.yaml"), &mut Lock::default())
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Level.High exceeds int32");
+    }
+
+    #[test]
+    fn test_render_enum_reserved() {
+        let code = render_enum(
+            "Level",
+            &[("Unspecified", 0, None), ("High", 2, None)],
+            &[("low".to_string(), 1)],
+        );
+        assert_eq!(
+            code,
+            "enum Level {\n    LEVEL_UNSPECIFIED = 0;\n    LEVEL_HIGH = 2;\n    reserved 1;\n    reserved \"LEVEL_LOW\";\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_lock() {
+        let members = |names: &[(&str, Option<u32>)]| {
+            names
+                .iter()
+                .map(|(name, number)| (name.to_string(), *number))
+                .collect::<Vec<_>>()
+        };
+
+        let mut lock = Lock::default();
+        let Assigned { numbers, removed } = lock
+            .assign(
+                "a.yaml",
+                "A",
+                &members(&[("x", None), ("y", Some(5)), ("z", None)]),
+            )
+            .unwrap();
+        assert_eq!(numbers, vec![6, 5, 7]);
+        assert!(removed.is_empty());
+
+        // numbers are kept across reorder, removed ones are never reused
+        let Assigned { numbers, removed } = lock
+            .assign(
+                "a.yaml",
+                "A",
+                &members(&[("z", None), ("w", None), ("y", None)]),
+            )
+            .unwrap();
+        assert_eq!(numbers, vec![7, 8, 5]);
+        assert_eq!(removed, vec![("x".to_string(), 6)]);
+
+        let err = lock
+            .assign("a.yaml", "A", &members(&[("v", Some(6))]))
+            .unwrap_err();
+        assert_eq!(err.to_string(), "a.yaml: A.v reuses field number 6 of A.x");
+
+        // explicit number can not move a locked field
+        let err = lock
+            .assign("a.yaml", "A", &members(&[("z", Some(9))]))
+            .unwrap_err();
+        assert_eq!(err.to_string(), "a.yaml: A.z changes field number 7 to 9");
+
+        // lock survives a round trip
+        let path = std::env::temp_dir().join(format!("tot_spec_lock_{}", std::process::id()));
+        std::fs::write(&path, lock.to_string()).unwrap();
+        assert_eq!(Lock::load(&path), lock);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
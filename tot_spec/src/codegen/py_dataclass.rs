@@ -1,20 +1,27 @@
-use crate::{Definition, FieldDef, StringOrInteger, Type, TypeReference};
+use crate::{
+    Definition, EnumRepresentation, FieldDef, StringOrInteger, TimestampFormat, Type, TypeReference,
+};
 use std::fmt::Write;
-use std::path::{Path, PathBuf};
+use std::path::Path;
 
 use super::context::Context;
+use super::output::{FileTree, OutputFile};
 use super::utils::{indent, multiline_prefix_with};
 
-#[derive(Default)]
-pub struct PyDataclass {}
+pub struct PyDataclass {
+    context: Context,
+}
 
 impl super::Codegen for PyDataclass {
-    fn load_from_folder(_folder: &PathBuf) -> anyhow::Result<Self> {
-        Ok(Self::default())
+    fn load_from_context(context: &Context) -> anyhow::Result<Self> {
+        Ok(Self {
+            context: context.clone(),
+        })
     }
 
-    fn generate_for_folder(&self, folder: &PathBuf, output: &PathBuf) -> anyhow::Result<()> {
-        let context = Context::new_from_folder(folder)?;
+    fn generate(&self) -> anyhow::Result<FileTree> {
+        let context = &self.context;
+        let mut tree = FileTree::new();
 
         context.folder_tree().foreach_entry_recursively(|entry| {
             // python dataclass codegen needs to generate __init__.py for each folder
@@ -22,30 +29,18 @@ impl super::Codegen for PyDataclass {
                 return;
             }
 
-            let output_folder = output.join(entry.path());
-            std::fs::create_dir_all(&output_folder).unwrap();
-
-            let init_file = output_folder.join("__init__.py");
-            std::fs::OpenOptions::new()
-                .create(true)
-                .write(true)
-                .open(init_file)
-                .unwrap();
+            tree.add(OutputFile {
+                keep_existing: true,
+                ..OutputFile::new(entry.path().join("__init__.py"), "")
+            });
         });
 
         for (spec_path, _) in context.iter_specs() {
-            let mut output = output.join(spec_path);
+            let mut output = spec_path.clone();
             output.set_extension("py");
-
-            let parent_folder = output.parent().unwrap();
-            std::fs::create_dir_all(parent_folder)?;
-
-            let code = render(spec_path, &context)?;
-
-            std::fs::write(&output, code)?;
-            println!("write output to {:?}", output);
+            tree.add(OutputFile::new(output, render(spec_path, context)?));
         }
-        Ok(())
+        Ok(tree)
     }
 }
 
@@ -59,9 +54,12 @@ fn render(spec_path: &Path, context: &Context) -> anyhow::Result<String> {
     writeln!(result, "# import annotations to enable forward declaration")?;
     writeln!(result, "from __future__ import annotations")?;
     writeln!(result, "from dataclasses import dataclass")?;
+    writeln!(result, "import dataclasses")?;
     writeln!(result, "import abc")?;
     writeln!(result, "import typing")?;
     writeln!(result, "import decimal")?;
+    writeln!(result, "import datetime")?;
+    writeln!(result, "import re")?;
 
     writeln!(result, "")?;
 
@@ -121,6 +119,7 @@ fn render(spec_path: &Path, context: &Context) -> anyhow::Result<String> {
                 variants,
                 tag_name,
                 payload_name,
+                representation,
             } => {
                 let tag_name = tag_name.clone().unwrap_or_else(|| "type".to_string());
                 let payload_name = payload_name
@@ -145,29 +144,44 @@ fn render(spec_path: &Path, context: &Context) -> anyhow::Result<String> {
                     writeln!(code_block, "")?;
                     writeln!(code_block, "@staticmethod")?;
                     writeln!(code_block, "def from_dict(d):")?;
-                    writeln!(code_block, "    type_ = d[\"{}\"]", tag_name)?;
-
-                    for (variant_idx, variant) in variants.iter().enumerate() {
-                        let variant_name = &variant.name;
-                        let type_tag = variant_name.clone();
-                        let variant_cls_name = format!("{enum_name}_{variant_name}");
-
-                        if variant_idx == 0 {
-                            writeln!(code_block, "    if type_ == \"{}\":", type_tag)?;
-                        } else {
-                            writeln!(code_block, "    elif type_ == \"{}\":", type_tag)?;
+                    match representation {
+                        EnumRepresentation::Adjacent | EnumRepresentation::Internal => {
+                            writeln!(code_block, "    type_ = d[\"{}\"]", tag_name)?;
                         }
+                        EnumRepresentation::External => {
+                            // variant without payload is a plain string
+                            writeln!(code_block, "    if isinstance(d, str):")?;
+                            writeln!(code_block, "        type_, payload = d, None")?;
+                            writeln!(code_block, "    else:")?;
+                            writeln!(code_block, "        (type_, payload), = d.items()")?;
+                        }
+                        EnumRepresentation::Untagged => {}
+                    }
 
-                        if let Some(payload_type) = &variant.payload_type {
-                            writeln!(
-                                code_block,
-                                "        {} = d[\"{}\"]",
-                                payload_name, payload_name
-                            )?;
-
+                    if *representation == EnumRepresentation::Untagged {
+                        // try variants in order, the first match wins
+                        for variant in variants.iter() {
+                            let variant_cls_name = format!("{enum_name}_{}", variant.name);
+                            let Some(payload_type) = &variant.payload_type else {
+                                writeln!(code_block, "    if d is None:")?;
+                                writeln!(code_block, "        return {}()", variant_cls_name)?;
+                                continue;
+                            };
+
+                            writeln!(code_block, "    try:")?;
+                            if let Some(instance_type) = py_instance_type(payload_type) {
+                                writeln!(
+                                    code_block,
+                                    "        if not isinstance(d, {instance_type}):"
+                                )?;
+                                writeln!(
+                                    code_block,
+                                    "            raise TypeError(\"expect {instance_type}\")"
+                                )?;
+                            }
                             let payload_from_dict = from_dict_for_one_field(
                                 payload_type,
-                                &payload_name,
+                                "d",
                                 "payload_tmp",
                                 def,
                                 context,
@@ -178,16 +192,64 @@ fn render(spec_path: &Path, context: &Context) -> anyhow::Result<String> {
                                 "        return {}({}=payload_tmp)",
                                 variant_cls_name, payload_name
                             )?;
-                        } else {
-                            writeln!(code_block, "        return {}()", variant_cls_name)?;
+                            writeln!(code_block, "    except (KeyError, TypeError, ValueError):")?;
+                            writeln!(code_block, "        pass")?;
+                        }
+                        writeln!(
+                            code_block,
+                            "    raise ValueError(f\"no variant matches: {{d}}\")"
+                        )?;
+                    } else {
+                        for (variant_idx, variant) in variants.iter().enumerate() {
+                            let variant_name = &variant.name;
+                            let type_tag = def.variant_wire_name(variant);
+                            let variant_cls_name = format!("{enum_name}_{variant_name}");
+
+                            if variant_idx == 0 {
+                                writeln!(code_block, "    if type_ == \"{}\":", type_tag)?;
+                            } else {
+                                writeln!(code_block, "    elif type_ == \"{}\":", type_tag)?;
+                            }
+
+                            if let Some(payload_type) = &variant.payload_type {
+                                let payload_var = match representation {
+                                    EnumRepresentation::Adjacent => {
+                                        writeln!(
+                                            code_block,
+                                            "        {} = d[\"{}\"]",
+                                            payload_name, payload_name
+                                        )?;
+                                        payload_name.as_str()
+                                    }
+                                    // the payload's fields are along with the tag
+                                    EnumRepresentation::Internal => "d",
+                                    _ => "payload",
+                                };
+
+                                let payload_from_dict = from_dict_for_one_field(
+                                    payload_type,
+                                    payload_var,
+                                    "payload_tmp",
+                                    def,
+                                    context,
+                                )?;
+                                writeln!(code_block, "{}", indent(&payload_from_dict, 2))?;
+                                writeln!(
+                                    code_block,
+                                    "        return {}({}=payload_tmp)",
+                                    variant_cls_name, payload_name
+                                )?;
+                            } else {
+                                writeln!(code_block, "        return {}()", variant_cls_name)?;
+                            }
                         }
-                    }
 
-                    writeln!(code_block, "    else:")?;
-                    writeln!(
-                        code_block,
-                        "        raise ValueError(f\"invalid type: {{type_}}\")"
-                    )?;
+                        writeln!(code_block, "    else:")?;
+                        writeln!(
+                            code_block,
+                            "        raise ValueError(f\"invalid type: {{type_}}\")"
+                        )?;
+                    }
 
                     code_block
                 };
@@ -220,7 +282,13 @@ fn render(spec_path: &Path, context: &Context) -> anyhow::Result<String> {
                     {
                         writeln!(variant_code, "")?;
                         writeln!(variant_code, "    def to_dict(self):")?;
-                        writeln!(variant_code, "        type_ = \"{}\"", variant_name)?;
+                        if *representation != EnumRepresentation::Untagged {
+                            writeln!(
+                                variant_code,
+                                "        type_ = \"{}\"",
+                                def.variant_wire_name(variant)
+                            )?;
+                        }
 
                         if let Some(payload_type) = &variant.payload_type {
                             let payload_to_dict = to_dict_for_one_field(
@@ -231,18 +299,47 @@ fn render(spec_path: &Path, context: &Context) -> anyhow::Result<String> {
                                 context,
                             )?;
                             writeln!(variant_code, "{}", indent(&payload_to_dict, 2))?;
-                            writeln!(variant_code, "        return {{")?;
-                            writeln!(variant_code, "            \"{}\": type_,", tag_name)?;
-                            writeln!(
-                                variant_code,
-                                "            \"{}\": payload_tmp,",
-                                payload_name
-                            )?;
-                            writeln!(variant_code, "        }}")?;
+                            match representation {
+                                EnumRepresentation::Adjacent => {
+                                    writeln!(variant_code, "        return {{")?;
+                                    writeln!(variant_code, "            \"{}\": type_,", tag_name)?;
+                                    writeln!(
+                                        variant_code,
+                                        "            \"{}\": payload_tmp,",
+                                        payload_name
+                                    )?;
+                                    writeln!(variant_code, "        }}")?;
+                                }
+                                EnumRepresentation::Internal => {
+                                    writeln!(variant_code, "        return {{")?;
+                                    writeln!(variant_code, "            \"{}\": type_,", tag_name)?;
+                                    writeln!(variant_code, "            **payload_tmp,")?;
+                                    writeln!(variant_code, "        }}")?;
+                                }
+                                EnumRepresentation::External => {
+                                    writeln!(
+                                        variant_code,
+                                        "        return {{type_: payload_tmp}}"
+                                    )?;
+                                }
+                                EnumRepresentation::Untagged => {
+                                    writeln!(variant_code, "        return payload_tmp")?;
+                                }
+                            }
                         } else {
-                            writeln!(variant_code, "        return {{")?;
-                            writeln!(variant_code, "            \"{}\": type_,", tag_name)?;
-                            writeln!(variant_code, "        }}")?;
+                            match representation {
+                                EnumRepresentation::Adjacent | EnumRepresentation::Internal => {
+                                    writeln!(variant_code, "        return {{")?;
+                                    writeln!(variant_code, "            \"{}\": type_,", tag_name)?;
+                                    writeln!(variant_code, "        }}")?;
+                                }
+                                EnumRepresentation::External => {
+                                    writeln!(variant_code, "        return type_")?;
+                                }
+                                EnumRepresentation::Untagged => {
+                                    writeln!(variant_code, "        return None")?;
+                                }
+                            }
                         }
                     }
 
@@ -285,6 +382,14 @@ fn render(spec_path: &Path, context: &Context) -> anyhow::Result<String> {
 
                         if field.required {
                             writeln!(result, "    {}: {}", field.name, py_type_for_field(&field))?;
+                        } else if let Some(default) = &field.default {
+                            writeln!(
+                                result,
+                                "    {}: {} = {}",
+                                field.name,
+                                py_type_for_field(field),
+                                py_field_default(default)
+                            )?;
                         } else {
                             // for optional field, use None as default value
                             writeln!(
@@ -304,6 +409,11 @@ fn render(spec_path: &Path, context: &Context) -> anyhow::Result<String> {
                 writeln!(result, "")?;
                 let from_dict = generate_from_dict(&model.name, &fields, &def, context)?;
                 writeln!(result, "{}", indent(&from_dict, 1))?;
+
+                if let Some(validate) = generate_validate(&fields)? {
+                    writeln!(result)?;
+                    writeln!(result, "{}", indent(&validate, 1))?;
+                }
             }
 
             crate::ModelType::Virtual(..) => {
@@ -359,7 +469,11 @@ fn render(spec_path: &Path, context: &Context) -> anyhow::Result<String> {
                     crate::ConstType::I8
                     | crate::ConstType::I16
                     | crate::ConstType::I32
-                    | crate::ConstType::I64 => "int",
+                    | crate::ConstType::I64
+                    | crate::ConstType::U8
+                    | crate::ConstType::U16
+                    | crate::ConstType::U32
+                    | crate::ConstType::U64 => "int",
                     crate::ConstType::String => "str",
                 };
 
@@ -386,25 +500,90 @@ fn render(spec_path: &Path, context: &Context) -> anyhow::Result<String> {
 fn py_type_for_field(field: &FieldDef) -> String {
     let field_type = py_type(&field.type_);
 
-    if field.required {
+    if field.required || field.default.is_some() {
         field_type
     } else {
         format!("typing.Optional[{}]", field_type)
     }
 }
 
+/// python literal for json value
+fn py_literal(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => "None".to_string(),
+        serde_json::Value::Bool(true) => "True".to_string(),
+        serde_json::Value::Bool(false) => "False".to_string(),
+        serde_json::Value::Number(n) => n.to_string(),
+        serde_json::Value::String(_) => value.to_string(),
+        serde_json::Value::Array(items) => format!(
+            "[{}]",
+            items.iter().map(py_literal).collect::<Vec<_>>().join(", ")
+        ),
+        serde_json::Value::Object(items) => format!(
+            "{{{}}}",
+            items
+                .iter()
+                .map(|(k, v)| format!("{}: {}", serde_json::Value::from(k.as_str()), py_literal(v)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+/// default for dataclass field, list and dict are mutable so need default_factory
+fn py_field_default(value: &serde_json::Value) -> String {
+    if value.is_array() || value.is_object() {
+        format!(
+            "dataclasses.field(default_factory=lambda: {})",
+            py_literal(value)
+        )
+    } else {
+        py_literal(value)
+    }
+}
+
+/// type for dict key and set item, reference is only allowed for string const
+fn py_key_type(ty: &Type) -> String {
+    match ty {
+        Type::Reference(_) => "str".into(),
+        ty => py_type(ty),
+    }
+}
+
 fn py_type(ty: &Type) -> String {
     match ty {
         Type::Bool => "bool".into(),
-        Type::I8 | Type::I16 | Type::I32 | Type::I64 | Type::BigInt => "int".into(),
-        Type::F64 => "float".into(),
+        Type::I8
+        | Type::I16
+        | Type::I32
+        | Type::I64
+        | Type::U8
+        | Type::U16
+        | Type::U32
+        | Type::U64
+        | Type::BigInt => "int".into(),
+        Type::F32 | Type::F64 => "float".into(),
         Type::Bytes => "bytes".into(),
         Type::String => "str".into(),
+        Type::Timestamp { .. } => "datetime.datetime".into(),
+        Type::Date => "datetime.date".into(),
+        Type::Duration => "datetime.timedelta".into(),
         Type::List { item_type } => {
             format!("typing.List[{}]", py_type(item_type))
         }
-        Type::Map { value_type } => format!("typing.Dict[str, {}]", py_type(value_type)),
-        Type::Reference(TypeReference { namespace, target }) => match namespace {
+        Type::Set { item_type } => format!("typing.Set[{}]", py_key_type(item_type)),
+        Type::Map {
+            key_type,
+            value_type,
+        } => format!(
+            "typing.Dict[{}, {}]",
+            py_key_type(key_type),
+            py_type(value_type)
+        ),
+        // type args are not kept in python
+        Type::Reference(TypeReference {
+            namespace, target, ..
+        }) => match namespace {
             None => {
                 format!("{}", target)
             }
@@ -412,7 +591,8 @@ fn py_type(ty: &Type) -> String {
                 format!("{}.{}", namespace, target)
             }
         },
-        Type::Json => {
+        // python codegen does not generate generics, type param is Any
+        Type::Json | Type::TypeParam { .. } => {
             // now we just mark json as Any
             "typing.Any".to_string()
         }
@@ -431,6 +611,7 @@ fn generate_to_dict(
 
     for field in fields {
         writeln!(result, "\n    # {}", field.name)?;
+        let wire_name = def.field_wire_name(field);
 
         match &*field.type_ {
             Type::Bytes
@@ -442,7 +623,7 @@ fn generate_to_dict(
             | Type::Json => {
                 writeln!(
                     result,
-                    "    result[\"{field_name}\"] = self.{field_name}",
+                    "    result[\"{wire_name}\"] = self.{field_name}",
                     field_name = field.name,
                 )?;
             }
@@ -461,14 +642,14 @@ fn generate_to_dict(
 
                 if field.required {
                     writeln!(result, "{}", indent(&to_dict, 1))?;
-                    writeln!(result, "    result[\"{field_name}\"] = {tmp_var_name}")?;
+                    writeln!(result, "    result[\"{wire_name}\"] = {tmp_var_name}")?;
                 } else {
                     writeln!(result, "    if self.{field_name} is None:")?;
-                    writeln!(result, "        result[\"{field_name}\"] = None")?;
+                    writeln!(result, "        result[\"{wire_name}\"] = None")?;
                     writeln!(result, "    else:")?;
 
                     writeln!(result, "{}", indent(&to_dict, 2))?;
-                    writeln!(result, "        result[\"{field_name}\"] = {tmp_var_name}")?;
+                    writeln!(result, "        result[\"{wire_name}\"] = {tmp_var_name}")?;
                 }
             }
         }
@@ -479,6 +660,72 @@ fn generate_to_dict(
     Ok(result)
 }
 
+/// generate `validate` for fields with constraints, None if no field has constraints
+fn generate_validate(fields: &[FieldDef]) -> anyhow::Result<Option<String>> {
+    let fields = fields
+        .iter()
+        .filter(|f| !f.constraints.is_empty())
+        .collect::<Vec<_>>();
+    if fields.is_empty() {
+        return Ok(None);
+    }
+
+    let mut result = "".to_string();
+    writeln!(result, "def validate(self):")?;
+
+    for field in fields {
+        let field_name = &field.name;
+        let constraints = &field.constraints;
+
+        let mut checks = vec![];
+        if let Some(min) = &constraints.min {
+            checks.push((format!("value < {min}"), format!("expect value >= {min}")));
+        }
+        if let Some(max) = &constraints.max {
+            checks.push((format!("value > {max}"), format!("expect value <= {max}")));
+        }
+        if constraints.non_empty {
+            checks.push(("len(value) == 0".into(), "expect non empty value".into()));
+        }
+        if let Some(min_length) = constraints.min_length {
+            checks.push((
+                format!("len(value) < {min_length}"),
+                format!("expect length >= {min_length}"),
+            ));
+        }
+        if let Some(max_length) = constraints.max_length {
+            checks.push((
+                format!("len(value) > {max_length}"),
+                format!("expect length <= {max_length}"),
+            ));
+        }
+        if let Some(pattern) = &constraints.pattern {
+            let pattern_literal = serde_json::to_string(pattern)?;
+            checks.push((
+                format!("re.search({pattern_literal}, value) is None"),
+                format!("expect value to match {pattern_literal}"),
+            ));
+        }
+
+        writeln!(result, "\n    # {field_name}")?;
+        writeln!(result, "    value = self.{field_name}")?;
+        let indent_level = if field.required || field.default.is_some() {
+            1
+        } else {
+            writeln!(result, "    if value is not None:")?;
+            2
+        };
+
+        for (condition, message) in checks {
+            let message = serde_json::to_string(&format!("field:{field_name} {message}"))?;
+            let check = format!("if {condition}:\n    raise ValueError({message})");
+            writeln!(result, "{}", indent(&check, indent_level))?;
+        }
+    }
+
+    Ok(Some(result))
+}
+
 fn to_dict_for_one_field(
     ty: &Type,
     in_expr: &str,
@@ -487,12 +734,37 @@ fn to_dict_for_one_field(
     context: &Context,
 ) -> anyhow::Result<String> {
     Ok(match ty {
-        Type::Bool | Type::I8 | Type::I16 | Type::I32 | Type::I64 | Type::F64 | Type::String => {
+        Type::Bool
+        | Type::I8
+        | Type::I16
+        | Type::I32
+        | Type::I64
+        | Type::U8
+        | Type::U16
+        | Type::U32
+        | Type::U64
+        | Type::F32
+        | Type::F64
+        | Type::String => {
             format!("{out_var} = {in_expr}")
         }
         Type::Bytes => {
             format!("{out_var} = list({in_expr})")
         }
+        Type::Timestamp {
+            format: TimestampFormat::Rfc3339,
+        }
+        | Type::Date => {
+            format!("{out_var} = {in_expr}.isoformat()")
+        }
+        Type::Timestamp {
+            format: TimestampFormat::EpochMillis,
+        } => {
+            format!("{out_var} = round({in_expr}.timestamp() * 1000)")
+        }
+        Type::Duration => {
+            format!("{out_var} = {in_expr} // datetime.timedelta(milliseconds=1)")
+        }
         Type::List { item_type } => {
             let mut result = "".to_string();
             writeln!(result, "{out_var} = []",)?;
@@ -503,7 +775,11 @@ fn to_dict_for_one_field(
             writeln!(result, "    {out_var}.append(item_tmp)")?;
             result
         }
-        Type::Map { value_type } => {
+        Type::Set { .. } => {
+            // set items are string or integer, json has no set so encode as list
+            format!("{out_var} = list({in_expr})")
+        }
+        Type::Map { value_type, .. } => {
             let mut result = "".to_string();
             writeln!(result, "{out_var} = {{}}",)?;
             writeln!(result, "for key, item in {in_expr}.items():")?;
@@ -516,6 +792,12 @@ fn to_dict_for_one_field(
         Type::Reference(TypeReference { .. }) => {
             format!("{out_var} = {in_expr}.to_dict()")
         }
+        Type::TypeParam { .. } => {
+            // value of type param can be model or plain value
+            format!(
+                "{out_var} = {in_expr}.to_dict() if hasattr({in_expr}, \"to_dict\") else {in_expr}"
+            )
+        }
         Type::Json => {
             // for json type, it can be either dict, list, int, str, float, None, but it does not contain
             // user defined struct, should be fine assign it to output dict
@@ -540,6 +822,7 @@ fn generate_from_dict(
     for field in fields {
         let field_name = &field.name;
         let field_var_name = format!("{field_name}_tmp");
+        let wire_name = def.field_wire_name(field);
 
         fields_init_codes.push(format!("{field_name} = {field_var_name},"));
 
@@ -548,20 +831,28 @@ fn generate_from_dict(
         match &*field.type_ {
             Type::Bool | Type::I8 | Type::I64 | Type::F64 | Type::String => {
                 if field.required {
-                    writeln!(code_block, "{field_var_name} = d[\"{field_name}\"]")?;
+                    writeln!(code_block, "{field_var_name} = d[\"{wire_name}\"]")?;
+                } else if let Some(default) = &field.default {
+                    // explicit null also falls back to default
+                    writeln!(code_block, "{field_var_name} = {}", py_literal(default))?;
+                    writeln!(
+                        code_block,
+                        "if (item := d.get(\"{wire_name}\")) is not None:"
+                    )?;
+                    writeln!(code_block, "    {field_var_name} = item")?;
                 } else {
                     writeln!(
                         code_block,
-                        "{field_var_name} = d.get(\"{field_name}\", None)"
+                        "{field_var_name} = d.get(\"{wire_name}\", None)"
                     )?;
                 }
             }
             ty @ Type::Bytes => {
                 if field.required {
-                    writeln!(code_block, "{field_var_name} = bytes(d[\"{field_name}\"])")?;
+                    writeln!(code_block, "{field_var_name} = bytes(d[\"{wire_name}\"])")?;
                 } else {
                     writeln!(code_block, "{field_var_name} = None")?;
-                    writeln!(code_block, "if item := d.get(\"{field_name}\"):")?;
+                    writeln!(code_block, "if item := d.get(\"{wire_name}\"):")?;
 
                     let from_dict_code_block =
                         from_dict_for_one_field(ty, "item", field_name, def, context)?;
@@ -573,16 +864,31 @@ fn generate_from_dict(
                 if field.required {
                     let from_dict_code_block = from_dict_for_one_field(
                         ty,
-                        &format!("d[\"{field_name}\"]"),
+                        &format!("d[\"{wire_name}\"]"),
                         &field_var_name,
                         def,
                         context,
                     )?;
 
                     writeln!(code_block, "{}", from_dict_code_block)?;
+                } else if let Some(default) = &field.default {
+                    writeln!(code_block, "{field_var_name} = {}", py_literal(default))?;
+                    writeln!(
+                        code_block,
+                        "if (item := d.get(\"{wire_name}\")) is not None:"
+                    )?;
+
+                    let from_dict_code_block =
+                        from_dict_for_one_field(ty, "item", &field_var_name, def, context)?;
+
+                    writeln!(code_block, "{}", indent(&from_dict_code_block, 1))?;
                 } else {
+                    // 0, empty string and empty list are valid values, only null is absent
                     writeln!(code_block, "{field_var_name} = None")?;
-                    writeln!(code_block, "if item := d.get(\"{field_name}\"):")?;
+                    writeln!(
+                        code_block,
+                        "if (item := d.get(\"{wire_name}\")) is not None:"
+                    )?;
 
                     let from_dict_code_block =
                         from_dict_for_one_field(ty, "item", &field_var_name, def, context)?;
@@ -607,6 +913,35 @@ fn generate_from_dict(
     Ok(result)
 }
 
+/// python type for isinstance check of value in json, None if not able to check
+fn py_instance_type(ty: &Type) -> Option<&'static str> {
+    match ty {
+        Type::Bool => Some("bool"),
+        Type::F32 | Type::F64 => Some("(int, float)"),
+        Type::String
+        | Type::Decimal
+        | Type::BigInt
+        | Type::Date
+        | Type::Timestamp {
+            format: TimestampFormat::Rfc3339,
+        } => Some("str"),
+        Type::List { .. } | Type::Set { .. } => Some("list"),
+        Type::Map { .. } => Some("dict"),
+        ty if ty.is_integer()
+            || matches!(
+                ty,
+                Type::Duration
+                    | Type::Timestamp {
+                        format: TimestampFormat::EpochMillis
+                    }
+            ) =>
+        {
+            Some("int")
+        }
+        _ => None,
+    }
+}
+
 fn from_dict_for_one_field(
     ty: &Type,
     in_expr: &str,
@@ -615,15 +950,44 @@ fn from_dict_for_one_field(
     context: &Context,
 ) -> anyhow::Result<String> {
     Ok(match ty {
-        Type::Bool | Type::F64 | Type::String => {
+        Type::Bool | Type::F32 | Type::F64 | Type::String => {
             format!("{out_var} = {in_expr}")
         }
-        Type::I8 | Type::I16 | Type::I32 | Type::I64 | Type::BigInt => {
+        Type::I8
+        | Type::I16
+        | Type::I32
+        | Type::I64
+        | Type::U8
+        | Type::U16
+        | Type::U32
+        | Type::U64
+        | Type::BigInt => {
             format!("{out_var} = int({in_expr})")
         }
         Type::Bytes => {
             format!("{out_var} = bytes({in_expr})")
         }
+        Type::Timestamp {
+            format: TimestampFormat::Rfc3339,
+        } => {
+            // fromisoformat only accepts "Z" suffix since python 3.11
+            format!(
+                "{out_var} = datetime.datetime.fromisoformat({in_expr}.replace(\"Z\", \"+00:00\"))"
+            )
+        }
+        Type::Timestamp {
+            format: TimestampFormat::EpochMillis,
+        } => {
+            format!(
+                "{out_var} = datetime.datetime.fromtimestamp({in_expr} / 1000, tz=datetime.timezone.utc)"
+            )
+        }
+        Type::Date => {
+            format!("{out_var} = datetime.date.fromisoformat({in_expr})")
+        }
+        Type::Duration => {
+            format!("{out_var} = datetime.timedelta(milliseconds={in_expr})")
+        }
         Type::List { item_type } => {
             let mut result = "".to_string();
             writeln!(result, "{out_var} = []")?;
@@ -634,22 +998,35 @@ fn from_dict_for_one_field(
             writeln!(result, "    {out_var}.append(item_tmp)")?;
             result
         }
-        Type::Map { value_type } => {
+        Type::Set { .. } => {
+            format!("{out_var} = set({in_expr})")
+        }
+        Type::Map {
+            key_type,
+            value_type,
+        } => {
+            // json object key is always string
+            let key_expr = if key_type.is_integer() {
+                "int(key)"
+            } else {
+                "key"
+            };
             let mut result = "".to_string();
             writeln!(result, "{out_var} = {{}}")?;
             writeln!(result, "for key, item in {in_expr}.items():")?;
             let from_dict_for_item =
                 from_dict_for_one_field(value_type, "item", "item_tmp", def, context)?;
             writeln!(result, "{}", indent(&from_dict_for_item, 1))?;
-            writeln!(result, "    {out_var}[key] = item_tmp")?;
+            writeln!(result, "    {out_var}[{key_expr}] = item_tmp")?;
             result
         }
         Type::Reference(TypeReference { .. }) => {
             let py_type = py_type(&ty);
             format!("{out_var} = {py_type}.from_dict({in_expr})")
         }
-        Type::Json => {
+        Type::Json | Type::TypeParam { .. } => {
             // for json type, it should be fine to just assign to property
+            // type param is not known in python, keep the value as is
             format!("{out_var} = {in_expr}")
         }
         Type::Decimal => {
@@ -668,6 +1045,7 @@ fn py_const_literal(val: &StringOrInteger) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::path::PathBuf;
 
     #[test]
     fn test_py_codegen() {
@@ -712,6 +1090,38 @@ mod tests {
                 "src/codegen/fixtures/specs/enum_custom_tag.yaml",
                 "src/codegen/fixtures/py_dataclass/enum_custom_tag.py",
             ),
+            (
+                "src/codegen/fixtures/specs/time.yaml",
+                "src/codegen/fixtures/py_dataclass/time.py",
+            ),
+            (
+                "src/codegen/fixtures/specs/unsigned.yaml",
+                "src/codegen/fixtures/py_dataclass/unsigned.py",
+            ),
+            (
+                "src/codegen/fixtures/specs/constraints.yaml",
+                "src/codegen/fixtures/py_dataclass/constraints.py",
+            ),
+            (
+                "src/codegen/fixtures/specs/default.yaml",
+                "src/codegen/fixtures/py_dataclass/default.py",
+            ),
+            (
+                "src/codegen/fixtures/specs/wire_name.yaml",
+                "src/codegen/fixtures/py_dataclass/wire_name.py",
+            ),
+            (
+                "src/codegen/fixtures/specs/enum_representation.yaml",
+                "src/codegen/fixtures/py_dataclass/enum_representation.py",
+            ),
+            (
+                "src/codegen/fixtures/specs/generic.yaml",
+                "src/codegen/fixtures/py_dataclass/generic.py",
+            ),
+            (
+                "src/codegen/fixtures/specs/map_key_set.yaml",
+                "src/codegen/fixtures/py_dataclass/map_key_set.py",
+            ),
         ];
 
         for (spec, expected) in specs.iter() {
@@ -732,4 +1142,29 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_decode_zero() {
+        let context =
+            Context::new_from_folder(&PathBuf::from("src/codegen/fixtures/specs")).unwrap();
+        let code = render(Path::new("unsigned.yaml"), &context).unwrap();
+        let script = format!(
+            "{code}\nm = TestUnsigned.from_dict({{\"u8_value\": 1, \"u32_value\": 0, \"f32_value\": 0.0}})\nprint(m.u32_value, m.f32_value)"
+        );
+
+        // python is optional for building tot_spec
+        let Ok(output) = std::process::Command::new("python3")
+            .args(["-c", &script])
+            .output()
+        else {
+            eprintln!("python3 not found, skip");
+            return;
+        };
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "0 0.0");
+    }
 }
@@ -1,18 +1,22 @@
 use crate::codegen::style::Case;
 use crate::{
-    codegen::utils::indent, models::Definition, ConstType, ConstValueDef, FieldDef, ModelDef,
-    StringOrInteger, StructDef, Type, TypeReference, VariantDef,
+    codegen::utils::indent, models::Definition, ConstType, ConstValueDef, EnumRepresentation,
+    FieldDef, ModelDef, Nullability, StringOrInteger, StructDef, TimestampFormat, Type,
+    TypeReference, VariantDef,
 };
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 use std::{borrow::Cow, fmt::Write, path::PathBuf};
 
 use super::context::Context;
+use super::output::{FileTree, OutputFile};
 use super::{utils::folder_tree::Entry, utils::multiline_prefix_with};
 
 pub struct RsSerde {
     context: Context,
     config: CodegenConfig,
+    /// declare modules with `include!` from `OUT_DIR` instead of `mod x;`
+    include_out_dir: bool,
 }
 
 #[derive(Default, Debug, Serialize, Deserialize)]
@@ -27,54 +31,51 @@ pub struct TypeOverwrites {
     decimal: Option<String>,
 }
 
-impl super::Codegen for RsSerde {
-    fn load_from_folder(folder: &PathBuf) -> anyhow::Result<Self> {
-        let context = Context::new_from_folder(folder)?;
+impl RsSerde {
+    /// render modules for `include!(concat!(env!("OUT_DIR"), "/mod.rs"))` in build scripts
+    pub fn include_out_dir(mut self) -> Self {
+        self.include_out_dir = true;
+        self
+    }
+}
 
+impl super::Codegen for RsSerde {
+    fn load_from_context(context: &Context) -> anyhow::Result<Self> {
         // load codegen config from spec_config.yaml file
         let config = context.load_codegen_config::<CodegenConfig>("rs_serde")?;
 
         Ok(Self {
-            context,
+            context: context.clone(),
             config: config.unwrap_or_default(),
+            include_out_dir: false,
         })
     }
 
-    fn generate_for_folder(&self, _folder: &PathBuf, output: &PathBuf) -> anyhow::Result<()> {
+    fn generate(&self) -> anyhow::Result<FileTree> {
         let context = &self.context;
+        let mut tree = FileTree::new();
 
+        let mut folder_result = Ok(());
         context.folder_tree().foreach_entry_recursively(|entry| {
-            let outputs = self.render_folder(entry).unwrap();
-            for (file_relative_path, content) in outputs {
-                let file_path = output.join(file_relative_path);
-                println!("write output to {:?}", file_path);
-                if let Some(parent) = file_path.parent() {
-                    let _ = std::fs::create_dir_all(parent);
-                }
-                std::fs::write(file_path, content).unwrap();
+            if folder_result.is_err() {
+                return;
+            }
+            match self.render_folder(entry) {
+                Ok(outputs) => outputs
+                    .into_iter()
+                    .for_each(|(path, content)| tree.add(OutputFile::new(path, content))),
+                Err(e) => folder_result = Err(e),
             }
         });
+        folder_result?;
 
         for (spec, _) in context.iter_specs() {
-            let output = {
-                let mut output = output.clone();
-                output.push(spec);
-                output.set_extension("rs");
-                output
-            };
-
-            println!("generating spec={spec:?} output={output:?}");
-
-            let parent_folder = output.parent().unwrap();
-            std::fs::create_dir_all(parent_folder)?;
-
-            let code = self.render(&spec)?;
-
-            std::fs::write(&output, code).unwrap();
-            println!("write output to {:?}", output);
+            let mut output = spec.clone();
+            output.set_extension("rs");
+            tree.add(OutputFile::new(output, self.render(spec)?));
         }
 
-        Ok(())
+        Ok(tree)
     }
 }
 
@@ -90,10 +91,23 @@ impl RsSerde {
 
         let mut code = "".to_string();
         for child in children {
+            let mod_name = child.path().file_stem().unwrap().to_str().unwrap();
+            if !self.include_out_dir {
+                writeln!(code, "pub mod {};", mod_name).unwrap();
+                continue;
+            }
+
+            // `mod x;` in an included file resolves relative to the including file,
+            // so each module is included from OUT_DIR explicitly
+            let mod_file = if child.is_empty() {
+                child.path().with_extension("rs")
+            } else {
+                child.path().join("mod.rs")
+            };
+            let mod_file = mod_file.to_str().unwrap().replace('\\', "/");
             writeln!(
                 code,
-                "pub mod {};",
-                child.path().file_stem().unwrap().to_str().unwrap()
+                "pub mod {mod_name} {{ include!(concat!(env!(\"OUT_DIR\"), \"/{mod_file}\")); }}"
             )
             .unwrap();
         }
@@ -189,17 +203,23 @@ impl RsSerde {
                     variants,
                     tag_name,
                     payload_name,
+                    representation,
                 } => {
-                    let tag_name = tag_name.clone().unwrap_or_else(|| "type".to_string());
-                    let payload_name = payload_name
-                        .clone()
-                        .unwrap_or_else(|| "payload".to_string());
+                    let tag_name = tag_name.as_deref().unwrap_or("type");
+                    let payload_name = payload_name.as_deref().unwrap_or("payload");
+                    let serde_attr = enum_serde_attr(*representation, tag_name, payload_name);
                     let code =
-                        self.render_enum(model, &derived, variants, def, &tag_name, &payload_name)?;
+                        self.render_enum(model, &derived, variants, def, serde_attr.as_deref())?;
                     writeln!(model_code, "{}", code.trim())?;
                 }
                 crate::ModelType::Struct(struct_def) => {
-                    let code = self.render_struct(&model_name, &derived, struct_def, def)?;
+                    let code = self.render_struct(
+                        &model_name,
+                        &model.type_params,
+                        &derived,
+                        struct_def,
+                        def,
+                    )?;
                     writeln!(model_code, "{}", code.trim())?;
                 }
 
@@ -267,6 +287,7 @@ impl RsSerde {
     fn render_struct(
         &self,
         model_name: &str,
+        type_params: &[String],
         derived: &[&str],
         struct_def: &StructDef,
         def: &Definition,
@@ -274,28 +295,34 @@ impl RsSerde {
         let mut result = "".to_string();
         let model_code = &mut result;
 
-        {
-            writeln!(model_code, "{}", self.render_derived(&derived))?;
-            writeln!(model_code, "pub struct {model_name} {{")?;
-
-            let mut fields = vec![];
-            if let Some(virtual_name) = &struct_def.extend {
-                match def.get_model(&virtual_name) {
-                    Some(model) => match &model.type_ {
-                        crate::ModelType::Virtual(struct_def) => {
-                            fields.extend(struct_def.fields.clone());
-                        }
-                        _ => {
-                            anyhow::bail!("model is not virtual: {}", virtual_name);
-                        }
-                    },
-                    None => anyhow::bail!("not able to find virtual model: {}", virtual_name),
-                }
+        let mut fields = vec![];
+        if let Some(virtual_name) = &struct_def.extend {
+            match def.get_model(&virtual_name) {
+                Some(model) => match &model.type_ {
+                    crate::ModelType::Virtual(struct_def) => {
+                        fields.extend(struct_def.fields.clone());
+                    }
+                    _ => {
+                        anyhow::bail!("model is not virtual: {}", virtual_name);
+                    }
+                },
+                None => anyhow::bail!("not able to find virtual model: {}", virtual_name),
             }
+        }
+
+        fields.extend(struct_def.fields.clone());
 
-            fields.extend(struct_def.fields.clone());
+        let generics = rs_generics(type_params);
+        {
+            writeln!(model_code, "{}", self.render_derived(&derived))?;
+            writeln!(model_code, "pub struct {model_name}{generics} {{")?;
 
-            let fields_def_code = self.render_fields_def(&fields)?;
+            // turbofish is required to call fns of generic type in path
+            let default_fn_owner = match type_params.is_empty() {
+                true => model_name.to_string(),
+                false => format!("{model_name}::{generics}"),
+            };
+            let fields_def_code = self.render_fields_def(&fields, def, Some(&default_fn_owner))?;
             writeln!(model_code, "{}", indent(fields_def_code, 1))?;
 
             writeln!(model_code, "}}")?;
@@ -303,7 +330,10 @@ impl RsSerde {
 
         if let Some(virtual_name) = &struct_def.extend {
             writeln!(model_code, "")?;
-            writeln!(model_code, "impl {virtual_name} for {model_name} {{")?;
+            writeln!(
+                model_code,
+                "impl{generics} {virtual_name} for {model_name}{generics} {{"
+            )?;
             match def.get_model(&virtual_name) {
                 Some(model) => match &model.type_ {
                     crate::ModelType::Virtual(struct_def) => {
@@ -338,10 +368,164 @@ impl RsSerde {
             writeln!(model_code, "}}")?;
         }
 
+        if let Some(default_code) = self.render_default_fns(model_name, type_params, &fields)? {
+            writeln!(model_code)?;
+            writeln!(model_code, "{default_code}")?;
+        }
+
+        if let Some(validate_code) = self.render_validate(model_name, type_params, &fields)? {
+            writeln!(model_code)?;
+            writeln!(model_code, "{validate_code}")?;
+        }
+
         Ok(result)
     }
 
-    fn render_fields_def(&self, fields: &[FieldDef]) -> anyhow::Result<String> {
+    /// render `default_xxx` fns referenced by `#[serde(default = "...")]`, None if no field has default
+    fn render_default_fns(
+        &self,
+        model_name: &str,
+        type_params: &[String],
+        fields: &[FieldDef],
+    ) -> anyhow::Result<Option<String>> {
+        let fields = fields
+            .iter()
+            .filter_map(|f| f.default.as_ref().map(|d| (f, d)))
+            .collect::<Vec<_>>();
+        if fields.is_empty() {
+            return Ok(None);
+        }
+
+        let generics = rs_generics(type_params);
+        let mut code = "".to_string();
+        writeln!(code, "impl{generics} {model_name}{generics} {{")?;
+        for (field, default) in fields {
+            let (field_name_rs, _) = to_identifier(&field.name);
+            let field_type = self.rs_type_for_field(field);
+            writeln!(code, "fn default_{field_name_rs}() -> {field_type} {{")?;
+            writeln!(code, "{}", rs_default_literal(default, &field.type_))?;
+            writeln!(code, "}}")?;
+        }
+        writeln!(code, "}}")?;
+
+        Ok(Some(code))
+    }
+
+    /// render `validate` method for fields with constraints, None if no field has constraints
+    fn render_validate(
+        &self,
+        model_name: &str,
+        type_params: &[String],
+        fields: &[FieldDef],
+    ) -> anyhow::Result<Option<String>> {
+        let fields = fields
+            .iter()
+            .filter(|f| !f.constraints.is_empty())
+            .collect::<Vec<_>>();
+        if fields.is_empty() {
+            return Ok(None);
+        }
+
+        let generics = rs_generics(type_params);
+        let mut code = "".to_string();
+        writeln!(code, "impl{generics} {model_name}{generics} {{")?;
+        writeln!(
+            code,
+            "/// validate fields against constraints defined in spec"
+        )?;
+        writeln!(code, "pub fn validate(&self) -> Result<(), String> {{")?;
+
+        for field in fields {
+            let field_name = &field.name;
+            let (field_name_rs, _) = to_identifier(field_name);
+            let constraints = &field.constraints;
+
+            if field.required || field.default.is_some() {
+                writeln!(code, "{{ let value = &self.{field_name_rs};")?;
+            } else if field.nullability == Some(Nullability::OptionalNullable) {
+                writeln!(code, "if let Some(value) = self.{field_name_rs}.value() {{")?;
+            } else {
+                writeln!(code, "if let Some(value) = &self.{field_name_rs} {{")?;
+            }
+
+            if let Some(min) = &constraints.min {
+                let min_rs = rs_number_literal(min, &field.type_);
+                writeln!(code, "if *value < {min_rs} {{")?;
+                writeln!(
+                    code,
+                    "return Err(\"field:{field_name} expect value >= {min}\".to_string());"
+                )?;
+                writeln!(code, "}}")?;
+            }
+            if let Some(max) = &constraints.max {
+                let max_rs = rs_number_literal(max, &field.type_);
+                writeln!(code, "if *value > {max_rs} {{")?;
+                writeln!(
+                    code,
+                    "return Err(\"field:{field_name} expect value <= {max}\".to_string());"
+                )?;
+                writeln!(code, "}}")?;
+            }
+
+            let length_expr = match &*field.type_ {
+                Type::String => "value.chars().count()",
+                _ => "value.len()",
+            };
+            if constraints.non_empty {
+                writeln!(code, "if value.is_empty() {{")?;
+                writeln!(
+                    code,
+                    "return Err(\"field:{field_name} expect non empty value\".to_string());"
+                )?;
+                writeln!(code, "}}")?;
+            }
+            if let Some(min_length) = constraints.min_length {
+                writeln!(code, "if {length_expr} < {min_length} {{")?;
+                writeln!(
+                    code,
+                    "return Err(\"field:{field_name} expect length >= {min_length}\".to_string());"
+                )?;
+                writeln!(code, "}}")?;
+            }
+            if let Some(max_length) = constraints.max_length {
+                writeln!(code, "if {length_expr} > {max_length} {{")?;
+                writeln!(
+                    code,
+                    "return Err(\"field:{field_name} expect length <= {max_length}\".to_string());"
+                )?;
+                writeln!(code, "}}")?;
+            }
+
+            if let Some(pattern) = &constraints.pattern {
+                writeln!(
+                    code,
+                    "static PATTERN: std::sync::LazyLock<regex::Regex> = std::sync::LazyLock::new(|| regex::Regex::new({pattern:?}).unwrap());"
+                )?;
+                writeln!(code, "if !PATTERN.is_match(value) {{")?;
+                writeln!(
+                    code,
+                    "return Err(format!(\"field:{field_name} expect value to match {{:?}}\", PATTERN.as_str()));"
+                )?;
+                writeln!(code, "}}")?;
+            }
+
+            writeln!(code, "}}")?;
+        }
+
+        writeln!(code, "Ok(())")?;
+        writeln!(code, "}}")?;
+        writeln!(code, "}}")?;
+
+        Ok(Some(code))
+    }
+
+    /// render fields, `default_fn_owner` is the type which holds `default_xxx` fns for field defaults
+    fn render_fields_def(
+        &self,
+        fields: &[FieldDef],
+        def: &Definition,
+        default_fn_owner: Option<&str>,
+    ) -> anyhow::Result<String> {
         let mut result = "".to_string();
         let code = &mut result;
         for field in fields.iter() {
@@ -354,11 +538,33 @@ impl RsSerde {
                 writeln!(code, "#[{attr}]")?;
             }
 
-            let field_name = &field.name;
-            let (field_name_rs, modified) = to_identifier(field_name);
+            let (field_name_rs, _) = to_identifier(&field.name);
+            let wire_name = def.field_wire_name(field);
 
-            if modified {
-                writeln!(code, "#[serde(rename = \"{field_name}\")]")?;
+            if wire_name.ne(&field_name_rs) {
+                writeln!(code, "#[serde(rename = \"{wire_name}\")]")?;
+            }
+            match field.nullability {
+                Some(Nullability::Optional) => writeln!(
+                    code,
+                    "#[serde(default, skip_serializing_if = \"std::option::Option::is_none\")]"
+                )?,
+                // deserialize_with makes serde treat missing field as error
+                Some(Nullability::Nullable) => writeln!(
+                    code,
+                    "#[serde(deserialize_with = \"std::option::Option::deserialize\")]"
+                )?,
+                Some(Nullability::OptionalNullable) => writeln!(
+                    code,
+                    "#[serde(default, skip_serializing_if = \"tot_spec_util::tri_state::TriState::is_absent\")]"
+                )?,
+                None => {}
+            }
+            if let (Some(owner), Some(_)) = (default_fn_owner, &field.default) {
+                writeln!(
+                    code,
+                    "#[serde(default = \"{owner}::default_{field_name_rs}\")]"
+                )?;
             }
             writeln!(
                 code,
@@ -376,8 +582,7 @@ impl RsSerde {
         derived: &[&str],
         variants: &[VariantDef],
         def: &Definition,
-        tag_name: &str,
-        payload_name: &str,
+        serde_attr: Option<&str>,
     ) -> anyhow::Result<String> {
         let model_name = &model.name;
 
@@ -387,11 +592,9 @@ impl RsSerde {
             Some("true") => {
                 // create separate type for each variant
                 writeln!(model_code, "{}", self.render_derived(&derived))?;
-                writeln!(
-                    model_code,
-                    "#[serde(tag = \"{}\", content = \"{}\")]",
-                    tag_name, payload_name
-                )?;
+                if let Some(serde_attr) = serde_attr {
+                    writeln!(model_code, "{serde_attr}")?;
+                }
                 writeln!(model_code, "pub enum {} {{", &model.name)?;
 
                 for variant in variants {
@@ -402,6 +605,9 @@ impl RsSerde {
                         let comment = multiline_prefix_with(desc, "/// ");
                         writeln!(model_code, "{}", indent(&comment, 1))?;
                     }
+                    if let Some(rename) = variant_rename(variant, def) {
+                        writeln!(model_code, "    {rename}")?;
+                    }
                     writeln!(model_code, "    {variant_name}({variant_type_name}),",)?;
                 }
                 writeln!(model_code, "}}")?;
@@ -422,8 +628,13 @@ impl RsSerde {
                             extend: None,
                             fields: fields.clone(),
                         };
-                        let struct_code =
-                            self.render_struct(&variant_type_name, &derived, &struct_def, def)?;
+                        let struct_code = self.render_struct(
+                            &variant_type_name,
+                            &[],
+                            &derived,
+                            &struct_def,
+                            def,
+                        )?;
                         writeln!(code, "{}", struct_code)?;
                     } else {
                         writeln!(code, "{}", self.render_derived(&derived))?;
@@ -442,11 +653,9 @@ impl RsSerde {
             _ => {
                 // create separate type for each variant
                 writeln!(model_code, "{}", self.render_derived(&derived))?;
-                writeln!(
-                    model_code,
-                    "#[serde(tag = \"{}\", content = \"{}\")]",
-                    tag_name, payload_name
-                )?;
+                if let Some(serde_attr) = serde_attr {
+                    writeln!(model_code, "{serde_attr}")?;
+                }
                 writeln!(model_code, "pub enum {} {{", &model.name)?;
 
                 for variant in variants {
@@ -454,6 +663,9 @@ impl RsSerde {
                         let comment = multiline_prefix_with(desc, "/// ");
                         writeln!(model_code, "{}", indent(&comment, 1))?;
                     }
+                    if let Some(rename) = variant_rename(variant, def) {
+                        writeln!(model_code, "    {rename}")?;
+                    }
 
                     if let Some(payload_type) = &variant.payload_type {
                         writeln!(
@@ -463,7 +675,7 @@ impl RsSerde {
                             self.rs_type(&payload_type)
                         )?;
                     } else if let Some(fields) = &variant.payload_fields {
-                        let fields_def_code = self.render_fields_def(&fields)?;
+                        let fields_def_code = self.render_fields_def(&fields, def, None)?;
 
                         writeln!(model_code, "    {} {{", variant.name,)?;
                         writeln!(model_code, "{}", indent(&fields_def_code, 2))?;
@@ -510,6 +722,10 @@ impl RsSerde {
             ConstType::I16 => "i16",
             ConstType::I32 => "i32",
             ConstType::I64 => "i64",
+            ConstType::U8 => "u8",
+            ConstType::U16 => "u16",
+            ConstType::U32 => "u32",
+            ConstType::U64 => "u64",
             // from_value able to accept &str for all lifetime
             ConstType::String => "&str",
         };
@@ -598,13 +814,25 @@ impl RsSerde {
             .attribute("rs_type")
             .map(|s| s.to_string())
             .unwrap_or(self.rs_type(&field.type_));
-        if field.required {
+        // field with default is always present after deserialize
+        if field.required || field.default.is_some() {
             ty
+        } else if field.nullability == Some(Nullability::OptionalNullable) {
+            format!("tot_spec_util::tri_state::TriState<{}>", ty)
         } else {
             format!("std::option::Option<{}>", ty)
         }
     }
 
+    /// type for map key and set item, string const holds &'static str which can not be
+    /// deserialized from owned json, so use String for it
+    fn rs_key_type(&self, ty_: &Type) -> String {
+        match ty_ {
+            Type::Reference(_) => "std::string::String".into(),
+            ty_ => self.rs_type(ty_),
+        }
+    }
+
     fn rs_type(&self, ty_: &Type) -> String {
         match ty_ {
             Type::Bool => "bool".into(),
@@ -612,26 +840,55 @@ impl RsSerde {
             Type::I16 => "i16".into(),
             Type::I32 => "i32".into(),
             Type::I64 => "i64".into(),
+            Type::U8 => "u8".into(),
+            Type::U16 => "u16".into(),
+            Type::U32 => "u32".into(),
+            Type::U64 => "u64".into(),
+            Type::F32 => "f32".into(),
             Type::F64 => "f64".into(),
             Type::Bytes => "std::vec::Vec<u8>".into(),
             Type::String => "std::string::String".into(),
+            Type::Timestamp {
+                format: TimestampFormat::Rfc3339,
+            } => "chrono::DateTime<chrono::Utc>".into(),
+            Type::Timestamp {
+                format: TimestampFormat::EpochMillis,
+            } => "tot_spec_util::time::EpochMillis".into(),
+            Type::Date => "chrono::NaiveDate".into(),
+            Type::Duration => "tot_spec_util::time::DurationMillis".into(),
             Type::List { item_type } => {
                 format!("std::vec::Vec<{}>", self.rs_type(item_type))
             }
-            Type::Map { value_type } => {
+            Type::Set { item_type } => {
+                format!("std::collections::HashSet<{}>", self.rs_key_type(item_type))
+            }
+            Type::Map {
+                key_type,
+                value_type,
+            } => {
                 format!(
-                    "std::collections::HashMap<std::string::String, {}>",
+                    "std::collections::HashMap<{}, {}>",
+                    self.rs_key_type(key_type),
                     self.rs_type(value_type)
                 )
             }
             Type::Reference(TypeReference {
-                namespace: None,
-                target,
-            }) => target.clone(),
-            Type::Reference(TypeReference {
-                namespace: Some(namespace),
+                namespace,
                 target,
-            }) => format!("{namespace}::{target}"),
+                args,
+            }) => {
+                let target = match namespace {
+                    Some(namespace) => format!("{namespace}::{target}"),
+                    None => target.clone(),
+                };
+                if args.is_empty() {
+                    target
+                } else {
+                    let args = args.iter().map(|a| self.rs_type(a)).collect::<Vec<_>>();
+                    format!("{target}<{}>", args.join(", "))
+                }
+            }
+            Type::TypeParam { param } => param.clone(),
             Type::Json => "serde_json::Value".to_string(),
             Type::Decimal => self.decimal_type(),
             Type::BigInt => self.bigint_type(),
@@ -659,6 +916,41 @@ impl RsSerde {
     }
 }
 
+/// generics of a generic type, e.g. `<T>`, empty if no type params
+fn rs_generics(type_params: &[String]) -> String {
+    if type_params.is_empty() {
+        "".to_string()
+    } else {
+        format!("<{}>", type_params.join(", "))
+    }
+}
+
+/// serde rename attribute for variant if its wire name differs from name
+fn variant_rename(variant: &VariantDef, def: &Definition) -> Option<String> {
+    let wire_name = def.variant_wire_name(variant);
+    if wire_name.ne(&variant.name) {
+        Some(format!("#[serde(rename = \"{wire_name}\")]"))
+    } else {
+        None
+    }
+}
+
+/// serde attribute for enum's representation, external is serde's default
+fn enum_serde_attr(
+    representation: EnumRepresentation,
+    tag_name: &str,
+    payload_name: &str,
+) -> Option<String> {
+    match representation {
+        EnumRepresentation::Adjacent => Some(format!(
+            "#[serde(tag = \"{tag_name}\", content = \"{payload_name}\")]"
+        )),
+        EnumRepresentation::Internal => Some(format!("#[serde(tag = \"{tag_name}\")]")),
+        EnumRepresentation::External => None,
+        EnumRepresentation::Untagged => Some("#[serde(untagged)]".to_string()),
+    }
+}
+
 fn extend_derived<'a>(derived: &[&'a str], more: &[&'a str]) -> Vec<&'a str> {
     let mut derived = derived.to_vec();
 
@@ -682,10 +974,55 @@ fn rs_type_for_const(const_type: &ConstType) -> &'static str {
         ConstType::I16 => "i16",
         ConstType::I32 => "i32",
         ConstType::I64 => "i64",
+        ConstType::U8 => "u8",
+        ConstType::U16 => "u16",
+        ConstType::U32 => "u32",
+        ConstType::U64 => "u64",
         ConstType::String => "&'static str",
     }
 }
 
+/// number literal for constraint bound, float types need a decimal point
+fn rs_number_literal(number: &serde_json::Number, ty: &Type) -> String {
+    let literal = number.to_string();
+    if matches!(ty, Type::F32 | Type::F64) && !literal.contains(['.', 'e', 'E']) {
+        format!("{literal}.0")
+    } else {
+        literal
+    }
+}
+
+/// literal for default value, the value is validated against ty in Context
+fn rs_default_literal(value: &serde_json::Value, ty: &Type) -> String {
+    match (value, ty) {
+        (serde_json::Value::Number(number), ty) => rs_number_literal(number, ty),
+        (serde_json::Value::String(s), _) => format!("{s:?}.to_string()"),
+        (serde_json::Value::Array(items), Type::List { item_type }) => format!(
+            "vec![{}]",
+            items
+                .iter()
+                .map(|item| rs_default_literal(item, item_type))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        (serde_json::Value::Object(items), Type::Map { .. }) if items.is_empty() => {
+            "Default::default()".to_string()
+        }
+        (serde_json::Value::Object(items), Type::Map { value_type, .. }) => format!(
+            "[{}].into_iter().collect()",
+            items
+                .iter()
+                .map(|(key, item)| format!(
+                    "({key:?}.to_string(), {})",
+                    rs_default_literal(item, value_type)
+                ))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        (value, _) => value.to_string(),
+    }
+}
+
 fn rs_const_literal(val: &StringOrInteger) -> String {
     match val {
         StringOrInteger::String(s) => format!("\"{s}\""),
@@ -809,8 +1146,60 @@ mod tests {
                 "src/codegen/fixtures/specs/rs_keyword.yaml",
                 "src/codegen/fixtures/rs_serde/keyword.rs",
             ),
+            (
+                "src/codegen/fixtures/specs/time.yaml",
+                "src/codegen/fixtures/rs_serde/time.rs",
+            ),
+            (
+                "src/codegen/fixtures/specs/unsigned.yaml",
+                "src/codegen/fixtures/rs_serde/unsigned.rs",
+            ),
+            (
+                "src/codegen/fixtures/specs/constraints.yaml",
+                "src/codegen/fixtures/rs_serde/constraints.rs",
+            ),
+            (
+                "src/codegen/fixtures/specs/default.yaml",
+                "src/codegen/fixtures/rs_serde/default.rs",
+            ),
+            (
+                "src/codegen/fixtures/specs/wire_name.yaml",
+                "src/codegen/fixtures/rs_serde/wire_name.rs",
+            ),
+            (
+                "src/codegen/fixtures/specs/nullability.yaml",
+                "src/codegen/fixtures/rs_serde/nullability.rs",
+            ),
+            (
+                "src/codegen/fixtures/specs/enum_representation.yaml",
+                "src/codegen/fixtures/rs_serde/enum_representation.rs",
+            ),
+            (
+                "src/codegen/fixtures/specs/generic.yaml",
+                "src/codegen/fixtures/rs_serde/generic.rs",
+            ),
+            (
+                "src/codegen/fixtures/specs/map_key_set.yaml",
+                "src/codegen/fixtures/rs_serde/map_key_set.rs",
+            ),
         ] {
             test_def(PathBuf::from(spec).as_path(), &expected);
         }
     }
+
+    #[test]
+    fn test_validate_pattern_literal() {
+        let codegen =
+            RsSerde::load_from_folder(&PathBuf::from("src/codegen/fixtures/specs/")).unwrap();
+        let field = serde_yaml::from_str::<FieldDef>(
+            "name: name\ntype: string\nconstraints:\n  pattern: '^\"#[a-z]+\\d$'",
+        )
+        .unwrap();
+        let code = codegen
+            .render_validate("Test", &[], &[field])
+            .unwrap()
+            .unwrap();
+        assert!(code.contains(r##"regex::Regex::new("^\"#[a-z]+\\d$")"##));
+        syn::parse_file(&code).unwrap();
+    }
 }
@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::path::Path;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Style {
     #[serde(default)]
     field_name_case: Case,
@@ -13,12 +13,18 @@ pub struct Style {
     ignores: Vec<String>,
 }
 
-#[derive(Default, Debug, Serialize, Deserialize)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Case {
     #[serde(rename = "snake")]
     Snake,
     #[serde(rename = "camel")]
     Camel,
+    #[serde(rename = "pascal")]
+    Pascal,
+    #[serde(rename = "upper_snake")]
+    UpperSnake,
+    #[serde(rename = "kebab")]
+    Kebab,
     #[default]
     Unspecified,
 }
@@ -29,22 +35,37 @@ impl Case {
     }
 
     pub fn convert<'a>(&self, name: &'a str) -> Cow<'a, str> {
+        self.convert_with(name, &[])
+    }
+
+    /// same as `convert`, but also splits words by underscore, e.g: camel of `user_id` is
+    /// `userId`, used for wire names
+    pub fn rename<'a>(&self, name: &'a str) -> Cow<'a, str> {
+        self.convert_with(name, &[Boundary::Underscore])
+    }
+
+    fn convert_with<'a>(&self, name: &'a str, extra: &[Boundary]) -> Cow<'a, str> {
         let convert_case = match self {
             Case::Snake => ConvertCase::Snake,
             Case::Camel => ConvertCase::Camel,
+            Case::Pascal => ConvertCase::Pascal,
+            Case::UpperSnake => ConvertCase::UpperSnake,
+            Case::Kebab => ConvertCase::Kebab,
             Case::Unspecified => return Cow::Borrowed(name),
         };
 
-        name.with_boundaries(&[
+        let mut boundaries = vec![
             Boundary::DigitUpper,
             Boundary::DigitLower,
             Boundary::Space,
             Boundary::Acronym,
             Boundary::Hyphen,
             Boundary::LowerUpper,
-        ])
-        .to_case(convert_case)
-        .into()
+        ];
+        boundaries.extend_from_slice(extra);
+        name.with_boundaries(&boundaries)
+            .to_case(convert_case)
+            .into()
     }
 }
 
@@ -61,7 +82,8 @@ impl Style {
         false
     }
 
-    pub fn validate_model(&self, model: &ModelDef) -> Vec<String> {
+    /// returns field name and violation for each violation
+    pub fn validate_model<'a>(&self, model: &'a ModelDef) -> Vec<(&'a str, String)> {
         match &model.type_ {
             ModelType::Struct(st_) => self.validate_field_name(&st_.fields),
             ModelType::Virtual(st_) => self.validate_field_name(&st_.fields),
@@ -69,15 +91,18 @@ impl Style {
         }
     }
 
-    fn validate_field_name(&self, fields: &[FieldDef]) -> Vec<String> {
+    fn validate_field_name<'a>(&self, fields: &'a [FieldDef]) -> Vec<(&'a str, String)> {
         let mut violations = vec![];
         for field in fields {
             if !self.field_name_case.is_case(&field.name) {
-                violations.push(format!(
-                    "field {} is not case {:?} should be {}",
-                    field.name,
-                    self.field_name_case,
-                    self.field_name_case.convert(&field.name)
+                violations.push((
+                    field.name.as_str(),
+                    format!(
+                        "field {} is not case {:?} should be {}",
+                        field.name,
+                        self.field_name_case,
+                        self.field_name_case.convert(&field.name)
+                    ),
                 ));
             }
         }
@@ -100,6 +125,14 @@ mod tests {
         assert_eq!(Case::Snake.convert("i8Value"), "i8_value");
         assert_eq!(Case::Snake.convert("i64Value"), "i64_value");
         assert_eq!(Case::Snake.convert("HTTPRequest"), "http_request");
+
+        // style check does not split by underscore
+        assert!(Case::Camel.is_case("user_id"));
+
+        assert_eq!(Case::Camel.rename("user_id"), "userId");
+        assert_eq!(Case::Pascal.rename("user_id"), "UserId");
+        assert_eq!(Case::UpperSnake.rename("AddRequest"), "ADD_REQUEST");
+        assert_eq!(Case::Kebab.rename("AddRequest"), "add-request");
     }
 
     #[test]
@@ -1,6 +1,11 @@
+use super::output::{FileTree, OutputFile};
 use super::Codegen;
-use crate::codegen::context::Context;
-use crate::{Definition, FieldDef, MethodDef, ModelDef, ModelType, Type, TypeReference};
+use crate::codegen::context::{relative_to_current_dir, Context};
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::{
+    Constraints, Definition, EnumRepresentation, FieldDef, MethodDef, ModelDef, ModelType,
+    TimestampFormat, Type, TypeReference, VariantDef,
+};
 use anyhow::anyhow;
 use indexmap::IndexMap;
 use openapiv3::{
@@ -89,8 +94,8 @@ mod serde_default {
     }
 }
 
-#[derive(Default)]
 pub struct Swagger {
+    context: Context,
     skip_failed: bool,
 }
 
@@ -121,15 +126,19 @@ impl Swagger {
 }
 
 impl Codegen for Swagger {
-    fn load_from_folder(_folder: &PathBuf) -> anyhow::Result<Self> {
-        Ok(Self::default())
+    fn load_from_context(context: &Context) -> anyhow::Result<Self> {
+        Ok(Self {
+            context: context.clone(),
+            skip_failed: false,
+        })
     }
 
-    fn generate_for_folder(&self, folder: &PathBuf, output: &PathBuf) -> anyhow::Result<()> {
-        // load codegen config from spec_config.yaml file
-        let config = Swagger::load_config(&folder.join("spec_config.yaml"))?.unwrap_or_default();
+    fn generate(&self) -> anyhow::Result<FileTree> {
+        let context = &self.context;
 
-        let context = Context::new_from_folder(folder)?;
+        // load codegen config from spec_config.yaml file
+        let config = Swagger::load_config(&context.root_folder().join("spec_config.yaml"))?
+            .unwrap_or_default();
 
         let mut openapi_spec = OpenAPI {
             openapi: "3.0.0".to_string(),
@@ -149,21 +158,24 @@ impl Codegen for Swagger {
         };
         openapi_spec.components = Some(Components::default());
 
+        let mut tree = FileTree::new();
         for (spec, _) in context.iter_specs() {
-            println!("swagger rendering {spec:?}");
-            match self.render_one_spec(spec, &context, &mut openapi_spec, &config) {
+            match self.render_one_spec(spec, context, &mut openapi_spec, &config) {
                 Ok(_) => continue,
-                Err(_) if self.skip_failed => continue,
+                Err(e) if self.skip_failed => {
+                    let spec_path = relative_to_current_dir(&context.root_folder().join(spec));
+                    tree.diagnostics.push(
+                        Diagnostic::new(spec_path, "swagger-skipped", format!("skipped, {e}"))
+                            .with_severity(Severity::Warning),
+                    );
+                }
                 Err(e) => return Err(e),
             }
         }
 
-        let output_file = output.join("openapi.yaml");
         let yaml_str = serde_yaml::to_string(&openapi_spec)?;
-
-        std::fs::write(&output_file, yaml_str)?;
-
-        Ok(())
+        tree.add(OutputFile::new("openapi.yaml", yaml_str));
+        Ok(tree)
     }
 }
 
@@ -236,7 +248,7 @@ impl Swagger {
                                     schema: Some(type_to_schema(
                                         &Type::Reference(method.request.0.clone()),
                                         true,
-                                        spec,
+                                        &Scope::new(spec),
                                         context,
                                     )?),
                                     examples: {
@@ -259,15 +271,9 @@ impl Swagger {
                                                 ).into_iter().map(|v| format!("example:{example_name} {v}")));
                                             }
                                             if !violations.is_empty() {
-                                                for violate in violations {
-                                                    println!(
-                                                        "violate: sepc:{spec:?} method:{method_name:?} {violate:?}",
-                                                        method_name = method_name,
-                                                        violate = violate
-                                                    );
-                                                }
                                                 anyhow::bail!(
-                                                    "swagger method example validate failed"
+                                                    "swagger method example validate failed, method:{method_name:?}\n{}",
+                                                    violations.join("\n")
                                                 )
                                             }
                                         }
@@ -343,11 +349,16 @@ impl Swagger {
             let model_desc = model.desc.clone();
             let model_name = &model.name;
 
+            if !model.type_params.is_empty() {
+                // generic model is monomorphized for each type args it is used with
+                continue;
+            }
+
             let schema = match &model.type_ {
                 ModelType::Struct(st_) => {
                     let mut object_type = openapiv3::ObjectType::default();
 
-                    let properties = fields_to_properties(&st_.fields, spec, context)?;
+                    let properties = fields_to_properties(&st_.fields, &Scope::new(spec), context)?;
                     for (name, property_schema) in properties {
                         object_type.properties.insert(name, property_schema);
                     }
@@ -363,19 +374,116 @@ impl Swagger {
                         },
                     })
                 }
-                ModelType::Enum { ref variants, .. } => {
+                ModelType::Enum {
+                    ref variants,
+                    tag_name,
+                    payload_name,
+                    representation,
+                } => {
+                    let tag_name = tag_name.as_deref().unwrap_or("type");
+                    let payload_name = payload_name.as_deref().unwrap_or("payload");
+
                     let mut variant_schemas = vec![];
+                    let mut mapping = IndexMap::new();
                     for variant in variants.iter() {
-                        // todo: enum variant embeded should converge to a separate model def
-
-                        let payload_type = variant
-                            .payload_type
-                            .as_ref()
-                            .ok_or_else(|| anyhow!("swagger enum now only support payload_type"))?;
-                        let variant_schema = type_to_schema(&payload_type.0, true, spec, context)?;
-                        variant_schemas.push(variant_schema);
+                        let wire_name = def.variant_wire_name(variant).into_owned();
+                        let payload_schema = variant_payload_schema(variant, spec, context)?;
+
+                        let variant_schema = match representation {
+                            EnumRepresentation::Adjacent => {
+                                let mut object_type = tag_object_type(tag_name, &wire_name);
+                                if let Some(payload_schema) = payload_schema {
+                                    object_type
+                                        .properties
+                                        .insert(payload_name.to_string(), boxed(payload_schema));
+                                    object_type.required.push(payload_name.to_string());
+                                }
+                                object_schema(object_type)
+                            }
+                            EnumRepresentation::Internal => {
+                                let mut object_type = tag_object_type(tag_name, &wire_name);
+                                match payload_schema {
+                                    // payload fields are merged into the tag object
+                                    Some(ReferenceOr::Item(Schema {
+                                        schema_kind:
+                                            SchemaKind::Type(openapiv3::Type::Object(payload)),
+                                        ..
+                                    })) => {
+                                        object_type.properties.extend(payload.properties);
+                                        object_schema(object_type)
+                                    }
+                                    Some(payload_schema) => ReferenceOr::Item(Schema {
+                                        schema_kind: SchemaKind::AllOf {
+                                            all_of: vec![
+                                                object_schema(object_type),
+                                                payload_schema,
+                                            ],
+                                        },
+                                        schema_data: Default::default(),
+                                    }),
+                                    None => object_schema(object_type),
+                                }
+                            }
+                            EnumRepresentation::External => match payload_schema {
+                                Some(payload_schema) => {
+                                    let mut object_type = openapiv3::ObjectType::default();
+                                    object_type
+                                        .properties
+                                        .insert(wire_name.clone(), boxed(payload_schema));
+                                    object_type.required.push(wire_name.clone());
+                                    object_type.additional_properties =
+                                        Some(AdditionalProperties::Any(false));
+                                    object_schema(object_type)
+                                }
+                                None => ReferenceOr::Item(Schema {
+                                    schema_kind: SchemaKind::Type(openapiv3::Type::String(
+                                        openapiv3::StringType {
+                                            enumeration: vec![Some(wire_name.clone())],
+                                            ..Default::default()
+                                        },
+                                    )),
+                                    schema_data: Default::default(),
+                                }),
+                            },
+                            EnumRepresentation::Untagged => match payload_schema {
+                                Some(payload_schema) => payload_schema,
+                                None => ReferenceOr::Item(Schema {
+                                    schema_kind: SchemaKind::Any(Default::default()),
+                                    schema_data: SchemaData {
+                                        nullable: true,
+                                        description: Some("null".to_string()),
+                                        ..Default::default()
+                                    },
+                                }),
+                            },
+                        };
+
+                        match representation {
+                            // tagged variants are registered as components so the
+                            // discriminator is able to map to them
+                            EnumRepresentation::Adjacent | EnumRepresentation::Internal => {
+                                let variant_fqdn =
+                                    format!("{}_{}", model_fqdn(spec, model_name), variant.name);
+                                let reference = format!("#/components/schemas/{variant_fqdn}");
+                                openapi_spec
+                                    .components
+                                    .as_mut()
+                                    .unwrap()
+                                    .schemas
+                                    .insert(variant_fqdn, variant_schema);
+                                mapping.insert(wire_name, reference.clone());
+                                variant_schemas.push(ReferenceOr::Reference { reference });
+                            }
+                            _ => variant_schemas.push(variant_schema),
+                        }
                     }
 
+                    let discriminator = (!mapping.is_empty()).then(|| openapiv3::Discriminator {
+                        property_name: tag_name.to_string(),
+                        mapping,
+                        extensions: Default::default(),
+                    });
+
                     ReferenceOr::Item(Schema {
                         schema_kind: SchemaKind::OneOf {
                             one_of: variant_schemas,
@@ -383,6 +491,7 @@ impl Swagger {
                         schema_data: SchemaData {
                             title: Some(model_fqdn(spec, model_name)),
                             description: model_desc,
+                            discriminator,
                             ..Default::default()
                         },
                     })
@@ -392,7 +501,7 @@ impl Swagger {
                 }
                 ModelType::NewType { inner_type } => {
                     let inner_type = &inner_type.as_ref().0;
-                    type_to_schema(inner_type, true, spec, context)?
+                    type_to_schema(inner_type, true, &Scope::new(spec), context)?
                 }
                 ModelType::Const { .. } => {
                     continue;
@@ -407,14 +516,185 @@ impl Swagger {
                 .insert(model_fqdn(spec, model_name), schema);
         }
 
+        // schemas for generic models with type args used in this spec
+        let mut types = vec![];
+        for model in def.models.iter().filter(|m| m.type_params.is_empty()) {
+            types.extend(model.types().into_iter().cloned());
+        }
+        for method in methods.iter() {
+            types.push(Type::Reference(method.request.0.clone()));
+            types.push(Type::Reference(method.response.0.clone()));
+        }
+        let schemas = &mut openapi_spec.components.as_mut().unwrap().schemas;
+        for ty in types.iter() {
+            register_generic_schemas(ty, &Scope::new(spec), context, schemas)?;
+        }
+
         Ok(())
     }
 }
 
+/// Scope to resolve types in. Generic model's fields are resolved in its own scope, and
+/// its type args are resolved in the parent scope where the model is used.
+struct Scope<'a> {
+    spec: &'a PathBuf,
+    type_params: &'a [String],
+    type_args: &'a [Type],
+    parent: Option<&'a Scope<'a>>,
+}
+
+impl<'a> Scope<'a> {
+    fn new(spec: &'a PathBuf) -> Self {
+        Self {
+            spec,
+            type_params: &[],
+            type_args: &[],
+            parent: None,
+        }
+    }
+
+    /// get the type arg for param, and the scope the arg is resolved in
+    fn resolve(&self, param: &str) -> anyhow::Result<(&'a Type, &'a Scope<'a>)> {
+        let idx = self
+            .type_params
+            .iter()
+            .position(|p| p.eq(param))
+            .ok_or_else(|| anyhow!("type param {param} not found"))?;
+        let arg = self
+            .type_args
+            .get(idx)
+            .ok_or_else(|| anyhow!("type arg for {param} not found"))?;
+        let parent = self
+            .parent
+            .ok_or_else(|| anyhow!("type param {param} is not in generic model"))?;
+        Ok((arg, parent))
+    }
+}
+
+/// spec path of the model which type_ref points to
+fn model_spec_for_reference(
+    type_ref: &TypeReference,
+    spec_path: &PathBuf,
+    context: &Context,
+) -> anyhow::Result<PathBuf> {
+    let TypeReference {
+        namespace, target, ..
+    } = type_ref;
+    match namespace {
+        None => Ok(spec_path.to_owned()),
+        Some(namespace) => {
+            let include_path = context.get_include_path(namespace, spec_path)?;
+            let include_def = context.get_definition(&include_path)?;
+
+            let _ = include_def
+                .get_model(&target)
+                .ok_or_else(|| anyhow!("Not able to load model {namespace}.{target}"))?;
+
+            Ok(include_path)
+        }
+    }
+}
+
+/// name used for ty in schema name of generic model, e.g. `Page_User` for `Page<User>`
+fn schema_name_for_type(ty: &Type, scope: &Scope, context: &Context) -> anyhow::Result<String> {
+    Ok(match ty {
+        Type::Reference(type_ref) => {
+            let spec = model_spec_for_reference(type_ref, scope.spec, context)?;
+            let mut name = model_fqdn(&spec, &type_ref.target);
+            for arg in type_ref.args.iter() {
+                name.push('_');
+                name.push_str(&schema_name_for_type(arg, scope, context)?);
+            }
+            name
+        }
+        Type::TypeParam { param } => {
+            let (arg, parent) = scope.resolve(param)?;
+            schema_name_for_type(arg, parent, context)?
+        }
+        Type::List { item_type } => {
+            format!("list_{}", schema_name_for_type(item_type, scope, context)?)
+        }
+        Type::Set { item_type } => {
+            format!("set_{}", schema_name_for_type(item_type, scope, context)?)
+        }
+        Type::Map { value_type, .. } => {
+            format!("map_{}", schema_name_for_type(value_type, scope, context)?)
+        }
+        ty => serde_json::to_value(ty)?["name"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+    })
+}
+
+/// register schemas for generic models used in ty, with type params replaced by type args
+fn register_generic_schemas(
+    ty: &Type,
+    scope: &Scope,
+    context: &Context,
+    schemas: &mut IndexMap<String, ReferenceOr<Schema>>,
+) -> anyhow::Result<()> {
+    let type_ref = match ty {
+        Type::List { item_type } | Type::Set { item_type } => {
+            return register_generic_schemas(item_type, scope, context, schemas)
+        }
+        Type::Map { value_type, .. } => {
+            return register_generic_schemas(value_type, scope, context, schemas)
+        }
+        Type::Reference(type_ref) if !type_ref.args.is_empty() => type_ref,
+        _ => return Ok(()),
+    };
+
+    for arg in type_ref.args.iter() {
+        register_generic_schemas(arg, scope, context, schemas)?;
+    }
+
+    let name = schema_name_for_type(ty, scope, context)?;
+    if schemas.contains_key(&name) {
+        return Ok(());
+    }
+
+    let model = context.get_model_def_for_reference(type_ref, scope.spec)?;
+    let Some(st_) = model.type_.struct_def() else {
+        anyhow::bail!("generic model {} should be struct", model.name);
+    };
+    let model_spec = model_spec_for_reference(type_ref, scope.spec, context)?;
+    let model_scope = Scope {
+        spec: &model_spec,
+        type_params: &model.type_params,
+        type_args: &type_ref.args,
+        parent: Some(scope),
+    };
+
+    // insert before fields are visited, model may refer to itself
+    schemas.insert(name.clone(), object_schema(Default::default()));
+
+    let mut object_type = openapiv3::ObjectType::default();
+    for (name, property_schema) in fields_to_properties(&st_.fields, &model_scope, context)? {
+        object_type.properties.insert(name, property_schema);
+    }
+    schemas.insert(
+        name,
+        ReferenceOr::Item(Schema {
+            schema_kind: SchemaKind::Type(openapiv3::Type::Object(object_type)),
+            schema_data: SchemaData {
+                description: model.desc.clone(),
+                ..Default::default()
+            },
+        }),
+    );
+
+    for field in st_.fields.iter() {
+        register_generic_schemas(&field.type_, &model_scope, context, schemas)?;
+    }
+
+    Ok(())
+}
+
 fn type_to_schema(
     ty_: &Type,
     required: bool,
-    spec_path: &PathBuf,
+    scope: &Scope,
     context: &Context,
 ) -> anyhow::Result<ReferenceOr<Schema>> {
     let schema_kind = match ty_ {
@@ -431,6 +711,25 @@ fn type_to_schema(
                 ..Default::default()
             }))
         }
+        Type::U8 | Type::U16 | Type::U32 | Type::U64 => {
+            let (number_format, maximum) = match ty_ {
+                Type::U8 => (openapiv3::IntegerFormat::Int32, Some(u8::MAX.into())),
+                Type::U16 => (openapiv3::IntegerFormat::Int32, Some(u16::MAX.into())),
+                Type::U32 => (openapiv3::IntegerFormat::Int64, Some(u32::MAX.into())),
+                _ => (openapiv3::IntegerFormat::Int64, None),
+            };
+
+            SchemaKind::Type(openapiv3::Type::Integer(openapiv3::IntegerType {
+                format: openapiv3::VariantOrUnknownOrEmpty::Item(number_format),
+                minimum: Some(0),
+                maximum,
+                ..Default::default()
+            }))
+        }
+        Type::F32 => SchemaKind::Type(openapiv3::Type::Number(openapiv3::NumberType {
+            format: openapiv3::VariantOrUnknownOrEmpty::Item(openapiv3::NumberFormat::Float),
+            ..Default::default()
+        })),
         Type::F64 => SchemaKind::Type(openapiv3::Type::Number(openapiv3::NumberType {
             format: openapiv3::VariantOrUnknownOrEmpty::Item(openapiv3::NumberFormat::Double),
             ..Default::default()
@@ -441,9 +740,26 @@ fn type_to_schema(
                 ..Default::default()
             }))
         }
-        Type::List { item_type } => {
+        Type::Timestamp {
+            format: TimestampFormat::Rfc3339,
+        } => SchemaKind::Type(openapiv3::Type::String(openapiv3::StringType {
+            format: openapiv3::VariantOrUnknownOrEmpty::Item(openapiv3::StringFormat::DateTime),
+            ..Default::default()
+        })),
+        Type::Date => SchemaKind::Type(openapiv3::Type::String(openapiv3::StringType {
+            format: openapiv3::VariantOrUnknownOrEmpty::Item(openapiv3::StringFormat::Date),
+            ..Default::default()
+        })),
+        Type::Timestamp {
+            format: TimestampFormat::EpochMillis,
+        }
+        | Type::Duration => SchemaKind::Type(openapiv3::Type::Integer(openapiv3::IntegerType {
+            format: openapiv3::VariantOrUnknownOrEmpty::Item(openapiv3::IntegerFormat::Int64),
+            ..Default::default()
+        })),
+        Type::List { item_type } | Type::Set { item_type } => {
             let item_type_ref = &item_type.as_ref().0;
-            let item_schema = match type_to_schema(item_type_ref, false, spec_path, context)? {
+            let item_schema = match type_to_schema(item_type_ref, false, scope, context)? {
                 ReferenceOr::Reference { reference } => ReferenceOr::Reference { reference },
                 ReferenceOr::Item(item) => ReferenceOr::Item(Box::new(item)),
             };
@@ -452,7 +768,7 @@ fn type_to_schema(
                 items: Some(item_schema),
                 min_items: None,
                 max_items: None,
-                unique_items: false,
+                unique_items: matches!(ty_, Type::Set { .. }),
             }))
         }
         Type::Map { .. } => SchemaKind::Type(openapiv3::Type::Object(openapiv3::ObjectType {
@@ -463,25 +779,17 @@ fn type_to_schema(
             additional_properties: Some(AdditionalProperties::Any(true)),
             ..Default::default()
         })),
-        Type::Reference(TypeReference { namespace, target }) => {
-            let spec = match namespace {
-                None => spec_path.to_owned(),
-                Some(namespace) => {
-                    let include_path = context.get_include_path(namespace, spec_path)?;
-                    let include_def = context.get_definition(&include_path)?;
-
-                    let _ = include_def
-                        .get_model(&target)
-                        .ok_or_else(|| anyhow!("Not able to load model {namespace}.{target}"))?;
-
-                    include_path
-                }
-            };
-
+        Type::Reference(_) => {
+            // generic model's schema is registered with type args in the name
+            let name = schema_name_for_type(ty_, scope, context)?;
             return Ok(ReferenceOr::Reference {
-                reference: format!("#/components/schemas/{}", model_fqdn(&spec, target)),
+                reference: format!("#/components/schemas/{name}"),
             });
         }
+        Type::TypeParam { param } => {
+            let (arg, parent) = scope.resolve(param)?;
+            return type_to_schema(arg, required, parent, context);
+        }
     };
 
     Ok(ReferenceOr::Item(Schema {
@@ -511,18 +819,23 @@ fn model_fqdn(spec_path: &PathBuf, model_name: &str) -> String {
 
 fn fields_to_properties(
     fields: &[FieldDef],
-    spec: &PathBuf,
+    scope: &Scope,
     context: &Context,
 ) -> anyhow::Result<Vec<(String, ReferenceOr<Box<Schema>>)>> {
     let mut properties = vec![];
+    let def = context.get_definition(scope.spec)?;
 
     for field in fields.iter() {
-        let field_schema = type_to_schema(&field.type_, field.required, spec, context)?;
+        let field_schema = type_to_schema(&field.type_, !field.may_be_null(), scope, context)?;
         properties.push((
-            field.name.to_string(),
+            def.field_wire_name(field).into_owned(),
             match field_schema {
                 ReferenceOr::Reference { reference } => ReferenceOr::Reference { reference },
-                ReferenceOr::Item(item) => ReferenceOr::boxed_item(item),
+                ReferenceOr::Item(mut item) => {
+                    item.schema_data.default = field.default.clone();
+                    apply_constraints(&mut item, &field.constraints);
+                    ReferenceOr::boxed_item(item)
+                }
             },
         ));
     }
@@ -530,6 +843,106 @@ fn fields_to_properties(
     Ok(properties)
 }
 
+/// schema for variant's payload, None for variant without payload
+fn variant_payload_schema(
+    variant: &VariantDef,
+    spec: &PathBuf,
+    context: &Context,
+) -> anyhow::Result<Option<ReferenceOr<Schema>>> {
+    if let Some(payload_type) = &variant.payload_type {
+        return Ok(Some(type_to_schema(
+            payload_type,
+            true,
+            &Scope::new(spec),
+            context,
+        )?));
+    }
+
+    let Some(fields) = &variant.payload_fields else {
+        return Ok(None);
+    };
+    let mut object_type = openapiv3::ObjectType::default();
+    for (name, property_schema) in fields_to_properties(fields, &Scope::new(spec), context)? {
+        object_type.properties.insert(name, property_schema);
+    }
+    Ok(Some(object_schema(object_type)))
+}
+
+/// object type with only the tag property
+fn tag_object_type(tag_name: &str, wire_name: &str) -> openapiv3::ObjectType {
+    let tag_schema = Schema {
+        schema_kind: SchemaKind::Type(openapiv3::Type::String(openapiv3::StringType {
+            enumeration: vec![Some(wire_name.to_string())],
+            ..Default::default()
+        })),
+        schema_data: Default::default(),
+    };
+
+    let mut object_type = openapiv3::ObjectType::default();
+    object_type
+        .properties
+        .insert(tag_name.to_string(), ReferenceOr::boxed_item(tag_schema));
+    object_type.required.push(tag_name.to_string());
+    object_type
+}
+
+fn object_schema(object_type: openapiv3::ObjectType) -> ReferenceOr<Schema> {
+    ReferenceOr::Item(Schema {
+        schema_kind: SchemaKind::Type(openapiv3::Type::Object(object_type)),
+        schema_data: Default::default(),
+    })
+}
+
+fn boxed(schema: ReferenceOr<Schema>) -> ReferenceOr<Box<Schema>> {
+    match schema {
+        ReferenceOr::Reference { reference } => ReferenceOr::Reference { reference },
+        ReferenceOr::Item(item) => ReferenceOr::boxed_item(item),
+    }
+}
+
+/// set field constraints on the schema generated for field's type
+fn apply_constraints(schema: &mut Schema, constraints: &Constraints) {
+    let min_length = match (constraints.min_length, constraints.non_empty) {
+        (Some(min_length), non_empty) => Some(min_length.max(non_empty as u64) as usize),
+        (None, true) => Some(1),
+        (None, false) => None,
+    };
+    let max_length = constraints.max_length.map(|l| l as usize);
+
+    match &mut schema.schema_kind {
+        SchemaKind::Type(openapiv3::Type::Integer(integer_type)) => {
+            if let Some(min) = constraints.min.as_ref().and_then(|n| n.as_i64()) {
+                integer_type.minimum = Some(min);
+            }
+            if let Some(max) = constraints.max.as_ref().and_then(|n| n.as_i64()) {
+                integer_type.maximum = Some(max);
+            }
+        }
+        SchemaKind::Type(openapiv3::Type::Number(number_type)) => {
+            if let Some(min) = constraints.min.as_ref().and_then(|n| n.as_f64()) {
+                number_type.minimum = Some(min);
+            }
+            if let Some(max) = constraints.max.as_ref().and_then(|n| n.as_f64()) {
+                number_type.maximum = Some(max);
+            }
+        }
+        SchemaKind::Type(openapiv3::Type::String(string_type)) => {
+            string_type.min_length = min_length;
+            string_type.max_length = max_length;
+            string_type.pattern = constraints.pattern.clone();
+        }
+        SchemaKind::Type(openapiv3::Type::Array(array_type)) => {
+            array_type.min_items = min_length;
+            array_type.max_items = max_length;
+        }
+        SchemaKind::Type(openapiv3::Type::Object(object_type)) => {
+            object_type.min_properties = min_length;
+            object_type.max_properties = max_length;
+        }
+        _ => {}
+    }
+}
+
 fn to_components(path: &PathBuf) -> Vec<String> {
     assert!(path.is_relative());
 
@@ -620,7 +1033,7 @@ fn response_schema(
         None => type_to_schema(
             &Type::Reference(method.response.0.clone()),
             true,
-            spec,
+            &Scope::new(spec),
             context,
         ),
         Some(response_template) => {
@@ -638,7 +1051,7 @@ fn response_schema(
 
             let mut object_type = openapiv3::ObjectType::default();
 
-            let properties = fields_to_properties(&fields, spec, context)?;
+            let properties = fields_to_properties(&fields, &Scope::new(spec), context)?;
             for (name, property_schema) in properties {
                 object_type.properties.insert(name, property_schema);
             }
@@ -659,7 +1072,9 @@ mod tests {
 
     #[test]
     fn test_swagger() {
-        let codegen = Swagger { skip_failed: true };
+        let mut codegen =
+            Swagger::load_from_folder(&PathBuf::from("src/codegen/fixtures/specs")).unwrap();
+        codegen.skip_failed = true;
         codegen
             .generate_for_folder(
                 &PathBuf::from("src/codegen/fixtures/specs"),
@@ -1,9 +1,12 @@
 use std::borrow::Cow;
 use std::fmt::Write;
-use std::path::{Path, PathBuf};
+use std::path::Path;
 
 use crate::codegen::context::Context;
-use crate::{FieldDef, Type, TypeReference};
+use crate::codegen::output::{FileTree, OutputFile};
+use crate::{
+    Definition, EnumRepresentation, FieldDef, TimestampFormat, Type, TypeReference, VariantDef,
+};
 
 use super::utils::{indent, multiline_prefix_with, to_pascal_case};
 
@@ -12,14 +15,14 @@ pub struct SwiftCodable {
 }
 
 impl super::Codegen for SwiftCodable {
-    fn load_from_folder(folder: &PathBuf) -> anyhow::Result<Self> {
-        let context = Context::new_from_folder(folder)?;
-        Ok(Self { context })
+    fn load_from_context(context: &Context) -> anyhow::Result<Self> {
+        Ok(Self {
+            context: context.clone(),
+        })
     }
 
-    fn generate_for_folder(&self, _folder: &PathBuf, output: &PathBuf) -> anyhow::Result<()> {
-        std::fs::create_dir_all(output).unwrap();
-
+    fn generate(&self) -> anyhow::Result<FileTree> {
+        let mut tree = FileTree::new();
         for (spec_path, _) in self.context.iter_specs() {
             // Use the spec file name (without extension) as output file name
             let file_stem = spec_path
@@ -27,15 +30,16 @@ impl super::Codegen for SwiftCodable {
                 .and_then(|s| s.to_str())
                 .unwrap_or("output");
 
-            let out_path = output.join(format!("{}.swift", file_stem));
-
-            let code = render(&spec_path, &self.context)?;
+            let code = render(spec_path, &self.context)?;
+            tree.add(OutputFile::new(format!("{}.swift", file_stem), code));
+        }
 
-            std::fs::write(&out_path, code).unwrap();
-            println!("write output to {:?}", out_path);
+        // shared by all specs, a wrapper in each file would be redeclared in the module
+        if let Some(code) = render_time_wrappers(&self.context) {
+            tree.add(OutputFile::new(TIME_WRAPPERS_FILE, code));
         }
 
-        Ok(())
+        Ok(tree)
     }
 }
 
@@ -70,6 +74,7 @@ fn render(spec_path: &Path, context: &Context) -> anyhow::Result<String> {
                 variants,
                 tag_name,
                 payload_name,
+                representation,
             } => {
                 let tag_name = tag_name.clone().unwrap_or_else(|| "type".to_string());
                 let payload_name = payload_name
@@ -90,6 +95,20 @@ fn render(spec_path: &Path, context: &Context) -> anyhow::Result<String> {
                     }
                 }
 
+                if *representation != EnumRepresentation::Adjacent {
+                    let code = generate_enum_coding(
+                        variants,
+                        def,
+                        *representation,
+                        &tag_name,
+                        &package_name,
+                    )?;
+                    writeln!(result)?;
+                    writeln!(result, "{}", indent(code.trim(), 1))?;
+                    writeln!(result, "}}")?;
+                    continue;
+                }
+
                 writeln!(result, "\n    // coding keys")?;
                 writeln!(result, "    enum CodingKeys: String, CodingKey {{")?;
                 writeln!(result, "        case {}, {}", tag_name, payload_name)?;
@@ -119,7 +138,8 @@ fn render(spec_path: &Path, context: &Context) -> anyhow::Result<String> {
 
                         let mut case_block = "".to_string();
 
-                        writeln!(case_block, "case \"{variant_name}\":")?;
+                        let wire_name = def.variant_wire_name(variant);
+                        writeln!(case_block, "case \"{wire_name}\":")?;
                         if let Some(payload_type) = &variant.payload_type {
                             let payload_type = swift_type(&payload_type, &package_name);
                             writeln!(case_block, "    let {} = try container.decode({payload_type}.self, forKey:.{})", payload_name, payload_name)?;
@@ -169,6 +189,7 @@ fn render(spec_path: &Path, context: &Context) -> anyhow::Result<String> {
 
                         for variant in variants.iter() {
                             let name = &variant.name;
+                            let wire_name = def.variant_wire_name(variant);
                             let mut case_code = "".to_string();
 
                             if variant.payload_type.is_some() {
@@ -176,7 +197,7 @@ fn render(spec_path: &Path, context: &Context) -> anyhow::Result<String> {
 
                                 writeln!(
                                     case_code,
-                                    "    try container.encode(\"{name}\", forKey: .{})",
+                                    "    try container.encode(\"{wire_name}\", forKey: .{})",
                                     tag_name
                                 )?;
                                 writeln!(
@@ -188,7 +209,7 @@ fn render(spec_path: &Path, context: &Context) -> anyhow::Result<String> {
                                 writeln!(case_code, "case .{name}:")?;
                                 writeln!(
                                     case_code,
-                                    "    try container.encode(\"{name}\", forKey: .{})",
+                                    "    try container.encode(\"{wire_name}\", forKey: .{})",
                                     tag_name
                                 )?;
                             }
@@ -213,8 +234,26 @@ fn render(spec_path: &Path, context: &Context) -> anyhow::Result<String> {
             crate::ModelType::Struct(struct_def) => {
                 let mut fields: Vec<FieldDef> = vec![];
 
+                // type params are constrained to Codable to make the struct Codable
+                let generics = match model.type_params.is_empty() {
+                    true => "".to_string(),
+                    false => format!(
+                        "<{}>",
+                        model
+                            .type_params
+                            .iter()
+                            .map(|p| format!("{p}: Codable"))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                };
+
                 if let Some(base) = &struct_def.extend {
-                    writeln!(result, "public struct {}: Codable, {base} {{", model.name)?;
+                    writeln!(
+                        result,
+                        "public struct {}{generics}: Codable, {base} {{",
+                        model.name
+                    )?;
                     let base_model = def.get_model(&base).unwrap();
                     match &base_model.type_ {
                         crate::ModelType::Virtual(struct_def) => {
@@ -225,17 +264,14 @@ fn render(spec_path: &Path, context: &Context) -> anyhow::Result<String> {
                         }
                     }
                 } else {
-                    writeln!(result, "public struct {}: Codable {{", model.name)?;
+                    writeln!(result, "public struct {}{generics}: Codable {{", model.name)?;
                 }
 
                 fields.extend(struct_def.fields.clone());
 
                 for field in fields.iter() {
                     let field_name = &field.name;
-                    let mut field_type = swift_type(&field.type_, &package_name);
-                    if !field.required {
-                        field_type = format!("{field_type}?");
-                    }
+                    let field_type = swift_type_for_field(field, &package_name);
 
                     writeln!(result, "    public var {field_name}: {field_type}")?;
                 }
@@ -246,6 +282,30 @@ fn render(spec_path: &Path, context: &Context) -> anyhow::Result<String> {
                 writeln!(result, "")?;
                 writeln!(result, "{}", indent(code_block.trim(), 1))?;
 
+                // coding keys are synthesized unless some wire name differs from field name
+                if fields.iter().any(|f| def.field_wire_name(f).ne(&f.name)) {
+                    writeln!(result)?;
+                    writeln!(result, "    enum CodingKeys: String, CodingKey {{")?;
+                    for field in fields.iter() {
+                        let field_name = &field.name;
+                        let wire_name = def.field_wire_name(field);
+                        if wire_name.ne(field_name) {
+                            writeln!(result, "        case {field_name} = \"{wire_name}\"")?;
+                        } else {
+                            writeln!(result, "        case {field_name}")?;
+                        }
+                    }
+                    writeln!(result, "    }}")?;
+                }
+
+                // synthesized decoder requires all non optional keys, fields with
+                // default need a custom one
+                if fields.iter().any(|f| f.default.is_some()) {
+                    let code_block = generate_decoder_init(&fields, &package_name)?;
+                    writeln!(result)?;
+                    writeln!(result, "{}", indent(code_block.trim(), 1))?;
+                }
+
                 writeln!(result, "}}")?;
             }
             crate::ModelType::Virtual(struct_def) => {
@@ -253,10 +313,7 @@ fn render(spec_path: &Path, context: &Context) -> anyhow::Result<String> {
 
                 for field in struct_def.fields.iter() {
                     let field_name = &field.name;
-                    let mut field_type = swift_type(&field.type_, &package_name);
-                    if !field.required {
-                        field_type = format!("{field_type}?");
-                    }
+                    let field_type = swift_type_for_field(field, &package_name);
 
                     writeln!(result, "    var {field_name}: {field_type} {{")?;
                     writeln!(result, "        get")?;
@@ -281,6 +338,10 @@ fn render(spec_path: &Path, context: &Context) -> anyhow::Result<String> {
                     crate::ConstType::I16 => "Int16",
                     crate::ConstType::I32 => "Int32",
                     crate::ConstType::I64 => "Int64",
+                    crate::ConstType::U8 => "UInt8",
+                    crate::ConstType::U16 => "UInt16",
+                    crate::ConstType::U32 => "UInt32",
+                    crate::ConstType::U64 => "UInt64",
                     crate::ConstType::String => "String",
                 };
 
@@ -314,25 +375,403 @@ fn swift_type(ty: &Type, package_name: &str) -> String {
         Type::I16 => "Int16".into(),
         Type::I32 => "Int32".into(),
         Type::I64 => "Int64".into(),
+        Type::U8 => "UInt8".into(),
+        Type::U16 => "UInt16".into(),
+        Type::U32 => "UInt32".into(),
+        Type::U64 => "UInt64".into(),
+        Type::F32 => "Float32".into(),
         Type::F64 => "Float64".into(),
         Type::Bytes => "Data".into(),
         Type::String => "String".into(),
+        Type::Timestamp {
+            format: TimestampFormat::Rfc3339,
+        } => "Rfc3339Timestamp".into(),
+        Type::Timestamp {
+            format: TimestampFormat::EpochMillis,
+        } => "EpochMillisTimestamp".into(),
+        Type::Date => "LocalDate".into(),
+        // duration in millis
+        Type::Duration => "Int64".into(),
         Type::List { item_type } => {
             format!("[{}]", swift_type(item_type, package_name))
         }
-        Type::Map { value_type } => {
-            format!("[String:{}]", swift_type(value_type, package_name))
+        Type::Set { item_type } => {
+            let item_type = match &item_type.0 {
+                // string const is an enum without Codable conformance
+                Type::Reference(_) => "String".into(),
+                ty => swift_type(ty, package_name),
+            };
+            format!("Set<{item_type}>")
+        }
+        Type::Map {
+            key_type,
+            value_type,
+        } => {
+            // Dictionary is encoded as json object only when key is String or Int
+            let key_type = if key_type.is_integer() {
+                "Int"
+            } else {
+                "String"
+            };
+            format!("[{key_type}:{}]", swift_type(value_type, package_name))
+        }
+        Type::Reference(TypeReference {
+            namespace,
+            target,
+            args,
+        }) => {
+            let target = match namespace {
+                Some(ns) => format!("{}.{}", to_pascal_case(ns), target),
+                None => format!("{}.{}", package_name, target),
+            };
+            if args.is_empty() {
+                target
+            } else {
+                let args = args
+                    .iter()
+                    .map(|a| swift_type(a, package_name))
+                    .collect::<Vec<_>>();
+                format!("{target}<{}>", args.join(", "))
+            }
         }
-        Type::Reference(TypeReference { namespace, target }) => match namespace {
-            Some(ns) => format!("{}.{}", to_pascal_case(ns), target),
-            None => format!("{}.{}", package_name, target),
-        },
+        Type::TypeParam { param } => param.clone(),
         Type::Json => "Any".to_string(),
         Type::Decimal => "Decimal".to_string(),
         Type::BigInt => "Int64".to_string(),
     }
 }
 
+/// time wrappers used by specs, None if no spec uses time types
+fn render_time_wrappers(context: &Context) -> Option<String> {
+    let wrappers = TIME_WRAPPERS
+        .iter()
+        .filter(|(wrapper_type, _)| {
+            context
+                .iter_specs()
+                .any(|(_, def)| uses_type(def, wrapper_type))
+        })
+        .map(|(_, code)| *code)
+        .collect::<Vec<_>>();
+    match wrappers.is_empty() {
+        true => None,
+        false => Some(format!("import Foundation\n\n{}", wrappers.join("\n"))),
+    }
+}
+
+/// whether models of spec use the swift type
+fn uses_type(def: &Definition, swift_ty: &str) -> bool {
+    def.models.iter().any(|model| {
+        model
+            .member_types()
+            .iter()
+            .any(|(_, ty)| contains_type(ty, &|ty| swift_type(ty, "") == swift_ty))
+    })
+}
+
+fn contains_type(ty: &Type, pred: &dyn Fn(&Type) -> bool) -> bool {
+    if pred(ty) {
+        return true;
+    }
+    match ty {
+        Type::List { item_type } | Type::Set { item_type } => contains_type(item_type, pred),
+        Type::Map {
+            key_type,
+            value_type,
+        } => contains_type(key_type, pred) || contains_type(value_type, pred),
+        Type::Reference(type_ref) => type_ref.args.iter().any(|arg| contains_type(arg, pred)),
+        _ => false,
+    }
+}
+
+const TIME_WRAPPERS_FILE: &str = "TotSpecTime.swift";
+
+/// Date's wire format is decided by the coder's strategy, which is shared by all fields,
+/// so each time format has its own wrapper
+const TIME_WRAPPERS: [(&str, &str); 3] = [
+    ("Rfc3339Timestamp", RFC3339_TIMESTAMP),
+    ("EpochMillisTimestamp", EPOCH_MILLIS_TIMESTAMP),
+    ("LocalDate", LOCAL_DATE),
+];
+
+const RFC3339_TIMESTAMP: &str = r#"// timestamp in rfc3339 string
+public struct Rfc3339Timestamp: Codable, Hashable {
+    public var date: Date
+
+    public init(_ date: Date) {
+        self.date = date
+    }
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        let value = try container.decode(String.self)
+        let formatter = ISO8601DateFormatter()
+        // fractional seconds are optional
+        for options: ISO8601DateFormatter.Options in [[.withInternetDateTime, .withFractionalSeconds], [.withInternetDateTime]] {
+            formatter.formatOptions = options
+            if let date = formatter.date(from: value) {
+                self.date = date
+                return
+            }
+        }
+        throw DecodingError.dataCorruptedError(in: container, debugDescription: "invalid rfc3339 timestamp \(value)")
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        let formatter = ISO8601DateFormatter()
+        formatter.formatOptions = [.withInternetDateTime, .withFractionalSeconds]
+        var container = encoder.singleValueContainer()
+        try container.encode(formatter.string(from: date))
+    }
+}
+"#;
+
+const EPOCH_MILLIS_TIMESTAMP: &str = r#"// timestamp in millis since unix epoch
+public struct EpochMillisTimestamp: Codable, Hashable {
+    public var date: Date
+
+    public init(_ date: Date) {
+        self.date = date
+    }
+
+    public init(from decoder: Decoder) throws {
+        let millis = try decoder.singleValueContainer().decode(Int64.self)
+        self.date = Date(timeIntervalSince1970: Double(millis) / 1000)
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        try container.encode(Int64((date.timeIntervalSince1970 * 1000).rounded()))
+    }
+}
+"#;
+
+const LOCAL_DATE: &str = r#"// date in YYYY-MM-DD, the date is at midnight in utc
+public struct LocalDate: Codable, Hashable {
+    public var date: Date
+
+    public init(_ date: Date) {
+        self.date = date
+    }
+
+    private static func formatter() -> DateFormatter {
+        let formatter = DateFormatter()
+        formatter.calendar = Calendar(identifier: .iso8601)
+        formatter.locale = Locale(identifier: "en_US_POSIX")
+        formatter.timeZone = TimeZone(identifier: "UTC")
+        formatter.dateFormat = "yyyy-MM-dd"
+        return formatter
+    }
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        let value = try container.decode(String.self)
+        guard let date = LocalDate.formatter().date(from: value) else {
+            throw DecodingError.dataCorruptedError(in: container, debugDescription: "invalid date \(value)")
+        }
+        self.date = date
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        try container.encode(LocalDate.formatter().string(from: date))
+    }
+}
+"#;
+
+/// coding keys, decoder and encoder for internal, external and untagged enum
+fn generate_enum_coding(
+    variants: &[VariantDef],
+    def: &Definition,
+    representation: EnumRepresentation,
+    tag_name: &str,
+    package_name: &str,
+) -> anyhow::Result<String> {
+    let mut result = "".to_string();
+    let (payload_variants, unit_variants): (Vec<_>, Vec<_>) =
+        variants.iter().partition(|v| v.payload_type.is_some());
+
+    // coding keys, external enum is keyed by variant
+    match representation {
+        EnumRepresentation::Internal => {
+            writeln!(result, "// coding keys")?;
+            writeln!(result, "enum CodingKeys: String, CodingKey {{")?;
+            writeln!(result, "    case {tag_name}")?;
+            writeln!(result, "}}")?;
+            writeln!(result)?;
+        }
+        EnumRepresentation::External if !payload_variants.is_empty() => {
+            writeln!(result, "// coding keys")?;
+            writeln!(result, "enum CodingKeys: String, CodingKey {{")?;
+            for variant in payload_variants.iter() {
+                let wire_name = def.variant_wire_name(variant);
+                writeln!(result, "    case {} = \"{wire_name}\"", variant.name)?;
+            }
+            writeln!(result, "}}")?;
+            writeln!(result)?;
+        }
+        _ => {}
+    }
+
+    // decoder
+    let mut body = "".to_string();
+    match representation {
+        EnumRepresentation::Internal => {
+            writeln!(
+                body,
+                "let container = try decoder.container(keyedBy: CodingKeys.self)"
+            )?;
+            writeln!(
+                body,
+                "let {tag_name} = try container.decode(String.self, forKey: .{tag_name})"
+            )?;
+            writeln!(body, "switch {tag_name} {{")?;
+            for variant in variants {
+                let variant_name = &variant.name;
+                writeln!(body, "    case \"{}\":", def.variant_wire_name(variant))?;
+                match &variant.payload_type {
+                    Some(payload_type) => {
+                        let payload_type = swift_type(payload_type, package_name);
+                        writeln!(
+                            body,
+                            "        self = .{variant_name}(try {payload_type}(from: decoder))"
+                        )?;
+                    }
+                    None => writeln!(body, "        self = .{variant_name}")?,
+                }
+            }
+            writeln!(body, "    default:")?;
+            writeln!(body, "        throw ModelError.Error")?;
+            writeln!(body, "}}")?;
+        }
+        EnumRepresentation::External => {
+            if !unit_variants.is_empty() {
+                writeln!(body, "// variant without payload is a plain string")?;
+                writeln!(
+                    body,
+                    "if let tag = try? decoder.singleValueContainer().decode(String.self) {{"
+                )?;
+                writeln!(body, "    switch tag {{")?;
+                for variant in unit_variants.iter() {
+                    writeln!(body, "        case \"{}\":", def.variant_wire_name(variant))?;
+                    writeln!(body, "            self = .{}", variant.name)?;
+                }
+                writeln!(body, "        default:")?;
+                writeln!(body, "            throw ModelError.Error")?;
+                writeln!(body, "    }}")?;
+                writeln!(body, "    return")?;
+                writeln!(body, "}}")?;
+            }
+            if !payload_variants.is_empty() {
+                writeln!(
+                    body,
+                    "let container = try decoder.container(keyedBy: CodingKeys.self)"
+                )?;
+                writeln!(body, "guard let key = container.allKeys.first else {{")?;
+                writeln!(body, "    throw ModelError.Error")?;
+                writeln!(body, "}}")?;
+                writeln!(body, "switch key {{")?;
+                for variant in payload_variants.iter() {
+                    let variant_name = &variant.name;
+                    let payload_type =
+                        swift_type(variant.payload_type.as_ref().unwrap(), package_name);
+                    writeln!(body, "    case .{variant_name}:")?;
+                    writeln!(
+                        body,
+                        "        self = .{variant_name}(try container.decode({payload_type}.self, forKey: .{variant_name}))"
+                    )?;
+                }
+                writeln!(body, "}}")?;
+            } else {
+                writeln!(body, "throw ModelError.Error")?;
+            }
+        }
+        EnumRepresentation::Untagged => {
+            writeln!(body, "// try variants in order, the first match wins")?;
+            for variant in variants {
+                let variant_name = &variant.name;
+                match &variant.payload_type {
+                    Some(payload_type) => {
+                        let payload_type = swift_type(payload_type, package_name);
+                        writeln!(
+                            body,
+                            "if let payload = try? {payload_type}(from: decoder) {{"
+                        )?;
+                        writeln!(body, "    self = .{variant_name}(payload)")?;
+                    }
+                    None => {
+                        writeln!(
+                            body,
+                            "if let container = try? decoder.singleValueContainer(), container.decodeNil() {{"
+                        )?;
+                        writeln!(body, "    self = .{variant_name}")?;
+                    }
+                }
+                writeln!(body, "    return")?;
+                writeln!(body, "}}")?;
+            }
+            writeln!(body, "throw ModelError.Error")?;
+        }
+        EnumRepresentation::Adjacent => unreachable!("adjacent enum is coded by caller"),
+    }
+    writeln!(result, "// decoder")?;
+    writeln!(result, "public init(from decoder: Decoder) throws {{")?;
+    writeln!(result, "{}", indent(body.trim(), 1))?;
+    writeln!(result, "}}")?;
+
+    // encoder
+    let mut body = "".to_string();
+    if representation == EnumRepresentation::Internal {
+        writeln!(
+            body,
+            "var container = encoder.container(keyedBy: CodingKeys.self)"
+        )?;
+    }
+    writeln!(body, "switch self {{")?;
+    for variant in variants {
+        let variant_name = &variant.name;
+        let wire_name = def.variant_wire_name(variant);
+        if variant.payload_type.is_some() {
+            writeln!(body, "    case let .{variant_name}(payload):")?;
+        } else {
+            writeln!(body, "    case .{variant_name}:")?;
+        }
+
+        let lines = match (representation, variant.payload_type.is_some()) {
+            (EnumRepresentation::Internal, true) => vec![
+                format!("try container.encode(\"{wire_name}\", forKey: .{tag_name})"),
+                "try payload.encode(to: encoder)".to_string(),
+            ],
+            (EnumRepresentation::Internal, false) => vec![format!(
+                "try container.encode(\"{wire_name}\", forKey: .{tag_name})"
+            )],
+            (EnumRepresentation::External, true) => vec![
+                "var container = encoder.container(keyedBy: CodingKeys.self)".to_string(),
+                format!("try container.encode(payload, forKey: .{variant_name})"),
+            ],
+            (EnumRepresentation::External, false) => vec![
+                "var container = encoder.singleValueContainer()".to_string(),
+                format!("try container.encode(\"{wire_name}\")"),
+            ],
+            (_, true) => vec!["try payload.encode(to: encoder)".to_string()],
+            (_, false) => vec![
+                "var container = encoder.singleValueContainer()".to_string(),
+                "try container.encodeNil()".to_string(),
+            ],
+        };
+        for line in lines {
+            writeln!(body, "        {line}")?;
+        }
+    }
+    writeln!(body, "}}")?;
+    writeln!(result)?;
+    writeln!(result, "// encoder")?;
+    writeln!(result, "public func encode(to encoder: Encoder) throws {{")?;
+    writeln!(result, "{}", indent(body.trim(), 1))?;
+    writeln!(result, "}}")?;
+
+    Ok(result)
+}
+
 fn generate_memberwise_init(fields: &[FieldDef], package_name: &str) -> anyhow::Result<String> {
     let mut code = "".to_string();
 
@@ -345,6 +784,12 @@ fn generate_memberwise_init(fields: &[FieldDef], package_name: &str) -> anyhow::
                     "{field_name}: {}",
                     swift_type(&field.type_, package_name)
                 ));
+            } else if let Some(default) = &field.default {
+                field_params.push(format!(
+                    "{field_name}: {} = {}",
+                    swift_type(&field.type_, package_name),
+                    swift_literal(default)
+                ));
             } else {
                 field_params.push(format!(
                     "{field_name}: {}? = nil",
@@ -368,9 +813,99 @@ fn generate_memberwise_init(fields: &[FieldDef], package_name: &str) -> anyhow::
     Ok(code)
 }
 
+fn generate_decoder_init(fields: &[FieldDef], package_name: &str) -> anyhow::Result<String> {
+    let mut code = "".to_string();
+
+    writeln!(code, "public init(from decoder: Decoder) throws {{")?;
+    writeln!(
+        code,
+        "    let container = try decoder.container(keyedBy: CodingKeys.self)"
+    )?;
+    for field in fields.iter() {
+        let field_name = &field.name;
+        let field_type = swift_type(&field.type_, package_name);
+        if field.required {
+            writeln!(
+                code,
+                "    self.{field_name} = try container.decode({field_type}.self, forKey: .{field_name})"
+            )?;
+        } else if let Some(default) = &field.default {
+            writeln!(
+                code,
+                "    self.{field_name} = try container.decodeIfPresent({field_type}.self, forKey: .{field_name}) ?? {}",
+                swift_literal(default)
+            )?;
+        } else {
+            writeln!(
+                code,
+                "    self.{field_name} = try container.decodeIfPresent({field_type}.self, forKey: .{field_name})"
+            )?;
+        }
+    }
+    writeln!(code, "}}")?;
+
+    Ok(code)
+}
+
+fn swift_type_for_field(field: &FieldDef, package_name: &str) -> String {
+    let field_type = swift_type(&field.type_, package_name);
+    if field.required || field.default.is_some() {
+        field_type
+    } else {
+        format!("{field_type}?")
+    }
+}
+
+/// swift literal for json value
+fn swift_literal(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => "nil".to_string(),
+        serde_json::Value::Bool(b) => b.to_string(),
+        serde_json::Value::Number(n) => n.to_string(),
+        serde_json::Value::String(s) => swift_string(s),
+        serde_json::Value::Array(items) => format!(
+            "[{}]",
+            items
+                .iter()
+                .map(swift_literal)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        serde_json::Value::Object(items) if items.is_empty() => "[:]".to_string(),
+        serde_json::Value::Object(items) => format!(
+            "[{}]",
+            items
+                .iter()
+                .map(|(k, v)| format!("{}: {}", swift_string(k), swift_literal(v)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+/// swift string literal, json escapes like `\u0001` are invalid in swift
+fn swift_string(value: &str) -> String {
+    let mut result = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            '\0' => result.push_str("\\0"),
+            c if c.is_control() => write!(result, "\\u{{{:x}}}", c as u32).unwrap(),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::path::PathBuf;
 
     fn test_def(spec: &str, code_path: &str) {
         let context =
@@ -404,8 +939,61 @@ mod tests {
                 "enum_custom_tag.yaml",
                 "src/codegen/fixtures/swift_codable/enum_custom_tag.swift",
             ),
+            ("time.yaml", "src/codegen/fixtures/swift_codable/time.swift"),
+            (
+                "unsigned.yaml",
+                "src/codegen/fixtures/swift_codable/unsigned.swift",
+            ),
+            (
+                "default.yaml",
+                "src/codegen/fixtures/swift_codable/default.swift",
+            ),
+            (
+                "wire_name.yaml",
+                "src/codegen/fixtures/swift_codable/wire_name.swift",
+            ),
+            (
+                "enum_representation.yaml",
+                "src/codegen/fixtures/swift_codable/enum_representation.swift",
+            ),
+            (
+                "generic.yaml",
+                "src/codegen/fixtures/swift_codable/generic.swift",
+            ),
+            (
+                "map_key_set.yaml",
+                "src/codegen/fixtures/swift_codable/map_key_set.swift",
+            ),
         ] {
             test_def(spec, expected);
         }
     }
+
+    #[test]
+    fn test_time_wrappers() {
+        let context =
+            Context::new_from_folder(&PathBuf::from("src/codegen/fixtures/specs")).unwrap();
+        let rendered = render_time_wrappers(&context).unwrap();
+        let code_path = "src/codegen/fixtures/swift_codable/TotSpecTime.swift";
+
+        #[cfg(not(feature = "test_update_spec"))]
+        {
+            let code = std::fs::read_to_string(code_path).unwrap();
+            pretty_assertions::assert_eq!(code.trim(), rendered.as_str().trim());
+        }
+
+        #[cfg(feature = "test_update_spec")]
+        {
+            std::fs::write(code_path, rendered).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_swift_literal() {
+        let value = serde_json::json!({"k\"ey": ["café \"a\"\\\n\u{1}"]});
+        assert_eq!(
+            swift_literal(&value),
+            r#"["k\"ey": ["café \"a\"\\\n\u{1}"]]"#
+        );
+    }
 }
@@ -1,15 +1,16 @@
 use crate::{
     codegen::utils::{multiline_prefix_with, to_pascal_case},
     models::Definition,
-    ConstType, ConstValueDef, FieldDef, StringOrInteger, StructDef, Type, TypeReference,
-    VariantDef,
+    ConstType, ConstValueDef, EnumRepresentation, FieldDef, Nullability, StringOrInteger,
+    StructDef, TimestampFormat, Type, TypeReference, VariantDef,
 };
 use convert_case::Casing;
 use serde::{Deserialize, Serialize};
 use std::fmt::Write;
-use std::path::{Path, PathBuf};
+use std::path::Path;
 
 use super::context::Context;
+use super::output::{FileTree, OutputFile};
 
 pub struct TypeScript {
     context: Context,
@@ -60,26 +61,58 @@ impl TypeScript {
     fn ts_type(&self, ty: &Type) -> String {
         match ty {
             Type::Bool => "boolean".into(),
-            Type::I8 | Type::I16 | Type::I32 | Type::F64 => "number".into(),
-            Type::I64 => self.bigint_type(),
+            Type::I8
+            | Type::I16
+            | Type::I32
+            | Type::U8
+            | Type::U16
+            | Type::U32
+            | Type::F32
+            | Type::F64 => "number".into(),
+            Type::I64 | Type::U64 => self.bigint_type(),
             Type::Bytes => "Uint8Array".into(),
             Type::String => "string".into(),
+            Type::Timestamp {
+                format: TimestampFormat::Rfc3339,
+            }
+            | Type::Date => "string".into(),
+            Type::Timestamp {
+                format: TimestampFormat::EpochMillis,
+            }
+            | Type::Duration => "number".into(),
             Type::List { item_type } => {
                 format!("{}[]", self.ts_type(item_type))
             }
-            Type::Map { value_type } => {
-                format!("Record<string, {}>", self.ts_type(value_type))
+            Type::Set { item_type } => {
+                format!("Set<{}>", self.ts_type(item_type))
             }
-            Type::Reference(TypeReference {
-                namespace: None,
-                target,
-            }) => to_pascal_case(target),
-            Type::Reference(TypeReference {
-                namespace: Some(namespace),
-                target,
-            }) => {
-                format!("{}.{}", to_snake_case(namespace), to_pascal_case(target))
+            Type::Map {
+                key_type,
+                value_type,
+            } => match &**key_type {
+                // string const key is a union of literals, not all of them need to be present
+                Type::Reference(tref) => format!(
+                    "Partial<Record<{}, {}>>",
+                    self.get_type_reference(tref),
+                    self.ts_type(value_type)
+                ),
+                // i64 and u64 may not fit in number, keep the key as is
+                Type::I64 | Type::U64 => format!("Record<string, {}>", self.ts_type(value_type)),
+                key_type if key_type.is_integer() => {
+                    format!("Record<number, {}>", self.ts_type(value_type))
+                }
+                _ => format!("Record<string, {}>", self.ts_type(value_type)),
+            },
+            Type::Reference(tref) if tref.args.is_empty() => self.get_type_reference(tref),
+            Type::Reference(tref) => {
+                let args = tref
+                    .args
+                    .iter()
+                    .map(|a| self.ts_type(a))
+                    .collect::<Vec<_>>();
+                format!("{}<{}>", self.get_type_reference(tref), args.join(", "))
             }
+            Type::TypeParam { param } => param.clone(),
             Type::Json => self.json_type(),
             Type::Decimal => self.decimal_type(),
             Type::BigInt => self.bigint_type(),
@@ -100,10 +133,22 @@ impl TypeScript {
             .and_then(|v| v.parse::<bool>().ok())
             .unwrap_or(false);
 
-        if field.required || force_optional {
-            ty
-        } else {
-            format!("{} | undefined", ty)
+        match field.nullability {
+            _ if field.required || force_optional => ty,
+            // absence is expressed by `?:` on the property
+            Some(Nullability::Optional) => ty,
+            Some(Nullability::Nullable | Nullability::OptionalNullable) => format!("{} | null", ty),
+            None => format!("{} | undefined", ty),
+        }
+    }
+
+    /// property marker, `?` for field which may be absent, `!` for field always assigned
+    fn ts_property_marker(&self, field: &FieldDef) -> &'static str {
+        match field.nullability {
+            _ if field.required => "!",
+            Some(Nullability::Optional | Nullability::OptionalNullable) => "?",
+            Some(Nullability::Nullable) => "!",
+            None => "",
         }
     }
 
@@ -114,6 +159,7 @@ impl TypeScript {
     fn render_struct(
         &self,
         model_name: &str,
+        type_params: &[String],
         struct_def: &StructDef,
         def: &Definition,
         spec_path: &Path,
@@ -135,7 +181,7 @@ impl TypeScript {
         let json_fields: Vec<(String, String, &FieldDef)> = fields
             .iter()
             .map(|f| {
-                let json_name = f.name.clone();
+                let json_name = def.field_wire_name(f).into_owned();
                 let ts_type = self.ts_type_for_field(f);
                 (json_name, ts_type, f)
             })
@@ -143,11 +189,16 @@ impl TypeScript {
 
         // Generate TypeScript class
         let pascal_name = to_pascal_case(model_name);
+        let generics = match type_params.is_empty() {
+            true => "".to_string(),
+            false => format!("<{}>", type_params.join(", ")),
+        };
         writeln!(
             result,
-            "{} {} {{",
+            "{} {}{} {{",
             self.export_keyword("class"),
-            pascal_name
+            pascal_name,
+            generics
         )?;
 
         // Fields (camelCase)
@@ -167,17 +218,22 @@ impl TypeScript {
 
             let field_name = self.ts_field_name(field);
             let field_type = self.ts_type_for_field(field);
-            let definite_assignment = if field.required { "!" } else { "" };
             writeln!(
                 result,
                 "    {}{}: {};",
-                field_name, definite_assignment, field_type
+                field_name,
+                self.ts_property_marker(field),
+                field_type
             )?;
         }
 
         // Constructor
         writeln!(result)?;
-        writeln!(result, "    constructor(data: Partial<{}>) {{", pascal_name)?;
+        writeln!(
+            result,
+            "    constructor(data: Partial<{}{}>) {{",
+            pascal_name, generics
+        )?;
         writeln!(result, "        Object.assign(this, data);")?;
         writeln!(result, "    }}")?;
 
@@ -193,7 +249,12 @@ impl TypeScript {
             for (json_name, ts_type, field) in &json_fields {
                 let field_name = self.ts_field_name(field);
                 let converted = self.convert_to_json(&field_name, ts_type, field, spec_path);
-                writeln!(result, "            {}: {},", json_name, converted)?;
+                writeln!(
+                    result,
+                    "            {}: {},",
+                    ts_property_key(json_name),
+                    converted
+                )?;
             }
             writeln!(result, "        }};")?;
             writeln!(result, "    }}")?;
@@ -210,6 +271,24 @@ impl TypeScript {
             )?;
             writeln!(result, "        return new {}({{}});", pascal_name)?;
             writeln!(result, "    }}")?;
+        } else if !type_params.is_empty() {
+            // value of type param is converted by the fn passed in
+            let from_json_params = type_params
+                .iter()
+                .map(|p| format!(", fromJSON{p}: (json: any) => {p}"))
+                .collect::<String>();
+            writeln!(
+                result,
+                "    static fromJSON{generics}(json: any{from_json_params}): {pascal_name}{generics} {{",
+            )?;
+            writeln!(result, "        return new {}{}({{", pascal_name, generics)?;
+            for (json_name, _, field) in &json_fields {
+                let field_name = self.ts_field_name(field);
+                let converted = self.convert_from_json(json_name, field, spec_path);
+                writeln!(result, "            {}: {},", field_name, converted)?;
+            }
+            writeln!(result, "        }});")?;
+            writeln!(result, "    }}")?;
         } else {
             writeln!(result, "    static fromJSON(json: any): {} {{", pascal_name)?;
             writeln!(result, "        return new {}({{", pascal_name)?;
@@ -222,6 +301,13 @@ impl TypeScript {
             writeln!(result, "    }}")?;
         }
 
+        // validate method, only for struct with constraints
+        let validate = self.render_validate(&fields)?;
+        if !validate.is_empty() {
+            writeln!(result)?;
+            write!(result, "{}", validate)?;
+        }
+
         writeln!(result, "}}")?;
 
         // Export JSON type
@@ -229,9 +315,27 @@ impl TypeScript {
         if json_fields.is_empty() {
             writeln!(result, "export type {} = {{}};", json_type_name)?;
         } else {
-            writeln!(result, "export type {} = {{", json_type_name)?;
-            for (json_name, ts_type, _) in &json_fields {
-                writeln!(result, "    {}: {};", json_name, ts_type)?;
+            writeln!(result, "export type {}{} = {{", json_type_name, generics)?;
+            for (json_name, ts_type, field) in &json_fields {
+                let optional_marker = match field.nullability {
+                    Some(Nullability::Optional | Nullability::OptionalNullable) => "?",
+                    _ => "",
+                };
+                // set is encoded as array in json
+                let ts_type = match &*field.type_ {
+                    Type::Set { item_type } => {
+                        let item_type = self.ts_type(item_type);
+                        ts_type.replacen(&format!("Set<{item_type}>"), &format!("{item_type}[]"), 1)
+                    }
+                    _ => ts_type.clone(),
+                };
+                writeln!(
+                    result,
+                    "    {}{}: {};",
+                    ts_property_key(json_name),
+                    optional_marker,
+                    ts_type
+                )?;
             }
             writeln!(result, "}}")?;
         }
@@ -239,6 +343,87 @@ impl TypeScript {
         Ok(result)
     }
 
+    fn render_validate(&self, fields: &[FieldDef]) -> anyhow::Result<String> {
+        let mut result = String::new();
+        let fields = fields
+            .iter()
+            .filter(|f| !f.constraints.is_empty())
+            .collect::<Vec<_>>();
+        if fields.is_empty() {
+            return Ok(result);
+        }
+
+        writeln!(result, "    validate(): void {{")?;
+        for field in fields {
+            let json_name = &field.name;
+            let field_name = self.ts_field_name(field);
+            let constraints = &field.constraints;
+
+            let length_expr = match &*field.type_ {
+                Type::Map { .. } => "Object.keys(value).length",
+                Type::Set { .. } => "value.size",
+                _ => "value.length",
+            };
+
+            let mut checks = vec![];
+            if let Some(min) = &constraints.min {
+                checks.push((format!("value < {min}"), format!("expect value >= {min}")));
+            }
+            if let Some(max) = &constraints.max {
+                checks.push((format!("value > {max}"), format!("expect value <= {max}")));
+            }
+            if constraints.non_empty {
+                checks.push((
+                    format!("{length_expr} === 0"),
+                    "expect non empty value".to_string(),
+                ));
+            }
+            if let Some(min_length) = constraints.min_length {
+                checks.push((
+                    format!("{length_expr} < {min_length}"),
+                    format!("expect length >= {min_length}"),
+                ));
+            }
+            if let Some(max_length) = constraints.max_length {
+                checks.push((
+                    format!("{length_expr} > {max_length}"),
+                    format!("expect length <= {max_length}"),
+                ));
+            }
+            if let Some(pattern) = &constraints.pattern {
+                let pattern_literal = serde_json::to_string(pattern)?;
+                checks.push((
+                    format!("!new RegExp({pattern_literal}).test(value)"),
+                    format!("expect value to match {pattern_literal}"),
+                ));
+            }
+
+            writeln!(result, "        {{")?;
+            writeln!(result, "            const value = this.{field_name};")?;
+            let indent_level = if field.required {
+                3
+            } else {
+                writeln!(
+                    result,
+                    "            if (value !== undefined && value !== null) {{"
+                )?;
+                4
+            };
+            for (condition, message) in checks {
+                let message = serde_json::to_string(&format!("field:{json_name} {message}"))?;
+                let check = format!("if ({condition}) {{\n    throw new Error({message});\n}}");
+                writeln!(result, "{}", indent(check, indent_level))?;
+            }
+            if !field.required {
+                writeln!(result, "            }}")?;
+            }
+            writeln!(result, "        }}")?;
+        }
+        writeln!(result, "    }}")?;
+
+        Ok(result)
+    }
+
     fn convert_to_json(
         &self,
         field_name: &str,
@@ -247,11 +432,25 @@ impl TypeScript {
         spec_path: &Path,
     ) -> String {
         // Check if it's an optional type
-        let is_optional = ts_type.ends_with(" | undefined");
+        let is_optional = ts_type.ends_with(" | undefined")
+            || (!field.required && field.nullability == Some(Nullability::Optional));
+        // `&&` keeps both null and undefined as is
+        let is_nullable = ts_type.ends_with(" | null");
+
+        // json has no set, encode as array
+        if let Type::Set { .. } = &*field.type_ {
+            if field.required {
+                return format!("Array.from(this.{field_name})");
+            }
+            return format!("this.{0} && Array.from(this.{0})", field_name);
+        }
 
         // Check if this is a nested struct (Reference type)
         if let Type::Reference(tref) = &*field.type_ {
             if self.should_call_to_json(tref, spec_path) {
+                if is_nullable {
+                    return format!("this.{0} && this.{0}.toJSON()", field_name);
+                }
                 if is_optional {
                     return format!("this.{}?.toJSON()", field_name);
                 }
@@ -264,6 +463,9 @@ impl TypeScript {
         if let Type::List { item_type } = &*field.type_ {
             if let Type::Reference(tref) = &**item_type.as_ref() {
                 if self.should_call_to_json(tref, spec_path) {
+                    if is_nullable {
+                        return format!("this.{0} && this.{0}.map((e) => e.toJSON())", field_name);
+                    }
                     if is_optional {
                         return format!("this.{}?.map((e) => e.toJSON())", field_name);
                     }
@@ -326,22 +528,38 @@ impl TypeScript {
     }
 
     fn convert_from_json(&self, json_name: &str, field: &FieldDef, spec_path: &Path) -> String {
+        let json_expr = ts_property_access("json", json_name);
         let ts_type = self.ts_type_for_field(field);
-        let is_optional = ts_type.ends_with(" | undefined");
+        let is_optional = ts_type.ends_with(" | undefined")
+            || (!field.required && field.nullability == Some(Nullability::Optional));
+        let is_nullable = ts_type.ends_with(" | null");
+
+        if is_generic(&field.type_) || matches!(&*field.type_, Type::Set { .. }) {
+            let Some(converted) = self.parse_json_expr(&field.type_, &json_expr, spec_path) else {
+                return json_expr;
+            };
+            if field.required {
+                return converted;
+            }
+            return format!("{json_expr} == null ? {json_expr} : {converted}");
+        }
 
         // Check if this is a nested struct (Reference type)
         if let Type::Reference(tref) = &*field.type_ {
             let target_type = self.get_type_reference(tref);
             if self.should_call_from_json(tref, spec_path) {
+                if is_nullable {
+                    return format!("{0} && {1}.fromJSON({0})", json_expr, target_type);
+                }
                 if is_optional {
                     return format!(
-                        "json.{} ? {}.fromJSON(json.{}) : undefined",
-                        json_name, target_type, json_name
+                        "{} ? {}.fromJSON({}) : undefined",
+                        json_expr, target_type, json_expr
                     );
                 }
-                return format!("{}.fromJSON(json.{})", target_type, json_name);
+                return format!("{}.fromJSON({})", target_type, json_expr);
             }
-            return format!("json.{}", json_name);
+            return json_expr;
         }
 
         // Check if this is an array of references
@@ -349,28 +567,65 @@ impl TypeScript {
             if let Type::Reference(tref) = &**item_type.as_ref() {
                 let target_type = self.get_type_reference(tref);
                 if self.should_call_from_json(tref, spec_path) {
+                    if is_nullable {
+                        return format!(
+                            "{0} && {0}.map((e: any) => {1}.fromJSON(e))",
+                            json_expr, target_type
+                        );
+                    }
                     if is_optional {
                         return format!(
-                            "json.{}?.map((e: any) => {}.fromJSON(e))",
-                            json_name, target_type
+                            "{}?.map((e: any) => {}.fromJSON(e))",
+                            json_expr, target_type
                         );
                     }
-                    return format!(
-                        "json.{}.map((e: any) => {}.fromJSON(e))",
-                        json_name, target_type
-                    );
+                    return format!("{}.map((e: any) => {}.fromJSON(e))", json_expr, target_type);
                 }
             }
         }
 
         // For primitive types, arrays, Record, just return the field
-        format!("json.{}", json_name)
+        json_expr
+    }
+
+    /// expression converts json `expr` to `ty`, None if no conversion is needed
+    fn parse_json_expr(&self, ty: &Type, expr: &str, spec_path: &Path) -> Option<String> {
+        match ty {
+            Type::TypeParam { param } => Some(format!("fromJSON{param}({expr})")),
+            Type::Reference(tref) if self.should_call_from_json(tref, spec_path) => {
+                let args = tref
+                    .args
+                    .iter()
+                    .map(|arg| match self.parse_json_expr(arg, "e", spec_path) {
+                        Some(converted) => format!(", (e: any) => {converted}"),
+                        None => ", (e: any) => e".to_string(),
+                    })
+                    .collect::<String>();
+                Some(format!(
+                    "{}.fromJSON({expr}{args})",
+                    self.get_type_reference(tref)
+                ))
+            }
+            Type::List { item_type } => {
+                let converted = self.parse_json_expr(item_type, "e", spec_path)?;
+                Some(format!("{expr}.map((e: any) => {converted})"))
+            }
+            Type::Set { .. } => Some(format!("new Set({expr})")),
+            Type::Map { value_type, .. } => {
+                let converted = self.parse_json_expr(value_type, "v", spec_path)?;
+                Some(format!(
+                    "Object.fromEntries(Object.entries({expr}).map(([k, v]: [string, any]) => [k, {converted}]))"
+                ))
+            }
+            _ => None,
+        }
     }
 
     fn render_enum(
         &self,
         model: &crate::ModelDef,
         variants: &[VariantDef],
+        def: &Definition,
         tag_name: &str,
         payload_name: &str,
     ) -> anyhow::Result<String> {
@@ -395,7 +650,12 @@ impl TypeScript {
                 write!(result, "    ")?;
             }
 
-            write!(result, "{{ {}: \"{}\"", tag_name, variant.name)?;
+            write!(
+                result,
+                "{{ {}: \"{}\"",
+                tag_name,
+                def.variant_wire_name(variant)
+            )?;
 
             if let Some(payload_type) = &variant.payload_type {
                 let payload_ts = self.ts_type(payload_type);
@@ -425,6 +685,81 @@ impl TypeScript {
         Ok(result)
     }
 
+    /// render enum for internal, external and untagged representation
+    fn render_enum_with_representation(
+        &self,
+        model: &crate::ModelDef,
+        variants: &[VariantDef],
+        def: &Definition,
+        representation: EnumRepresentation,
+        tag_name: &str,
+    ) -> anyhow::Result<String> {
+        let mut result = String::new();
+
+        writeln!(
+            result,
+            "{} {} =",
+            self.export_keyword("type"),
+            to_pascal_case(&model.name)
+        )?;
+
+        for (idx, variant) in variants.iter().enumerate() {
+            if idx == 0 {
+                write!(result, "    ")?;
+            } else {
+                write!(result, "    | ")?;
+            }
+
+            if let Some(desc) = &variant.desc {
+                writeln!(result, "{}", indent(multiline_prefix_with(desc, "// "), 1))?;
+                write!(result, "    ")?;
+            }
+
+            let wire_name = def.variant_wire_name(variant);
+            let payload_ts = match (&variant.payload_type, &variant.payload_fields) {
+                (Some(payload_type), _) => Some(self.ts_type(payload_type)),
+                (None, Some(fields)) => {
+                    let mut fields_ts = "{".to_string();
+                    for field in fields {
+                        write!(
+                            fields_ts,
+                            " {}: {};",
+                            ts_property_key(&def.field_wire_name(field)),
+                            self.ts_type_for_field(field)
+                        )?;
+                    }
+                    fields_ts.push_str(" }");
+                    Some(fields_ts)
+                }
+                (None, None) => None,
+            };
+
+            let variant_ts = match (representation, payload_ts) {
+                (EnumRepresentation::Internal, Some(payload_ts)) => {
+                    format!("({{ {tag_name}: \"{wire_name}\" }} & {payload_ts})")
+                }
+                (EnumRepresentation::Internal, None) => {
+                    format!("{{ {tag_name}: \"{wire_name}\" }}")
+                }
+                (EnumRepresentation::External, Some(payload_ts)) => {
+                    format!("{{ {}: {payload_ts} }}", ts_property_key(&wire_name))
+                }
+                (EnumRepresentation::External, None) => format!("\"{wire_name}\""),
+                (_, Some(payload_ts)) => payload_ts,
+                (_, None) => "null".to_string(),
+            };
+            writeln!(result, "{}", variant_ts)?;
+
+            if idx < variants.len() - 1 {
+                writeln!(result)?;
+            }
+        }
+
+        writeln!(result, ";")?;
+
+        Ok(result)
+    }
+
     fn render_virtual(&self, model_name: &str, struct_def: &StructDef) -> String {
         let mut result = String::new();
 
@@ -567,22 +902,35 @@ impl TypeScript {
                     variants,
                     tag_name,
                     payload_name,
+                    representation,
                 } => {
-                    let tag_name = tag_name.clone().unwrap_or_else(|| "type".to_string());
-                    let payload_name = payload_name
-                        .clone()
-                        .unwrap_or_else(|| "payload".to_string());
-                    writeln!(
-                        result,
-                        "{}",
-                        self.render_enum(model, variants, &tag_name, &payload_name)?
-                    )?;
+                    let tag_name = tag_name.as_deref().unwrap_or("type");
+                    let payload_name = payload_name.as_deref().unwrap_or("payload");
+                    let code = match representation {
+                        EnumRepresentation::Adjacent => {
+                            self.render_enum(model, variants, def, tag_name, payload_name)?
+                        }
+                        _ => self.render_enum_with_representation(
+                            model,
+                            variants,
+                            def,
+                            *representation,
+                            tag_name,
+                        )?,
+                    };
+                    writeln!(result, "{}", code)?;
                 }
                 crate::ModelType::Struct(struct_def) => {
                     writeln!(
                         result,
                         "{}",
-                        self.render_struct(&model.name, struct_def, def, spec_path)?
+                        self.render_struct(
+                            &model.name,
+                            &model.type_params,
+                            struct_def,
+                            def,
+                            spec_path
+                        )?
                     )?;
                 }
                 crate::ModelType::Virtual(struct_def) => {
@@ -614,34 +962,27 @@ impl TypeScript {
 }
 
 impl super::Codegen for TypeScript {
-    fn load_from_folder(folder: &PathBuf) -> anyhow::Result<Self>
+    fn load_from_context(context: &Context) -> anyhow::Result<Self>
     where
         Self: Sized,
     {
-        let context = Context::new_from_folder(folder)?;
         let config = context.load_codegen_config::<TypeScriptConfig>("typescript")?;
 
         Ok(Self {
-            context,
+            context: context.clone(),
             config: config.unwrap_or_default(),
         })
     }
 
-    fn generate_for_folder(&self, _folder: &PathBuf, output: &PathBuf) -> anyhow::Result<()> {
+    fn generate(&self) -> anyhow::Result<FileTree> {
+        let mut tree = FileTree::new();
         for (spec_path, _) in self.context.iter_specs() {
-            let mut output_path = output.join(spec_path);
+            let mut output_path = spec_path.clone();
             output_path.set_extension("ts");
-
-            let parent_folder = output_path.parent().unwrap();
-            std::fs::create_dir_all(parent_folder)?;
-
-            let code = self.render(spec_path)?;
-
-            std::fs::write(&output_path, code)?;
-            println!("write output to {:?}", output_path);
+            tree.add(OutputFile::new(output_path, self.render(spec_path)?));
         }
 
-        Ok(())
+        Ok(tree)
     }
 }
 
@@ -718,6 +1059,18 @@ fn to_camel_case_reserved(name: &str) -> String {
     }
 }
 
+/// whether the type refers to type params or generic models
+fn is_generic(ty: &Type) -> bool {
+    match ty {
+        Type::TypeParam { .. } => true,
+        Type::Reference(tref) => !tref.args.is_empty(),
+        Type::List { item_type } => is_generic(item_type),
+        Type::Set { item_type } => is_generic(item_type),
+        Type::Map { value_type, .. } => is_generic(value_type),
+        _ => false,
+    }
+}
+
 fn to_snake_case(name: &str) -> String {
     let mut result = String::new();
     for c in name.chars() {
@@ -733,6 +1086,33 @@ fn to_snake_case(name: &str) -> String {
     result
 }
 
+fn is_ts_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .map(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        .unwrap_or_default()
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+/// key in object literal or type, quoted if not a valid identifier
+fn ts_property_key(name: &str) -> String {
+    if is_ts_identifier(name) {
+        name.to_string()
+    } else {
+        format!("\"{name}\"")
+    }
+}
+
+/// property access expression for `obj`, use index access if not a valid identifier
+fn ts_property_access(obj: &str, name: &str) -> String {
+    if is_ts_identifier(name) {
+        format!("{obj}.{name}")
+    } else {
+        format!("{obj}[\"{name}\"]")
+    }
+}
+
 fn indent(s: String, level: usize) -> String {
     let indent = "    ".repeat(level);
     s.lines()
@@ -752,6 +1132,7 @@ fn ts_const_literal(val: &StringOrInteger) -> String {
 mod tests {
     use super::*;
     use crate::codegen::Codegen;
+    use std::path::PathBuf;
 
     #[test]
     fn test_render() {
@@ -822,6 +1203,38 @@ mod tests {
                 "src/codegen/fixtures/specs/enum_custom_tag.yaml",
                 "src/codegen/fixtures/typescript/enum_custom_tag.ts",
             ),
+            (
+                "src/codegen/fixtures/specs/time.yaml",
+                "src/codegen/fixtures/typescript/time.ts",
+            ),
+            (
+                "src/codegen/fixtures/specs/unsigned.yaml",
+                "src/codegen/fixtures/typescript/unsigned.ts",
+            ),
+            (
+                "src/codegen/fixtures/specs/constraints.yaml",
+                "src/codegen/fixtures/typescript/constraints.ts",
+            ),
+            (
+                "src/codegen/fixtures/specs/wire_name.yaml",
+                "src/codegen/fixtures/typescript/wire_name.ts",
+            ),
+            (
+                "src/codegen/fixtures/specs/nullability.yaml",
+                "src/codegen/fixtures/typescript/nullability.ts",
+            ),
+            (
+                "src/codegen/fixtures/specs/enum_representation.yaml",
+                "src/codegen/fixtures/typescript/enum_representation.ts",
+            ),
+            (
+                "src/codegen/fixtures/specs/generic.yaml",
+                "src/codegen/fixtures/typescript/generic.ts",
+            ),
+            (
+                "src/codegen/fixtures/specs/map_key_set.yaml",
+                "src/codegen/fixtures/typescript/map_key_set.ts",
+            ),
         ] {
             test_def(PathBuf::from(spec).as_path(), expected);
         }
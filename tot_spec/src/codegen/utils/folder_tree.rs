@@ -2,7 +2,7 @@ use std::path::PathBuf;
 
 /// Helper to build a tree of folders
 /// makes it easy to iterate over the folders and files in a specific order
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct FolderTree {
     root: Entry,
 }
@@ -32,7 +32,7 @@ impl FolderTree {
 }
 
 /// A single entry in the folder tree
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct Entry {
     path: PathBuf,
     component: String,
@@ -0,0 +1,227 @@
+use serde::Serialize;
+use std::fmt;
+use std::path::PathBuf;
+
+/// Position in spec file, both line and column start from 1
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// A problem found in spec, rendered as `file:line:col: message` followed by a snippet
+#[derive(Debug, Clone, Serialize)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// kind of the problem, e.g: `unresolved-reference`
+    pub code: &'static str,
+    /// path of the spec file
+    pub spec: PathBuf,
+    pub model: Option<String>,
+    /// field, variant or const value of the model
+    pub field: Option<String>,
+    pub message: String,
+    pub location: Option<Location>,
+    /// the line at location
+    pub snippet: Option<String>,
+}
+
+impl Diagnostic {
+    pub fn new(spec: impl Into<PathBuf>, code: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Error,
+            code,
+            spec: spec.into(),
+            model: None,
+            field: None,
+            message: message.into(),
+            location: None,
+            snippet: None,
+        }
+    }
+
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    pub fn with_model(mut self, model: impl Into<String>) -> Self {
+        self.model = Some(model.into());
+        self
+    }
+
+    pub fn with_field(mut self, field: impl Into<String>) -> Self {
+        self.field = Some(field.into());
+        self
+    }
+
+    pub fn with_location(mut self, location: Location, snippet: Option<&str>) -> Self {
+        self.location = Some(location);
+        self.snippet = snippet.map(Into::into);
+        self
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(location) = self.location else {
+            return write!(f, "{}: {}", self.spec.display(), self.message);
+        };
+        write!(
+            f,
+            "{}:{}:{}: {}",
+            self.spec.display(),
+            location.line,
+            location.column,
+            self.message
+        )?;
+
+        if let Some(snippet) = &self.snippet {
+            let line_no = location.line.to_string();
+            let gutter = " ".repeat(line_no.len());
+            let marker_indent = " ".repeat(location.column.saturating_sub(1));
+            write!(f, "\n{gutter} |")?;
+            write!(f, "\n{line_no} | {snippet}")?;
+            write!(f, "\n{gutter} | {marker_indent}^")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Diagnostic {}
+
+/// All problems found in specs, returned as error when loading specs fails
+#[derive(Debug, Clone, Default)]
+pub struct Diagnostics(pub Vec<Diagnostic>);
+
+impl Diagnostics {
+    /// json array of diagnostics
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(&self.0).unwrap()
+    }
+
+    /// SARIF 2.1.0 log, which can be uploaded to code scanning tools
+    pub fn to_sarif(&self) -> serde_json::Value {
+        let mut rules = self.0.iter().map(|d| d.code).collect::<Vec<_>>();
+        rules.sort();
+        rules.dedup();
+
+        let results = self
+            .0
+            .iter()
+            .map(|d| {
+                let mut physical_location = serde_json::json!({
+                    "artifactLocation": {
+                        "uri": d.spec.to_string_lossy().replace('\\', "/"),
+                    },
+                });
+                if let Some(location) = d.location {
+                    physical_location["region"] = serde_json::json!({
+                        "startLine": location.line,
+                        "startColumn": location.column,
+                    });
+                }
+                serde_json::json!({
+                    "ruleId": d.code,
+                    "level": d.severity,
+                    "message": { "text": d.message },
+                    "locations": [{ "physicalLocation": physical_location }],
+                })
+            })
+            .collect::<Vec<_>>();
+
+        serde_json::json!({
+            "version": "2.1.0",
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "tot_spec",
+                        "informationUri": "https://github.com/shuoli84/tot_spec",
+                        "rules": rules.iter().map(|id| serde_json::json!({ "id": id })).collect::<Vec<_>>(),
+                    },
+                },
+                "results": results,
+            }],
+        })
+    }
+}
+
+impl From<Diagnostic> for Diagnostics {
+    fn from(value: Diagnostic) -> Self {
+        Self(vec![value])
+    }
+}
+
+impl fmt::Display for Diagnostics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for diagnostic in self.0.iter() {
+            writeln!(f, "{diagnostic}")?;
+        }
+        write!(f, "{} problems found", self.0.len())
+    }
+}
+
+impl std::error::Error for Diagnostics {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sarif() {
+        let diagnostics = Diagnostics(vec![
+            Diagnostic::new(
+                "spec/a.yaml",
+                "unresolved-reference",
+                "A references unknown model B",
+            )
+            .with_model("A")
+            .with_field("b")
+            .with_location(
+                Location {
+                    line: 3,
+                    column: 17,
+                },
+                Some("    type: B"),
+            ),
+            Diagnostic::new("spec/b.yaml", "parse-error", "invalid yaml"),
+        ]);
+
+        let json = diagnostics.to_json();
+        assert_eq!(json[0]["severity"], "error");
+        assert_eq!(json[0]["code"], "unresolved-reference");
+        assert_eq!(json[0]["spec"], "spec/a.yaml");
+        assert_eq!(json[0]["model"], "A");
+        assert_eq!(json[0]["field"], "b");
+        assert_eq!(json[0]["location"]["line"], 3);
+        assert_eq!(json[1]["location"], serde_json::Value::Null);
+
+        let sarif = diagnostics.to_sarif();
+        let run = &sarif["runs"][0];
+        assert_eq!(
+            run["tool"]["driver"]["rules"],
+            serde_json::json!([{"id": "parse-error"}, {"id": "unresolved-reference"}])
+        );
+        let result = &run["results"][0];
+        assert_eq!(result["ruleId"], "unresolved-reference");
+        assert_eq!(result["level"], "error");
+        assert_eq!(
+            result["locations"][0]["physicalLocation"],
+            serde_json::json!({
+                "artifactLocation": {"uri": "spec/a.yaml"},
+                "region": {"startLine": 3, "startColumn": 17},
+            })
+        );
+        assert!(run["results"][1]["locations"][0]["physicalLocation"]
+            .get("region")
+            .is_none());
+    }
+}
@@ -0,0 +1,624 @@
+use crate::codegen::Context;
+use crate::{
+    ConstValueDef, Definition, FieldDef, MethodDef, ModelDef, ModelType, TimestampFormat, Type,
+    TypeReference, VariantDef,
+};
+use serde::Serialize;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// A change of model, field, variant, const value or method between two versions of specs
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Change {
+    /// whether specs or data of the old version may not work with the new one
+    pub breaking: bool,
+    /// kind of the change, e.g: `field-removed`
+    pub code: &'static str,
+    /// path of the spec relative to root folder
+    pub spec: PathBuf,
+    pub model: Option<String>,
+    pub method: Option<String>,
+    /// field, variant or const value of the model, variant's payload field is `Variant.field`
+    pub member: Option<String>,
+    pub message: String,
+}
+
+impl Change {
+    fn new(spec: &Path, code: &'static str, breaking: bool, message: impl Into<String>) -> Self {
+        Self {
+            breaking,
+            code,
+            spec: spec.to_path_buf(),
+            model: None,
+            method: None,
+            member: None,
+            message: message.into(),
+        }
+    }
+
+    fn with_model(mut self, model: impl Into<String>) -> Self {
+        self.model = Some(model.into());
+        self
+    }
+
+    fn with_method(mut self, method: impl Into<String>) -> Self {
+        self.method = Some(method.into());
+        self
+    }
+
+    fn with_member(mut self, member: impl Into<String>) -> Self {
+        self.member = Some(member.into());
+        self
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let level = if self.breaking {
+            "breaking"
+        } else {
+            "non-breaking"
+        };
+        write!(f, "{level}: {}", self.spec.display())?;
+        if let Some(item) = self.model.as_ref().or(self.method.as_ref()) {
+            write!(f, " {item}")?;
+            if let Some(member) = &self.member {
+                write!(f, ".{member}")?;
+            }
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// All changes between two versions of specs
+#[derive(Debug, Clone, Default)]
+pub struct Changes(pub Vec<Change>);
+
+impl Changes {
+    pub fn breaking_count(&self) -> usize {
+        self.0.iter().filter(|c| c.breaking).count()
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "breaking": self.breaking_count() > 0,
+            "changes": self.0,
+        })
+    }
+}
+
+impl fmt::Display for Changes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for change in self.0.iter() {
+            writeln!(f, "{change}")?;
+        }
+        write!(
+            f,
+            "{} changes found, {} breaking",
+            self.0.len(),
+            self.breaking_count()
+        )
+    }
+}
+
+/// compare specs in base and head, specs are matched by path and items by name.
+///
+/// Breaking: removed spec item, changed type, kind, wire name or enum representation, field
+/// became required or optional, added required field, changed constraints unless all removed,
+/// renamed or changed const value.
+/// Non-breaking: added spec item or optional field, removed constraints, changed desc or default.
+pub fn diff(base: &Context, head: &Context) -> Changes {
+    let mut specs = base
+        .iter_specs()
+        .chain(head.iter_specs())
+        .map(|(spec, _)| spec.clone())
+        .collect::<Vec<_>>();
+    specs.sort();
+    specs.dedup();
+
+    let empty = Definition::default();
+    let mut changes = vec![];
+    for spec in specs.iter() {
+        let base_def = base.get_definition(spec).unwrap_or(&empty);
+        let head_def = head.get_definition(spec).unwrap_or(&empty);
+        DefinitionDiff {
+            spec,
+            base: base_def,
+            head: head_def,
+            changes: &mut changes,
+        }
+        .diff();
+    }
+    Changes(changes)
+}
+
+struct DefinitionDiff<'a> {
+    spec: &'a Path,
+    base: &'a Definition,
+    head: &'a Definition,
+    changes: &'a mut Vec<Change>,
+}
+
+impl DefinitionDiff<'_> {
+    fn diff(&mut self) {
+        for (name, base, head) in match_by_name(&self.base.models, &self.head.models, |m| &m.name) {
+            match (base, head) {
+                (Some(base), Some(head)) => self.diff_model(base, head),
+                (Some(_), None) => self.model_change(&name, "model-removed", true, "model removed"),
+                (None, Some(_)) => self.model_change(&name, "model-added", false, "model added"),
+                (None, None) => {}
+            }
+        }
+
+        let (base_methods, head_methods) = (&self.base.methods, &self.head.methods);
+        for (name, base, head) in match_by_name(base_methods, head_methods, |m| &m.name) {
+            let change = match (base, head) {
+                (Some(base), Some(head)) => {
+                    self.diff_method(base, head);
+                    continue;
+                }
+                (Some(_), None) => Change::new(self.spec, "method-removed", true, "method removed"),
+                (None, Some(_)) => Change::new(self.spec, "method-added", false, "method added"),
+                (None, None) => continue,
+            };
+            self.changes.push(change.with_method(name));
+        }
+    }
+
+    fn model_change(&mut self, model: &str, code: &'static str, breaking: bool, message: &str) {
+        self.changes
+            .push(Change::new(self.spec, code, breaking, message).with_model(model));
+    }
+
+    fn member_change(
+        &mut self,
+        model: &str,
+        member: &str,
+        code: &'static str,
+        breaking: bool,
+        message: impl Into<String>,
+    ) {
+        self.changes.push(
+            Change::new(self.spec, code, breaking, message)
+                .with_model(model)
+                .with_member(member),
+        );
+    }
+
+    fn diff_model(&mut self, base: &ModelDef, head: &ModelDef) {
+        let model = base.name.as_str();
+        if base.desc != head.desc {
+            self.model_change(model, "desc-changed", false, "desc changed");
+        }
+        if base.type_params != head.type_params {
+            let message = format!(
+                "type params changed from <{}> to <{}>",
+                base.type_params.join(", "),
+                head.type_params.join(", ")
+            );
+            self.model_change(model, "type-params-changed", true, &message);
+        }
+
+        match (&base.type_, &head.type_) {
+            (ModelType::Struct(base_st), ModelType::Struct(head_st))
+            | (ModelType::Virtual(base_st), ModelType::Virtual(head_st)) => {
+                if base_st.extend != head_st.extend {
+                    let message = format!(
+                        "extend changed from {} to {}",
+                        base_st.extend.as_deref().unwrap_or("none"),
+                        head_st.extend.as_deref().unwrap_or("none")
+                    );
+                    self.model_change(model, "extend-changed", true, &message);
+                }
+                self.diff_fields(model, None, &base_st.fields, &head_st.fields);
+            }
+            (
+                ModelType::Enum {
+                    variants: base_variants,
+                    tag_name: base_tag,
+                    payload_name: base_payload,
+                    representation: base_repr,
+                },
+                ModelType::Enum {
+                    variants: head_variants,
+                    tag_name: head_tag,
+                    payload_name: head_payload,
+                    representation: head_repr,
+                },
+            ) => {
+                if (base_tag, base_payload, base_repr) != (head_tag, head_payload, head_repr) {
+                    self.model_change(
+                        model,
+                        "representation-changed",
+                        true,
+                        "representation, tag_name or payload_name changed",
+                    );
+                }
+                self.diff_variants(model, base_variants, head_variants);
+            }
+            (
+                ModelType::NewType {
+                    inner_type: base_inner,
+                },
+                ModelType::NewType {
+                    inner_type: head_inner,
+                },
+            ) => {
+                let (base_ty, head_ty) = (type_string(base_inner), type_string(head_inner));
+                if base_ty != head_ty {
+                    let message = format!("inner type changed from {base_ty} to {head_ty}");
+                    self.model_change(model, "type-changed", true, &message);
+                }
+            }
+            (
+                ModelType::Const {
+                    value_type: base_value_type,
+                    values: base_values,
+                },
+                ModelType::Const {
+                    value_type: head_value_type,
+                    values: head_values,
+                },
+            ) => {
+                let (base_ty, head_ty) =
+                    (json_string(base_value_type), json_string(head_value_type));
+                if base_ty != head_ty {
+                    // value type is serialized as a json string, e.g: "i8"
+                    let message = format!(
+                        "value type changed from {} to {}",
+                        base_ty.trim_matches('"'),
+                        head_ty.trim_matches('"')
+                    );
+                    self.model_change(model, "type-changed", true, &message);
+                }
+                self.diff_const_values(model, base_values, head_values);
+            }
+            (base_type, head_type) => {
+                let message = format!(
+                    "kind changed from {} to {}",
+                    model_kind(base_type),
+                    model_kind(head_type)
+                );
+                self.model_change(model, "kind-changed", true, &message);
+            }
+        }
+    }
+
+    /// fields of struct, or payload fields of variant if variant is set
+    fn diff_fields(
+        &mut self,
+        model: &str,
+        variant: Option<&str>,
+        base: &[FieldDef],
+        head: &[FieldDef],
+    ) {
+        for (name, base_field, head_field) in match_by_name(base, head, |f| &f.name) {
+            let member = match variant {
+                Some(variant) => format!("{variant}.{name}"),
+                None => name.clone(),
+            };
+            let (base_field, head_field) = match (base_field, head_field) {
+                (Some(base_field), Some(head_field)) => (base_field, head_field),
+                (Some(_), None) => {
+                    self.member_change(model, &member, "field-removed", true, "field removed");
+                    continue;
+                }
+                (None, Some(head_field)) if head_field.required => {
+                    let message = "required field added";
+                    self.member_change(model, &member, "required-field-added", true, message);
+                    continue;
+                }
+                (None, Some(_)) => {
+                    self.member_change(model, &member, "field-added", false, "field added");
+                    continue;
+                }
+                (None, None) => continue,
+            };
+
+            let (base_ty, head_ty) = (
+                type_string(&base_field.type_),
+                type_string(&head_field.type_),
+            );
+            if base_ty != head_ty {
+                let message = format!("type changed from {base_ty} to {head_ty}");
+                self.member_change(model, &member, "type-changed", true, message);
+            }
+            match (base_field.required, head_field.required) {
+                (false, true) => {
+                    let message = "field became required";
+                    self.member_change(model, &member, "field-required", true, message);
+                }
+                (true, false) => {
+                    let message = "field became optional";
+                    self.member_change(model, &member, "field-optional", true, message);
+                }
+                _ => {}
+            }
+            if base_field.nullability != head_field.nullability {
+                let message = "nullability changed";
+                self.member_change(model, &member, "nullability-changed", true, message);
+            }
+            let base_wire_name = self.base.field_wire_name(base_field);
+            let head_wire_name = self.head.field_wire_name(head_field);
+            if base_wire_name != head_wire_name {
+                let message =
+                    format!("wire name changed from {base_wire_name} to {head_wire_name}");
+                self.member_change(model, &member, "wire-name-changed", true, message);
+            }
+            if json_string(&base_field.constraints) != json_string(&head_field.constraints) {
+                // loosened constraints are not told apart, only removing all is safe
+                let breaking = !head_field.constraints.is_empty();
+                let message = "constraints changed";
+                self.member_change(model, &member, "constraints-changed", breaking, message);
+            }
+            if base_field.default != head_field.default {
+                let message = "default changed";
+                self.member_change(model, &member, "default-changed", false, message);
+            }
+            if base_field.desc != head_field.desc {
+                self.member_change(model, &member, "desc-changed", false, "desc changed");
+            }
+        }
+    }
+
+    fn diff_variants(&mut self, model: &str, base: &[VariantDef], head: &[VariantDef]) {
+        for (name, base_variant, head_variant) in match_by_name(base, head, |v| &v.name) {
+            let (base_variant, head_variant) = match (base_variant, head_variant) {
+                (Some(base_variant), Some(head_variant)) => (base_variant, head_variant),
+                (Some(_), None) => {
+                    let message = "variant removed";
+                    self.member_change(model, &name, "variant-removed", true, message);
+                    continue;
+                }
+                (None, Some(_)) => {
+                    let message = "variant added";
+                    self.member_change(model, &name, "variant-added", false, message);
+                    continue;
+                }
+                (None, None) => continue,
+            };
+
+            let base_ty = base_variant.payload_type.as_ref().map(|ty| type_string(ty));
+            let head_ty = head_variant.payload_type.as_ref().map(|ty| type_string(ty));
+            if base_ty != head_ty
+                || base_variant.payload_fields.is_some() != head_variant.payload_fields.is_some()
+            {
+                let message = format!(
+                    "payload changed from {} to {}",
+                    payload_string(base_variant),
+                    payload_string(head_variant)
+                );
+                self.member_change(model, &name, "payload-changed", true, message);
+            } else if let (Some(base_fields), Some(head_fields)) =
+                (&base_variant.payload_fields, &head_variant.payload_fields)
+            {
+                self.diff_fields(model, Some(&name), base_fields, head_fields);
+            }
+
+            let base_wire_name = self.base.variant_wire_name(base_variant);
+            let head_wire_name = self.head.variant_wire_name(head_variant);
+            if base_wire_name != head_wire_name {
+                let message =
+                    format!("wire name changed from {base_wire_name} to {head_wire_name}");
+                self.member_change(model, &name, "wire-name-changed", true, message);
+            }
+            if base_variant.desc != head_variant.desc {
+                self.member_change(model, &name, "desc-changed", false, "desc changed");
+            }
+        }
+    }
+
+    fn diff_const_values(&mut self, model: &str, base: &[ConstValueDef], head: &[ConstValueDef]) {
+        let has_name = |values: &[ConstValueDef], name: &str| values.iter().any(|v| v.name == name);
+        // name of the value in values
+        let name_of_value = |values: &[ConstValueDef], value: &str| {
+            values
+                .iter()
+                .find(|v| json_string(&v.value) == value)
+                .map(|v| v.name.clone())
+        };
+
+        for (name, base_value, head_value) in match_by_name(base, head, |v| &v.name) {
+            match (base_value, head_value) {
+                (Some(base_value), Some(head_value)) => {
+                    let (base_v, head_v) = (
+                        json_string(&base_value.value),
+                        json_string(&head_value.value),
+                    );
+                    if base_v != head_v {
+                        let message = format!("value changed from {base_v} to {head_v}");
+                        self.member_change(model, &name, "const-value-changed", true, message);
+                    }
+                    if base_value.desc != head_value.desc {
+                        self.member_change(model, &name, "desc-changed", false, "desc changed");
+                    }
+                }
+                (Some(base_value), None) => {
+                    let value = json_string(&base_value.value);
+                    match name_of_value(head, &value) {
+                        Some(new_name) if !has_name(base, &new_name) => {
+                            let message = format!("renamed to {new_name}");
+                            self.member_change(model, &name, "const-value-renamed", true, message);
+                        }
+                        _ => {
+                            let message = "value removed";
+                            self.member_change(model, &name, "const-value-removed", true, message);
+                        }
+                    }
+                }
+                (None, Some(head_value)) => {
+                    // the new name of a renamed value is reported by the rename
+                    let value = json_string(&head_value.value);
+                    let renamed = name_of_value(base, &value)
+                        .map_or(false, |old_name| !has_name(head, &old_name));
+                    if !renamed {
+                        let message = "value added";
+                        self.member_change(model, &name, "const-value-added", false, message);
+                    }
+                }
+                (None, None) => {}
+            }
+        }
+    }
+
+    fn diff_method(&mut self, base: &MethodDef, head: &MethodDef) {
+        let pairs = [
+            ("request", &base.request, &head.request),
+            ("response", &base.response, &head.response),
+        ];
+        for (label, base_ty, head_ty) in pairs {
+            let (base_ty, head_ty) = (reference_string(base_ty), reference_string(head_ty));
+            if base_ty != head_ty {
+                let message = format!("{label} changed from {base_ty} to {head_ty}");
+                self.changes.push(
+                    Change::new(self.spec, "type-changed", true, message).with_method(&base.name),
+                );
+            }
+        }
+        if base.desc != head.desc {
+            self.changes.push(
+                Change::new(self.spec, "desc-changed", false, "desc changed")
+                    .with_method(&base.name),
+            );
+        }
+    }
+}
+
+/// items with the same name in base and head, in base order, then items only in head
+fn match_by_name<'b, T>(
+    base: &'b [T],
+    head: &'b [T],
+    name_of: impl Fn(&T) -> &String,
+) -> Vec<(String, Option<&'b T>, Option<&'b T>)> {
+    let find = |items: &'b [T], name: &str| items.iter().find(|item| name_of(item) == name);
+    let mut seen = BTreeSet::new();
+    base.iter()
+        .chain(head.iter())
+        .map(&name_of)
+        .filter(|name| seen.insert(name.as_str()))
+        .map(|name| (name.clone(), find(base, name), find(head, name)))
+        .collect()
+}
+
+fn model_kind(model_type: &ModelType) -> &'static str {
+    match model_type {
+        ModelType::Enum { .. } => "enum",
+        ModelType::Struct(_) => "struct",
+        ModelType::Virtual(_) => "virtual",
+        ModelType::NewType { .. } => "new_type",
+        ModelType::Const { .. } => "const",
+    }
+}
+
+fn payload_string(variant: &VariantDef) -> String {
+    match (&variant.payload_type, &variant.payload_fields) {
+        (Some(ty), _) => type_string(ty),
+        (None, Some(_)) => "fields".to_string(),
+        (None, None) => "none".to_string(),
+    }
+}
+
+fn json_string(value: &impl Serialize) -> String {
+    serde_json::to_string(value).unwrap()
+}
+
+/// type in spec syntax, e.g: `map[string, list[User]]`
+fn type_string(ty: &Type) -> String {
+    match ty {
+        Type::Bool => "bool".into(),
+        Type::I8 => "i8".into(),
+        Type::I16 => "i16".into(),
+        Type::I32 => "i32".into(),
+        Type::I64 => "i64".into(),
+        Type::U8 => "u8".into(),
+        Type::U16 => "u16".into(),
+        Type::U32 => "u32".into(),
+        Type::U64 => "u64".into(),
+        Type::F32 => "f32".into(),
+        Type::F64 => "f64".into(),
+        Type::Decimal => "decimal".into(),
+        Type::BigInt => "bigint".into(),
+        Type::Bytes => "bytes".into(),
+        Type::String => "string".into(),
+        Type::Timestamp {
+            format: TimestampFormat::Rfc3339,
+        } => "timestamp".into(),
+        Type::Timestamp {
+            format: TimestampFormat::EpochMillis,
+        } => "timestamp[epoch_millis]".into(),
+        Type::Date => "date".into(),
+        Type::Duration => "duration".into(),
+        Type::List { item_type } => format!("list[{}]", type_string(item_type)),
+        Type::Map {
+            key_type,
+            value_type,
+        } => format!(
+            "map[{}, {}]",
+            type_string(key_type),
+            type_string(value_type)
+        ),
+        Type::Set { item_type } => format!("set[{}]", type_string(item_type)),
+        Type::Reference(type_ref) => reference_string(type_ref),
+        Type::TypeParam { param } => param.clone(),
+        Type::Json => "json".into(),
+    }
+}
+
+fn reference_string(type_ref: &TypeReference) -> String {
+    let mut s = match &type_ref.namespace {
+        Some(namespace) => format!("{namespace}.{}", type_ref.target),
+        None => type_ref.target.clone(),
+    };
+    if !type_ref.args.is_empty() {
+        let args = type_ref.args.iter().map(type_string).collect::<Vec<_>>();
+        s.push_str(&format!("<{}>", args.join(", ")));
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff() {
+        let base = Context::new_from_folder(&"src/codegen/fixtures/diff/base".into()).unwrap();
+        let head = Context::new_from_folder(&"src/codegen/fixtures/diff/head".into()).unwrap();
+        let changes = diff(&base, &head);
+
+        let report = changes.0.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            report,
+            vec![
+                "breaking: user.yaml User.id: type changed from i64 to string",
+                "breaking: user.yaml User.email: field became required",
+                "non-breaking: user.yaml User.name: desc changed",
+                "breaking: user.yaml User.nick: field removed",
+                "breaking: user.yaml User.age: wire name changed from age to user_age",
+                "non-breaking: user.yaml User.bio: field added",
+                "breaking: user.yaml User.org: required field added",
+                "breaking: user.yaml Event.Created.at: type changed from i64 to timestamp",
+                "breaking: user.yaml Event.Deleted: variant removed",
+                "non-breaking: user.yaml Event.Updated: variant added",
+                "breaking: user.yaml Role.Admin: renamed to Administrator",
+                "breaking: user.yaml Role.Guest: value changed from \"guest\" to \"visitor\"",
+                "non-breaking: user.yaml Role.Owner: value added",
+                "breaking: user.yaml UserId: kind changed from new_type to struct",
+                "breaking: user.yaml Legacy: model removed",
+                "non-breaking: user.yaml Group: model added",
+                "breaking: user.yaml GetUser: response changed from User to Group",
+                "non-breaking: user.yaml ListUsers: method added",
+            ]
+        );
+        assert_eq!(changes.breaking_count(), 12);
+
+        let json = changes.to_json();
+        assert_eq!(json["breaking"], true);
+        assert_eq!(json["changes"][0]["code"], "type-changed");
+        assert_eq!(json["changes"][0]["model"], "User");
+        assert_eq!(json["changes"][0]["member"], "id");
+
+        assert!(diff(&base, &base).0.is_empty());
+    }
+}
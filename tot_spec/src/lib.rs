@@ -2,3 +2,9 @@ pub mod models;
 pub use models::*;
 
 pub mod codegen;
+pub mod diagnostic;
+pub mod diff;
+pub mod source_map;
+
+mod builder;
+pub use builder::Builder;
@@ -1,5 +1,8 @@
+use crate::codegen::style::Case;
+use crate::diagnostic::{Diagnostic, Location};
+use crate::source_map::SourceMap;
 use serde::{Deserialize, Serialize};
-use std::{collections::BTreeMap, path::Path};
+use std::{borrow::Cow, collections::BTreeMap, path::Path};
 
 /// Parse context
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
@@ -15,6 +18,11 @@ pub struct Definition {
     /// method definitions
     /// NOTE: each codegen can decide whether generate code for `methods`
     pub methods: Vec<MethodDef>,
+    /// case convention for wire names of fields and variants, explicit `wire_name` wins
+    pub rename_all: Case,
+    /// locations of items in the spec file
+    #[serde(skip)]
+    pub source_map: SourceMap,
 }
 
 impl Definition {
@@ -35,13 +43,73 @@ impl Definition {
         }
     }
 
-    /// load definition from path
+    /// load definition from path, parse error is returned as located `Diagnostic`
     pub fn load_from_yaml(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
         let content = std::fs::read_to_string(path)?;
-        let def = serde_yaml::from_str::<Definition>(&content)?;
+        let source_map = SourceMap::parse(&content);
+        serde_helper::take_invalid_type_string();
+        let mut def = serde_yaml::from_str::<Definition>(&content).map_err(|e| {
+            let message = e.to_string();
+            let invalid_type_string = serde_helper::take_invalid_type_string();
+            let Some(location) = e.location() else {
+                return Diagnostic::new(path, "parse-error", message);
+            };
+            let mut location = Location {
+                line: location.line(),
+                column: location.column(),
+            };
+            // location is kept in diagnostic, strip it from message
+            let suffix = format!(" at line {} column {}", location.line, location.column);
+            let message = message
+                .strip_suffix(&suffix)
+                .unwrap_or(&message)
+                .to_string();
+            // buffered content loses position of type string, find it after the reported location
+            if let Some(type_location) = invalid_type_string
+                .and_then(|type_string| source_map.find_value(location.line, &type_string))
+            {
+                location = type_location;
+            }
+            Diagnostic::new(path, "parse-error", message)
+                .with_location(location, source_map.line(location.line))
+        })?;
+        def.source_map = source_map;
+        def.resolve_type_params();
         Ok(def)
     }
 
+    /// references to a generic model's type params are parsed as model references,
+    /// turn them into `Type::TypeParam`
+    fn resolve_type_params(&mut self) {
+        for model in self.models.iter_mut() {
+            if model.type_params.is_empty() {
+                continue;
+            }
+            if let ModelType::Struct(struct_def) = &mut model.type_ {
+                for field in struct_def.fields.iter_mut() {
+                    field.type_.0.resolve_type_params(&model.type_params);
+                }
+            }
+        }
+    }
+
+    /// get the name used on the wire for field
+    pub fn field_wire_name<'a>(&self, field: &'a FieldDef) -> Cow<'a, str> {
+        match &field.wire_name {
+            Some(wire_name) => Cow::Borrowed(wire_name.as_str()),
+            None => self.rename_all.rename(&field.name),
+        }
+    }
+
+    /// get the name used on the wire for variant
+    pub fn variant_wire_name<'a>(&self, variant: &'a VariantDef) -> Cow<'a, str> {
+        match &variant.wire_name {
+            Some(wire_name) => Cow::Borrowed(wire_name.as_str()),
+            None => self.rename_all.rename(&variant.name),
+        }
+    }
+
     /// get include for namespace
     pub fn get_include(&self, namespace: &str) -> Option<&Include> {
         self.includes
@@ -86,6 +154,9 @@ pub struct ModelDef {
     pub name: String,
     #[serde(rename = "type")]
     pub type_: ModelType,
+    /// type params of a generic model, e.g. `T` for `Page<T>`
+    #[serde(default)]
+    pub type_params: Vec<String>,
     /// description of this model
     #[serde(default)]
     pub desc: Option<String>,
@@ -102,6 +173,35 @@ impl ModelDef {
     pub fn attribute(&self, name: &str) -> Option<&String> {
         self.attributes.get(name)
     }
+
+    /// types directly used by fields, variants and new type
+    pub fn types(&self) -> Vec<&Type> {
+        self.member_types().into_iter().map(|(_, ty)| ty).collect()
+    }
+
+    /// same as `types`, each type comes with its field or variant name, None for new type
+    pub fn member_types(&self) -> Vec<(Option<&str>, &Type)> {
+        match &self.type_ {
+            ModelType::Struct(st_) | ModelType::Virtual(st_) => st_
+                .fields
+                .iter()
+                .map(|f| (Some(f.name.as_str()), &*f.type_))
+                .collect(),
+            ModelType::Enum { variants, .. } => variants
+                .iter()
+                .flat_map(|v| {
+                    let payload_type = v.payload_type.as_deref();
+                    let payload_fields = v.payload_fields.iter().flatten().map(|f| &*f.type_);
+                    payload_type
+                        .into_iter()
+                        .chain(payload_fields)
+                        .map(|ty| (Some(v.name.as_str()), ty))
+                })
+                .collect(),
+            ModelType::NewType { inner_type } => vec![(None, &***inner_type)],
+            ModelType::Const { .. } => vec![],
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -114,6 +214,8 @@ pub enum ModelType {
         tag_name: Option<String>,
         #[serde(default)]
         payload_name: Option<String>,
+        #[serde(default)]
+        representation: EnumRepresentation,
     },
     #[serde(rename = "struct")]
     Struct(StructDef),
@@ -130,6 +232,24 @@ pub enum ModelType {
     },
 }
 
+/// How enum is represented in json
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EnumRepresentation {
+    /// `{"type": "A", "payload": ...}`, keys are set by `tag_name` and `payload_name`
+    #[default]
+    #[serde(rename = "adjacent")]
+    Adjacent,
+    /// `{"type": "A", ...fields}`, payload should be fields or a struct, key is set by `tag_name`
+    #[serde(rename = "internal")]
+    Internal,
+    /// `{"A": ...}`, variant without payload is `"A"`
+    #[serde(rename = "external")]
+    External,
+    /// payload only, variant without payload is `null`, the first matching variant wins
+    #[serde(rename = "untagged")]
+    Untagged,
+}
+
 impl Default for ModelType {
     fn default() -> Self {
         Self::Struct(StructDef::default())
@@ -183,6 +303,77 @@ pub struct FieldDef {
     #[serde(default)]
     /// whether this field is required
     pub required: bool,
+
+    #[serde(default)]
+    /// constraints on field's value, checked by example validation and generated `validate`
+    pub constraints: Constraints,
+
+    #[serde(default)]
+    /// default value in json, used when the field is missing. field with default
+    /// should not be required
+    pub default: Option<serde_json::Value>,
+
+    #[serde(default)]
+    /// name used as json key, defaults to `name` converted by definition's `rename_all`
+    pub wire_name: Option<String>,
+
+    #[serde(default)]
+    /// how a non required field treats absent and null, unset means absent and null are
+    /// treated the same
+    pub nullability: Option<Nullability>,
+}
+
+/// Nullability of a non required field
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Nullability {
+    /// field may be absent, null is not allowed
+    #[serde(rename = "optional")]
+    Optional,
+    /// field should be present, and may be null
+    #[serde(rename = "nullable")]
+    Nullable,
+    /// field may be absent or null, and absent is different from null
+    #[serde(rename = "optional_nullable")]
+    OptionalNullable,
+}
+
+/// Constraints for a field's value
+///
+/// `min`/`max` apply to numeric types, `min_length`/`max_length`/`non_empty` apply to
+/// string, list and map, `pattern` applies to string.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Constraints {
+    /// inclusive lower bound
+    #[serde(default)]
+    pub min: Option<serde_json::Number>,
+    /// inclusive upper bound
+    #[serde(default)]
+    pub max: Option<serde_json::Number>,
+    /// min length, chars for string and items for list and map
+    #[serde(default)]
+    pub min_length: Option<u64>,
+    /// max length, chars for string and items for list and map
+    #[serde(default)]
+    pub max_length: Option<u64>,
+    /// regex the string should match, not anchored
+    #[serde(default)]
+    pub pattern: Option<String>,
+    /// whether the value should not be empty
+    #[serde(default)]
+    pub non_empty: bool,
+}
+
+impl Constraints {
+    /// whether no constraint is set
+    pub fn is_empty(&self) -> bool {
+        self.min.is_none()
+            && self.max.is_none()
+            && self.min_length.is_none()
+            && self.max_length.is_none()
+            && self.pattern.is_none()
+            && !self.non_empty
+    }
 }
 
 impl FieldDef {
@@ -194,6 +385,10 @@ impl FieldDef {
             type_: type_.into(),
             attributes: Default::default(),
             required: false,
+            constraints: Default::default(),
+            default: None,
+            wire_name: None,
+            nullability: None,
         }
     }
 
@@ -208,6 +403,40 @@ impl FieldDef {
         self
     }
 
+    /// set field's constraints
+    pub fn with_constraints(mut self, constraints: Constraints) -> Self {
+        self.constraints = constraints;
+        self
+    }
+
+    /// set field's default value
+    pub fn with_default(mut self, default: serde_json::Value) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// set field's wire name
+    pub fn with_wire_name(mut self, wire_name: impl Into<String>) -> Self {
+        self.wire_name = Some(wire_name.into());
+        self
+    }
+
+    /// set field's nullability
+    pub fn with_nullability(mut self, nullability: Nullability) -> Self {
+        self.nullability = Some(nullability);
+        self
+    }
+
+    /// whether the field may be absent on the wire
+    pub fn may_be_absent(&self) -> bool {
+        !self.required && self.nullability != Some(Nullability::Nullable)
+    }
+
+    /// whether the field may be null on the wire
+    pub fn may_be_null(&self) -> bool {
+        !self.required && self.default.is_none() && self.nullability != Some(Nullability::Optional)
+    }
+
     pub fn with_attribute(
         mut self,
         attr_name: impl Into<String>,
@@ -253,6 +482,10 @@ pub enum ConstType {
     I16,
     I32,
     I64,
+    U8,
+    U16,
+    U32,
+    U64,
     String,
 }
 
@@ -260,6 +493,9 @@ pub enum ConstType {
 pub struct TypeReference {
     pub namespace: Option<String>,
     pub target: String,
+    /// type args for generic model, e.g. `User` for `Page<User>`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<Type>,
 }
 
 impl TypeReference {
@@ -269,6 +505,7 @@ impl TypeReference {
             Some(TypeReference {
                 namespace: namespace.map(Into::into),
                 target: identifier.to_string(),
+                args: vec![],
             })
         } else {
             None
@@ -290,6 +527,16 @@ pub enum Type {
     I32,
     #[serde(rename = "i64")]
     I64,
+    #[serde(rename = "u8")]
+    U8,
+    #[serde(rename = "u16")]
+    U16,
+    #[serde(rename = "u32")]
+    U32,
+    #[serde(rename = "u64")]
+    U64,
+    #[serde(rename = "f32")]
+    F32,
     #[serde(rename = "f64")]
     F64,
     #[serde(rename = "decimal")]
@@ -300,14 +547,40 @@ pub enum Type {
     Bytes,
     #[serde(rename = "string")]
     String,
+    /// a point in time, the wire format is decided by `format`
+    #[serde(rename = "timestamp")]
+    Timestamp {
+        #[serde(default)]
+        format: TimestampFormat,
+    },
+    /// calendar date, encoded as "YYYY-MM-DD"
+    #[serde(rename = "date")]
+    Date,
+    /// duration, encoded as integer millis
+    #[serde(rename = "duration")]
+    Duration,
     #[serde(rename = "list")]
     List {
         item_type: Box<serde_helper::StringOrStruct<Type>>,
     },
+    /// json object, keys are encoded as string
     #[serde(rename = "map")]
-    Map { value_type: Box<Type> },
+    Map {
+        /// string, integer or string const
+        #[serde(default = "serde_helper::string_type")]
+        key_type: Box<Type>,
+        value_type: Box<Type>,
+    },
+    /// json array with unique items
+    #[serde(rename = "set")]
+    Set {
+        item_type: Box<serde_helper::StringOrStruct<Type>>,
+    },
     #[serde(rename = "ref")]
     Reference(TypeReference),
+    /// type param of the generic model, e.g. `T` in `Page<T>`
+    #[serde(rename = "type_param")]
+    TypeParam { param: String },
     /// json object
     #[serde(rename = "json")]
     Json,
@@ -321,17 +594,138 @@ impl Type {
     }
 
     pub fn map(value_type: Type) -> Self {
+        Self::map_with_key(Type::String, value_type)
+    }
+
+    pub fn map_with_key(key_type: Type, value_type: Type) -> Self {
         Self::Map {
+            key_type: key_type.into(),
             value_type: value_type.into(),
         }
     }
 
+    pub fn set(item_type: Type) -> Self {
+        Self::Set {
+            item_type: Box::new(item_type.into()),
+        }
+    }
+
     pub fn reference(target: impl Into<String>) -> Self {
         Self::Reference(TypeReference {
             namespace: None,
             target: target.into(),
+            args: vec![],
         })
     }
+
+    pub fn timestamp(format: TimestampFormat) -> Self {
+        Self::Timestamp { format }
+    }
+
+    /// whether the type is a fixed size integer
+    pub fn is_integer(&self) -> bool {
+        matches!(
+            self,
+            Type::I8
+                | Type::I16
+                | Type::I32
+                | Type::I64
+                | Type::U8
+                | Type::U16
+                | Type::U32
+                | Type::U64
+        )
+    }
+
+    /// whether the type is a fixed size integer or float
+    pub fn is_numeric(&self) -> bool {
+        self.is_integer() || matches!(self, Type::F32 | Type::F64)
+    }
+
+    /// replace type params with args, params without arg are kept
+    pub fn substitute(&self, params: &[String], args: &[Type]) -> Type {
+        match self {
+            Type::TypeParam { param } => params
+                .iter()
+                .position(|p| p.eq(param))
+                .and_then(|idx| args.get(idx))
+                .cloned()
+                .unwrap_or_else(|| self.clone()),
+            Type::List { item_type } => Type::list(item_type.substitute(params, args)),
+            Type::Map {
+                key_type,
+                value_type,
+            } => Type::map_with_key(
+                key_type.substitute(params, args),
+                value_type.substitute(params, args),
+            ),
+            Type::Set { item_type } => Type::set(item_type.substitute(params, args)),
+            Type::Reference(type_ref) => Type::Reference(TypeReference {
+                namespace: type_ref.namespace.clone(),
+                target: type_ref.target.clone(),
+                args: type_ref
+                    .args
+                    .iter()
+                    .map(|arg| arg.substitute(params, args))
+                    .collect(),
+            }),
+            ty => ty.clone(),
+        }
+    }
+
+    /// turn references to `params` into `Type::TypeParam`
+    fn resolve_type_params(&mut self, params: &[String]) {
+        match self {
+            Type::List { item_type } | Type::Set { item_type } => {
+                item_type.0.resolve_type_params(params)
+            }
+            Type::Map {
+                key_type,
+                value_type,
+            } => {
+                key_type.resolve_type_params(params);
+                value_type.resolve_type_params(params);
+            }
+            Type::Reference(type_ref) => {
+                if type_ref.namespace.is_none()
+                    && type_ref.args.is_empty()
+                    && params.contains(&type_ref.target)
+                {
+                    *self = Type::TypeParam {
+                        param: type_ref.target.clone(),
+                    };
+                } else {
+                    for arg in type_ref.args.iter_mut() {
+                        arg.resolve_type_params(params);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Wire format for timestamp
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TimestampFormat {
+    /// rfc3339 string, e.g: "2023-01-01T00:00:00Z"
+    #[default]
+    #[serde(rename = "rfc3339")]
+    Rfc3339,
+    /// integer millis since unix epoch
+    #[serde(rename = "epoch_millis")]
+    EpochMillis,
+}
+
+impl TimestampFormat {
+    /// try parse from string
+    pub fn try_parse(s: &str) -> Option<Self> {
+        match s.trim() {
+            "rfc3339" => Some(Self::Rfc3339),
+            "epoch_millis" => Some(Self::EpochMillis),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -343,6 +737,9 @@ pub struct VariantDef {
     pub payload_fields: Option<Vec<FieldDef>>,
     #[serde(default)]
     pub desc: Option<String>,
+    /// name used as tag value, defaults to `name` converted by definition's `rename_all`
+    #[serde(default)]
+    pub wire_name: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -368,7 +765,16 @@ mod serde_helper {
     use super::*;
     use anyhow::{anyhow, bail};
     use serde::{de::Visitor, Deserialize, Deserializer};
-    use std::{fmt, marker::PhantomData, ops::Deref};
+    use std::{cell::RefCell, fmt, marker::PhantomData, ops::Deref};
+
+    thread_local! {
+        /// the last type string failed to parse, serde errors only keep the message
+        static INVALID_TYPE_STRING: RefCell<Option<String>> = const { RefCell::new(None) };
+    }
+
+    pub(super) fn take_invalid_type_string() -> Option<String> {
+        INVALID_TYPE_STRING.with(|s| s.borrow_mut().take())
+    }
 
     #[allow(dead_code)]
     #[derive(Debug)]
@@ -391,6 +797,16 @@ mod serde_helper {
             Ok((Type::I32, rest))
         } else if let Some(rest) = s.strip_prefix("i64") {
             Ok((Type::I64, rest))
+        } else if let Some(rest) = s.strip_prefix("u8") {
+            Ok((Type::U8, rest))
+        } else if let Some(rest) = s.strip_prefix("u16") {
+            Ok((Type::U16, rest))
+        } else if let Some(rest) = s.strip_prefix("u32") {
+            Ok((Type::U32, rest))
+        } else if let Some(rest) = s.strip_prefix("u64") {
+            Ok((Type::U64, rest))
+        } else if let Some(rest) = s.strip_prefix("f32") {
+            Ok((Type::F32, rest))
         } else if let Some(rest) = s.strip_prefix("f64") {
             Ok((Type::F64, rest))
         } else if let Some(rest) = s.strip_prefix("string") {
@@ -417,33 +833,71 @@ mod serde_helper {
                 bail!(format!("invalid type: {}", s));
             }
         } else if let Some(rest) = s.strip_prefix("map") {
+            // map[value_type] or map[key_type, value_type]
             if let Some(rest) = rest.trim().strip_prefix("[") {
-                let (value_type, rest) = parse_type(rest)?;
+                let (first_type, rest) = parse_type(rest)?;
+                let (key_type, value_type, rest) = match rest.trim_start().strip_prefix(",") {
+                    Some(rest) => {
+                        let (value_type, rest) = parse_type(rest)?;
+                        (first_type, value_type, rest)
+                    }
+                    None => (Type::String, first_type, rest),
+                };
 
-                if let Some(rest) = rest.strip_prefix("]") {
-                    Ok((
-                        Type::Map {
-                            value_type: Box::new(value_type),
-                        },
-                        rest,
-                    ))
+                if let Some(rest) = rest.trim_start().strip_prefix("]") {
+                    Ok((Type::map_with_key(key_type, value_type), rest))
                 } else {
                     bail!(format!("invalid type: {}", s));
                 }
             } else {
                 bail!(format!("invalid type: {}", s));
             }
+        } else if let Some(rest) = s
+            .strip_prefix("set")
+            .and_then(|rest| rest.trim_start().strip_prefix("["))
+        {
+            let (item_type, rest) = parse_type(rest)?;
+            if let Some(rest) = rest.trim_start().strip_prefix("]") {
+                Ok((Type::set(item_type), rest))
+            } else {
+                bail!(format!("invalid type: {}", s));
+            }
         } else if let Some(rest) = s.strip_prefix("json") {
             Ok((Type::Json, rest))
         } else if let Some(rest) = s.strip_prefix("decimal") {
             Ok((Type::Decimal, rest))
         } else if let Some(rest) = s.strip_prefix("bigint") {
             Ok((Type::BigInt, rest))
+        } else if let Some(rest) = s.strip_prefix("timestamp") {
+            // timestamp or timestamp[format]
+            match rest.trim_start().strip_prefix("[") {
+                Some(format_s) => {
+                    let Some((format_s, rest)) = format_s.split_once(']') else {
+                        bail!("invalid type: {}", s);
+                    };
+                    let Some(format) = TimestampFormat::try_parse(format_s) else {
+                        bail!("invalid timestamp format: {}", format_s);
+                    };
+                    Ok((Type::Timestamp { format }, rest))
+                }
+                None => Ok((
+                    Type::Timestamp {
+                        format: TimestampFormat::default(),
+                    },
+                    rest,
+                )),
+            }
+        } else if let Some(rest) = s.strip_prefix("date") {
+            Ok((Type::Date, rest))
+        } else if let Some(rest) = s.strip_prefix("duration") {
+            Ok((Type::Duration, rest))
         } else if let Some(((namespace, identifier), rest)) = if_identifier(s) {
+            let (args, rest) = parse_type_args(rest)?;
             Ok((
                 Type::Reference(TypeReference {
                     namespace: namespace.map(Into::into),
                     target: identifier.to_string(),
+                    args,
                 }),
                 rest,
             ))
@@ -452,6 +906,28 @@ mod serde_helper {
         }
     }
 
+    /// parse optional type args like `<User, i64>`, and return rest of str
+    fn parse_type_args(s: &str) -> anyhow::Result<(Vec<Type>, &str)> {
+        let Some(mut rest) = s.trim_start().strip_prefix("<") else {
+            return Ok((vec![], s));
+        };
+
+        let mut args = vec![];
+        loop {
+            let (arg, arg_rest) = parse_type(rest)?;
+            args.push(arg);
+
+            let arg_rest = arg_rest.trim_start();
+            if let Some(arg_rest) = arg_rest.strip_prefix(",") {
+                rest = arg_rest;
+            } else if let Some(arg_rest) = arg_rest.strip_prefix(">") {
+                return Ok((args, arg_rest));
+            } else {
+                bail!("invalid type args: {}", s);
+            }
+        }
+    }
+
     pub fn if_identifier(s: &str) -> Option<((Option<&str>, &str), &str)> {
         let s = s.trim();
         let mut index: Option<usize> = None;
@@ -487,6 +963,10 @@ mod serde_helper {
                 ConstType::I16 => "i16",
                 ConstType::I32 => "i32",
                 ConstType::I64 => "i64",
+                ConstType::U8 => "u8",
+                ConstType::U16 => "u16",
+                ConstType::U32 => "u32",
+                ConstType::U64 => "u64",
                 ConstType::String => "string",
             };
 
@@ -505,7 +985,7 @@ mod serde_helper {
                 type Value = ConstType;
 
                 fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                    formatter.write_str("expecting string: i8/i64/string")
+                    formatter.write_str("expecting string: i8/i16/i32/i64/u8/u16/u32/u64/string")
                 }
 
                 fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
@@ -517,6 +997,10 @@ mod serde_helper {
                         "i16" => Ok(ConstType::I16),
                         "i32" => Ok(ConstType::I32),
                         "i64" => Ok(ConstType::I64),
+                        "u8" => Ok(ConstType::U8),
+                        "u16" => Ok(ConstType::U16),
+                        "u32" => Ok(ConstType::U32),
+                        "u64" => Ok(ConstType::U64),
                         "string" => Ok(ConstType::String),
                         _ => Err(E::custom(format!("invalid value: {}", v))),
                     }
@@ -541,16 +1025,18 @@ mod serde_helper {
     impl FromStr for TypeReference {
         fn from_str(s: &str) -> anyhow::Result<Self> {
             if let Some(((namespace, identifier), rest)) = if_identifier(s) {
-                if rest.is_empty() {
+                let (args, rest) = parse_type_args(rest)?;
+                if rest.trim().is_empty() {
                     Ok(TypeReference {
                         namespace: namespace.map(|s| s.to_string()),
                         target: identifier.to_string(),
+                        args,
                     })
                 } else {
-                    Err(anyhow!("s is not identifier"))
+                    Err(anyhow!("invalid type reference: {}", s))
                 }
             } else {
-                Err(anyhow!("s is not identifier"))
+                Err(anyhow!("invalid type reference: {}", s))
             }
         }
     }
@@ -612,7 +1098,12 @@ mod serde_helper {
             where
                 E: serde::de::Error,
             {
-                Ok(StringOrStruct(<T as FromStr>::from_str(value).unwrap()))
+                let parsed = <T as FromStr>::from_str(value).map_err(|e| {
+                    // kept so it can be located in spec
+                    INVALID_TYPE_STRING.with(|s| *s.borrow_mut() = Some(value.to_string()));
+                    E::custom(format!("invalid type string {value:?}: {e}"))
+                })?;
+                Ok(StringOrStruct(parsed))
             }
 
             fn visit_map<M>(self, map: M) -> Result<Self::Value, M::Error>
@@ -691,9 +1182,13 @@ mod serde_helper {
         "json".to_string()
     }
 
+    pub fn string_type() -> Box<Type> {
+        Box::new(Type::String)
+    }
+
     #[cfg(test)]
     mod tests {
-        use super::if_identifier;
+        use super::*;
         #[test]
         fn test_if_identifier() {
             assert_eq!(if_identifier("normal").unwrap(), ((None, "normal"), ""));
@@ -702,5 +1197,110 @@ mod serde_helper {
                 ((Some("namespace"), "normal"), "")
             );
         }
+
+        #[test]
+        fn test_parse_time_types() {
+            assert!(matches!(
+                Type::from_str("timestamp").unwrap(),
+                Type::Timestamp {
+                    format: TimestampFormat::Rfc3339
+                }
+            ));
+            assert!(matches!(
+                Type::from_str("timestamp[epoch_millis]").unwrap(),
+                Type::Timestamp {
+                    format: TimestampFormat::EpochMillis
+                }
+            ));
+            assert!(matches!(Type::from_str("date").unwrap(), Type::Date));
+            assert!(matches!(
+                Type::from_str("list[duration]").unwrap(),
+                Type::List { .. }
+            ));
+            assert!(Type::from_str("timestamp[unknown]").is_err());
+            assert!(Type::from_str("timestamp[epoch_millis").is_err());
+        }
+
+        #[test]
+        fn test_parse_type_args() {
+            let Type::List { item_type } = Type::from_str("list[ns.Page<User, map[i64]>]").unwrap()
+            else {
+                panic!("expect list");
+            };
+            let Type::Reference(type_ref) = &item_type.0 else {
+                panic!("expect reference");
+            };
+            assert_eq!(type_ref.namespace.as_deref(), Some("ns"));
+            assert_eq!(type_ref.target, "Page");
+            assert!(matches!(type_ref.args[0], Type::Reference(_)));
+            assert!(matches!(type_ref.args[1], Type::Map { .. }));
+
+            let type_ref = TypeReference::from_str("Page<User>").unwrap();
+            assert_eq!(type_ref.args.len(), 1);
+
+            assert!(Type::from_str("Page<User").is_err());
+            assert!(Type::from_str("Page<>").is_err());
+        }
+
+        #[test]
+        fn test_parse_map_and_set() {
+            let Type::Map {
+                key_type,
+                value_type,
+            } = Type::from_str("map[i64]").unwrap()
+            else {
+                panic!("expect map");
+            };
+            assert!(matches!(*key_type, Type::String));
+            assert!(matches!(*value_type, Type::I64));
+
+            let Type::Map { key_type, .. } = Type::from_str("map[u32, list[string]]").unwrap()
+            else {
+                panic!("expect map");
+            };
+            assert!(matches!(*key_type, Type::U32));
+
+            assert!(matches!(
+                Type::from_str("set[string]").unwrap(),
+                Type::Set { .. }
+            ));
+            assert!(matches!(
+                Type::from_str("settings").unwrap(),
+                Type::Reference(_)
+            ));
+            assert!(Type::from_str("map[i64, string").is_err());
+        }
+
+        #[test]
+        fn test_parse_unsigned_and_f32() {
+            assert!(matches!(Type::from_str("u8").unwrap(), Type::U8));
+            assert!(matches!(Type::from_str("u16").unwrap(), Type::U16));
+            assert!(matches!(Type::from_str("u32").unwrap(), Type::U32));
+            assert!(matches!(Type::from_str("u64").unwrap(), Type::U64));
+            assert!(matches!(Type::from_str("f32").unwrap(), Type::F32));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_error_location() {
+        let err = Definition::load_from_yaml("src/codegen/fixtures/parse_error/bad_type.yaml")
+            .unwrap_err()
+            .downcast::<Diagnostic>()
+            .unwrap();
+        assert_eq!(
+            err.location,
+            Some(Location {
+                line: 9,
+                column: 17
+            })
+        );
+        assert_eq!(err.snippet.as_deref(), Some("          type: list[string"));
+        assert!(err.message.contains(r#"invalid type string "list[string""#));
+        assert!(!err.message.contains(" at line "));
     }
 }
@@ -0,0 +1,346 @@
+use crate::diagnostic::Location;
+use std::collections::HashMap;
+
+/// Locations of includes, models, methods and model members in spec yaml.
+/// Only block style yaml is tracked, items written in flow style are not located.
+#[derive(Default, Debug, Clone)]
+pub struct SourceMap {
+    lines: Vec<String>,
+    includes: HashMap<String, Location>,
+    models: HashMap<String, ItemSpan>,
+    methods: HashMap<String, Location>,
+}
+
+#[derive(Default, Debug, Clone)]
+struct ItemSpan {
+    location: Location,
+    type_location: Option<Location>,
+    /// fields, variants or const values
+    members: HashMap<String, ItemSpan>,
+}
+
+impl SourceMap {
+    pub fn parse(content: &str) -> Self {
+        let lines = content
+            .lines()
+            .enumerate()
+            .filter_map(|(idx, text)| Line::parse(idx + 1, text))
+            .collect::<Vec<_>>();
+
+        let mut source_map = Self {
+            lines: content.lines().map(Into::into).collect(),
+            ..Default::default()
+        };
+
+        for (idx, line) in lines.iter().enumerate() {
+            if line.indent != 0 || line.dash {
+                continue;
+            }
+            match line.key {
+                Some("includes") => {
+                    for item in list_items(&lines, idx) {
+                        if let Some((namespace, location)) = item_key(item, "namespace") {
+                            source_map.includes.insert(namespace, location);
+                        }
+                    }
+                }
+                Some("models") => {
+                    for item in list_items(&lines, idx) {
+                        if let Some((name, span)) = item_span(item) {
+                            source_map.models.insert(name, span);
+                        }
+                    }
+                }
+                Some("methods") => {
+                    for item in list_items(&lines, idx) {
+                        if let Some((name, location)) = item_key(item, "name") {
+                            source_map.methods.insert(name, location);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        source_map
+    }
+
+    /// location of the include with namespace
+    pub fn include(&self, namespace: &str) -> Option<Location> {
+        self.includes.get(namespace).copied()
+    }
+
+    /// location of the model's name
+    pub fn model(&self, model: &str) -> Option<Location> {
+        self.models.get(model).map(|m| m.location)
+    }
+
+    /// location of the method's name
+    pub fn method(&self, method: &str) -> Option<Location> {
+        self.methods.get(method).copied()
+    }
+
+    /// location of the field, variant or const value's name
+    pub fn member(&self, model: &str, member: &str) -> Option<Location> {
+        self.models
+            .get(model)?
+            .members
+            .get(member)
+            .map(|m| m.location)
+    }
+
+    /// location of the type string of a field or variant, new type's inner type if member is None
+    pub fn member_type(&self, model: &str, member: Option<&str>) -> Option<Location> {
+        let model = self.models.get(model)?;
+        match member {
+            Some(member) => model.members.get(member)?.type_location,
+            None => model.type_location,
+        }
+    }
+
+    /// location of the first value equal to value, at or after line
+    pub fn find_value(&self, line: usize, value: &str) -> Option<Location> {
+        self.lines
+            .iter()
+            .enumerate()
+            .skip(line.saturating_sub(1))
+            .filter_map(|(idx, text)| Line::parse(idx + 1, text))
+            .find(|l| l.key.is_some() && unquote(l.value) == value)
+            .map(|l| l.value_location())
+    }
+
+    /// text of the line, line starts from 1
+    pub fn line(&self, line: usize) -> Option<&str> {
+        self.lines.get(line.checked_sub(1)?).map(|l| l.as_str())
+    }
+}
+
+/// a non empty, non comment line
+struct Line<'a> {
+    line: usize,
+    indent: usize,
+    /// line starts a list item
+    dash: bool,
+    /// indent of the content after dash
+    content_indent: usize,
+    key: Option<&'a str>,
+    key_column: usize,
+    value: &'a str,
+    value_column: usize,
+}
+
+impl<'a> Line<'a> {
+    fn parse(line: usize, text: &'a str) -> Option<Self> {
+        let content = text.trim_start_matches(' ');
+        if content.is_empty() || content.starts_with('#') {
+            return None;
+        }
+        let indent = text.len() - content.len();
+
+        let (dash, content_indent, content) = match content.strip_prefix('-') {
+            Some(rest) if rest.is_empty() || rest.starts_with(' ') => {
+                let trimmed = rest.trim_start_matches(' ');
+                (true, indent + 1 + rest.len() - trimmed.len(), trimmed)
+            }
+            _ => (false, indent, content),
+        };
+
+        let key_len = content
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(content.len());
+        let rest = &content[key_len..];
+        let is_key = key_len > 0 && (rest == ":" || rest.starts_with(": "));
+        if !is_key {
+            return Some(Self {
+                line,
+                indent,
+                dash,
+                content_indent,
+                key: None,
+                key_column: content_indent + 1,
+                value: content,
+                value_column: content_indent + 1,
+            });
+        }
+
+        let value = rest[1..].trim_start_matches(' ');
+        let value_column = content_indent + key_len + (rest.len() - value.len()) + 1;
+        Some(Self {
+            line,
+            indent,
+            dash,
+            content_indent,
+            key: Some(&content[..key_len]),
+            key_column: content_indent + 1,
+            value: value.trim_end(),
+            value_column,
+        })
+    }
+
+    fn key_location(&self) -> Location {
+        Location {
+            line: self.line,
+            column: self.key_column,
+        }
+    }
+
+    fn value_location(&self) -> Location {
+        Location {
+            line: self.line,
+            column: self.value_column,
+        }
+    }
+}
+
+/// items of the list under the key at key_idx, each item is a slice starts with the dash line
+fn list_items<'a, 'b>(lines: &'b [Line<'a>], key_idx: usize) -> Vec<&'b [Line<'a>]> {
+    let key_line = &lines[key_idx];
+    let mut items = vec![];
+    let Some(first) = lines.get(key_idx + 1) else {
+        return items;
+    };
+    // block sequence can be at the same indent as its key
+    if !first.dash || first.indent < key_line.content_indent {
+        return items;
+    }
+
+    let item_indent = first.indent;
+    let mut start = key_idx + 1;
+    for idx in key_idx + 2..=lines.len() {
+        let line = lines.get(idx);
+        let is_end = match line {
+            Some(line) => line.indent <= item_indent,
+            None => true,
+        };
+        if !is_end {
+            continue;
+        }
+        items.push(&lines[start..idx]);
+        match line {
+            Some(line) if line.indent == item_indent && line.dash => start = idx,
+            _ => break,
+        }
+    }
+    items
+}
+
+/// keys of a list item, which are the dash line's key and lines at the same content indent
+fn item_keys<'a, 'b>(item: &'b [Line<'a>]) -> impl Iterator<Item = (usize, &'b Line<'a>)> {
+    let indent = item.first().map(|l| l.content_indent).unwrap_or_default();
+    item.iter()
+        .enumerate()
+        .filter(move |(idx, line)| *idx == 0 || (line.indent == indent && !line.dash))
+        .filter(|(_, line)| line.key.is_some())
+}
+
+/// value and location of the key in item
+fn item_key(item: &[Line], key: &str) -> Option<(String, Location)> {
+    let (_, line) = item_keys(item).find(|(_, line)| line.key == Some(key))?;
+    Some((unquote(line.value), line.key_location()))
+}
+
+/// span of a model or member, members are items in fields, variants and values
+fn item_span(item: &[Line]) -> Option<(String, ItemSpan)> {
+    let (name, location) = item_key(item, "name")?;
+    let mut span = ItemSpan {
+        location,
+        ..Default::default()
+    };
+
+    for (idx, line) in item_keys(item) {
+        match line.key {
+            Some("type" | "payload_type" | "inner_type") if !line.value.is_empty() => {
+                span.type_location = Some(line.value_location());
+            }
+            Some("type") => {
+                // model's type is a map, members and new type's inner type are inside
+                let type_lines = &item[idx + 1..];
+                if type_lines
+                    .first()
+                    .map_or(false, |l| l.indent <= line.indent)
+                {
+                    continue;
+                }
+                for (type_idx, type_line) in item_keys(type_lines) {
+                    let type_idx = idx + 1 + type_idx;
+                    match type_line.key {
+                        Some("fields" | "variants" | "values") => {
+                            for member in list_items(item, type_idx) {
+                                if let Some((name, member_span)) = item_span(member) {
+                                    span.members.insert(name, member_span);
+                                }
+                            }
+                        }
+                        Some("inner_type") if !type_line.value.is_empty() => {
+                            span.type_location = Some(type_line.value_location());
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    Some((name, span))
+}
+
+fn unquote(value: &str) -> String {
+    let value = value.trim();
+    for quote in ['"', '\''] {
+        if let Some(inner) = value
+            .strip_prefix(quote)
+            .and_then(|v| v.strip_suffix(quote))
+        {
+            return inner.to_string();
+        }
+    }
+    value.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source_map() {
+        let source_map = SourceMap::parse(
+            r#"includes:
+  - path: base.yaml
+    namespace: base
+
+models:
+  # comment
+  - name: User
+    type:
+      name: struct
+      fields:
+        - name: id
+          type: i64
+        - type: list[Foo
+          name: "tags"
+
+  - name: Id
+    type:
+      name: new_type
+      inner_type: string
+
+methods:
+  - name: GetUser
+    request: User
+    response: User
+"#,
+        );
+
+        let at = |line, column| Some(Location { line, column });
+        assert_eq!(source_map.include("base"), at(3, 5));
+        assert_eq!(source_map.model("User"), at(7, 5));
+        assert_eq!(source_map.member("User", "id"), at(11, 11));
+        assert_eq!(source_map.member_type("User", Some("id")), at(12, 17));
+        assert_eq!(source_map.member("User", "tags"), at(14, 11));
+        assert_eq!(source_map.member_type("User", Some("tags")), at(13, 17));
+        assert_eq!(source_map.member_type("Id", None), at(19, 19));
+        assert_eq!(source_map.method("GetUser"), at(22, 5));
+        assert_eq!(source_map.line(12), Some("          type: i64"));
+        assert_eq!(source_map.find_value(7, "list[Foo"), at(13, 17));
+    }
+}
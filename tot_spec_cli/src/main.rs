@@ -1,15 +1,37 @@
-use clap::Parser;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use notify::{EventKind, RecursiveMode, Watcher};
 use path_absolutize::Absolutize;
-use std::path::PathBuf;
-use tot_spec::codegen::swagger::Swagger;
-use tot_spec::codegen::{
-    java_jackson::JavaJackson, py_dataclass::PyDataclass, rs_serde::RsSerde,
-    swift_codable::SwiftCodable, typescript::TypeScript, Codegen,
-};
+use similar::TextDiff;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::Receiver;
+use std::time::Duration;
+use tot_spec::codegen::output::{FileTree, OverwritePolicy, Writer};
+use tot_spec::codegen::{load_codegen, Context, Target};
+use tot_spec::diagnostic::{Diagnostic, Diagnostics};
 
 #[derive(Parser, Debug)]
-#[command(author, version, about, long_about = None)]
-struct Args {
+#[command(author, version, about, long_about = None, args_conflicts_with_subcommands = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// generate args without subcommand, kept for compatibility, use `generate` instead
+    #[command(flatten)]
+    generate: GenerateArgs,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// generate code for all specs in input folder
+    Generate(GenerateArgs),
+    /// load and validate all specs in input folder without generating code
+    Validate(InputArgs),
+    /// compare specs in base and head, fail if any change is breaking
+    Diff(DiffArgs),
+}
+
+#[derive(Args, Debug)]
+struct InputArgs {
     #[arg(
         short,
         long,
@@ -23,42 +45,377 @@ struct Args {
     )]
     spec_folder: Option<PathBuf>,
 
-    #[arg(short, long, default_value = "rs_serde")]
-    codegen: String,
+    #[arg(
+        long,
+        value_enum,
+        default_value = "text",
+        help = "format of spec problems, json and sarif are printed to stdout"
+    )]
+    diagnostics_format: DiagnosticsFormat,
+}
+
+impl InputArgs {
+    fn input(&self) -> anyhow::Result<PathBuf> {
+        self.input
+            .clone()
+            .or_else(|| self.spec_folder.clone())
+            .ok_or_else(|| anyhow::anyhow!("--input is required"))
+    }
+}
+
+#[derive(Args, Debug)]
+struct GenerateArgs {
+    #[command(flatten)]
+    input: InputArgs,
+
+    #[arg(
+        short,
+        long,
+        help = "codegen name, default rs_serde. without codegen and output, all targets in spec_config.yaml are generated"
+    )]
+    codegen: Option<String>,
 
     #[arg(
         short,
         long,
-        default_value = "examples/spec/",
-        help = "output path, if input is folder, then output must be folder"
+        help = "output path, if input is folder, then output must be folder, default examples/spec/"
+    )]
+    output: Option<PathBuf>,
+
+    #[arg(
+        long,
+        help = "compare generated code with output folder without writing, print a diff for each out of date file and fail"
     )]
-    output: PathBuf,
+    check: bool,
+
+    #[arg(
+        long,
+        conflicts_with = "check",
+        help = "delete generated files which no longer correspond to any spec, files not generated or edited after generation are kept"
+    )]
+    prune: bool,
+
+    #[arg(
+        long,
+        conflicts_with = "check",
+        help = "watch input folder and regenerate on spec changes, problems are printed and watching continues"
+    )]
+    watch: bool,
+}
+
+#[derive(Args, Debug)]
+struct DiffArgs {
+    #[arg(
+        long,
+        help = "old specs, a folder, or a git ref to compare with head folder at that ref"
+    )]
+    base: String,
+
+    #[arg(long, help = "root folder of new specs")]
+    head: PathBuf,
+
+    #[arg(
+        long,
+        value_enum,
+        default_value = "text",
+        help = "format of the report printed to stdout"
+    )]
+    format: ReportFormat,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum ReportFormat {
+    Text,
+    Json,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum DiagnosticsFormat {
+    Text,
+    Json,
+    Sarif,
 }
 
 fn main() -> anyhow::Result<()> {
-    let args = Args::parse();
-
-    let input = args.input.or(args.spec_folder).unwrap();
-
-    let codegen: Box<dyn Codegen> = match args.codegen.as_str() {
-        "rs_serde" => Box::new(RsSerde::load_from_folder(&input)?),
-        "java_jackson" => Box::new(JavaJackson::load_from_folder(&input)?),
-        "swift_codable" => Box::new(SwiftCodable::load_from_folder(&input)?),
-        "py_dataclass" => Box::new(PyDataclass::load_from_folder(&input)?),
-        "swagger" => Box::new(Swagger::load_from_folder(&input)?),
-        "typescript" => Box::new(TypeScript::load_from_folder(&input)?),
-        _ => anyhow::bail!("unknown codegen name"),
+    let cli = Cli::parse();
+    match cli.command {
+        Some(Command::Generate(args)) => with_diagnostics(&args.input, || generate(&args)),
+        Some(Command::Validate(args)) => with_diagnostics(&args, || validate(&args)),
+        Some(Command::Diff(args)) => diff(&args),
+        None => with_diagnostics(&cli.generate.input, || generate(&cli.generate)),
+    }
+}
+
+/// run f, problems in specs are printed in machine readable format if requested,
+/// other errors are returned as is
+fn with_diagnostics(
+    args: &InputArgs,
+    f: impl FnOnce() -> anyhow::Result<()>,
+) -> anyhow::Result<()> {
+    let format = args.diagnostics_format;
+    if format == DiagnosticsFormat::Text {
+        return f();
+    }
+
+    let (result, diagnostics) = match f() {
+        Ok(_) => (Ok(()), Diagnostics::default()),
+        // parse error of a single spec is a bare diagnostic
+        Err(e) => match e
+            .downcast::<Diagnostics>()
+            .or_else(|e| e.downcast::<Diagnostic>().map(Diagnostics::from))
+        {
+            Ok(diagnostics) => (Err(anyhow::anyhow!("validation failed")), diagnostics),
+            Err(e) => return Err(e),
+        },
+    };
+    let output = match format {
+        DiagnosticsFormat::Sarif => diagnostics.to_sarif(),
+        _ => diagnostics.to_json(),
     };
+    println!("{}", serde_json::to_string_pretty(&output)?);
+    result
+}
+
+fn generate(args: &GenerateArgs) -> anyhow::Result<()> {
+    let input = args.input.input()?;
+    if args.watch {
+        return watch(args, &input);
+    }
 
-    let output = absolute(&args.output);
+    // specs are loaded once and shared by all targets
+    let context = Context::new_from_folder(&input)?;
+    generate_targets(args, &context, OverwritePolicy::Always)
+}
+
+/// generate with -c and -o, or all targets in spec_config.yaml if both are missing
+fn generate_targets(
+    args: &GenerateArgs,
+    context: &Context,
+    overwrite: OverwritePolicy,
+) -> anyhow::Result<()> {
+    let use_config_targets =
+        args.codegen.is_none() && args.output.is_none() && !context.targets().is_empty();
+    let targets = if use_config_targets {
+        context.targets().to_vec()
+    } else {
+        vec![Target {
+            codegen: args.codegen.clone().unwrap_or("rs_serde".into()),
+            output: absolute(args.output.as_ref().unwrap_or(&"examples/spec/".into())),
+        }]
+    };
 
-    // create output folder
-    std::fs::create_dir_all(&output)?;
+    let mut file_count = 0;
+    let mut stale_count = 0;
+    for target in targets.iter() {
+        if use_config_targets {
+            eprintln!("target {} output {:?}", target.codegen, target.output);
+        }
+        let codegen = load_codegen(&target.codegen, context)?;
 
-    codegen.generate_for_folder(&input, &output)?;
+        let tree = codegen.generate()?;
+        for diagnostic in tree.diagnostics.iter() {
+            eprintln!("{:?}: {diagnostic}", diagnostic.severity);
+        }
+
+        // generated files are recorded in a manifest per codegen, so prune is safe
+        let writer = Writer::new(&target.output)
+            .overwrite(overwrite)
+            .manifest(&target.codegen)
+            .prune(args.prune);
+        if args.check {
+            file_count += tree.len();
+            stale_count += print_changes(&writer, &tree);
+            continue;
+        }
+
+        // create output folder
+        std::fs::create_dir_all(writer.output())?;
+
+        writer.write(&tree)?;
+    }
+
+    if args.check {
+        if stale_count > 0 {
+            anyhow::bail!("{stale_count} generated files are out of date");
+        }
+        eprintln!("{file_count} generated files are up to date");
+    }
     Ok(())
 }
 
+/// generate, then reload changed specs and regenerate on each change.
+/// outputs are only written when changed, problems are printed and watching continues
+fn watch(args: &GenerateArgs, input: &Path) -> anyhow::Result<()> {
+    let (tx, rx) = std::sync::mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx)?;
+    watcher.watch(input, RecursiveMode::Recursive)?;
+
+    let mut context: Option<Context> = None;
+    // changes are kept until reloaded successfully
+    let mut changed = vec![];
+    loop {
+        let result = match &context {
+            Some(context) => context.reload(&changed),
+            None => Context::new_from_folder(&input.to_path_buf()),
+        };
+        let result = result.and_then(|new_context| {
+            generate_targets(args, &new_context, OverwritePolicy::IfChanged)?;
+            Ok(new_context)
+        });
+        match result {
+            Ok(new_context) => {
+                context = Some(new_context);
+                changed.clear();
+            }
+            Err(e) => eprintln!("{e}"),
+        }
+
+        eprintln!("watching {input:?} for changes");
+        changed.extend(wait_for_changes(&rx)?);
+    }
+}
+
+/// block until specs change, events in a short window are returned together
+fn wait_for_changes(rx: &Receiver<notify::Result<notify::Event>>) -> anyhow::Result<Vec<PathBuf>> {
+    let mut changed = vec![];
+    loop {
+        let mut event = Some(rx.recv()?);
+        while let Some(result) = event {
+            match result {
+                Ok(event) if !matches!(event.kind, EventKind::Access(_)) => changed.extend(
+                    event
+                        .paths
+                        .into_iter()
+                        .filter(|p| p.extension().map(|ext| ext == "yaml").unwrap_or_default()),
+                ),
+                Ok(_) => {}
+                Err(e) => eprintln!("watch error: {e}"),
+            }
+            event = rx.recv_timeout(Duration::from_millis(100)).ok();
+        }
+
+        if !changed.is_empty() {
+            return Ok(changed);
+        }
+    }
+}
+
+/// print unified diff for each file differs from output folder, paths in diff are relative to output,
+/// returns number of changed files
+fn print_changes(writer: &Writer, tree: &FileTree) -> usize {
+    let changes = writer.changes(tree);
+    for change in changes.iter() {
+        // files outside output, e.g: protobuf lockfile, are shown by their absolute path
+        let header = |prefix: &str| match change.path.is_absolute() {
+            true => change.path.display().to_string(),
+            false => format!("{prefix}/{}", change.path.display()),
+        };
+        let old_header = match change.old {
+            Some(_) => header("a"),
+            None => "/dev/null".to_string(),
+        };
+        let new_header = header("b");
+
+        print!(
+            "{}",
+            TextDiff::from_lines(change.old.as_deref().unwrap_or_default(), &change.new)
+                .unified_diff()
+                .header(&old_header, &new_header)
+        );
+    }
+
+    changes.len()
+}
+
+fn validate(args: &InputArgs) -> anyhow::Result<()> {
+    let input = args.input()?;
+    if !input.is_dir() {
+        anyhow::bail!("input {input:?} is not a folder");
+    }
+    let spec_count = tot_spec::codegen::validate_folder(&input)?;
+    eprintln!("{spec_count} specs validated");
+    Ok(())
+}
+
+fn diff(args: &DiffArgs) -> anyhow::Result<()> {
+    if !args.head.is_dir() {
+        anyhow::bail!("head {:?} is not a folder", args.head);
+    }
+    let head = Context::new_from_folder(&args.head)?;
+
+    let base_folder = PathBuf::from(&args.base);
+    let base = if base_folder.is_dir() {
+        Context::new_from_folder(&base_folder)?
+    } else {
+        let exported = export_git_ref(&args.base, &args.head)?;
+        let base = Context::new_from_folder(&exported);
+        std::fs::remove_dir_all(&exported)?;
+        base?
+    };
+
+    let changes = tot_spec::diff::diff(&base, &head);
+    match args.format {
+        ReportFormat::Text => println!("{changes}"),
+        ReportFormat::Json => println!("{}", serde_json::to_string_pretty(&changes.to_json())?),
+    }
+
+    let breaking_count = changes.breaking_count();
+    if breaking_count > 0 {
+        anyhow::bail!("{breaking_count} breaking changes found");
+    }
+    Ok(())
+}
+
+/// write files of folder at git ref into a temp folder, returns the temp folder
+fn export_git_ref(git_ref: &str, folder: &Path) -> anyhow::Result<PathBuf> {
+    let git = |args: &[&str]| -> anyhow::Result<Vec<u8>> {
+        let output = std::process::Command::new("git")
+            .arg("-C")
+            .arg(folder)
+            .args(args)
+            .output()?;
+        if !output.status.success() {
+            anyhow::bail!(
+                "git {} failed: {}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        Ok(output.stdout)
+    };
+
+    // paths from ls-tree are relative to repo root, prefix is folder's path in repo
+    let prefix = String::from_utf8(git(&["rev-parse", "--show-prefix"])?)?;
+    let prefix = prefix.trim();
+    let files = String::from_utf8(git(&[
+        "ls-tree",
+        "-r",
+        "--name-only",
+        "--full-name",
+        git_ref,
+        "--",
+        ".",
+    ])?)?;
+    if files.trim().is_empty() {
+        anyhow::bail!("{folder:?} not found at {git_ref}");
+    }
+
+    let exported = std::env::temp_dir().join(format!("tot_spec_diff_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&exported);
+    for path in files.lines() {
+        let Some(relative) = path.strip_prefix(prefix) else {
+            continue;
+        };
+        let content = git(&["show", &format!("{git_ref}:{path}")])?;
+        let target = exported.join(relative);
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(target, content)?;
+    }
+    Ok(exported)
+}
+
 fn absolute(p: &PathBuf) -> PathBuf {
     p.absolutize().unwrap().to_path_buf()
 }
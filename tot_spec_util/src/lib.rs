@@ -1,2 +1,6 @@
 // serde mod for ibig::IBig to seriazlie to/deserialize from string
 pub mod big_int;
+// serde wrappers for timestamp in epoch millis and duration in millis
+pub mod time;
+// tri state wrapper to distinguish absent from null
+pub mod tri_state;
@@ -0,0 +1,149 @@
+use std::ops::Deref;
+
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A wrapper struct which serialize/deserialize a utc timestamp to/from epoch millis
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct EpochMillis(DateTime<Utc>);
+
+impl EpochMillis {
+    /// Create a new EpochMillis from chrono::DateTime
+    pub fn new(value: DateTime<Utc>) -> Self {
+        Self(value)
+    }
+
+    /// Get a reference to inner value
+    pub fn inner(&self) -> &DateTime<Utc> {
+        &self.0
+    }
+}
+
+impl From<DateTime<Utc>> for EpochMillis {
+    fn from(value: DateTime<Utc>) -> Self {
+        Self(value)
+    }
+}
+
+impl Deref for EpochMillis {
+    type Target = DateTime<Utc>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Serialize for EpochMillis {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_i64(self.0.timestamp_millis())
+    }
+}
+
+impl<'de> Deserialize<'de> for EpochMillis {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let millis = i64::deserialize(deserializer)?;
+        match Utc.timestamp_millis_opt(millis) {
+            chrono::LocalResult::Single(value) => Ok(Self(value)),
+            _ => Err(serde::de::Error::custom(format!(
+                "timestamp out of range: {millis}"
+            ))),
+        }
+    }
+}
+
+/// A wrapper struct which serialize/deserialize std::time::Duration to/from millis
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Default)]
+pub struct DurationMillis(std::time::Duration);
+
+impl DurationMillis {
+    /// Create a new DurationMillis from std::time::Duration
+    pub fn new(value: std::time::Duration) -> Self {
+        Self(value)
+    }
+
+    /// Get a reference to inner value
+    pub fn inner(&self) -> &std::time::Duration {
+        &self.0
+    }
+}
+
+impl From<std::time::Duration> for DurationMillis {
+    fn from(value: std::time::Duration) -> Self {
+        Self(value)
+    }
+}
+
+impl Deref for DurationMillis {
+    type Target = std::time::Duration;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Serialize for DurationMillis {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let millis = u64::try_from(self.0.as_millis()).map_err(serde::ser::Error::custom)?;
+        serializer.serialize_u64(millis)
+    }
+}
+
+impl<'de> Deserialize<'de> for DurationMillis {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let millis = u64::deserialize(deserializer)?;
+        Ok(Self(std::time::Duration::from_millis(millis)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_epoch_millis() {
+        #[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+        struct V {
+            value: EpochMillis,
+        }
+
+        let v = V {
+            value: EpochMillis(Utc.timestamp_millis_opt(1_700_000_000_123).unwrap()),
+        };
+
+        let json_str = serde_json::to_string(&v).unwrap();
+        assert_eq!("{\"value\":1700000000123}", json_str);
+        let v_back = serde_json::from_str::<V>(&json_str).unwrap();
+        assert_eq!(v, v_back);
+    }
+
+    #[test]
+    fn test_duration_millis() {
+        #[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+        struct V {
+            value: Option<DurationMillis>,
+        }
+
+        let v = V {
+            value: Some(DurationMillis(std::time::Duration::from_millis(1500))),
+        };
+
+        let json_str = serde_json::to_string(&v).unwrap();
+        assert_eq!("{\"value\":1500}", json_str);
+        let v_back = serde_json::from_str::<V>(&json_str).unwrap();
+        assert_eq!(v, v_back);
+
+        assert!(serde_json::from_str::<V>("{\"value\":-1}").is_err());
+    }
+}
@@ -0,0 +1,91 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A field value which distinguishes absent from explicit null
+///
+/// Use with `#[serde(default, skip_serializing_if = "TriState::is_absent")]`, so a missing
+/// field deserializes to `Absent` and `Absent` is not written back.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Hash, Default)]
+pub enum TriState<T> {
+    /// field is not present
+    #[default]
+    Absent,
+    /// field is present with null
+    Null,
+    /// field is present with value
+    Value(T),
+}
+
+impl<T> TriState<T> {
+    /// Whether the field is absent
+    pub fn is_absent(&self) -> bool {
+        matches!(self, Self::Absent)
+    }
+
+    /// Whether the field is explicit null
+    pub fn is_null(&self) -> bool {
+        matches!(self, Self::Null)
+    }
+
+    /// Get a reference to the value if present
+    pub fn value(&self) -> Option<&T> {
+        match self {
+            Self::Value(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl<T> From<Option<T>> for TriState<T> {
+    fn from(value: Option<T>) -> Self {
+        match value {
+            Some(value) => Self::Value(value),
+            None => Self::Null,
+        }
+    }
+}
+
+impl<T: Serialize> Serialize for TriState<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Value(value) => serializer.serialize_some(value),
+            Self::Absent | Self::Null => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for TriState<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // only called when the field is present, missing field falls back to default
+        Ok(Option::<T>::deserialize(deserializer)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tri_state() {
+        #[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+        struct V {
+            #[serde(default, skip_serializing_if = "TriState::is_absent")]
+            value: TriState<i64>,
+        }
+
+        for (json_str, value) in [
+            ("{}", TriState::Absent),
+            ("{\"value\":null}", TriState::Null),
+            ("{\"value\":1}", TriState::Value(1)),
+        ] {
+            let v = serde_json::from_str::<V>(json_str).unwrap();
+            assert_eq!(v, V { value });
+            assert_eq!(json_str, serde_json::to_string(&v).unwrap());
+        }
+    }
+}